@@ -8,9 +8,210 @@ defmodule SolanaBubblegum do
   """
 
   alias SolanaBubblegum.{Bubblegum, Types}
-  alias Types.MetadataArgs
+  alias Types.{
+    AirdropRequest,
+    ApproveCollectionAuthorityRequest,
+    AssetProof,
+    BurnRequest,
+    BurnWithDasRequest,
+    BulkMintRequest,
+    CancelDelegateRequest,
+    CancelRedeemRequest,
+    CoreBurnRequest,
+    CoreCreateRequest,
+    CoreTransferRequest,
+    CreateCollectionRequest,
+    CreateTreeConfigRequest,
+    DecompressRequest,
+    DelegateRequest,
+    HttpOptions,
+    MetadataArgs,
+    MintBatchToCollectionRequest,
+    MintCompleteRequest,
+    MintRequest,
+    MintToCollectionRequest,
+    OffchainMetadata,
+    RateLimit,
+    RedeemRequest,
+    RetryPolicy,
+    RevokeCollectionAuthorityRequest,
+    SendOptions,
+    SetCollectionSizeRequest,
+    SetDecompressibleStateRequest,
+    SetTreeDelegateRequest,
+    TransactionOptions,
+    TransferBatchRequest,
+    TransferRequest,
+    TransferWithDasRequest,
+    UpdateArgs,
+    UpdateCollectionRequest,
+    UpdateMetadataRequest,
+    UploadRequest,
+    UriCheckOptions,
+    VerifyCollectionRequest,
+    VerifyCreatorRequest
+  }
 
   @default_rpc_url "https://api.devnet.solana.com"
+  @default_confirmation_timeout 30_000
+  @default_finalization_timeout 60_000
+
+  @doc """
+  Opens a reusable connection to a Solana RPC endpoint.
+
+  Passing a URL as `:rpc_url` makes every call set up its own HTTP connection. For high
+  throughput, connect once and pass the connection as `:rpc_url` instead, so TCP and TLS
+  handshakes happen only once. DAS requests made through the connection reuse it as well.
+
+  ## Parameters
+
+  * `rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+  * `options` - Optional keyword list with additional parameters:
+    * `:commitment` - `:processed`, `:confirmed` or `:finalized` commitment for requests
+      (defaults to `:confirmed`)
+    * `:blockhash_refresh_slots` - Cache the latest blockhash and refresh it in the background
+      every this many slots, instead of fetching one for every transaction. A blockhash the
+      cluster reports as expired is dropped from the cache. At most 100 (no caching by default)
+    * `:rate_limit` - Hold RPC and DAS requests to a rate, as a `SolanaBubblegum.Types.RateLimit`
+      or a keyword list such as `[requests_per_second: 10, burst: 20]` (no limit by default)
+    * `:timeout` - Milliseconds before an RPC or DAS request is abandoned (defaults to 30 seconds)
+    * `:proxy` - URL of a proxy every request goes through, e.g. `"http://proxy.internal:3128"`
+      (defaults to none)
+    * `:headers` - Map or list of extra headers sent with every request, e.g.
+      `%{"x-api-key" => key}` (defaults to none)
+
+  ## Returns
+
+  * `{:ok, connection}` - A `SolanaBubblegum.Types.Connection`
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, conn} = SolanaBubblegum.connect("https://api.mainnet-beta.solana.com")
+      {:ok, result} = SolanaBubblegum.mint(payer_keypair_bs58, tree_pubkey, metadata, rpc_url: conn)
+
+  """
+  @spec connect(rpc_url :: String.t(), options :: keyword()) ::
+          {:ok, Types.Connection.t()} | {:error, Types.Error.t()}
+  def connect(rpc_url \\ @default_rpc_url, options \\ []) do
+    case Bubblegum.connect(
+           rpc_url,
+           Keyword.get(options, :commitment),
+           Keyword.get(options, :blockhash_refresh_slots),
+           rate_limit(options),
+           http_options(options)
+         ) do
+      {:ok, %{"connection" => connection}} -> {:ok, connection}
+      {:error, reason} -> {:error, reason}
+    end
+  end
+
+  @doc """
+  Opens a pool of RPC endpoints that can be passed as `:rpc_url` like a single connection.
+
+  Each request goes to a healthy endpoint picked by weight. Rate limits (429), server errors
+  (5xx) and timeouts mark the endpoint unhealthy and the request is retried on the next one.
+  A signed transaction is resent as is, so failing over never sends it twice with different
+  signatures.
+
+  ## Parameters
+
+  * `endpoints` - List of RPC URLs or `{url, weight}` tuples; a bare URL has weight 1
+  * `options` - Optional keyword list with additional parameters:
+    * `:commitment` - `:processed`, `:confirmed` or `:finalized` commitment for requests
+      (defaults to `:confirmed`)
+    * `:health_check_interval` - Milliseconds between `getHealth` checks of every endpoint
+      (no active checks by default)
+    * `:unhealthy_cooldown` - Milliseconds a failing endpoint is skipped (defaults to 30 seconds)
+    * `:rate_limit` - Request rate every endpoint is held to, see `connect/2`
+    * `:timeout`, `:proxy`, `:headers` - HTTP options of every endpoint, see `connect/2`
+
+  ## Returns
+
+  * `{:ok, pool}` - A `SolanaBubblegum.Types.Connection`
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, pool} =
+        SolanaBubblegum.connect_pool([{"https://primary.example.com", 3}, "https://backup.example.com"],
+          health_check_interval: 10_000
+        )
+
+      {:ok, result} = SolanaBubblegum.mint(payer_keypair_bs58, tree_pubkey, metadata, rpc_url: pool)
+
+  """
+  @spec connect_pool(endpoints :: [String.t() | {String.t(), pos_integer()}], options :: keyword()) ::
+          {:ok, Types.Connection.t()} | {:error, Types.Error.t()}
+  def connect_pool(endpoints, options \\ []) do
+    endpoints =
+      Enum.map(endpoints, fn
+        {url, weight} -> {url, weight}
+        url -> {url, 1}
+      end)
+
+    case Bubblegum.connect_pool(
+           endpoints,
+           Keyword.get(options, :commitment),
+           Keyword.get(options, :health_check_interval),
+           Keyword.get(options, :unhealthy_cooldown),
+           rate_limit(options),
+           http_options(options)
+         ) do
+      {:ok, %{"pool" => pool}} -> {:ok, pool}
+      {:error, reason} -> {:error, reason}
+    end
+  end
+
+  @doc """
+  Creates a cache of leaves and proofs that `transfer_with_das/4` consults before the DAS API.
+
+  Each lookup reads the sequence number of the asset's tree, a few bytes, instead of making the
+  two DAS requests. A cached proof is used only while the tree is unchanged, so it is most
+  useful when transferring many assets of a tree that sees no other writes in between.
+
+  ## Parameters
+
+  * `capacity` - Maximum number of assets kept; the least recently used is evicted first
+    (defaults to 1000)
+
+  ## Returns
+
+  * `{:ok, cache}` - A `SolanaBubblegum.Types.ProofCache`
+  * `{:error, reason}` - On a capacity of 0
+
+  ## Examples
+
+      {:ok, cache} = SolanaBubblegum.new_proof_cache()
+      {:ok, _} = SolanaBubblegum.transfer_with_das(payer_keypair_bs58, asset_id, new_owner, proof_cache: cache)
+
+  """
+  @spec new_proof_cache(capacity :: pos_integer()) :: {:ok, Types.ProofCache.t()} | {:error, Types.Error.t()}
+  def new_proof_cache(capacity \\ 1000) do
+    case Bubblegum.new_proof_cache(capacity) do
+      {:ok, %{"proof_cache" => cache}} -> {:ok, cache}
+      {:error, reason} -> {:error, reason}
+    end
+  end
+
+  @doc """
+  Reads how often a proof cache served a proof, how often it went to DAS, and how many assets
+  it holds.
+
+  ## Parameters
+
+  * `cache` - A cache returned by `new_proof_cache/1`
+
+  ## Returns
+
+  * `{:ok, %{hits: integer(), misses: integer(), size: integer(), capacity: integer()}}`
+  """
+  @spec proof_cache_stats(cache :: Types.ProofCache.t()) :: {:ok, map()}
+  def proof_cache_stats(cache) do
+    cache
+    |> Bubblegum.proof_cache_stats()
+    |> normalize_result()
+  end
 
   @doc """
   Creates a new Merkle tree configuration for compressed NFTs.
@@ -20,14 +221,21 @@ defmodule SolanaBubblegum do
   * `payer_keypair_bs58` - Base58 encoded keypair of the payer
   * `max_depth` - Maximum depth of the Merkle tree
   * `max_buffer_size` - Maximum buffer size for the Merkle tree
-  * `canopy_depth` - Canopy depth for the Merkle tree
+  * `canopy_depth` - Canopy depth for the Merkle tree, at most `max_depth`. Each cached level
+    is one less proof node to send with every later transfer, burn or delegate
   * `public` - Whether the tree is public or not
   * `options` - Optional keyword list with additional parameters:
     * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:tree_keypair` - Base58 encoded keypair for the tree account, e.g. to use a vanity or
+      pre-funded address; by default a new one is generated
+    * `:tree_creator_keypair` - Base58 encoded keypair of the tree creator, so one wallet can
+      pay the fees while another owns the tree; defaults to the payer
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
 
   ## Returns
 
-  * `{:ok, %{tree_pubkey: String.t(), signature: String.t()}}` - On success
+  * `{:ok, %{tree_pubkey: String.t(), signature: String.t()}}` - On success; a generated
+    tree keypair is included as `tree_keypair`, so the tree account can be recovered
   * `{:error, reason}` - On failure
 
   ## Examples
@@ -43,27 +251,264 @@ defmodule SolanaBubblegum do
 
   """
   @spec create_tree_config(
-          payer_keypair_bs58 :: String.t(),
+          payer_keypair_bs58 :: Types.keypair(),
           max_depth :: non_neg_integer(),
           max_buffer_size :: non_neg_integer(),
           canopy_depth :: non_neg_integer(),
           public :: boolean(),
           options :: keyword()
-        ) :: {:ok, map()} | {:error, String.t()}
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
   def create_tree_config(payer_keypair_bs58, max_depth, max_buffer_size, canopy_depth, public, options \\ []) do
-    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
-    
-    case Bubblegum.create_tree_config(
-           payer_keypair_bs58,
-           max_depth,
-           max_buffer_size,
-           canopy_depth,
-           public,
-           rpc_url
-         ) do
-      {:error, reason} -> {:error, reason}
-      result -> parse_json_result(result)
-    end
+    %CreateTreeConfigRequest{
+      payer_keypair: payer_keypair_bs58,
+      max_depth: max_depth,
+      max_buffer_size: max_buffer_size,
+      canopy_depth: canopy_depth,
+      public: public,
+      tree_keypair: Keyword.get(options, :tree_keypair),
+      tree_creator_keypair: Keyword.get(options, :tree_creator_keypair)
+    }
+    |> Bubblegum.create_tree_config(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Creates the collection NFT that compressed NFTs are minted into.
+
+  Mints a regular NFT through Token Metadata, with its metadata, master edition and sized
+  collection details, and sends the single token to the update authority. Pass the returned
+  `collection_mint` to `mint_to_collection/5`; the update authority is the collection authority.
+
+  ## Parameters
+
+  * `payer_keypair` - Keypair of the payer
+  * `name` - Name of the collection, at most 32 bytes
+  * `uri` - URI of the collection's off-chain metadata, at most 200 bytes
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:symbol` - Symbol of the collection, at most 10 bytes (defaults to none)
+    * `:seller_fee_basis_points` - Royalties, in basis points (defaults to 0)
+    * `:creators` - List of `SolanaBubblegum.Types.Creator`; only the update authority is
+      verified (defaults to none)
+    * `:is_mutable` - Whether the metadata can be updated later (defaults to `true`)
+    * `:mint_keypair` - Keypair of the collection mint, e.g. for a vanity address (generated
+      by default)
+    * `:update_authority_keypair` - Keypair of the update authority, when it is not the payer
+    * `:token_program_version` - `:token2022` to create the collection mint under Token-2022
+      (defaults to `:original`)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{collection_mint: String.t(), metadata: String.t(), master_edition: String.t(),
+    token_account: String.t(), token_program: String.t(), signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, %{collection_mint: collection}} =
+        SolanaBubblegum.create_collection_nft(payer_keypair, "My Collection", "https://arweave.net/collection.json")
+
+      {:ok, result} = SolanaBubblegum.mint_to_collection(payer_keypair, tree, collection, metadata)
+
+  """
+  @spec create_collection_nft(payer_keypair :: Types.keypair(), name :: String.t(), uri :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def create_collection_nft(payer_keypair, name, uri, options \\ []) do
+    %CreateCollectionRequest{
+      payer_keypair: payer_keypair,
+      name: name,
+      uri: uri,
+      symbol: Keyword.get(options, :symbol, ""),
+      seller_fee_basis_points: Keyword.get(options, :seller_fee_basis_points, 0),
+      creators: Keyword.get(options, :creators, []),
+      is_mutable: Keyword.get(options, :is_mutable, true),
+      mint_keypair: Keyword.get(options, :mint_keypair),
+      update_authority_keypair: Keyword.get(options, :update_authority_keypair),
+      token_program_version: Keyword.get(options, :token_program_version, :original)
+    }
+    |> Bubblegum.create_collection_nft(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Updates the metadata of a collection NFT: its name, symbol, URI, royalties, creators or
+  update authority.
+
+  Only the given fields change; the others keep their current value, which is read from the
+  metadata account first.
+
+  ## Parameters
+
+  * `payer_keypair` - Keypair of the payer, the update authority unless
+    `:update_authority_keypair` is given
+  * `collection_mint` - Mint address of the collection NFT
+  * `options` - Keyword list with the fields to change and additional parameters:
+    * `:name` - New name, at most 32 bytes
+    * `:symbol` - New symbol, at most 10 bytes
+    * `:uri` - New URI of the off-chain metadata, at most 200 bytes
+    * `:seller_fee_basis_points` - New royalties, in basis points
+    * `:creators` - New list of `SolanaBubblegum.Types.Creator`; creators verified before and
+      the update authority are verified
+    * `:primary_sale_happened` - Whether the primary sale happened
+    * `:is_mutable` - `false` to make the metadata immutable; this cannot be undone
+    * `:new_update_authority` - Public key of a new update authority
+    * `:update_authority_keypair` - Keypair of the current update authority, when it is not the payer
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{metadata: String.t(), signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure, with kind `:invalid_argument` when nothing is to be changed
+
+  ## Examples
+
+      {:ok, _result} =
+        SolanaBubblegum.update_collection_metadata(payer_keypair, collection_mint,
+          uri: "https://arweave.net/collection-v2.json",
+          seller_fee_basis_points: 250
+        )
+
+  """
+  @spec update_collection_metadata(payer_keypair :: Types.keypair(), collection_mint :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def update_collection_metadata(payer_keypair, collection_mint, options) do
+    %UpdateCollectionRequest{
+      payer_keypair: payer_keypair,
+      collection_mint: collection_mint,
+      name: Keyword.get(options, :name),
+      symbol: Keyword.get(options, :symbol),
+      uri: Keyword.get(options, :uri),
+      seller_fee_basis_points: Keyword.get(options, :seller_fee_basis_points),
+      creators: Keyword.get(options, :creators),
+      primary_sale_happened: Keyword.get(options, :primary_sale_happened),
+      is_mutable: Keyword.get(options, :is_mutable),
+      new_update_authority: Keyword.get(options, :new_update_authority),
+      update_authority_keypair: Keyword.get(options, :update_authority_keypair)
+    }
+    |> Bubblegum.update_collection_metadata(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Delegates collection authority to another wallet, e.g. a minting service, which can then
+  verify NFTs into the collection without holding the update authority key.
+
+  Pass the returned `collection_authority_record` along with the delegate's keypair as
+  `:collection_authority_record` and `:collection_authority_keypair` of `mint_to_collection/5`.
+
+  ## Parameters
+
+  * `payer_keypair` - Keypair of the payer, the update authority unless
+    `:update_authority_keypair` is given
+  * `collection_mint` - Mint address of the collection NFT
+  * `new_collection_authority` - Public key of the wallet to delegate to
+  * `options` - Optional keyword list with additional parameters:
+    * `:update_authority_keypair` - Keypair of the update authority, when it is not the payer
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{collection_authority_record: String.t(), signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec approve_collection_authority(
+          payer_keypair :: Types.keypair(),
+          collection_mint :: String.t(),
+          new_collection_authority :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def approve_collection_authority(payer_keypair, collection_mint, new_collection_authority, options \\ []) do
+    %ApproveCollectionAuthorityRequest{
+      payer_keypair: payer_keypair,
+      collection_mint: collection_mint,
+      new_collection_authority: new_collection_authority,
+      update_authority_keypair: Keyword.get(options, :update_authority_keypair)
+    }
+    |> Bubblegum.approve_collection_authority(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Revokes a collection authority delegated by `approve_collection_authority/4`.
+
+  ## Parameters
+
+  * `payer_keypair` - Keypair of the payer, who revokes unless `:revoke_authority_keypair` is given
+  * `collection_mint` - Mint address of the collection NFT
+  * `delegate_authority` - Public key of the delegated collection authority
+  * `options` - Optional keyword list with additional parameters:
+    * `:revoke_authority_keypair` - Keypair of the update authority or of the delegate itself,
+      when it is not the payer
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{collection_authority_record: String.t(), signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec revoke_collection_authority(
+          payer_keypair :: Types.keypair(),
+          collection_mint :: String.t(),
+          delegate_authority :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def revoke_collection_authority(payer_keypair, collection_mint, delegate_authority, options \\ []) do
+    %RevokeCollectionAuthorityRequest{
+      payer_keypair: payer_keypair,
+      collection_mint: collection_mint,
+      delegate_authority: delegate_authority,
+      revoke_authority_keypair: Keyword.get(options, :revoke_authority_keypair)
+    }
+    |> Bubblegum.revoke_collection_authority(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Sets the size of an unsized collection, turning it into a sized one.
+
+  Collections created before sized collections existed do not count their members; Bubblegum
+  needs a sized collection to verify compressed NFTs into. Set the size to the number of NFTs
+  already verified into the collection. This can only be done once.
+
+  ## Parameters
+
+  * `payer_keypair` - Keypair of the payer, the collection authority unless
+    `:collection_authority_keypair` is given
+  * `collection_mint` - Mint address of the collection NFT
+  * `size` - Number of NFTs already verified into the collection
+  * `options` - Optional keyword list with additional parameters:
+    * `:collection_authority_keypair` - Keypair of the update authority or a delegated
+      collection authority, when it is not the payer
+    * `:collection_authority_record` - Collection authority record PDA of a delegated
+      collection authority
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec set_collection_size(
+          payer_keypair :: Types.keypair(),
+          collection_mint :: String.t(),
+          size :: non_neg_integer(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def set_collection_size(payer_keypair, collection_mint, size, options \\ []) do
+    %SetCollectionSizeRequest{
+      payer_keypair: payer_keypair,
+      collection_mint: collection_mint,
+      size: size,
+      collection_authority_keypair: Keyword.get(options, :collection_authority_keypair),
+      collection_authority_record: Keyword.get(options, :collection_authority_record)
+    }
+    |> Bubblegum.set_collection_size(transaction_options(options))
+    |> normalize_result()
   end
 
   @doc """
@@ -77,10 +522,23 @@ defmodule SolanaBubblegum do
   * `metadata_args` - Metadata for the NFT
   * `options` - Optional keyword list with additional parameters:
     * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:leaf_owner` - Public key of the wallet that receives the NFT (defaults to the payer)
+    * `:leaf_delegate` - Public key of the delegate of the NFT (defaults to the leaf owner)
+    * `:tree_creator_or_delegate_keypair` - Base58 encoded keypair of the tree creator or
+      delegate, when it is not the payer
+    * `:collection_authority_keypair` - Base58 encoded keypair of the collection authority,
+      when it is not the payer
+    * `:collection_authority_record` - Collection authority record PDA of a delegated
+      collection authority
+    * `:validate_uris` - `true`, or a keyword list or `SolanaBubblegum.Types.UriCheckOptions`
+      with timeouts and concurrency, to fetch the metadata URI and check its JSON before minting
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
 
   ## Returns
 
-  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:ok, %{signature: String.t(), asset_id: String.t() | nil, leaf_index: non_neg_integer() | nil,
+    nonce: non_neg_integer() | nil}}` - On success. The asset ID, leaf index and nonce of the new NFT
+    are read from the events of the transaction once it is confirmed, and are nil when it is not
   * `{:error, reason}` - On failure
 
   ## Examples
@@ -113,89 +571,2878 @@ defmodule SolanaBubblegum do
 
   """
   @spec mint_to_collection(
-          payer_keypair_bs58 :: String.t(),
+          payer_keypair_bs58 :: Types.keypair(),
           tree_pubkey :: String.t(),
           collection_pubkey :: String.t(),
           metadata_args :: MetadataArgs.t(),
           options :: keyword()
-        ) :: {:ok, map()} | {:error, String.t()}
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
   def mint_to_collection(payer_keypair_bs58, tree_pubkey, collection_pubkey, metadata_args, options \\ []) do
-    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
-    
-    case Bubblegum.mint_to_collection_v1(
-           payer_keypair_bs58,
-           tree_pubkey,
-           collection_pubkey,
-           metadata_args,
-           rpc_url
-         ) do
-      {:error, reason} -> {:error, reason}
-      result -> parse_json_result(result)
-    end
+    %MintToCollectionRequest{
+      payer_keypair: payer_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      collection_mint: collection_pubkey,
+      metadata: metadata_args,
+      leaf_owner: Keyword.get(options, :leaf_owner),
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      tree_creator_or_delegate_keypair: Keyword.get(options, :tree_creator_or_delegate_keypair),
+      collection_authority_keypair: Keyword.get(options, :collection_authority_keypair),
+      collection_authority_record: Keyword.get(options, :collection_authority_record),
+      validate_uris: uri_check_options(Keyword.get(options, :validate_uris, false))
+    }
+    |> Bubblegum.mint_to_collection_v1(transaction_options(options))
+    |> normalize_result()
   end
 
   @doc """
-  Transfers a compressed NFT to a new owner.
+  Mints several compressed NFTs to a collection at once.
+
+  The mints are packed, in order, into as few transactions as the transaction size and compute
+  limits allow, and the transactions are sent one after another. A mint whose metadata is invalid
+  or whose transaction fails is reported in its item without stopping the others.
 
   ## Parameters
 
-  * `payer_keypair_bs58` - Base58 encoded keypair of the payer
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who becomes the leaf owner
   * `tree_pubkey` - Public key of the Merkle tree
-  * `leaf_owner` - Public key of the current owner
-  * `new_owner` - Public key of the new owner
-  * `asset_id` - Asset ID of the NFT
-  * `options` - Optional keyword list with additional parameters:
-    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+  * `collection_pubkey` - Public key of the collection
+  * `metadata_list` - Metadata of each NFT
+  * `options` - Same options as `mint_to_collection/5`. An explicit `:compute_unit_limit` caps
+    every transaction of the batch
 
   ## Returns
 
-  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:ok, %{signatures: [String.t()], items: [SolanaBubblegum.Types.BatchMintItem.t()], attempts: non_neg_integer()}}` -
+    When the keypairs and accounts are valid, with one item per metadata, in order
   * `{:error, reason}` - On failure
+  """
+  @spec mint_batch_to_collection(
+          payer_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          collection_pubkey :: String.t(),
+          metadata_list :: [MetadataArgs.t()],
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def mint_batch_to_collection(payer_keypair_bs58, tree_pubkey, collection_pubkey, metadata_list, options \\ []) do
+    %MintBatchToCollectionRequest{
+      payer_keypair: payer_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      collection_mint: collection_pubkey,
+      metadata: metadata_list,
+      tree_creator_or_delegate_keypair: Keyword.get(options, :tree_creator_or_delegate_keypair),
+      collection_authority_keypair: Keyword.get(options, :collection_authority_keypair),
+      collection_authority_record: Keyword.get(options, :collection_authority_record)
+    }
+    |> Bubblegum.mint_batch_to_collection_v1(transaction_options(options))
+    |> normalize_result()
+  end
 
-  ## Examples
+  @doc """
+  Mints thousands of compressed NFTs, e.g. for an airdrop, in the background.
 
-      # Example with valid keypair and addresses
-      iex> {:error, _reason} = SolanaBubblegum.transfer(
-      ...>   "4Xkh4QFN7eX7crQNpbPsKdVmSGCgvwoMQZi3J6QBfvZJM9L5jcUNTZ5cEFcXa9U5L87Csc3KQZqXaBgEn6YmYVhW",
-      ...>   "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
-      ...>   "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
-      ...>   "HXtBm8XZbxaTt41uqaKhwUAa6Z1aPyvJdsZVENiWsetg",
-      ...>   "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
-      ...> )
+  The mints are packed into transactions like `mint_batch_to_collection/5` does, and a pool of
+  workers sends the transactions concurrently. Failed sends are retried and re-signed with a fresh
+  blockhash as the `:retry` policy allows. The calling process receives
+  `{:bulk_mint_progress, ref, done, total, failures}` after every transaction and
+  `{:bubblegum_result, ref, {:ok, %{"signatures" => ..., "items" => ...}}}` once all are sent.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who becomes the leaf owner
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `metadata_list` - Metadata of each NFT
+  * `options` - Optional keyword list with additional parameters:
+    * `:collection_mint` - Collection to mint into; without it the NFTs have no collection
+    * `:workers` - Transactions sent at the same time (defaults to 8)
+    * `:max_transactions_per_second` - Upper bound on the send rate of all workers together
+    * `:tree_creator_or_delegate_keypair`, `:collection_authority_keypair` and
+      `:collection_authority_record` - As for `mint_to_collection/5`
+    * `:validate_uris` - As for `mint_to_collection/5`; every URI is checked before the job
+      starts, and an NFT whose URI fails is reported like one with invalid metadata
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
 
+  ## Returns
+
+  * `{:ok, ref}` - Once the job has started
+  * `{:error, reason}` - When a keypair or account is invalid
   """
-  @spec transfer(
-          payer_keypair_bs58 :: String.t(),
+  @spec bulk_mint(
+          payer_keypair_bs58 :: Types.keypair(),
           tree_pubkey :: String.t(),
-          leaf_owner :: String.t(),
-          new_owner :: String.t(),
-          asset_id :: String.t(),
+          metadata_list :: [MetadataArgs.t()],
           options :: keyword()
-        ) :: {:ok, map()} | {:error, String.t()}
-  def transfer(payer_keypair_bs58, tree_pubkey, leaf_owner, new_owner, asset_id, options \\ []) do
-    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
-    
-    case Bubblegum.transfer(
-           payer_keypair_bs58,
-           tree_pubkey,
-           leaf_owner,
-           new_owner,
-           asset_id,
-           rpc_url
-         ) do
+        ) :: {:ok, reference()} | {:error, Types.Error.t()}
+  def bulk_mint(payer_keypair_bs58, tree_pubkey, metadata_list, options \\ []) do
+    %BulkMintRequest{
+      payer_keypair: payer_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      collection_mint: Keyword.get(options, :collection_mint),
+      metadata: metadata_list,
+      tree_creator_or_delegate_keypair: Keyword.get(options, :tree_creator_or_delegate_keypair),
+      collection_authority_keypair: Keyword.get(options, :collection_authority_keypair),
+      collection_authority_record: Keyword.get(options, :collection_authority_record),
+      workers: Keyword.get(options, :workers, 8),
+      max_transactions_per_second: Keyword.get(options, :max_transactions_per_second),
+      validate_uris: uri_check_options(Keyword.get(options, :validate_uris, false))
+    }
+    |> Bubblegum.bulk_mint(transaction_options(options))
+  end
+
+  @doc """
+  Mints one NFT of the same metadata to each of many recipients in the background, e.g. for
+  an airdrop.
+
+  Runs like `bulk_mint/4`: the mints are packed into transactions, sent by a pool of workers
+  and retried as the `:retry` policy allows, and the calling process receives
+  `{:bulk_mint_progress, ref, done, total, failures}` after every transaction. Once all are
+  sent it receives `{:bubblegum_result, ref, {:ok, %{"signatures" => ..., "items" => ...}}}`
+  with a `SolanaBubblegum.Types.AirdropItem` per recipient, in the order given. Asset IDs are
+  read from the events of each confirmed transaction.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `metadata_args` - Metadata every NFT is minted with
+  * `recipients` - Public keys of the wallets that receive an NFT each; an invalid one fails
+    only its own NFT
+  * `options` - Optional keyword list with the options of `bulk_mint/4`
+
+  ## Returns
+
+  * `{:ok, ref}` - Once the job has started
+  * `{:error, reason}` - When a keypair, account or the metadata is invalid
+  """
+  @spec airdrop_to_owners(
+          payer_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          metadata_args :: MetadataArgs.t(),
+          recipients :: [String.t()],
+          options :: keyword()
+        ) :: {:ok, reference()} | {:error, Types.Error.t()}
+  def airdrop_to_owners(payer_keypair_bs58, tree_pubkey, metadata_args, recipients, options \\ []) do
+    %AirdropRequest{
+      payer_keypair: payer_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      collection_mint: Keyword.get(options, :collection_mint),
+      metadata: metadata_args,
+      recipients: recipients,
+      tree_creator_or_delegate_keypair: Keyword.get(options, :tree_creator_or_delegate_keypair),
+      collection_authority_keypair: Keyword.get(options, :collection_authority_keypair),
+      collection_authority_record: Keyword.get(options, :collection_authority_record),
+      workers: Keyword.get(options, :workers, 8),
+      max_transactions_per_second: Keyword.get(options, :max_transactions_per_second),
+      validate_uris: uri_check_options(Keyword.get(options, :validate_uris, false))
+    }
+    |> Bubblegum.airdrop_to_owners(transaction_options(options))
+  end
+
+  @doc """
+  Loads the metadata of many NFTs from a JSONL or CSV file, e.g. for `bulk_mint/4`.
+
+  The file is read one row at a time rather than at once. Every row is validated like the
+  metadata of a mint; a row that fails is left out and reported with its line, so one bad row
+  does not spoil the file.
+
+  A JSONL file holds one JSON object per line with the fields of
+  `SolanaBubblegum.Types.MetadataArgs`; blank lines are skipped. A CSV file starts with a header
+  row naming the columns, and lists creators in one `creators` column as `address:share` pairs
+  separated by `;`. In both, `name` and `uri` are required; `primary_sale_happened` defaults to
+  `false` and `is_mutable` to `true`.
+
+  ## Parameters
+
+  * `path` - Path of the file
+  * `options` - Optional keyword list with additional parameters:
+    * `:format` - `:jsonl` or `:csv` (defaults to `:csv` for a `.csv` extension, `:jsonl`
+      otherwise)
+
+  ## Returns
+
+  * `{:ok, %{metadata: metadata, lines: lines, errors: errors}}` - The valid metadata in file
+    order, the line of each, and a `SolanaBubblegum.Types.MetadataRowError` per invalid row
+  * `{:error, reason}` - When the file cannot be opened or read
+
+  ## Examples
+
+      {:ok, %{metadata: metadata, errors: []}} = SolanaBubblegum.load_metadata_file("drop.jsonl")
+      {:ok, ref} = SolanaBubblegum.bulk_mint(payer_keypair_bs58, tree_pubkey, metadata)
+
+  """
+  @spec load_metadata_file(path :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def load_metadata_file(path, options \\ []) do
+    path
+    |> Bubblegum.load_metadata_file(Keyword.get(options, :format))
+    |> normalize_result()
+  end
+
+  @doc """
+  Builds the JSON document the `uri` of an NFT's metadata points to.
+
+  The metadata is checked against the Metaplex token standard first: the name and image are
+  required, the name and symbol must fit their on-chain counterparts, URIs must be http(s),
+  `ar://` or `ipfs://`, trait types must be unique and the category one of the standard's.
+  Every violation is reported at once.
+
+  ## Parameters
+
+  * `metadata` - A `SolanaBubblegum.Types.OffchainMetadata`
+
+  ## Returns
+
+  * `{:ok, json}` - The JSON document, ready to upload
+  * `{:error, reason}` - An `:invalid_metadata` error listing the violations
+
+  ## Examples
+
+      {:ok, json} =
+        SolanaBubblegum.build_offchain_metadata(%OffchainMetadata{
+          name: "Drop #1",
+          image: "ar://image-transaction-id",
+          attributes: [%OffchainAttribute{trait_type: "Background", value: "Blue"}],
+          properties: %OffchainProperties{
+            files: [%OffchainFile{uri: "ar://image-transaction-id", mime_type: "image/png"}],
+            category: "image"
+          }
+        })
+
+  """
+  @spec build_offchain_metadata(metadata :: OffchainMetadata.t()) ::
+          {:ok, String.t()} | {:error, Types.Error.t()}
+  def build_offchain_metadata(%OffchainMetadata{} = metadata) do
+    case Bubblegum.build_offchain_metadata(metadata) do
+      {:ok, %{"json" => json}} -> {:ok, json}
       {:error, reason} -> {:error, reason}
-      result -> parse_json_result(result)
     end
   end
 
-  # Helper function to parse JSON results from the NIF
-  defp parse_json_result(json_string) do
-    case Jason.decode(json_string) do
-      {:ok, result} -> {:ok, atomize_keys(result)}
-      {:error, _} -> {:error, "Failed to parse JSON result: #{json_string}"}
+  @doc """
+  Parses existing off-chain JSON metadata and checks it the way `build_offchain_metadata/1`
+  checks new metadata.
+
+  ## Parameters
+
+  * `json` - The JSON document, e.g. as fetched from a metadata URI
+
+  ## Returns
+
+  * `{:ok, metadata}` - A `SolanaBubblegum.Types.OffchainMetadata`
+  * `{:error, reason}` - An `:invalid_metadata` error when the JSON cannot be parsed, lacks a
+    name or image, or breaks the token standard
+  """
+  @spec validate_offchain_metadata(json :: String.t()) ::
+          {:ok, OffchainMetadata.t()} | {:error, Types.Error.t()}
+  def validate_offchain_metadata(json) do
+    case Bubblegum.validate_offchain_metadata(json) do
+      {:ok, %{"metadata" => metadata}} -> {:ok, metadata}
+      {:error, reason} -> {:error, reason}
     end
   end
 
+  @doc """
+  Uploads off-chain metadata to permanent storage and returns the URI to mint it with.
+
+  With the `:irys` provider the payer signs the data as an Arweave data item and an Irys node
+  stores it. When the payer's balance on the node is below the price of the upload, the payer
+  first sends the node the difference, plus a tenth of the price as margin, in SOL.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair that signs the upload and pays for it
+  * `data` - A `SolanaBubblegum.Types.OffchainMetadata`, built with `build_offchain_metadata/1`,
+    or the data to store as a string
+  * `options` - Optional keyword list with additional parameters:
+    * `:content_type` - MIME type of the data (defaults to `"application/json"`)
+    * `:provider` - Storage provider (defaults to `:irys`)
+    * `:upload_node_url` - Node to upload to (defaults to the Irys devnet node, whose uploads
+      expire; use `"https://node1.irys.xyz"` on mainnet)
+    * `:gateway_url` - Gateway the URI points at (defaults to `"https://gateway.irys.xyz"`)
+    * `:fund` - Fund the payer's balance on the node when needed (defaults to `true`)
+    * `:tags` - Extra `{name, value}` tags stored with the data
+    * `:rpc_url` - URL of the Solana RPC endpoint the funding transfer is sent to (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options of the funding transfer, see
+      `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{id: String.t(), uri: String.t(), price: integer(), funding_signature: String.t() | nil}}` -
+    The id and URI of the upload, its price in lamports and the funding transfer, if one was sent
+  * `{:error, reason}` - On failure, e.g. an `:upload_error` when the node refuses the upload
+
+  ## Examples
+
+      {:ok, %{uri: uri}} = SolanaBubblegum.upload_metadata(payer_keypair_bs58, offchain_metadata)
+      {:ok, _} = SolanaBubblegum.mint(payer_keypair_bs58, tree_pubkey, %{metadata | uri: uri})
+
+  """
+  @spec upload_metadata(
+          payer_keypair_bs58 :: Types.keypair(),
+          data :: OffchainMetadata.t() | String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def upload_metadata(payer_keypair_bs58, data, options \\ [])
+
+  def upload_metadata(payer_keypair_bs58, %OffchainMetadata{} = metadata, options) do
+    with {:ok, json} <- build_offchain_metadata(metadata) do
+      upload_metadata(payer_keypair_bs58, json, options)
+    end
+  end
+
+  def upload_metadata(payer_keypair_bs58, data, options) do
+    %UploadRequest{
+      payer_keypair: payer_keypair_bs58,
+      data: data,
+      content_type: Keyword.get(options, :content_type, "application/json"),
+      provider: Keyword.get(options, :provider, :irys),
+      node_url: Keyword.get(options, :upload_node_url, "https://devnet.irys.xyz"),
+      gateway_url: Keyword.get(options, :gateway_url, "https://gateway.irys.xyz"),
+      fund: Keyword.get(options, :fund, true),
+      tags: Keyword.get(options, :tags, [])
+    }
+    |> Bubblegum.upload_metadata(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Mints an NFT from its off-chain metadata in one call: checks the metadata, uploads the JSON
+  like `upload_metadata/3`, builds the on-chain metadata from it and mints the NFT, to a
+  collection when `:collection_mint` is given.
+
+  The on-chain name, symbol and royalty are taken from the off-chain metadata, so the two
+  always agree. Both are checked before the upload, so invalid metadata costs nothing; once
+  uploaded, the JSON stays even when the mint fails.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who pays for the upload and the mint
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `metadata` - A `SolanaBubblegum.Types.OffchainMetadata`
+  * `options` - Optional keyword list with additional parameters:
+    * `:collection_mint` - Collection to mint into; without it the NFT has no collection and
+      the payer must be the tree creator or delegate
+    * `:creators` - Creators of the NFT, whose shares add up to 100 (defaults to none)
+    * `:is_mutable` - Whether the metadata can be updated later (defaults to `true`)
+    * `:leaf_owner`, `:leaf_delegate`, `:tree_creator_or_delegate_keypair`,
+      `:collection_authority_keypair` and `:collection_authority_record` - As for `mint_to_collection/5`
+    * `:provider`, `:upload_node_url`, `:gateway_url` and `:fund` - As for `upload_metadata/3`
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{asset_id: String.t() | nil, uri: String.t(), signature: String.t(), ...}}` - The asset
+    ID of the NFT, read from the events of the confirmed mint, the URI of its metadata and the
+    signature of the mint, along with `:upload_id`, `:upload_price`, `:funding_signature`,
+    `:leaf_index` and `:nonce`
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, %{asset_id: asset_id, uri: uri}} =
+        SolanaBubblegum.mint_complete(payer_keypair_bs58, tree_pubkey, offchain_metadata,
+          collection_mint: collection_mint
+        )
+
+  """
+  @spec mint_complete(
+          payer_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          metadata :: OffchainMetadata.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def mint_complete(payer_keypair_bs58, tree_pubkey, %OffchainMetadata{} = metadata, options \\ []) do
+    %MintCompleteRequest{
+      payer_keypair: payer_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      metadata: metadata,
+      collection_mint: Keyword.get(options, :collection_mint),
+      creators: Keyword.get(options, :creators, []),
+      is_mutable: Keyword.get(options, :is_mutable, true),
+      leaf_owner: Keyword.get(options, :leaf_owner),
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      tree_creator_or_delegate_keypair: Keyword.get(options, :tree_creator_or_delegate_keypair),
+      collection_authority_keypair: Keyword.get(options, :collection_authority_keypair),
+      collection_authority_record: Keyword.get(options, :collection_authority_record),
+      provider: Keyword.get(options, :provider, :irys),
+      node_url: Keyword.get(options, :upload_node_url, "https://devnet.irys.xyz"),
+      gateway_url: Keyword.get(options, :gateway_url, "https://gateway.irys.xyz"),
+      fund: Keyword.get(options, :fund, true)
+    }
+    |> Bubblegum.mint_complete(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Checks that metadata URIs are reachable and hold metadata JSON, without minting anything.
+
+  Each URI gets a HEAD request and a GET of its JSON, which must have a non-empty `name` and
+  `image`. `ar://` and `ipfs://` URIs are fetched through public gateways. Repeated URIs are
+  checked once. Mints check their URIs the same way when given `validate_uris: true`.
+
+  ## Parameters
+
+  * `uris` - Metadata URIs to check
+  * `options` - Optional keyword list with the fields of `SolanaBubblegum.Types.UriCheckOptions`:
+    * `:timeout_ms` - Timeout of each request (defaults to 10 seconds)
+    * `:concurrency` - URIs checked at the same time (defaults to 8)
+
+  ## Returns
+
+  * `{:ok, checks}` - A `SolanaBubblegum.Types.UriCheck` per URI, in the order given
+
+  ## Examples
+
+      {:ok, checks} = SolanaBubblegum.check_metadata_uris(Enum.map(metadata, & &1.uri))
+      for %{uri: uri, error: error} <- checks, error, do: IO.puts("#{uri}: #{error}")
+
+  """
+  @spec check_metadata_uris(uris :: [String.t()], options :: keyword()) ::
+          {:ok, [Types.UriCheck.t()]} | {:error, Types.Error.t()}
+  def check_metadata_uris(uris, options \\ []) do
+    case Bubblegum.check_metadata_uris(uris, struct!(UriCheckOptions, options)) do
+      {:ok, %{"checks" => checks}} -> {:ok, checks}
+      {:error, reason} -> {:error, reason}
+    end
+  end
+
+  @doc """
+  Transfers a compressed NFT to a new owner.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who must be the leaf owner or delegate
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the current owner
+  * `new_owner` - Public key of the new owner
+  * `asset_proof` - Merkle proof data for the leaf (see `SolanaBubblegum.Types.AssetProof`)
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      # Example with valid keypair, addresses and proof
+      iex> asset_proof = %SolanaBubblegum.Types.AssetProof{
+      ...>   root: "11111111111111111111111111111111",
+      ...>   data_hash: "11111111111111111111111111111111",
+      ...>   creator_hash: "11111111111111111111111111111111",
+      ...>   nonce: 0,
+      ...>   index: 0,
+      ...>   proof: []
+      ...> }
+      iex> {:error, _reason} = SolanaBubblegum.transfer(
+      ...>   "4Xkh4QFN7eX7crQNpbPsKdVmSGCgvwoMQZi3J6QBfvZJM9L5jcUNTZ5cEFcXa9U5L87Csc3KQZqXaBgEn6YmYVhW",
+      ...>   "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+      ...>   "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+      ...>   "HXtBm8XZbxaTt41uqaKhwUAa6Z1aPyvJdsZVENiWsetg",
+      ...>   asset_proof
+      ...> )
+
+  """
+  @spec transfer(
+          payer_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          new_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def transfer(payer_keypair_bs58, tree_pubkey, leaf_owner, new_owner, asset_proof, options \\ []) do
+    %TransferRequest{
+      payer_keypair: payer_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      leaf_owner: leaf_owner,
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      new_owner: new_owner,
+      asset_proof: asset_proof
+    }
+    |> Bubblegum.transfer(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Burns a compressed NFT.
+
+  ## Parameters
+
+  * `leaf_owner_keypair_bs58` - Base58 encoded keypair of the leaf owner, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `asset_proof` - Merkle proof data for the leaf (see `SolanaBubblegum.Types.AssetProof`)
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec burn(
+          leaf_owner_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def burn(leaf_owner_keypair_bs58, tree_pubkey, asset_proof, options \\ []) do
+    %BurnRequest{
+      leaf_owner_keypair: leaf_owner_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      asset_proof: asset_proof
+    }
+    |> Bubblegum.burn(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Delegates authority over a compressed NFT to a new delegate, e.g. a marketplace program.
+
+  The owner keeps ownership of the NFT; the delegate gains the right to transfer or burn it.
+
+  ## Parameters
+
+  * `leaf_owner_keypair_bs58` - Base58 encoded keypair of the leaf owner, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `new_delegate` - Public key of the new delegate
+  * `asset_proof` - Merkle proof data for the leaf
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:previous_delegate` - Public key of the current delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec delegate(
+          leaf_owner_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          new_delegate :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def delegate(leaf_owner_keypair_bs58, tree_pubkey, new_delegate, asset_proof, options \\ []) do
+    %DelegateRequest{
+      leaf_owner_keypair: leaf_owner_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      previous_delegate: Keyword.get(options, :previous_delegate),
+      new_delegate: new_delegate,
+      asset_proof: asset_proof
+    }
+    |> Bubblegum.delegate(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Cancels a delegation, returning delegate authority over a compressed NFT to its owner.
+
+  ## Parameters
+
+  * `leaf_owner_keypair_bs58` - Base58 encoded keypair of the leaf owner, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `previous_delegate` - Public key of the current delegate
+  * `asset_proof` - Merkle proof data for the leaf
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec cancel_delegate(
+          leaf_owner_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          previous_delegate :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def cancel_delegate(leaf_owner_keypair_bs58, tree_pubkey, previous_delegate, asset_proof, options \\ []) do
+    %CancelDelegateRequest{
+      leaf_owner_keypair: leaf_owner_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      previous_delegate: previous_delegate,
+      asset_proof: asset_proof
+    }
+    |> Bubblegum.cancel_delegate(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Redeems a compressed NFT, the first step of decompressing it into a regular NFT.
+
+  The leaf is removed from the tree and a voucher account is created for it.
+
+  ## Parameters
+
+  * `leaf_owner_keypair_bs58` - Base58 encoded keypair of the leaf owner, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `asset_proof` - Merkle proof data for the leaf
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{voucher: String.t(), signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec redeem(
+          leaf_owner_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def redeem(leaf_owner_keypair_bs58, tree_pubkey, asset_proof, options \\ []) do
+    %RedeemRequest{
+      leaf_owner_keypair: leaf_owner_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      asset_proof: asset_proof
+    }
+    |> Bubblegum.redeem(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Cancels a redeem, closing the voucher and returning the leaf to the tree.
+
+  ## Parameters
+
+  * `leaf_owner_keypair_bs58` - Base58 encoded keypair of the leaf owner, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `asset_proof` - Merkle proof data for the leaf, against the current tree root
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{voucher: String.t(), signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec cancel_redeem(
+          leaf_owner_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def cancel_redeem(leaf_owner_keypair_bs58, tree_pubkey, asset_proof, options \\ []) do
+    %CancelRedeemRequest{
+      leaf_owner_keypair: leaf_owner_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      asset_proof: asset_proof
+    }
+    |> Bubblegum.cancel_redeem(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Decompresses a redeemed compressed NFT into a regular Token Metadata NFT.
+
+  The leaf must have been redeemed with `redeem/4` first. The mint, metadata, master edition
+  and token account of the new NFT are derived from the tree and leaf nonce. Bubblegum V1 only
+  decompresses into original token program mints, so leaves minted with
+  `token_program_version: :token2022` are rejected with an `:invalid_argument` error.
+
+  ## Parameters
+
+  * `leaf_owner_keypair_bs58` - Base58 encoded keypair of the leaf owner, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `nonce` - Nonce of the redeemed leaf
+  * `metadata_args` - Metadata of the redeemed leaf, exactly as it was minted
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{mint: String.t(), signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec decompress_v1(
+          leaf_owner_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          nonce :: non_neg_integer(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def decompress_v1(leaf_owner_keypair_bs58, tree_pubkey, nonce, metadata_args, options \\ []) do
+    %DecompressRequest{
+      leaf_owner_keypair: leaf_owner_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      nonce: nonce,
+      metadata: metadata_args
+    }
+    |> Bubblegum.decompress_v1(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Mints a new compressed NFT that does not belong to a collection.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who must be the tree creator or delegate
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `metadata_args` - Metadata for the NFT
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:leaf_owner` - Public key of the wallet that receives the NFT (defaults to the payer)
+    * `:leaf_delegate` - Public key of the delegate of the NFT (defaults to the leaf owner)
+    * `:validate_uris` - `true`, or a keyword list or `SolanaBubblegum.Types.UriCheckOptions`
+      with timeouts and concurrency, to fetch the metadata URI and check its JSON before minting
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t(), asset_id: String.t() | nil, leaf_index: non_neg_integer() | nil,
+    nonce: non_neg_integer() | nil}}` - On success. The asset ID, leaf index and nonce of the new NFT
+    are read from the events of the transaction once it is confirmed, and are nil when it is not
+  * `{:error, reason}` - On failure
+  """
+  @spec mint(
+          payer_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def mint(payer_keypair_bs58, tree_pubkey, metadata_args, options \\ []) do
+    %MintRequest{
+      payer_keypair: payer_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      metadata: metadata_args,
+      leaf_owner: Keyword.get(options, :leaf_owner),
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      validate_uris: uri_check_options(Keyword.get(options, :validate_uris, false))
+    }
+    |> Bubblegum.mint_v1(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Verifies a creator of a compressed NFT. The creator must sign the transaction.
+
+  ## Parameters
+
+  * `creator_keypair_bs58` - Base58 encoded keypair of the creator, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec verify_creator(
+          creator_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def verify_creator(creator_keypair_bs58, tree_pubkey, leaf_owner, asset_proof, metadata_args, options \\ []) do
+    %VerifyCreatorRequest{
+      creator_keypair: creator_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      leaf_owner: leaf_owner,
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      asset_proof: asset_proof,
+      metadata: metadata_args
+    }
+    |> Bubblegum.verify_creator(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Unverifies a creator of a compressed NFT. The creator must sign the transaction.
+
+  ## Parameters
+
+  * `creator_keypair_bs58` - Base58 encoded keypair of the creator, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec unverify_creator(
+          creator_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def unverify_creator(creator_keypair_bs58, tree_pubkey, leaf_owner, asset_proof, metadata_args, options \\ []) do
+    %VerifyCreatorRequest{
+      creator_keypair: creator_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      leaf_owner: leaf_owner,
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      asset_proof: asset_proof,
+      metadata: metadata_args
+    }
+    |> Bubblegum.unverify_creator(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Verifies a compressed NFT as a member of its collection.
+
+  The collection set in `metadata_args` must match `collection_mint`.
+
+  ## Parameters
+
+  * `collection_authority_keypair_bs58` - Base58 encoded keypair of the collection authority, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `collection_mint` - Public key of the collection mint
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec verify_collection(
+          collection_authority_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          collection_mint :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def verify_collection(
+        collection_authority_keypair_bs58,
+        tree_pubkey,
+        leaf_owner,
+        collection_mint,
+        asset_proof,
+        metadata_args,
+        options \\ []
+      ) do
+    %VerifyCollectionRequest{
+      collection_authority_keypair: collection_authority_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      leaf_owner: leaf_owner,
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      collection_mint: collection_mint,
+      asset_proof: asset_proof,
+      metadata: metadata_args
+    }
+    |> Bubblegum.verify_collection(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Removes the verified flag from the collection of a compressed NFT.
+
+  ## Parameters
+
+  * `collection_authority_keypair_bs58` - Base58 encoded keypair of the collection authority, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `collection_mint` - Public key of the collection mint
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec unverify_collection(
+          collection_authority_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          collection_mint :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def unverify_collection(
+        collection_authority_keypair_bs58,
+        tree_pubkey,
+        leaf_owner,
+        collection_mint,
+        asset_proof,
+        metadata_args,
+        options \\ []
+      ) do
+    %VerifyCollectionRequest{
+      collection_authority_keypair: collection_authority_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      leaf_owner: leaf_owner,
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      collection_mint: collection_mint,
+      asset_proof: asset_proof,
+      metadata: metadata_args
+    }
+    |> Bubblegum.unverify_collection(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Sets the collection of a compressed NFT and verifies it in one step, e.g. to move it between collections.
+
+  The collection authority also signs as the tree creator or delegate.
+
+  ## Parameters
+
+  * `collection_authority_keypair_bs58` - Base58 encoded keypair of the collection authority, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `collection_mint` - Public key of the new collection mint
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec set_and_verify_collection(
+          collection_authority_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          collection_mint :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def set_and_verify_collection(
+        collection_authority_keypair_bs58,
+        tree_pubkey,
+        leaf_owner,
+        collection_mint,
+        asset_proof,
+        metadata_args,
+        options \\ []
+      ) do
+    %VerifyCollectionRequest{
+      collection_authority_keypair: collection_authority_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      leaf_owner: leaf_owner,
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      collection_mint: collection_mint,
+      asset_proof: asset_proof,
+      metadata: metadata_args
+    }
+    |> Bubblegum.set_and_verify_collection(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Updates the metadata of a mutable compressed NFT.
+
+  The authority is the tree creator or delegate for NFTs without a collection, and the
+  collection update authority for NFTs in a verified collection.
+
+  ## Parameters
+
+  * `authority_keypair_bs58` - Base58 encoded keypair of the update authority, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `asset_proof` - Merkle proof data for the leaf
+  * `current_metadata` - Current metadata of the leaf, as it is hashed in the tree; set
+    `collection_verified: true` for a leaf in a verified collection, such as one minted with
+    `mint_to_collection_v1/5`
+  * `update_args` - Metadata fields to change (see `SolanaBubblegum.Types.UpdateArgs`)
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec update_metadata(
+          authority_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          current_metadata :: MetadataArgs.t(),
+          update_args :: UpdateArgs.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def update_metadata(
+        authority_keypair_bs58,
+        tree_pubkey,
+        leaf_owner,
+        asset_proof,
+        current_metadata,
+        update_args,
+        options \\ []
+      ) do
+    %UpdateMetadataRequest{
+      authority_keypair: authority_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      leaf_owner: leaf_owner,
+      leaf_delegate: Keyword.get(options, :leaf_delegate),
+      asset_proof: asset_proof,
+      current_metadata: current_metadata,
+      update_args: update_args
+    }
+    |> Bubblegum.update_metadata(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Sets the tree delegate, handing minting authority for a private tree to another wallet.
+
+  ## Parameters
+
+  * `tree_creator_keypair_bs58` - Base58 encoded keypair of the tree creator, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `new_delegate` - Public key of the new tree delegate
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec set_tree_delegate(
+          tree_creator_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          new_delegate :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def set_tree_delegate(tree_creator_keypair_bs58, tree_pubkey, new_delegate, options \\ []) do
+    %SetTreeDelegateRequest{
+      tree_creator_keypair: tree_creator_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      new_delegate: new_delegate
+    }
+    |> Bubblegum.set_tree_delegate(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Controls whether the leaves of a Merkle tree can be decompressed into regular NFTs.
+
+  ## Parameters
+
+  * `tree_creator_keypair_bs58` - Base58 encoded keypair of the tree creator, who also pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `decompressible_state` - `:enabled` or `:disabled`
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec set_decompressible_state(
+          tree_creator_keypair_bs58 :: Types.keypair(),
+          tree_pubkey :: String.t(),
+          decompressible_state :: :enabled | :disabled,
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def set_decompressible_state(tree_creator_keypair_bs58, tree_pubkey, decompressible_state, options \\ [])
+      when decompressible_state in [:enabled, :disabled] do
+    %SetDecompressibleStateRequest{
+      tree_creator_keypair: tree_creator_keypair_bs58,
+      merkle_tree: tree_pubkey,
+      decompressible_state: decompressible_state
+    }
+    |> Bubblegum.set_decompressible_state(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Transfers a compressed NFT, fetching the leaf data and Merkle proof from the DAS API.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who must be the leaf owner or delegate
+  * `asset_id` - Asset ID of the NFT
+  * `new_owner` - Public key of the new owner
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:canopy_depth` - Canopy depth of the tree; the proof is trimmed to leave out the nodes
+      the canopy holds (read from the tree account by default)
+    * `:proof_cache` - A cache from `new_proof_cache/1` to take the proof from while the tree
+      is unchanged (none by default)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec transfer_with_das(
+          payer_keypair_bs58 :: Types.keypair(),
+          asset_id :: String.t(),
+          new_owner :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def transfer_with_das(payer_keypair_bs58, asset_id, new_owner, options \\ []) do
+    %TransferWithDasRequest{
+      payer_keypair: payer_keypair_bs58,
+      asset_id: asset_id,
+      new_owner: new_owner,
+      canopy_depth: Keyword.get(options, :canopy_depth),
+      proof_cache: Keyword.get(options, :proof_cache)
+    }
+    |> Bubblegum.transfer_with_das(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Transfers many compressed NFTs in one call, fetching their leaf data and Merkle proofs from
+  the DAS API.
+
+  The transfers are packed, in order, into as few transactions as the transaction size and
+  compute limits allow, and a pool of workers sends the transactions concurrently. Pass
+  `:address_lookup_tables` holding the tree accounts to fit more transfers into each one. An
+  asset DAS cannot serve, or whose transaction fails, is reported in its item without stopping
+  the others.
+
+  Transfers of the same tree in flight at once change its root under each other's proofs; the
+  tree accepts them as long as there are no more than its `max_buffer_size`.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who must be the owner or delegate of every asset
+  * `transfers` - `{asset_id, new_owner}` pairs
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:canopy_depth` - Canopy depth of every tree (read from each tree account by default)
+    * `:workers` - Proofs fetched and transactions sent at the same time (defaults to 8)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`.
+      An explicit `:compute_unit_limit` caps every transaction of the batch
+
+  ## Returns
+
+  * `{:ok, %{signatures: [String.t()], items: [SolanaBubblegum.Types.TransferBatchItem.t()], attempts: non_neg_integer()}}` -
+    When the payer keypair is valid, with one item per transfer, in order
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, %{items: items}} =
+        SolanaBubblegum.transfer_batch(payer_keypair_bs58, [{asset_id, new_owner}, {other_asset_id, new_owner}],
+          rpc_url: das_rpc_url
+        )
+
+  """
+  @spec transfer_batch(
+          payer_keypair_bs58 :: Types.keypair(),
+          transfers :: [{String.t(), String.t()}],
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def transfer_batch(payer_keypair_bs58, transfers, options \\ []) do
+    %TransferBatchRequest{
+      payer_keypair: payer_keypair_bs58,
+      transfers: transfers,
+      canopy_depth: Keyword.get(options, :canopy_depth),
+      workers: Keyword.get(options, :workers, 8)
+    }
+    |> Bubblegum.transfer_batch(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Burns a compressed NFT, fetching the leaf data and Merkle proof from the DAS API.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who must be the leaf owner or delegate
+  * `asset_id` - Asset ID of the NFT
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:canopy_depth` - Canopy depth of the tree; the proof is trimmed to leave out the nodes
+      the canopy holds (read from the tree account by default)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec burn_with_das(
+          payer_keypair_bs58 :: Types.keypair(),
+          asset_id :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def burn_with_das(payer_keypair_bs58, asset_id, options \\ []) do
+    %BurnWithDasRequest{
+      payer_keypair: payer_keypair_bs58,
+      asset_id: asset_id,
+      canopy_depth: Keyword.get(options, :canopy_depth)
+    }
+    |> Bubblegum.burn_with_das(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Creates an uncompressed Metaplex Core asset, e.g. for a collection moved off Bubblegum.
+
+  Core assets are regular accounts, so they need no tree or proof and are read like any
+  account. They go through the same signing, fee, retry and send options as every other call,
+  which lets one application use compressed NFTs and Core assets side by side.
+
+  ## Parameters
+
+  * `payer_keypair` - Keypair of the payer
+  * `name` - Name of the asset
+  * `uri` - URI of the asset's off-chain metadata
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:owner` - Owner of the asset (defaults to the payer)
+    * `:collection` - Core collection to create the asset in (defaults to none)
+    * `:update_authority` - Update authority of an asset outside a collection (defaults to the payer)
+    * `:asset_keypair` - Keypair of the asset account, e.g. for a vanity address (generated by default)
+    * `:authority_keypair` - Keypair of the collection's update authority, when it is not the payer
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{asset: String.t(), signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, %{asset: asset}} =
+        SolanaBubblegum.create_core_asset(payer_keypair, "My Asset", "https://arweave.net/asset.json")
+
+  """
+  @spec create_core_asset(payer_keypair :: Types.keypair(), name :: String.t(), uri :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def create_core_asset(payer_keypair, name, uri, options \\ []) do
+    %CoreCreateRequest{
+      payer_keypair: payer_keypair,
+      name: name,
+      uri: uri,
+      owner: Keyword.get(options, :owner),
+      collection: Keyword.get(options, :collection),
+      update_authority: Keyword.get(options, :update_authority),
+      asset_keypair: Keyword.get(options, :asset_keypair),
+      authority_keypair: Keyword.get(options, :authority_keypair)
+    }
+    |> Bubblegum.create_core_asset(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Transfers a Metaplex Core asset.
+
+  ## Parameters
+
+  * `payer_keypair` - Keypair of the payer, who is the owner unless `:authority_keypair` is given
+  * `asset` - Address of the Core asset
+  * `new_owner` - Public key of the new owner
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:collection` - Core collection of the asset; required when it is in one
+    * `:authority_keypair` - Keypair of the owner or a transfer delegate, when it is not the payer
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec transfer_core_asset(payer_keypair :: Types.keypair(), asset :: String.t(), new_owner :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def transfer_core_asset(payer_keypair, asset, new_owner, options \\ []) do
+    %CoreTransferRequest{
+      payer_keypair: payer_keypair,
+      asset: asset,
+      new_owner: new_owner,
+      collection: Keyword.get(options, :collection),
+      authority_keypair: Keyword.get(options, :authority_keypair)
+    }
+    |> Bubblegum.transfer_core_asset(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Burns a Metaplex Core asset, closing its account.
+
+  ## Parameters
+
+  * `payer_keypair` - Keypair of the payer, who is the owner unless `:authority_keypair` is given
+  * `asset` - Address of the Core asset
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:collection` - Core collection of the asset; required when it is in one
+    * `:authority_keypair` - Keypair of the owner or a burn delegate, when it is not the payer
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec burn_core_asset(payer_keypair :: Types.keypair(), asset :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def burn_core_asset(payer_keypair, asset, options \\ []) do
+    %CoreBurnRequest{
+      payer_keypair: payer_keypair,
+      asset: asset,
+      collection: Keyword.get(options, :collection),
+      authority_keypair: Keyword.get(options, :authority_keypair)
+    }
+    |> Bubblegum.burn_core_asset(transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Derives the asset ID of a compressed NFT from its tree and leaf nonce.
+
+  This is a pure computation of the `["asset", tree, nonce]` PDA of the Bubblegum program
+  and does not contact any RPC endpoint.
+
+  ## Parameters
+
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `nonce` - Nonce of the leaf, i.e. its index at mint time
+
+  ## Returns
+
+  * `{:ok, %{asset_id: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec get_asset_id(tree_pubkey :: String.t(), nonce :: non_neg_integer()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def get_asset_id(tree_pubkey, nonce) do
+    Bubblegum.get_asset_id(tree_pubkey, nonce)
+    |> normalize_result()
+  end
+
+  @doc """
+  Creates a counter of the leaf indexes mints to a tree will get, so asset IDs are known before
+  the mints confirm.
+
+  The counter starts at the `num_minted` of the tree config. Take an index with
+  `next_leaf_index/1` for every mint sent; concurrent callers never get the same one. Leaves get
+  their index in the order their mints land, so the prediction holds when the mints of a tree
+  are sent one after another from a single process and nothing else mints to it.
+
+  ## Parameters
+
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, counter}` - A `SolanaBubblegum.Types.MintCounter`
+  * `{:error, reason}` - On failure, e.g. when the tree does not exist
+
+  ## Examples
+
+      {:ok, counter} = SolanaBubblegum.new_mint_counter(tree_pubkey, rpc_url: conn)
+      {:ok, %{index: index, asset_id: asset_id}} = SolanaBubblegum.next_leaf_index(counter)
+      {:ok, _} = SolanaBubblegum.mint(payer_keypair_bs58, tree_pubkey, metadata, rpc_url: conn)
+
+  """
+  @spec new_mint_counter(tree_pubkey :: String.t(), options :: keyword()) ::
+          {:ok, Types.MintCounter.t()} | {:error, Types.Error.t()}
+  def new_mint_counter(tree_pubkey, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    case Bubblegum.new_mint_counter(tree_pubkey, retry_policy(options), rpc_url) do
+      {:ok, %{"mint_counter" => counter}} -> {:ok, counter}
+      {:error, reason} -> {:error, reason}
+    end
+  end
+
+  @doc """
+  Takes the leaf index of the next mint to a counter's tree and derives the asset ID the mint
+  creates. Each call takes a new index.
+
+  ## Parameters
+
+  * `counter` - A counter returned by `new_mint_counter/2`
+
+  ## Returns
+
+  * `{:ok, %{index: non_neg_integer(), asset_id: String.t()}}`
+  """
+  @spec next_leaf_index(counter :: Types.MintCounter.t()) :: {:ok, map()}
+  def next_leaf_index(counter) do
+    counter
+    |> Bubblegum.next_leaf_index()
+    |> normalize_result()
+  end
+
+  @doc """
+  Resets a mint counter to the `num_minted` of its tree config, once the mints sent so far are
+  confirmed or have failed.
+
+  Indexes taken for mints that failed are handed out again, and mints made by others are
+  skipped over. Mints still in flight when reconciling would be counted twice.
+
+  ## Parameters
+
+  * `counter` - A counter returned by `new_mint_counter/2`
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{next_leaf_index: non_neg_integer(), unused: non_neg_integer(), minted_elsewhere: non_neg_integer()}}` -
+    On success; `unused` counts indexes taken that no mint landed with
+  * `{:error, reason}` - On failure
+  """
+  @spec reconcile_mint_counter(counter :: Types.MintCounter.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def reconcile_mint_counter(counter, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.reconcile_mint_counter(counter, retry_policy(options), rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Generates a new random wallet keypair.
+
+  ## Returns
+
+  * `{:ok, %{keypair: String.t(), pubkey: String.t()}}` - The Base58 encoded keypair, as every
+    `*_keypair_bs58` argument takes it, and its public key
+
+  ## Examples
+
+      {:ok, %{keypair: payer_keypair_bs58, pubkey: payer}} = SolanaBubblegum.generate_keypair()
+
+  """
+  @spec generate_keypair() :: {:ok, map()}
+  def generate_keypair do
+    Bubblegum.generate_keypair()
+    |> normalize_result()
+  end
+
+  @doc """
+  Derives a keypair from a seed, e.g. one kept in a secrets store. The same seed always gives
+  the same keypair.
+
+  ## Parameters
+
+  * `seed` - Seed of at least 32 bytes; the first 32 become the secret key
+
+  ## Returns
+
+  * `{:ok, %{keypair: String.t(), pubkey: String.t()}}` - On success
+  * `{:error, reason}` - When the seed is too short
+
+  ## Examples
+
+      iex> SolanaBubblegum.keypair_from_seed(<<0::256>>)
+      {:ok, %{keypair: "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS", pubkey: "4zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"}}
+
+  """
+  @spec keypair_from_seed(seed :: binary()) :: {:ok, map()} | {:error, Types.Error.t()}
+  def keypair_from_seed(seed) do
+    Bubblegum.keypair_from_seed(seed)
+    |> normalize_result()
+  end
+
+  @doc """
+  Restores a wallet keypair from its BIP39 mnemonic, along the BIP44 path
+  `m/44'/501'/account'/change'` that Solana wallets such as Phantom and Solflare use.
+
+  ## Parameters
+
+  * `mnemonic` - English BIP39 mnemonic of 12 to 24 words
+  * `options` - Optional keyword list with additional parameters:
+    * `:account` - Account index of the path (defaults to 0)
+    * `:change` - Change index of the path (defaults to 0); `nil` derives along
+      `m/44'/501'/account'` instead
+    * `:passphrase` - BIP39 passphrase (defaults to none)
+
+  ## Returns
+
+  * `{:ok, %{keypair: String.t(), pubkey: String.t()}}` - On success
+  * `{:error, reason}` - When the mnemonic is not a valid BIP39 mnemonic
+  """
+  @spec keypair_from_mnemonic(mnemonic :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def keypair_from_mnemonic(mnemonic, options \\ []) do
+    Bubblegum.keypair_from_mnemonic(
+      mnemonic,
+      Keyword.get(options, :passphrase, ""),
+      Keyword.get(options, :account, 0),
+      Keyword.get(options, :change, 0)
+    )
+    |> normalize_result()
+  end
+
+  @doc """
+  Loads a keypair into memory owned by the native code, where it is wiped once no longer used.
+
+  Keypairs passed as strings or binaries live on in BEAM memory until it is garbage collected
+  and reused, and may show up in crash dumps. The returned secret key is a reference instead:
+  its bytes stay in native memory and are zeroed as soon as the BEAM garbage collects the last
+  reference to it. Pass it wherever a keypair is expected. Secret key copies the native code
+  makes while signing are zeroed after use too.
+
+  Loading an encrypted keypair, `{:encrypted, encrypted_keypair, passphrase}`, means the
+  plaintext keypair never reaches the BEAM at all.
+
+  ## Parameters
+
+  * `keypair` - Keypair to load, in any of the formats of `t:SolanaBubblegum.Types.keypair/0`
+
+  ## Returns
+
+  * `{:ok, %{secret_key: SolanaBubblegum.Types.SecretKey.t(), pubkey: String.t()}}` - On success
+  * `{:error, reason}` - When the keypair is invalid
+
+  ## Examples
+
+      {:ok, %{secret_key: payer}} = SolanaBubblegum.load_secret_key({:encrypted, encrypted, passphrase})
+      {:ok, result} = SolanaBubblegum.mint(payer, tree, metadata)
+
+  """
+  @spec load_secret_key(keypair :: Types.keypair()) :: {:ok, map()} | {:error, Types.Error.t()}
+  def load_secret_key(keypair) do
+    Bubblegum.load_secret_key(keypair)
+    |> normalize_result()
+  end
+
+  @doc """
+  Registers a keypair under a name, so calls can sign with `{:signer, name}` instead of the
+  keypair itself.
+
+  The keypair is decoded, and decrypted when encrypted, once here and held in native memory,
+  shared by every process of the node. Secrets then no longer travel through every call, where
+  they can end up in logs, crash reports or process state. Registering a name again replaces
+  its keypair.
+
+  ## Parameters
+
+  * `name` - Atom or string naming the signer; `:treasury` and `"treasury"` are the same signer
+  * `keypair` - Keypair to register, in any of the formats of `t:SolanaBubblegum.Types.keypair/0`
+
+  ## Returns
+
+  * `{:ok, %{pubkey: String.t()}}` - On success
+  * `{:error, reason}` - When the name or the keypair is invalid
+
+  ## Examples
+
+      {:ok, %{pubkey: treasury}} = SolanaBubblegum.register_signer(:treasury, {:encrypted, encrypted, passphrase})
+      {:ok, result} = SolanaBubblegum.mint({:signer, :treasury}, tree, metadata)
+
+  """
+  @spec register_signer(name :: atom() | String.t(), keypair :: Types.keypair()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def register_signer(name, keypair) do
+    Bubblegum.register_signer(name, keypair)
+    |> normalize_result()
+  end
+
+  @doc """
+  Removes a signer registered by `register_signer/2`. Its keypair is wiped once no call is
+  still signing with it; calls made afterwards with `{:signer, name}` fail with
+  `:invalid_keypair`.
+  """
+  @spec unregister_signer(name :: atom() | String.t()) :: :ok | {:error, Types.Error.t()}
+  def unregister_signer(name) do
+    Bubblegum.unregister_signer(name)
+  end
+
+  @doc """
+  Lists the signers registered by `register_signer/2`.
+
+  ## Returns
+
+  * `{:ok, %{String.t() => String.t()}}` - Each name, as a string, and the public key it signs as
+  """
+  @spec list_signers() :: {:ok, %{String.t() => String.t()}}
+  def list_signers do
+    Bubblegum.list_signers()
+  end
+
+  @doc """
+  Sets the process that is told about every RPC request and transaction, e.g. to feed metrics.
+  Only one handler is set at a time; setting another replaces it. `SolanaBubblegum.Telemetry`
+  is such a handler, which emits the reports as `:telemetry` events.
+
+  The handler receives `{:bubblegum_telemetry, event, measurements, metadata}` where
+
+  * `event` - `:rpc` for a single RPC or DAS request, `:transaction` for a transaction sent
+    with all its retries
+  * `measurements` - `%{duration: microseconds, attempts: pos_integer()}`; for an RPC request
+    `attempts` is the attempt of the retried operation it was made in
+  * `metadata` - `%{operation: String.t() | nil, endpoint: String.t() | nil, result: :ok | :error, error_class: atom() | nil}`.
+    The operation is the RPC method, e.g. `"getLatestBlockhash"`, or the first Bubblegum
+    instruction of a transaction, e.g. `"mint_v1"`. Transactions sent through a pool have no
+    endpoint. The error class is the retry class of a transient failure, such as `:rate_limited`,
+    otherwise the kind of the error
+
+  ## Parameters
+
+  * `pid` - Process to report to, or `nil` to stop reporting
+  """
+  @spec set_telemetry_handler(pid :: pid() | nil) :: :ok
+  def set_telemetry_handler(pid) do
+    Bubblegum.set_telemetry_handler(pid)
+  end
+
+  @doc """
+  Sets the process that receives the log records of the native library, including those of
+  the Solana crates it uses. Only one handler is set at a time; setting another replaces it.
+  `SolanaBubblegum.LogHandler` is such a handler, which logs the records with `Logger`.
+
+  The handler receives `{:bubblegum_log, level, target, message, fields}` where `level` is a
+  `Logger` level, `target` the Rust module that logged the record, and `fields` a map of the
+  record's other fields, such as the program logs of a failed transaction, as strings.
+
+  ## Parameters
+
+  * `pid` - Process to forward to, or `nil` to stop forwarding
+  """
+  @spec set_log_handler(pid :: pid() | nil) :: :ok
+  def set_log_handler(pid) do
+    Bubblegum.set_log_handler(pid)
+  end
+
+  @doc """
+  Sets the lowest level of the log records the native library forwards. Defaults to
+  `:warning`, which covers retries, failovers and failed transactions; `:debug` adds every
+  transaction sent, and `:trace` the requests of the underlying HTTP clients.
+
+  ## Parameters
+
+  * `level` - `:error`, `:warning`, `:info`, `:debug`, `:trace` or `:none`
+  """
+  @spec set_log_level(level :: :error | :warning | :info | :debug | :trace | :none) :: :ok
+  def set_log_level(level) do
+    Bubblegum.set_log_level(level)
+  end
+
+  @doc """
+  Encrypts a keypair under a passphrase, for storing signer keys at rest.
+
+  The key is stretched from the passphrase with Argon2id and a random salt, and the keypair is
+  sealed with XChaCha20-Poly1305. Every call produces a different result.
+
+  Instead of decrypting the keypair yourself, you can pass `{:encrypted, encrypted_keypair,
+  passphrase}` wherever a keypair is expected; it is decrypted inside the native code only for
+  the duration of the call.
+
+  ## Parameters
+
+  * `keypair` - Keypair to encrypt, in any of the formats of `t:SolanaBubblegum.Types.keypair/0`
+  * `passphrase` - Passphrase to derive the encryption key from
+
+  ## Returns
+
+  * `{:ok, encrypted_keypair}` - On success, Base64 encoded
+  * `{:error, reason}` - When the keypair is invalid
+
+  ## Examples
+
+      {:ok, encrypted} = SolanaBubblegum.encrypt_keypair(payer_keypair, passphrase)
+      {:ok, result} = SolanaBubblegum.mint({:encrypted, encrypted, passphrase}, tree, metadata)
+
+  """
+  @spec encrypt_keypair(keypair :: Types.keypair(), passphrase :: String.t()) ::
+          {:ok, String.t()} | {:error, Types.Error.t()}
+  def encrypt_keypair(keypair, passphrase) do
+    Bubblegum.encrypt_keypair(keypair, passphrase)
+  end
+
+  @doc """
+  Decrypts a keypair encrypted by `encrypt_keypair/2`.
+
+  ## Parameters
+
+  * `encrypted_keypair` - Base64 encoded encrypted keypair
+  * `passphrase` - Passphrase it was encrypted under
+
+  ## Returns
+
+  * `{:ok, %{keypair: String.t(), pubkey: String.t()}}` - On success
+  * `{:error, reason}` - With kind `:invalid_keypair` when the passphrase is wrong or the
+    encrypted keypair was altered
+  """
+  @spec decrypt_keypair(encrypted_keypair :: String.t(), passphrase :: String.t()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def decrypt_keypair(encrypted_keypair, passphrase) do
+    Bubblegum.decrypt_keypair(encrypted_keypair, passphrase)
+    |> normalize_result()
+  end
+
+  @doc """
+  Computes the data hash of a compressed NFT from its metadata, as the Bubblegum program does.
+
+  ## Parameters
+
+  * `metadata_args` - Metadata of the NFT; the hash covers whether its collection is verified,
+    so set `collection_verified: true` to match a leaf in a verified collection
+
+  ## Returns
+
+  * `{:ok, %{data_hash: String.t()}}` - On success, the hash is Base58 encoded
+  * `{:error, reason}` - On failure
+  """
+  @spec hash_metadata(metadata_args :: MetadataArgs.t()) :: {:ok, map()} | {:error, Types.Error.t()}
+  def hash_metadata(metadata_args) do
+    Bubblegum.hash_metadata(metadata_args)
+    |> normalize_result()
+  end
+
+  @doc """
+  Computes the creator hash of a compressed NFT from its creators, as the Bubblegum program does.
+
+  ## Parameters
+
+  * `creators` - List of `SolanaBubblegum.Types.Creator`
+
+  ## Returns
+
+  * `{:ok, %{creator_hash: String.t()}}` - On success, the hash is Base58 encoded
+  * `{:error, reason}` - On failure
+  """
+  @spec hash_creators(creators :: [Types.Creator.t()]) :: {:ok, map()} | {:error, Types.Error.t()}
+  def hash_creators(creators) do
+    Bubblegum.hash_creators(creators)
+    |> normalize_result()
+  end
+
+  @doc """
+  Computes the hash of a V1 leaf, matching what the Bubblegum program inserts into the tree.
+
+  Useful to check a DAS proof off-chain or to debug leaf mismatches.
+
+  ## Parameters
+
+  * `asset_id` - Asset ID of the NFT
+  * `owner` - Public key of the leaf owner
+  * `delegate` - Public key of the leaf delegate (the owner when there is no delegate)
+  * `nonce` - Nonce of the leaf
+  * `data_hash` - Base58 encoded data hash
+  * `creator_hash` - Base58 encoded creator hash
+
+  ## Returns
+
+  * `{:ok, %{leaf_hash: String.t()}}` - On success, the hash is Base58 encoded
+  * `{:error, reason}` - On failure
+  """
+  @spec compute_leaf_hash(
+          asset_id :: String.t(),
+          owner :: String.t(),
+          delegate :: String.t(),
+          nonce :: non_neg_integer(),
+          data_hash :: String.t(),
+          creator_hash :: String.t()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def compute_leaf_hash(asset_id, owner, delegate, nonce, data_hash, creator_hash) do
+    Bubblegum.compute_leaf_hash(asset_id, owner, delegate, nonce, data_hash, creator_hash)
+    |> normalize_result()
+  end
+
+  @doc """
+  Verifies a Merkle proof locally, without an RPC call.
+
+  Hashes the leaf up through the proof nodes exactly like the account compression program and
+  compares the result with `root`. Checking a DAS proof this way before sending a transaction
+  catches stale or mismatched proofs without paying fees.
+
+  ## Parameters
+
+  * `root` - Base58 encoded root the proof should lead to
+  * `leaf` - Base58 encoded leaf hash (see `compute_leaf_hash/6`)
+  * `proof` - Base58 encoded proof nodes, from the leaf level up
+  * `index` - Index of the leaf in the tree
+
+  ## Returns
+
+  * `{:ok, %{valid: boolean(), computed_root: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec verify_proof(
+          root :: String.t(),
+          leaf :: String.t(),
+          proof :: [String.t()],
+          index :: non_neg_integer()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def verify_proof(root, leaf, proof, index) do
+    Bubblegum.verify_proof(root, leaf, proof, index)
+    |> normalize_result()
+  end
+
+  @doc """
+  Computes how many NFTs a Merkle tree holds and the rent its account needs, before creating it.
+
+  Without options the rent is computed locally from the default rent parameters, like the
+  build-only `SolanaBubblegum.Build` functions do.
+
+  ## Parameters
+
+  * `max_depth` - Maximum depth of the tree; it holds `2^max_depth` leaves
+  * `max_buffer_size` - Maximum concurrent changes buffered by the tree
+  * `canopy_depth` - Depth of the cached canopy
+  * `options` - Optional keyword list with additional parameters:
+    * `:lamports_per_byte_year` - Rent rate to compute the rent with
+    * `:rpc_url` - Fetch the rent from this endpoint or connection instead of computing it
+
+  ## Returns
+
+  * `{:ok, %{capacity: integer(), account_size: integer(), rent_exempt_lamports: integer()}}` - On success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      iex> SolanaBubblegum.calculate_tree_cost(14, 64, 10)
+      {:ok, %{account_size: 97272, capacity: 16384, rent_exempt_lamports: 677904000}}
+
+  """
+  @spec calculate_tree_cost(
+          max_depth :: non_neg_integer(),
+          max_buffer_size :: pos_integer(),
+          canopy_depth :: non_neg_integer(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def calculate_tree_cost(max_depth, max_buffer_size, canopy_depth, options \\ []) do
+    Bubblegum.calculate_tree_cost(
+      max_depth,
+      max_buffer_size,
+      canopy_depth,
+      Keyword.get(options, :lamports_per_byte_year),
+      Keyword.get(options, :rpc_url)
+    )
+    |> normalize_result()
+  end
+
+  @doc """
+  Suggests tree shapes for a collection of NFTs.
+
+  Every tree shape spl-account-compression supports that holds `nft_count` NFTs and buffers at
+  least `:concurrency` changes is returned, cheapest first. Each gets the deepest canopy that
+  keeps proofs at 10 nodes or fewer while the account stays within Solana's 10 MiB limit.
+
+  ## Parameters
+
+  * `nft_count` - NFTs the tree has to hold
+  * `options` - Optional keyword list with additional parameters:
+    * `:concurrency` - Changes to the tree expected within the same slot, e.g. parallel
+      mints (defaults to 1)
+    * `:lamports_per_byte_year` and `:rpc_url` - How to get the rent, see `calculate_tree_cost/4`
+
+  ## Returns
+
+  * `{:ok, %{recommendations: [SolanaBubblegum.Types.TreeRecommendation.t()]}}` - On success
+  * `{:error, reason}` - When no supported tree is large enough
+  """
+  @spec recommend_tree_config(nft_count :: pos_integer(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def recommend_tree_config(nft_count, options \\ []) do
+    Bubblegum.recommend_tree_config(
+      nft_count,
+      Keyword.get(options, :concurrency, 1),
+      Keyword.get(options, :lamports_per_byte_year),
+      Keyword.get(options, :rpc_url)
+    )
+    |> normalize_result()
+  end
+
+  @doc """
+  Decodes the leaf and tree change events of a confirmed transaction, as an indexer would.
+
+  Bubblegum logs every leaf it writes, and spl-account-compression every change to a tree,
+  through inner instructions of the noop program. Data other programs log there is skipped.
+
+  ## Parameters
+
+  * `signature` - Signature of a confirmed transaction
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t(), slot: integer(), leaf_schema_events: [SolanaBubblegum.Types.LeafSchemaEvent.t()],
+    change_log_events: [SolanaBubblegum.Types.ChangeLogEvent.t()]}}` - On success
+  * `{:error, reason}` - On failure, e.g. when the transaction is not found
+  """
+  @spec parse_transaction_events(signature :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def parse_transaction_events(signature, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.parse_transaction_events(signature, retry_policy(options), rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Lists the confirmed transactions that touched an address, newest first. Listing a Merkle
+  tree gives every mint, transfer and burn of its assets.
+
+  Page through older transactions by passing the last signature of a page as `:before`.
+
+  ## Parameters
+
+  * `address` - Public key of the account, e.g. a Merkle tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:before` - Only list transactions older than this signature
+    * `:until` - Stop listing at this signature, e.g. the newest one already processed
+    * `:limit` - Maximum number of signatures, from 1 to 1000 (defaults to 1000)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{address: String.t(), signatures: [SolanaBubblegum.Types.SignatureInfo.t()]}}` - On success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, %{signatures: page}} = SolanaBubblegum.get_signatures_for_address(tree, limit: 100)
+      {:ok, %{signatures: next_page}} =
+        SolanaBubblegum.get_signatures_for_address(tree, limit: 100, before: List.last(page).signature)
+  """
+  @spec get_signatures_for_address(address :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def get_signatures_for_address(address, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.get_signatures_for_address(
+      address,
+      Keyword.get(options, :before),
+      Keyword.get(options, :until),
+      Keyword.get(options, :limit),
+      retry_policy(options),
+      rpc_url
+    )
+    |> normalize_result()
+  end
+
+  @doc """
+  Fetches a confirmed transaction with a summary of every instruction it ran, including the
+  ones invoked by other instructions, and the compression events it logged.
+
+  Bubblegum and spl-account-compression instructions are named, so a tree's history can be
+  told apart into mints, transfers, burns and so on without an IDL.
+
+  ## Parameters
+
+  * `signature` - Signature of a confirmed transaction
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t(), slot: integer(), block_time: integer() | nil, fee: integer(),
+    error: SolanaBubblegum.Types.Error.t() | nil, logs: [String.t()], instructions: [SolanaBubblegum.Types.InstructionSummary.t()],
+    leaf_schema_events: [SolanaBubblegum.Types.LeafSchemaEvent.t()], change_log_events: [SolanaBubblegum.Types.ChangeLogEvent.t()]}}` -
+    On success; `error` is set when the transaction failed
+  * `{:error, reason}` - On failure, e.g. when the transaction is not found
+  """
+  @spec get_transaction(signature :: String.t(), options :: keyword()) :: {:ok, map()} | {:error, Types.Error.t()}
+  def get_transaction(signature, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.get_transaction(signature, retry_policy(options), rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Streams the changes of a Merkle tree to a process as they are confirmed.
+
+  Subscribes to the logs of every transaction that mentions the tree over the Solana PubSub
+  websocket, fetches each successful one and decodes its events like
+  `parse_transaction_events/2`. The owner receives `{:bubblegum_tree_event, ref, %SolanaBubblegum.Types.TreeEvent{}}`
+  for every transaction with compression events, and `{:bubblegum_tree_error, ref, %SolanaBubblegum.Types.Error{}}`
+  when a transaction cannot be fetched or the websocket drops. A dropped websocket is reconnected
+  and resubscribed with a backoff of up to 30 seconds; transactions confirmed while it was down
+  are not replayed.
+
+  ## Parameters
+
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `owner` - Process that receives the messages
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint the transactions are fetched from (defaults to Devnet)
+    * `:ws_url` - URL of the websocket endpoint (defaults to the one of `:rpc_url`, e.g.
+      `wss://api.devnet.solana.com`)
+    * `:retry` - Retry policy for fetching the transactions, as a keyword list or a
+      `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{subscription: SolanaBubblegum.Types.TreeSubscription.t(), reference: reference()}}` - On success;
+    `reference` tags the messages
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, %{reference: ref}} = SolanaBubblegum.subscribe_tree(tree_pubkey, self())
+
+      receive do
+        {:bubblegum_tree_event, ^ref, event} -> event.leaf_schema_events
+      end
+
+  """
+  @spec subscribe_tree(tree_pubkey :: String.t(), owner :: pid(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def subscribe_tree(tree_pubkey, owner, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.subscribe_tree(tree_pubkey, owner, Keyword.get(options, :ws_url), retry_policy(options), rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Stops a subscription started with `subscribe_tree/3`. No messages arrive once the transaction
+  being decoded, if any, is delivered.
+  """
+  @spec unsubscribe_tree(subscription :: Types.TreeSubscription.t()) :: :ok
+  def unsubscribe_tree(subscription) do
+    Bubblegum.unsubscribe_tree(subscription)
+  end
+
+  @doc """
+  Reads the Bubblegum tree config of a Merkle tree: who may mint to it and how full it is.
+
+  ## Parameters
+
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.TreeConfigAccount{}}` - On success
+  * `{:error, reason}` - On failure, e.g. when the tree does not exist
+  """
+  @spec get_tree_config(tree_pubkey :: String.t(), options :: keyword()) ::
+          {:ok, Types.TreeConfigAccount.t()} | {:error, Types.Error.t()}
+  def get_tree_config(tree_pubkey, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.get_tree_config(tree_pubkey, retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Reads the header and current state of a concurrent Merkle tree: its current root, sequence
+  number and change log buffer. Useful when a proof no longer matches the tree or to watch how
+  full a tree is.
+
+  ## Parameters
+
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.MerkleTreeState{}}` - On success
+  * `{:error, reason}` - On failure, e.g. when the account is not a Merkle tree
+  """
+  @spec get_merkle_tree_state(tree_pubkey :: String.t(), options :: keyword()) ::
+          {:ok, Types.MerkleTreeState.t()} | {:error, Types.Error.t()}
+  def get_merkle_tree_state(tree_pubkey, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.get_merkle_tree_state(tree_pubkey, retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Funds a wallet from the faucet of the cluster, for devnet and testnet integration tests and
+  scripts. Mainnet has no faucet.
+
+  The faucet rate limits requests and caps their amount, typically at 1 or 2 SOL, so the request
+  is not retried. By default this waits until the airdrop is confirmed, so the funds can be spent
+  right away.
+
+  ## Parameters
+
+  * `pubkey` - Public key of the wallet to fund
+  * `lamports` - Amount to request, in lamports
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:timeout` - How long to wait for confirmation, in milliseconds, `0` to return as soon as
+      the airdrop is requested (defaults to 30 seconds)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t(), confirmed: boolean(), slot: non_neg_integer() | nil}}` - On
+    success; `confirmed` is `false` when the timeout passed first
+  * `{:error, reason}` - On failure, e.g. when the faucet refused the request
+
+  ## Examples
+
+      {:ok, %{pubkey: payer}} = SolanaBubblegum.generate_keypair()
+      {:ok, %{confirmed: true}} = SolanaBubblegum.request_airdrop(payer, 1_000_000_000)
+
+  """
+  @spec request_airdrop(pubkey :: String.t(), lamports :: non_neg_integer(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def request_airdrop(pubkey, lamports, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    timeout = Keyword.get(options, :timeout, @default_confirmation_timeout)
+
+    Bubblegum.request_airdrop(pubkey, lamports, timeout, rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Reads the balance of an account.
+
+  ## Parameters
+
+  * `pubkey` - Public key of the account
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{pubkey: String.t(), lamports: non_neg_integer()}}` - On success; accounts that do
+    not exist have a balance of 0
+  * `{:error, reason}` - On failure
+  """
+  @spec get_balance(pubkey :: String.t(), options :: keyword()) :: {:ok, map()} | {:error, Types.Error.t()}
+  def get_balance(pubkey, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.get_balance(pubkey, retry_policy(options), rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Reads an arbitrary account, e.g. a tree config, voucher or metadata account that has no
+  typed decoder in this library.
+
+  ## Parameters
+
+  * `pubkey` - Public key of the account
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:encoding` - `:base64` (default) to return the data Base64 encoded, or `:binary` for
+      the raw bytes, ready for Borsh decoding
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{pubkey: String.t(), lamports: non_neg_integer(), owner: String.t(), executable: boolean(), rent_epoch: non_neg_integer(), data: binary()}}` - On success
+  * `{:ok, nil}` - If the account does not exist
+  * `{:error, reason}` - On failure
+  """
+  @spec get_account_info(pubkey :: String.t(), options :: keyword()) ::
+          {:ok, map() | nil} | {:error, Types.Error.t()}
+  def get_account_info(pubkey, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    encoding = Keyword.get(options, :encoding, :base64)
+
+    Bubblegum.get_account_info(pubkey, encoding, retry_policy(options), rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Transfers SOL, e.g. to top up tree and mint payers from a treasury.
+
+  The transfer is sent like every other transaction of this module, so it takes the same
+  priority fee, send, retry, memo and idempotency options.
+
+  ## Parameters
+
+  * `from_keypair_bs58` - Base58 encoded keypair of the sender, who also pays the fees
+  * `to` - Public key of the recipient
+  * `lamports` - Amount to transfer, in lamports
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t(), from: String.t(), to: String.t(), lamports: pos_integer()}}` - On success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, %{signature: signature}} =
+        SolanaBubblegum.transfer_sol(treasury_keypair, payer, 500_000_000, idempotency_key: "top-up-42")
+
+  """
+  @spec transfer_sol(
+          from_keypair_bs58 :: Types.keypair(),
+          to :: String.t(),
+          lamports :: pos_integer(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def transfer_sol(from_keypair_bs58, to, lamports, options \\ []) do
+    Bubblegum.transfer_sol(from_keypair_bs58, to, lamports, transaction_options(options))
+    |> normalize_result()
+  end
+
+  @doc """
+  Submits a transaction that was signed outside of this library, e.g. one assembled with
+  `SolanaBubblegum.Build.transaction/3` and signed by a wallet or HSM.
+
+  ## Parameters
+
+  * `transaction` - Serialized signed transaction, either Base64 encoded or as raw bytes
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:timeout_ms` - Milliseconds before the call gives up, as for
+      `SolanaBubblegum.Types.TransactionOptions`
+    * Send options, see `SolanaBubblegum.Types.SendOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec send_raw_transaction(transaction :: binary(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def send_raw_transaction(transaction, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    
+    Bubblegum.send_raw_transaction(
+      decode_transaction(transaction),
+      send_options(options),
+      Keyword.get(options, :timeout_ms),
+      rpc_url
+    )
+    |> normalize_result()
+  end
+
+  @doc """
+  Signs a transaction with signatures produced elsewhere, e.g. by a KMS, an HSM or Fireblocks,
+  and submits it.
+
+  Build the transaction with `SolanaBubblegum.Build.transaction/3` and have every one of its
+  `"signers"` sign its `"message"` with ed25519. Each signature is checked against the message
+  before it is attached, and the transaction is only sent once every signer has signed.
+
+  ## Parameters
+
+  * `transaction` - Unsigned transaction, either Base64 encoded or as raw bytes
+  * `signatures` - Map or list of `{signer_pubkey, signature}`, where each signature is a raw
+    64 byte binary or a Base58 string
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:timeout_ms` - Milliseconds before the call gives up, as for
+      `SolanaBubblegum.Types.TransactionOptions`
+    * Send options, see `SolanaBubblegum.Types.SendOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure; `:invalid_signature` when a signature does not sign the
+    message, `:invalid_argument` when a signer is unknown or missing
+
+  ## Examples
+
+      {:ok, %{"transaction" => transaction, "message" => message}} =
+        SolanaBubblegum.Build.transaction(instructions, custody_pubkey)
+
+      signature = MyKms.sign_ed25519(key_id, Base.decode64!(message))
+      {:ok, %{signature: _}} = SolanaBubblegum.attach_signatures_and_send(transaction, %{custody_pubkey => signature})
+
+  """
+  @spec attach_signatures_and_send(
+          transaction :: binary(),
+          signatures :: %{String.t() => binary()} | [{String.t(), binary()}],
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def attach_signatures_and_send(transaction, signatures, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    transaction
+    |> decode_transaction()
+    |> Bubblegum.attach_signatures_and_send(
+      Enum.to_list(signatures),
+      send_options(options),
+      Keyword.get(options, :timeout_ms),
+      rpc_url
+    )
+    |> normalize_result()
+  end
+
+  @doc """
+  Waits for a transaction to reach the given commitment.
+
+  Together with the `confirm: false` send option, which returns the signature as soon as the
+  transaction is submitted, this lets callers confirm transactions on their own schedule. A
+  `:timeout` of `0` checks the status once, for polling.
+
+  ## Parameters
+
+  * `signature` - Signature of the transaction
+  * `commitment` - `:processed`, `:confirmed` or `:finalized` (defaults to `:confirmed`)
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:timeout` - How long to keep checking, in milliseconds (defaults to 30 seconds)
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t(), confirmed: boolean(), slot: non_neg_integer() | nil}}` - On
+    success; `confirmed` is `false` when the commitment was not reached before the timeout
+  * `{:error, reason}` - When the transaction failed or its status could not be fetched
+
+  ## Examples
+
+      {:ok, %{signature: signature}} = SolanaBubblegum.transfer(payer, tree, owner, new_owner, proof, confirm: false)
+      {:ok, %{confirmed: true}} = SolanaBubblegum.confirm_signature(signature, :finalized, timeout: 60_000)
+
+  """
+  @spec confirm_signature(
+          signature :: String.t(),
+          commitment :: :processed | :confirmed | :finalized,
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def confirm_signature(signature, commitment \\ :confirmed, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    timeout = Keyword.get(options, :timeout, @default_confirmation_timeout)
+    
+    Bubblegum.confirm_signature(signature, commitment, timeout, rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Notifies the caller once a transaction is finalized, without blocking it.
+
+  Send with the default `:confirmed` commitment for speed, then hand the signature to this
+  function to learn when the transaction can no longer be rolled back. It subscribes to the
+  signature over the Solana PubSub websocket and falls back to polling the signature status
+  when the websocket is unavailable or drops.
+
+  The caller receives `{:bubblegum_result, ref, {:ok, %{"signature" => ..., "finalized" => ...,
+  "slot" => ..., "error" => ...}}}`. `finalized` is `false` when the timeout passed first, and
+  `error` is a `SolanaBubblegum.Types.Error` when the transaction was finalized as failed.
+
+  ## Parameters
+
+  * `signature` - Signature of the transaction
+  * `timeout` - How long to wait, in milliseconds (defaults to 60 seconds)
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:ws_url` - URL of the websocket endpoint (defaults to the one of `:rpc_url`)
+
+  ## Returns
+
+  * `{:ok, ref}` - Once the wait has started
+  * `{:error, reason}` - When the signature is invalid
+
+  ## Examples
+
+      {:ok, %{signature: signature}} = SolanaBubblegum.mint(payer, tree, metadata)
+      {:ok, ref} = SolanaBubblegum.await_finalized(signature)
+
+      receive do
+        {:bubblegum_result, ^ref, {:ok, %{"finalized" => true, "slot" => slot}}} -> slot
+      end
+
+  """
+  @spec await_finalized(signature :: String.t(), timeout :: non_neg_integer(), options :: keyword()) ::
+          {:ok, reference()} | {:error, Types.Error.t()}
+  def await_finalized(signature, timeout \\ @default_finalization_timeout, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.await_finalized(signature, timeout, Keyword.get(options, :ws_url), rpc_url)
+  end
+
+  @doc """
+  Cancels an operation started with `async: true`, or by `await_finalized/3`.
+
+  The caller receives `{:bubblegum_result, ref, {:error, reason}}` right away, and no other
+  result for `ref`. The background work sends no further transaction. The reason is of kind
+  `:cancelled` when nothing was sent yet, and of kind `:cancelled_unknown_outcome` when a
+  transaction was already under way; that one may still land, so check its outcome, e.g. by
+  retrying with the same `:idempotency_key`.
+
+  ## Parameters
+
+  * `ref` - Reference returned by the operation
+
+  ## Returns
+
+  * `:ok` - When the operation was cancelled
+  * `{:error, reason}` - When it had already replied
+
+  ## Examples
+
+      {:ok, ref} = SolanaBubblegum.mint(payer_keypair_bs58, tree_pubkey, metadata, async: true)
+      :ok = SolanaBubblegum.cancel(ref)
+
+  """
+  @spec cancel(ref :: reference()) :: :ok | {:error, Types.Error.t()}
+  def cancel(ref) do
+    Bubblegum.cancel(ref)
+  end
+
+  @doc """
+  Simulates a serialized transaction without sending it.
+
+  Signatures are not verified, so unsigned transactions from `SolanaBubblegum.Build.transaction/3`
+  can be simulated before they are handed off for signing. To simulate one of the operations of
+  this module instead, pass `simulate_only: true` to it.
+
+  ## Parameters
+
+  * `transaction` - Serialized transaction, either Base64 encoded or as raw bytes
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:replace_recent_blockhash` - Simulate with the latest blockhash instead of the
+      transaction's own (defaults to `false`)
+
+  ## Returns
+
+  * `{:ok, %{logs: [String.t()], units_consumed: non_neg_integer() | nil, error: String.t() | nil}}` - When
+    the simulation ran; `error` describes why the transaction would fail, if it would
+  * `{:error, reason}` - When the transaction could not be simulated
+  """
+  @spec simulate(transaction :: binary(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def simulate(transaction, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    replace_recent_blockhash = Keyword.get(options, :replace_recent_blockhash, false)
+    
+    Bubblegum.simulate_transaction(decode_transaction(transaction), replace_recent_blockhash, rpc_url)
+    |> normalize_result()
+  end
+
+  # Serialized transactions start with the signature count, so raw bytes are never printable
+  defp decode_transaction(transaction) do
+    with true <- String.printable?(transaction),
+         {:ok, bytes} <- Base.decode64(transaction) do
+      bytes
+    else
+      _ -> transaction
+    end
+  end
+
+  # Per-call transaction settings shared by every function that signs and sends
+  defp transaction_options(options) do
+    %TransactionOptions{
+      rpc_url: Keyword.get(options, :rpc_url, @default_rpc_url),
+      compute_unit_limit: Keyword.get(options, :compute_unit_limit),
+      compute_unit_price_micro_lamports: Keyword.get(options, :compute_unit_price_micro_lamports),
+      priority_fee: Keyword.get(options, :priority_fee),
+      priority_fee_percentile: Keyword.get(options, :priority_fee_percentile),
+      priority_fee_url: Keyword.get(options, :priority_fee_url),
+      address_lookup_tables: Keyword.get(options, :address_lookup_tables, []),
+      simulate_only: Keyword.get(options, :simulate_only, false),
+      dry_run: Keyword.get(options, :dry_run, false),
+      idempotency_key: Keyword.get(options, :idempotency_key),
+      memo: Keyword.get(options, :memo),
+      send_options: send_options(options),
+      async_reply: Keyword.get(options, :async, false),
+      timeout_ms: Keyword.get(options, :timeout_ms),
+      retry: retry_policy(options)
+    }
+  end
+
+  defp rate_limit(options) do
+    case Keyword.get(options, :rate_limit) do
+      nil -> nil
+      %RateLimit{} = rate_limit -> rate_limit
+      rate_limit -> struct!(RateLimit, rate_limit)
+    end
+  end
+
+  # No HTTP option given keeps the default clients
+  defp http_options(options) do
+    case Keyword.take(options, [:timeout, :proxy, :headers]) do
+      [] ->
+        nil
+
+      http ->
+        %HttpOptions{
+          timeout: Keyword.get(http, :timeout),
+          proxy: Keyword.get(http, :proxy),
+          headers: Enum.map(Keyword.get(http, :headers, []), fn {name, value} -> {to_string(name), value} end)
+        }
+    end
+  end
+
+  defp retry_policy(options) do
+    case Keyword.get(options, :retry, []) do
+      %RetryPolicy{} = policy -> policy
+      retry -> struct(RetryPolicy, retry)
+    end
+  end
+
+  defp uri_check_options(validate_uris) do
+    case validate_uris do
+      false -> nil
+      nil -> nil
+      true -> %UriCheckOptions{}
+      %UriCheckOptions{} = check -> check
+      check -> struct!(UriCheckOptions, check)
+    end
+  end
+
+  defp send_options(options) do
+    %SendOptions{
+      skip_preflight: Keyword.get(options, :skip_preflight, false),
+      preflight_commitment: Keyword.get(options, :preflight_commitment),
+      max_retries: Keyword.get(options, :max_retries),
+      min_context_slot: Keyword.get(options, :min_context_slot),
+      confirm: Keyword.get(options, :confirm, true),
+      commitment: Keyword.get(options, :commitment)
+    }
+  end
+
+  @doc """
+  Recommends a compute unit price from recent prioritization fees.
+
+  By default the `getRecentPrioritizationFees` RPC method is queried for the given accounts and
+  the requested percentile of the returned fees is picked. When `:priority_fee_url` points to a
+  Helius endpoint, its `getPriorityFeeEstimate` method is used instead.
+
+  ## Parameters
+
+  * `accounts` - Public keys of the accounts the transaction writes to
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:percentile` - Percentile of recent fees to pick, 0 to 100 (defaults to 75)
+    * `:priority_fee_url` - URL of a Helius RPC endpoint, including the API key
+
+  ## Returns
+
+  * `{:ok, %{micro_lamports: non_neg_integer()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec get_priority_fee_estimate(accounts :: [String.t()], options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def get_priority_fee_estimate(accounts, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    percentile = Keyword.get(options, :percentile)
+    priority_fee_url = Keyword.get(options, :priority_fee_url)
+    
+    Bubblegum.get_priority_fee_estimate(accounts, percentile, priority_fee_url, rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Estimates what a serialized transaction costs its fee payer, in lamports.
+
+  The base fee comes from the `getFeeForMessage` RPC method, priced with the latest blockhash so
+  unsigned transactions from `SolanaBubblegum.Build.transaction/3` with a stale one can be
+  estimated too. The priority fee is the compute unit price times the compute unit limit the
+  transaction sets, or the default budget of its instructions. Rent covers the accounts the
+  transaction creates: the lamports its system program instructions fund accounts with, and the
+  rent-exempt minimum of the tree config of `create_tree_config` and of the mint, token account,
+  metadata and master edition of `decompress_v1`.
+
+  ## Parameters
+
+  * `transaction` - Serialized transaction, either Base64 encoded or as raw bytes
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:priority_fee` - `:auto` or `{:micro_lamports, n}` to price a transaction that sets no
+      compute unit price with the priority fee it would be sent with; ignored when it sets one
+    * `:priority_fee_percentile` - Percentile of recent fees picked by `:auto` (defaults to 75)
+    * `:priority_fee_url` - Helius endpoint whose `getPriorityFeeEstimate` method `:auto` uses
+    * `:retry` - Retry policy for transient errors, see `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{base_fee_lamports: non_neg_integer(), priority_fee_lamports: non_neg_integer(),
+    rent_lamports: non_neg_integer(), total_lamports: non_neg_integer(),
+    compute_units: non_neg_integer(), compute_unit_price_micro_lamports: non_neg_integer() | nil}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec estimate_fee(transaction :: binary(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def estimate_fee(transaction, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    priority_fee = Keyword.get(options, :priority_fee)
+    percentile = Keyword.get(options, :priority_fee_percentile)
+    priority_fee_url = Keyword.get(options, :priority_fee_url)
+    
+    decode_transaction(transaction)
+    |> Bubblegum.estimate_fee(priority_fee, percentile, priority_fee_url, retry_policy(options), rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Creates an address lookup table owned by the given authority.
+
+  Register the proof accounts of a tree in the table with `extend_lookup_table/4`, then pass
+  its address in `:address_lookup_tables` to fit transfers with deep proofs in one transaction.
+  A new table can be used from the slot after it was last extended.
+
+  ## Parameters
+
+  * `authority_keypair_bs58` - Base58 encoded keypair of the table authority, who also pays the fees
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t(), lookup_table: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec create_lookup_table(authority_keypair_bs58 :: Types.keypair(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def create_lookup_table(authority_keypair_bs58, options \\ []) do
+    tx_options = transaction_options(options)
+    
+    Bubblegum.create_lookup_table(authority_keypair_bs58, tx_options)
+    |> normalize_result()
+  end
+
+  @doc """
+  Adds addresses to an address lookup table.
+
+  The addresses are sent in batches of 20, one transaction each, so a whole proof can be
+  registered in a single call.
+
+  ## Parameters
+
+  * `authority_keypair_bs58` - Base58 encoded keypair of the table authority, who also pays the fees
+  * `lookup_table` - Address of the lookup table
+  * `addresses` - Addresses to add, such as the tree and the nodes of an asset proof
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signatures: [String.t()], lookup_table: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec extend_lookup_table(
+          authority_keypair_bs58 :: Types.keypair(),
+          lookup_table :: String.t(),
+          addresses :: [String.t()],
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def extend_lookup_table(authority_keypair_bs58, lookup_table, addresses, options \\ []) do
+    tx_options = transaction_options(options)
+    
+    Bubblegum.extend_lookup_table(authority_keypair_bs58, lookup_table, addresses, tx_options)
+    |> normalize_result()
+  end
+
+  @doc """
+  Deactivates an address lookup table.
+
+  A deactivated table can no longer be extended or used by new transactions, and can be
+  closed with `close_lookup_table/3` after a cooldown of about 513 slots.
+
+  ## Parameters
+
+  * `authority_keypair_bs58` - Base58 encoded keypair of the table authority, who also pays the fees
+  * `lookup_table` - Address of the lookup table
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec deactivate_lookup_table(
+          authority_keypair_bs58 :: Types.keypair(),
+          lookup_table :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def deactivate_lookup_table(authority_keypair_bs58, lookup_table, options \\ []) do
+    tx_options = transaction_options(options)
+    
+    Bubblegum.deactivate_lookup_table(authority_keypair_bs58, lookup_table, tx_options)
+    |> normalize_result()
+  end
+
+  @doc """
+  Closes a deactivated address lookup table and reclaims its rent.
+
+  ## Parameters
+
+  * `authority_keypair_bs58` - Base58 encoded keypair of the table authority, who also pays the fees
+  * `lookup_table` - Address of the lookup table
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:recipient` - Account receiving the reclaimed rent (defaults to the authority)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec close_lookup_table(
+          authority_keypair_bs58 :: Types.keypair(),
+          lookup_table :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def close_lookup_table(authority_keypair_bs58, lookup_table, options \\ []) do
+    tx_options = transaction_options(options)
+    recipient = Keyword.get(options, :recipient)
+    
+    Bubblegum.close_lookup_table(authority_keypair_bs58, lookup_table, recipient, tx_options)
+    |> normalize_result()
+  end
+
+  @doc """
+  Checks whether a tree is empty, i.e. every leaf minted to it was burned or redeemed.
+
+  The check compares the current root of the tree with the root of a tree of empty leaves, so it
+  holds no matter how many leaves were minted. Redeemed leaves are listed as `vouchers`; each
+  holds rent its leaf owner gets back with `cancel_redeem/4` or by decompressing the leaf.
+
+  Bubblegum is the authority of its trees and has no instruction to close one, so the rent of an
+  empty tree and its tree config cannot be reclaimed; `locked_lamports` reports how much it is.
+
+  ## Parameters
+
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %{tree: String.t(), num_minted: non_neg_integer(), empty: boolean(),
+    vouchers: [Types.Voucher.t()], locked_lamports: non_neg_integer()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec check_tree_empty(tree_pubkey :: String.t(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def check_tree_empty(tree_pubkey, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    Bubblegum.check_tree_empty(tree_pubkey, retry_policy(options), rpc_url)
+    |> normalize_result()
+  end
+
+  @doc """
+  Reclaims the rent of a lookup table that is no longer needed.
+
+  Closing a table takes two transactions about 513 slots apart, so call this until the
+  `status` is `:closed`: an active table is deactivated, a table still cooling down is left
+  alone, and a table whose cooldown has passed is closed. Until then `closable_after_slot`
+  tells when to call again.
+
+  ## Parameters
+
+  * `authority_keypair_bs58` - Base58 encoded keypair of the table authority, who pays the fees
+  * `lookup_table` - Address of the lookup table
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:recipient` - Public key that receives the rent (defaults to the authority)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{lookup_table: String.t(), status: :deactivated | :cooling_down | :closed,
+    closable_after_slot: non_neg_integer() | nil, reclaimed_lamports: non_neg_integer()}}` - On
+    success, with the `signature` of the transaction when one was sent
+  * `{:error, reason}` - On failure
+  """
+  @spec reclaim_lookup_table(
+          authority_keypair_bs58 :: Types.keypair(),
+          lookup_table :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Types.Error.t()}
+  def reclaim_lookup_table(authority_keypair_bs58, lookup_table, options \\ []) do
+    tx_options = transaction_options(options)
+    recipient = Keyword.get(options, :recipient)
+    
+    Bubblegum.reclaim_lookup_table(authority_keypair_bs58, lookup_table, recipient, tx_options)
+    |> normalize_result()
+  end
+
+  @doc """
+  Creates a durable nonce account.
+
+  Transactions built with `SolanaBubblegum.Build.transaction/3` and its `:nonce` option use the
+  nonce stored in this account instead of a recent blockhash, so they stay valid until they
+  are sent, however long signing takes.
+
+  ## Parameters
+
+  * `payer_keypair_bs58` - Base58 encoded keypair of the payer, who funds the rent-exempt balance
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of the Solana RPC endpoint (defaults to Devnet)
+    * `:nonce_authority` - Public key allowed to advance the nonce (defaults to the payer)
+    * Compute budget, priority fee, send and retry options, see `SolanaBubblegum.Types.TransactionOptions`
+
+  ## Returns
+
+  * `{:ok, %{signature: String.t(), nonce_account: String.t(), nonce_authority: String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec create_nonce_account(payer_keypair_bs58 :: Types.keypair(), options :: keyword()) ::
+          {:ok, map()} | {:error, Types.Error.t()}
+  def create_nonce_account(payer_keypair_bs58, options \\ []) do
+    tx_options = transaction_options(options)
+    nonce_authority = Keyword.get(options, :nonce_authority)
+    
+    Bubblegum.create_nonce_account(payer_keypair_bs58, nonce_authority, tx_options)
+    |> normalize_result()
+  end
+
+  # Helper function to parse JSON results from the NIF
+  # NIFs return `{:ok, map}` with string keys, `{:ok, ref}` when run with `async: true`,
+  # or `{:error, %SolanaBubblegum.Types.Error{}}`
+  defp normalize_result({:ok, result}) when is_map(result), do: {:ok, atomize_keys(result)}
+  defp normalize_result(result), do: result
+
   # Helper function to convert string keys to atoms
   defp atomize_keys(map) when is_map(map) do
     Map.new(map, fn {k, v} -> {String.to_atom(k), v} end)
@@ -6,112 +6,3269 @@ defmodule SolanaBubblegum.Bubblegum do
 
   use Rustler, otp_app: :solana_bubblegum, crate: "bubblegum"
 
-  alias SolanaBubblegum.Types.MetadataArgs
+  alias SolanaBubblegum.Types.{
+    AirdropRequest,
+    ApproveCollectionAuthorityRequest,
+    AssetProof,
+    BurnRequest,
+    BurnWithDasRequest,
+    BulkMintRequest,
+    CancelDelegateRequest,
+    CancelRedeemRequest,
+    Connection,
+    CoreBurnRequest,
+    CoreCreateRequest,
+    CoreTransferRequest,
+    CreateCollectionRequest,
+    CreateTreeConfigRequest,
+    Creator,
+    DecompressRequest,
+    DelegateRequest,
+    Error,
+    HttpOptions,
+    Instruction,
+    InstructionSummary,
+    MetadataArgs,
+    MetadataRowError,
+    MintBatchToCollectionRequest,
+    MintCompleteRequest,
+    MintCounter,
+    MintRequest,
+    MintToCollectionRequest,
+    OffchainMetadata,
+    PageOptions,
+    ProofCache,
+    RateLimit,
+    RedeemRequest,
+    RetryPolicy,
+    RevokeCollectionAuthorityRequest,
+    SearchAssetsParams,
+    SendOptions,
+    SetCollectionSizeRequest,
+    SetDecompressibleStateRequest,
+    SetTreeDelegateRequest,
+    SignatureInfo,
+    SnapshotOptions,
+    TransactionOptions,
+    TransferBatchRequest,
+    TransferRequest,
+    TransferWithDasRequest,
+    TreeSubscription,
+    UpdateArgs,
+    UpdateCollectionRequest,
+    UpdateMetadataRequest,
+    UploadRequest,
+    UriCheck,
+    UriCheckOptions,
+    VerifyCollectionRequest,
+    VerifyCreatorRequest,
+    Voucher
+  }
 
   # NIF functions
   @doc """
   Creates a new Merkle tree configuration for compressed NFTs.
 
   ## Parameters
-  - payer_keypair_bs58: Base58 encoded keypair of the payer
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.CreateTreeConfigRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{tree_pubkey: String.t(), signature: String.t()}}` on success, plus `tree_keypair`
+    when the tree keypair was generated
+  - `{:error, reason}` on failure
+  """
+  @spec create_tree_config({CreateTreeConfigRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def create_tree_config(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for create_tree_config that takes the request and transaction options separately.
+  """
+  @spec create_tree_config(CreateTreeConfigRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def create_tree_config(%CreateTreeConfigRequest{} = request, %TransactionOptions{} = tx_options) do
+    create_tree_config({request, tx_options})
+  end
+
+  @doc """
+  Creates a collection NFT through Token Metadata: the mint, its metadata with sized collection
+  details and its master edition.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.CreateCollectionRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{"collection_mint" => String.t(), "metadata" => String.t(), "master_edition" => String.t(), "token_account" => String.t(), "signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec create_collection_nft({CreateCollectionRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def create_collection_nft(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for create_collection_nft that takes the request and transaction options separately.
+  """
+  @spec create_collection_nft(CreateCollectionRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def create_collection_nft(%CreateCollectionRequest{} = request, %TransactionOptions{} = tx_options) do
+    create_collection_nft({request, tx_options})
+  end
+
+  @doc """
+  Updates the Token Metadata of a collection NFT with UpdateV1.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.UpdateCollectionRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{"metadata" => String.t(), "signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec update_collection_metadata({UpdateCollectionRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def update_collection_metadata(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for update_collection_metadata that takes the request and transaction options separately.
+  """
+  @spec update_collection_metadata(UpdateCollectionRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def update_collection_metadata(%UpdateCollectionRequest{} = request, %TransactionOptions{} = tx_options) do
+    update_collection_metadata({request, tx_options})
+  end
+
+  @doc """
+  Creates a collection authority record that lets another wallet verify NFTs into a collection.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.ApproveCollectionAuthorityRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{"collection_authority_record" => String.t(), "signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec approve_collection_authority({ApproveCollectionAuthorityRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def approve_collection_authority(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for approve_collection_authority that takes the request and transaction options separately.
+  """
+  @spec approve_collection_authority(ApproveCollectionAuthorityRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def approve_collection_authority(%ApproveCollectionAuthorityRequest{} = request, %TransactionOptions{} = tx_options) do
+    approve_collection_authority({request, tx_options})
+  end
+
+  @doc """
+  Closes the collection authority record of a delegated collection authority.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.RevokeCollectionAuthorityRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{"collection_authority_record" => String.t(), "signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec revoke_collection_authority({RevokeCollectionAuthorityRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def revoke_collection_authority(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for revoke_collection_authority that takes the request and transaction options separately.
+  """
+  @spec revoke_collection_authority(RevokeCollectionAuthorityRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def revoke_collection_authority(%RevokeCollectionAuthorityRequest{} = request, %TransactionOptions{} = tx_options) do
+    revoke_collection_authority({request, tx_options})
+  end
+
+  @doc """
+  Sets the size of an unsized collection, turning it into a sized one.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.SetCollectionSizeRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec set_collection_size({SetCollectionSizeRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def set_collection_size(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for set_collection_size that takes the request and transaction options separately.
+  """
+  @spec set_collection_size(SetCollectionSizeRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def set_collection_size(%SetCollectionSizeRequest{} = request, %TransactionOptions{} = tx_options) do
+    set_collection_size({request, tx_options})
+  end
+
+  @doc """
+  Mints a new compressed NFT to a collection.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.MintToCollectionRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t(), asset_id: String.t() | nil, leaf_index: integer() | nil, nonce: integer() | nil}}`
+    on success; the asset fields are nil unless the mint was confirmed
+  - `{:error, reason}` on failure
+  """
+  @spec mint_to_collection_v1({MintToCollectionRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def mint_to_collection_v1(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for mint_to_collection_v1 that takes the request and transaction options separately.
+  """
+  @spec mint_to_collection_v1(MintToCollectionRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def mint_to_collection_v1(%MintToCollectionRequest{} = request, %TransactionOptions{} = tx_options) do
+    mint_to_collection_v1({request, tx_options})
+  end
+
+  @doc """
+  Mints several compressed NFTs to a collection, packing as many mints as fit the
+  transaction size and compute limits into each transaction.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.MintBatchToCollectionRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transactions
+
+  ## Returns
+  - `{:ok, %{signatures: [String.t()], items: [SolanaBubblegum.Types.BatchMintItem.t()]}}` on success, even when some
+    items failed
+  - `{:error, reason}` when the keypairs or accounts are invalid
+  """
+  @spec mint_batch_to_collection_v1({MintBatchToCollectionRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def mint_batch_to_collection_v1(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for mint_batch_to_collection_v1 that takes the request and transaction options separately.
+  """
+  @spec mint_batch_to_collection_v1(MintBatchToCollectionRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def mint_batch_to_collection_v1(%MintBatchToCollectionRequest{} = request, %TransactionOptions{} = tx_options) do
+    mint_batch_to_collection_v1({request, tx_options})
+  end
+
+  @doc """
+  Starts minting many compressed NFTs from a pool of background workers.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.BulkMintRequest`
+  - tx_options: RPC endpoint, compute budget and retry settings for every transaction
+
+  ## Returns
+  - `{:ok, ref}` once the job has started. The calling process then receives
+    `{:bulk_mint_progress, ref, done, total, failures}` after every transaction and finally
+    `{:bubblegum_result, ref, {:ok, %{"signatures" => [String.t()], "items" => [BatchMintItem.t()]}}}`
+  - `{:error, reason}` when the keypairs or accounts are invalid
+  """
+  @spec bulk_mint({BulkMintRequest.t(), TransactionOptions.t()}) ::
+          {:ok, reference()} | {:error, Error.t()}
+  def bulk_mint(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for bulk_mint that takes the request and transaction options separately.
+  """
+  @spec bulk_mint(BulkMintRequest.t(), TransactionOptions.t()) ::
+          {:ok, reference()} | {:error, Error.t()}
+  def bulk_mint(%BulkMintRequest{} = request, %TransactionOptions{} = tx_options) do
+    bulk_mint({request, tx_options})
+  end
+
+  @doc """
+  Starts minting one NFT of the same metadata to each of many recipients from a pool of
+  background workers.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.AirdropRequest`
+  - tx_options: RPC endpoint, compute budget and retry settings for every transaction
+
+  ## Returns
+  - `{:ok, ref}` once the job has started. The calling process then receives
+    `{:bulk_mint_progress, ref, done, total, failures}` after every transaction and finally
+    `{:bubblegum_result, ref, {:ok, %{"signatures" => [String.t()], "items" => [AirdropItem.t()]}}}`
+  - `{:error, reason}` when the keypairs, accounts or metadata are invalid
+  """
+  @spec airdrop_to_owners({AirdropRequest.t(), TransactionOptions.t()}) ::
+          {:ok, reference()} | {:error, Error.t()}
+  def airdrop_to_owners(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for airdrop_to_owners that takes the request and transaction options separately.
+  """
+  @spec airdrop_to_owners(AirdropRequest.t(), TransactionOptions.t()) ::
+          {:ok, reference()} | {:error, Error.t()}
+  def airdrop_to_owners(%AirdropRequest{} = request, %TransactionOptions{} = tx_options) do
+    airdrop_to_owners({request, tx_options})
+  end
+
+  @doc """
+  Reads the metadata of many NFTs from a JSONL or CSV file, one row at a time.
+
+  ## Parameters
+  - path: Path of the file
+  - format: `:jsonl` or `:csv`, or `nil` to go by the extension
+
+  ## Returns
+  - `{:ok, %{"metadata" => [MetadataArgs.t()], "lines" => [integer()], "errors" => [MetadataRowError.t()]}}`
+    where `lines` holds the line of each metadata in the file
+  - `{:error, reason}` when the file cannot be opened or read
+  """
+  @spec load_metadata_file({String.t(), :jsonl | :csv | nil}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def load_metadata_file(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for load_metadata_file that takes individual arguments.
+  """
+  @spec load_metadata_file(_path :: String.t(), _format :: :jsonl | :csv | nil) ::
+          {:ok, map()} | {:error, Error.t()}
+  def load_metadata_file(path, format) do
+    load_metadata_file({path, format})
+  end
+
+  @doc """
+  Builds the off-chain JSON metadata of an NFT.
+
+  ## Parameters
+  - metadata: Fields of the JSON document
+
+  ## Returns
+  - `{:ok, %{"json" => String.t()}}` on success
+  - `{:error, reason}` listing every field that breaks the token standard
+  """
+  @spec build_offchain_metadata(OffchainMetadata.t()) :: {:ok, map()} | {:error, Error.t()}
+  def build_offchain_metadata(_metadata),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Parses and checks existing off-chain JSON metadata.
+
+  ## Parameters
+  - json: The JSON document
+
+  ## Returns
+  - `{:ok, %{"metadata" => OffchainMetadata.t()}}` on success
+  - `{:error, reason}` when the JSON cannot be parsed or breaks the token standard
+  """
+  @spec validate_offchain_metadata(String.t()) :: {:ok, map()} | {:error, Error.t()}
+  def validate_offchain_metadata(_json),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Uploads data, usually off-chain metadata JSON, to permanent storage.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.UploadRequest`
+  - tx_options: RPC endpoint and send settings of the funding transaction, and the retry
+    policy of every request
+
+  ## Returns
+  - `{:ok, %{"id" => String.t(), "uri" => String.t(), "price" => integer(), "funding_signature" => String.t() | nil}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec upload_metadata({UploadRequest.t(), TransactionOptions.t()}) :: {:ok, map()} | {:error, Error.t()}
+  def upload_metadata(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for upload_metadata that takes the request and transaction options separately.
+  """
+  @spec upload_metadata(UploadRequest.t(), TransactionOptions.t()) :: {:ok, map()} | {:error, Error.t()}
+  def upload_metadata(%UploadRequest{} = request, %TransactionOptions{} = tx_options) do
+    upload_metadata({request, tx_options})
+  end
+
+  @doc """
+  Uploads the off-chain metadata of an NFT and mints the NFT against its URI.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.MintCompleteRequest`
+  - tx_options: RPC endpoint, compute budget and retry settings of the funding and mint transactions
+
+  ## Returns
+  - `{:ok, %{"asset_id" => String.t() | nil, "uri" => String.t(), "signature" => String.t(), ...}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec mint_complete({MintCompleteRequest.t(), TransactionOptions.t()}) :: {:ok, map()} | {:error, Error.t()}
+  def mint_complete(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for mint_complete that takes the request and transaction options separately.
+  """
+  @spec mint_complete(MintCompleteRequest.t(), TransactionOptions.t()) :: {:ok, map()} | {:error, Error.t()}
+  def mint_complete(%MintCompleteRequest{} = request, %TransactionOptions{} = tx_options) do
+    mint_complete({request, tx_options})
+  end
+
+  @doc """
+  Fetches metadata URIs and checks that each holds metadata JSON with a name and an image.
+
+  ## Parameters
+  - uris: Metadata URIs; `ar://` and `ipfs://` URIs are fetched through public gateways
+  - options: Timeouts and concurrency of the requests
+
+  ## Returns
+  - `{:ok, %{"checks" => [UriCheck.t()]}}` with a check per URI, in the order given
+  """
+  @spec check_metadata_uris({[String.t()], UriCheckOptions.t()}) :: {:ok, map()}
+  def check_metadata_uris(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for check_metadata_uris that takes individual arguments.
+  """
+  @spec check_metadata_uris(_uris :: [String.t()], _options :: UriCheckOptions.t()) :: {:ok, map()}
+  def check_metadata_uris(uris, %UriCheckOptions{} = options) do
+    check_metadata_uris({uris, options})
+  end
+
+  @doc """
+  Transfers a compressed NFT to a new owner.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.TransferRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec transfer({TransferRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def transfer(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for transfer that takes the request and transaction options separately.
+  """
+  @spec transfer(TransferRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def transfer(%TransferRequest{} = request, %TransactionOptions{} = tx_options) do
+    transfer({request, tx_options})
+  end
+
+  @doc """
+  Burns a compressed NFT.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.BurnRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec burn({BurnRequest.t(), TransactionOptions.t()}) :: {:ok, map()} | {:error, Error.t()}
+  def burn(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for burn that takes the request and transaction options separately.
+  """
+  @spec burn(BurnRequest.t(), TransactionOptions.t()) :: {:ok, map()} | {:error, Error.t()}
+  def burn(%BurnRequest{} = request, %TransactionOptions{} = tx_options) do
+    burn({request, tx_options})
+  end
+
+  @doc """
+  Delegates authority over a compressed NFT to a new delegate.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.DelegateRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec delegate({DelegateRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def delegate(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for delegate that takes the request and transaction options separately.
+  """
+  @spec delegate(DelegateRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def delegate(%DelegateRequest{} = request, %TransactionOptions{} = tx_options) do
+    delegate({request, tx_options})
+  end
+
+  @doc """
+  Cancels an existing delegation by delegating the compressed NFT back to its owner.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.CancelDelegateRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec cancel_delegate({CancelDelegateRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def cancel_delegate(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for cancel_delegate that takes the request and transaction options separately.
+  """
+  @spec cancel_delegate(CancelDelegateRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def cancel_delegate(%CancelDelegateRequest{} = request, %TransactionOptions{} = tx_options) do
+    cancel_delegate({request, tx_options})
+  end
+
+  @doc """
+  Redeems a compressed NFT, removing the leaf from the tree and creating a voucher for decompression.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.RedeemRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{voucher: String.t(), signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec redeem({RedeemRequest.t(), TransactionOptions.t()}) :: {:ok, map()} | {:error, Error.t()}
+  def redeem(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for redeem that takes the request and transaction options separately.
+  """
+  @spec redeem(RedeemRequest.t(), TransactionOptions.t()) :: {:ok, map()} | {:error, Error.t()}
+  def redeem(%RedeemRequest{} = request, %TransactionOptions{} = tx_options) do
+    redeem({request, tx_options})
+  end
+
+  @doc """
+  Cancels a redeem, closing the voucher and restoring the leaf to the tree.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.CancelRedeemRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{voucher: String.t(), signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec cancel_redeem({CancelRedeemRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def cancel_redeem(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for cancel_redeem that takes the request and transaction options separately.
+  """
+  @spec cancel_redeem(CancelRedeemRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def cancel_redeem(%CancelRedeemRequest{} = request, %TransactionOptions{} = tx_options) do
+    cancel_redeem({request, tx_options})
+  end
+
+  @doc """
+  Decompresses a redeemed leaf into a regular Token Metadata NFT.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.DecompressRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{mint: String.t(), signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec decompress_v1({DecompressRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def decompress_v1(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for decompress_v1 that takes the request and transaction options separately.
+  """
+  @spec decompress_v1(DecompressRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def decompress_v1(%DecompressRequest{} = request, %TransactionOptions{} = tx_options) do
+    decompress_v1({request, tx_options})
+  end
+
+  @doc """
+  Mints a new compressed NFT without a collection.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.MintRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t(), asset_id: String.t() | nil, leaf_index: integer() | nil, nonce: integer() | nil}}`
+    on success; the asset fields are nil unless the mint was confirmed
+  - `{:error, reason}` on failure
+  """
+  @spec mint_v1({MintRequest.t(), TransactionOptions.t()}) :: {:ok, map()} | {:error, Error.t()}
+  def mint_v1(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for mint_v1 that takes the request and transaction options separately.
+  """
+  @spec mint_v1(MintRequest.t(), TransactionOptions.t()) :: {:ok, map()} | {:error, Error.t()}
+  def mint_v1(%MintRequest{} = request, %TransactionOptions{} = tx_options) do
+    mint_v1({request, tx_options})
+  end
+
+  @doc """
+  Marks a creator of a compressed NFT as verified.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.VerifyCreatorRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec verify_creator({VerifyCreatorRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def verify_creator(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for verify_creator that takes the request and transaction options separately.
+  """
+  @spec verify_creator(VerifyCreatorRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def verify_creator(%VerifyCreatorRequest{} = request, %TransactionOptions{} = tx_options) do
+    verify_creator({request, tx_options})
+  end
+
+  @doc """
+  Marks a creator of a compressed NFT as unverified.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.VerifyCreatorRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec unverify_creator({VerifyCreatorRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def unverify_creator(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for unverify_creator that takes the request and transaction options separately.
+  """
+  @spec unverify_creator(VerifyCreatorRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def unverify_creator(%VerifyCreatorRequest{} = request, %TransactionOptions{} = tx_options) do
+    unverify_creator({request, tx_options})
+  end
+
+  @doc """
+  Verifies a compressed NFT as a member of its collection.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.VerifyCollectionRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec verify_collection({VerifyCollectionRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def verify_collection(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for verify_collection that takes the request and transaction options separately.
+  """
+  @spec verify_collection(VerifyCollectionRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def verify_collection(%VerifyCollectionRequest{} = request, %TransactionOptions{} = tx_options) do
+    verify_collection({request, tx_options})
+  end
+
+  @doc """
+  Unverifies a compressed NFT as a member of its collection.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.VerifyCollectionRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec unverify_collection({VerifyCollectionRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def unverify_collection(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for unverify_collection that takes the request and transaction options separately.
+  """
+  @spec unverify_collection(VerifyCollectionRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def unverify_collection(%VerifyCollectionRequest{} = request, %TransactionOptions{} = tx_options) do
+    unverify_collection({request, tx_options})
+  end
+
+  @doc """
+  Sets the collection of a compressed NFT and verifies it in one step.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.VerifyCollectionRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec set_and_verify_collection({VerifyCollectionRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def set_and_verify_collection(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for set_and_verify_collection that takes the request and transaction options separately.
+  """
+  @spec set_and_verify_collection(VerifyCollectionRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def set_and_verify_collection(%VerifyCollectionRequest{} = request, %TransactionOptions{} = tx_options) do
+    set_and_verify_collection({request, tx_options})
+  end
+
+  @doc """
+  Updates the metadata of a mutable compressed NFT.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.UpdateMetadataRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec update_metadata({UpdateMetadataRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def update_metadata(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for update_metadata that takes the request and transaction options separately.
+  """
+  @spec update_metadata(UpdateMetadataRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def update_metadata(%UpdateMetadataRequest{} = request, %TransactionOptions{} = tx_options) do
+    update_metadata({request, tx_options})
+  end
+
+  @doc """
+  Sets the delegate allowed to mint into a Merkle tree.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.SetTreeDelegateRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec set_tree_delegate({SetTreeDelegateRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def set_tree_delegate(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for set_tree_delegate that takes the request and transaction options separately.
+  """
+  @spec set_tree_delegate(SetTreeDelegateRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def set_tree_delegate(%SetTreeDelegateRequest{} = request, %TransactionOptions{} = tx_options) do
+    set_tree_delegate({request, tx_options})
+  end
+
+  @doc """
+  Enables or disables decompression for the leaves of a Merkle tree.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.SetDecompressibleStateRequest`
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec set_decompressible_state({SetDecompressibleStateRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def set_decompressible_state(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for set_decompressible_state that takes the request and transaction options separately.
+  """
+  @spec set_decompressible_state(SetDecompressibleStateRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def set_decompressible_state(%SetDecompressibleStateRequest{} = request, %TransactionOptions{} = tx_options) do
+    set_decompressible_state({request, tx_options})
+  end
+
+  @doc """
+  Fetches an asset from a DAS-enabled RPC endpoint.
+
+  ## Parameters
+  - asset_id: Asset ID to look up
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.Asset{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_asset(
+          {String.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_asset(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_asset that takes individual arguments.
+  """
+  @spec get_asset(
+          _asset_id :: String.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_asset(asset_id, retry, rpc_url) do
+    get_asset({asset_id, retry, rpc_url})
+  end
+
+  @doc """
+  Fetches the Merkle proof of an asset from a DAS-enabled RPC endpoint.
+
+  ## Parameters
+  - asset_id: Asset ID to look up
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.DasAssetProof{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_asset_proof(
+          {String.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_asset_proof(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_asset_proof that takes individual arguments.
+  """
+  @spec get_asset_proof(
+          _asset_id :: String.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_asset_proof(asset_id, retry, rpc_url) do
+    get_asset_proof({asset_id, retry, rpc_url})
+  end
+
+  @doc """
+  Transfers a compressed NFT, fetching its leaf data and proof from DAS.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.TransferWithDasRequest`
+  - tx_options: DAS-enabled RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec transfer_with_das({TransferWithDasRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def transfer_with_das(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for transfer_with_das that takes the request and transaction options separately.
+  """
+  @spec transfer_with_das(TransferWithDasRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def transfer_with_das(%TransferWithDasRequest{} = request, %TransactionOptions{} = tx_options) do
+    transfer_with_das({request, tx_options})
+  end
+
+  @doc """
+  Transfers many compressed NFTs, fetching their leaf data and Merkle proofs from the DAS API
+  and packing the transfers into as few transactions as fit.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.TransferBatchRequest`
+  - tx_options: DAS-enabled RPC endpoint and compute budget settings of every transaction
+
+  ## Returns
+  - `{:ok, %{"signatures" => [String.t()], "items" => [SolanaBubblegum.Types.TransferBatchItem.t()], ...}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec transfer_batch({TransferBatchRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def transfer_batch(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for transfer_batch that takes the request and transaction options separately.
+  """
+  @spec transfer_batch(TransferBatchRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def transfer_batch(%TransferBatchRequest{} = request, %TransactionOptions{} = tx_options) do
+    transfer_batch({request, tx_options})
+  end
+
+  @doc """
+  Creates an uncompressed Metaplex Core asset.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.CoreCreateRequest`
+  - tx_options: RPC endpoint, compute budget and retry settings of the transaction
+
+  ## Returns
+  - `{:ok, %{"asset" => String.t(), "signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec create_core_asset({CoreCreateRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def create_core_asset(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for create_core_asset that takes the request and transaction options separately.
+  """
+  @spec create_core_asset(CoreCreateRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def create_core_asset(%CoreCreateRequest{} = request, %TransactionOptions{} = tx_options) do
+    create_core_asset({request, tx_options})
+  end
+
+  @doc """
+  Transfers a Metaplex Core asset.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.CoreTransferRequest`
+  - tx_options: RPC endpoint, compute budget and retry settings of the transaction
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec transfer_core_asset({CoreTransferRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def transfer_core_asset(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for transfer_core_asset that takes the request and transaction options separately.
+  """
+  @spec transfer_core_asset(CoreTransferRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def transfer_core_asset(%CoreTransferRequest{} = request, %TransactionOptions{} = tx_options) do
+    transfer_core_asset({request, tx_options})
+  end
+
+  @doc """
+  Burns a Metaplex Core asset, closing its account.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.CoreBurnRequest`
+  - tx_options: RPC endpoint, compute budget and retry settings of the transaction
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec burn_core_asset({CoreBurnRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def burn_core_asset(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for burn_core_asset that takes the request and transaction options separately.
+  """
+  @spec burn_core_asset(CoreBurnRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def burn_core_asset(%CoreBurnRequest{} = request, %TransactionOptions{} = tx_options) do
+    burn_core_asset({request, tx_options})
+  end
+
+  @doc """
+  Burns a compressed NFT, fetching its leaf data and proof from DAS.
+
+  ## Parameters
+  - request: Named arguments of the operation, see `SolanaBubblegum.Types.BurnWithDasRequest`
+  - tx_options: DAS-enabled RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec burn_with_das({BurnWithDasRequest.t(), TransactionOptions.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def burn_with_das(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for burn_with_das that takes the request and transaction options separately.
+  """
+  @spec burn_with_das(BurnWithDasRequest.t(), TransactionOptions.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def burn_with_das(%BurnWithDasRequest{} = request, %TransactionOptions{} = tx_options) do
+    burn_with_das({request, tx_options})
+  end
+
+  @doc """
+  Lists the assets owned by a wallet from a DAS-enabled RPC endpoint.
+
+  ## Parameters
+  - owner: Public key of the owner
+  - page_options: Pagination options
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.AssetList{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_assets_by_owner(
+          {String.t(), PageOptions.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_assets_by_owner(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_assets_by_owner that takes individual arguments.
+  """
+  @spec get_assets_by_owner(
+          _owner :: String.t(),
+          _page_options :: PageOptions.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_assets_by_owner(owner, page_options, retry, rpc_url) do
+    get_assets_by_owner({owner, page_options, retry, rpc_url})
+  end
+
+  @doc """
+  Lists the assets in a group (e.g. a collection) from a DAS-enabled RPC endpoint.
+
+  ## Parameters
+  - group_key: Group key, e.g. "collection"
+  - group_value: Group value, e.g. the collection mint
+  - page_options: Pagination and sorting options
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.AssetList{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_assets_by_group(
+          {String.t(), String.t(), PageOptions.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_assets_by_group(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_assets_by_group that takes individual arguments.
+  """
+  @spec get_assets_by_group(
+          _group_key :: String.t(),
+          _group_value :: String.t(),
+          _page_options :: PageOptions.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_assets_by_group(group_key, group_value, page_options, retry, rpc_url) do
+    get_assets_by_group({group_key, group_value, page_options, retry, rpc_url})
+  end
+
+  @doc """
+  Lists the assets of a creator from a DAS-enabled RPC endpoint.
+
+  ## Parameters
+  - creator: Public key of the creator
+  - only_verified: Whether to only return assets where the creator is verified
+  - page_options: Pagination and sorting options
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.AssetList{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_assets_by_creator(
+          {String.t(), boolean(), PageOptions.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_assets_by_creator(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_assets_by_creator that takes individual arguments.
+  """
+  @spec get_assets_by_creator(
+          _creator :: String.t(),
+          _only_verified :: boolean(),
+          _page_options :: PageOptions.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_assets_by_creator(creator, only_verified, page_options, retry, rpc_url) do
+    get_assets_by_creator({creator, only_verified, page_options, retry, rpc_url})
+  end
+
+  @doc """
+  Searches assets with compound filters on a DAS-enabled RPC endpoint.
+
+  ## Parameters
+  - search_params: Search filters
+  - page_options: Pagination and sorting options
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.AssetList{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec search_assets(
+          {SearchAssetsParams.t(), PageOptions.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def search_assets(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for search_assets that takes individual arguments.
+  """
+  @spec search_assets(
+          _search_params :: SearchAssetsParams.t(),
+          _page_options :: PageOptions.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def search_assets(search_params, page_options, retry, rpc_url) do
+    search_assets({search_params, page_options, retry, rpc_url})
+  end
+
+  @doc """
+  Fetches the royalty of an asset and its creator splits from a DAS-enabled RPC endpoint.
+
+  ## Parameters
+  - asset_id: Asset ID to look up
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.RoyaltyInfo{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_royalty_info(
+          {String.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_royalty_info(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_royalty_info that takes individual arguments.
+  """
+  @spec get_royalty_info(
+          _asset_id :: String.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_royalty_info(asset_id, retry, rpc_url) do
+    get_royalty_info({asset_id, retry, rpc_url})
+  end
+
+  @doc """
+  Checks with a DAS-enabled RPC endpoint whether an asset belongs to an expected owner.
+
+  ## Parameters
+  - asset_id: Asset ID to look up
+  - expected_owner: Public key the asset should belong to
+  - require_active: Whether a burnt or frozen asset counts as not owned
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.OwnershipCheck{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec verify_ownership(
+          {String.t(), String.t(), boolean(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def verify_ownership(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for verify_ownership that takes individual arguments.
+  """
+  @spec verify_ownership(
+          _asset_id :: String.t(),
+          _expected_owner :: String.t(),
+          _require_active :: boolean(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def verify_ownership(asset_id, expected_owner, require_active, retry, rpc_url) do
+    verify_ownership({asset_id, expected_owner, require_active, retry, rpc_url})
+  end
+
+  @doc """
+  Counts the assets each owner holds in a collection from a DAS-enabled RPC endpoint.
+
+  ## Parameters
+  - collection_mint: Mint of the collection NFT
+  - options: Where to start, how many pages to fetch and which assets count
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of a DAS-enabled Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.HolderSnapshot{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec snapshot_holders(
+          {String.t(), SnapshotOptions.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def snapshot_holders(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for snapshot_holders that takes individual arguments.
+  """
+  @spec snapshot_holders(
+          _collection_mint :: String.t(),
+          _options :: SnapshotOptions.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def snapshot_holders(collection_mint, options, retry, rpc_url) do
+    snapshot_holders({collection_mint, options, retry, rpc_url})
+  end
+
+  @doc """
+  Derives the asset ID of a leaf locally, without an RPC call.
+
+  ## Parameters
+  - tree_pubkey: Public key of the Merkle tree
+  - nonce: Nonce (leaf index at mint time) of the leaf
+
+  ## Returns
+  - `{:ok, %{asset_id: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_asset_id(
+          {String.t(), non_neg_integer()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_asset_id(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_asset_id that takes individual arguments.
+  """
+  @spec get_asset_id(
+          _tree_pubkey :: String.t(),
+          _nonce :: non_neg_integer()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_asset_id(tree_pubkey, nonce) do
+    get_asset_id({tree_pubkey, nonce})
+  end
+
+  @doc """
+  Computes the data hash of a leaf from its metadata.
+
+  ## Parameters
+  - metadata_args: Metadata of the leaf
+
+  ## Returns
+  - `{:ok, %{data_hash: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec hash_metadata(MetadataArgs.t()) :: {:ok, map()} | {:error, Error.t()}
+  def hash_metadata(_metadata_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Generates a new random keypair.
+
+  ## Returns
+  - `{:ok, %{"keypair" => String.t(), "pubkey" => String.t()}}`
+  """
+  @spec generate_keypair() :: {:ok, map()}
+  def generate_keypair(),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Derives a keypair from a seed of at least 32 bytes.
+
+  ## Parameters
+  - seed: Seed bytes; the first 32 become the secret key
+
+  ## Returns
+  - `{:ok, %{"keypair" => String.t(), "pubkey" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec keypair_from_seed(binary()) :: {:ok, map()} | {:error, Error.t()}
+  def keypair_from_seed(_seed),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Loads a keypair into a resource whose bytes are wiped when it is garbage collected.
+
+  ## Parameters
+  - keypair: Keypair in any of the accepted formats
+
+  ## Returns
+  - `{:ok, %{"secret_key" => SecretKey.t(), "pubkey" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec load_secret_key(SolanaBubblegum.Types.keypair()) :: {:ok, map()} | {:error, Error.t()}
+  def load_secret_key(_keypair),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Registers a keypair under a name, replacing any keypair registered under it before.
+
+  ## Parameters
+  - name: Atom or string naming the signer
+  - keypair: Keypair in any of the accepted formats
+
+  ## Returns
+  - `{:ok, %{"pubkey" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec register_signer({atom() | String.t(), SolanaBubblegum.Types.keypair()}) :: {:ok, map()} | {:error, Error.t()}
+  def register_signer(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for register_signer that takes individual arguments.
+  """
+  @spec register_signer(_name :: atom() | String.t(), _keypair :: SolanaBubblegum.Types.keypair()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def register_signer(name, keypair) do
+    register_signer({name, keypair})
+  end
+
+  @doc """
+  Removes a registered signer and wipes its keypair.
+
+  ## Parameters
+  - name: Atom or string the signer was registered under
+
+  ## Returns
+  - `:ok`
+  """
+  @spec unregister_signer(atom() | String.t()) :: :ok | {:error, Error.t()}
+  def unregister_signer(_name),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Lists the registered signers.
+
+  ## Returns
+  - `{:ok, %{String.t() => String.t()}}` mapping each name to its public key
+  """
+  @spec list_signers() :: {:ok, map()}
+  def list_signers(),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Sets the process that receives a message for every RPC request and transaction.
+
+  ## Parameters
+  - pid: Process to send `{:bubblegum_telemetry, event, measurements, metadata}` to, or nil to stop reporting
+
+  ## Returns
+  - `:ok`
+  """
+  @spec set_telemetry_handler(pid() | nil) :: :ok
+  def set_telemetry_handler(_pid),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Sets the process that receives the log records of the native library.
+
+  ## Parameters
+  - pid: Process to send `{:bubblegum_log, level, target, message, fields}` to, or nil to stop forwarding
+
+  ## Returns
+  - `:ok`
+  """
+  @spec set_log_handler(pid() | nil) :: :ok
+  def set_log_handler(_pid),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Sets the lowest level of the log records the native library forwards.
+
+  ## Parameters
+  - level: `:error`, `:warning`, `:info`, `:debug`, `:trace` or `:none`
+
+  ## Returns
+  - `:ok`
+  """
+  @spec set_log_level(:error | :warning | :info | :debug | :trace | :none) :: :ok
+  def set_log_level(_level),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Derives a keypair from a BIP39 mnemonic along the BIP44 path `m/44'/501'/account'/change'`.
+
+  ## Parameters
+  - mnemonic: English BIP39 mnemonic
+  - passphrase: BIP39 passphrase, empty for none
+  - account: Account index of the path
+  - change: Change index of the path, or nil for `m/44'/501'/account'`
+
+  ## Returns
+  - `{:ok, %{"keypair" => String.t(), "pubkey" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec keypair_from_mnemonic({String.t(), String.t(), non_neg_integer(), non_neg_integer() | nil}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def keypair_from_mnemonic(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for keypair_from_mnemonic that takes individual arguments.
+  """
+  @spec keypair_from_mnemonic(
+          _mnemonic :: String.t(),
+          _passphrase :: String.t(),
+          _account :: non_neg_integer(),
+          _change :: non_neg_integer() | nil
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def keypair_from_mnemonic(mnemonic, passphrase, account, change) do
+    keypair_from_mnemonic({mnemonic, passphrase, account, change})
+  end
+
+  @doc """
+  Encrypts a keypair under a passphrase with Argon2id and XChaCha20-Poly1305.
+
+  ## Parameters
+  - keypair: Keypair in any of the accepted formats
+  - passphrase: Passphrase the encryption key is derived from
+
+  ## Returns
+  - `{:ok, String.t()}` with the Base64 encoded encrypted keypair on success
+  - `{:error, reason}` on failure
+  """
+  @spec encrypt_keypair({SolanaBubblegum.Types.keypair(), String.t()}) :: {:ok, String.t()} | {:error, Error.t()}
+  def encrypt_keypair(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for encrypt_keypair that takes individual arguments.
+  """
+  @spec encrypt_keypair(_keypair :: SolanaBubblegum.Types.keypair(), _passphrase :: String.t()) ::
+          {:ok, String.t()} | {:error, Error.t()}
+  def encrypt_keypair(keypair, passphrase) do
+    encrypt_keypair({keypair, passphrase})
+  end
+
+  @doc """
+  Decrypts a keypair encrypted by encrypt_keypair.
+
+  ## Parameters
+  - encrypted_keypair: Base64 encoded encrypted keypair
+  - passphrase: Passphrase it was encrypted under
+
+  ## Returns
+  - `{:ok, %{"keypair" => String.t(), "pubkey" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec decrypt_keypair({String.t(), String.t()}) :: {:ok, map()} | {:error, Error.t()}
+  def decrypt_keypair(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for decrypt_keypair that takes individual arguments.
+  """
+  @spec decrypt_keypair(_encrypted_keypair :: String.t(), _passphrase :: String.t()) ::
+          {:ok, map()} | {:error, Error.t()}
+  def decrypt_keypair(encrypted_keypair, passphrase) do
+    decrypt_keypair({encrypted_keypair, passphrase})
+  end
+
+  @doc """
+  Computes the creator hash of a leaf from its creators.
+
+  ## Parameters
+  - creators: Creators of the leaf
+
+  ## Returns
+  - `{:ok, %{creator_hash: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec hash_creators([Creator.t()]) :: {:ok, map()} | {:error, Error.t()}
+  def hash_creators(_creators),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Computes the hash of a V1 leaf as stored in the Merkle tree.
+
+  ## Parameters
+  - asset_id: Asset ID of the leaf
+  - owner: Public key of the leaf owner
+  - delegate: Public key of the leaf delegate
+  - nonce: Nonce of the leaf
+  - data_hash: Base58 encoded data hash
+  - creator_hash: Base58 encoded creator hash
+
+  ## Returns
+  - `{:ok, %{leaf_hash: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec compute_leaf_hash(
+          {String.t(), String.t(), String.t(), non_neg_integer(), String.t(), String.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def compute_leaf_hash(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for compute_leaf_hash that takes individual arguments.
+  """
+  @spec compute_leaf_hash(
+          _asset_id :: String.t(),
+          _owner :: String.t(),
+          _delegate :: String.t(),
+          _nonce :: non_neg_integer(),
+          _data_hash :: String.t(),
+          _creator_hash :: String.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def compute_leaf_hash(asset_id, owner, delegate, nonce, data_hash, creator_hash) do
+    compute_leaf_hash({asset_id, owner, delegate, nonce, data_hash, creator_hash})
+  end
+
+  @doc """
+  Verifies a Merkle proof locally.
+
+  ## Parameters
+  - root: Base58 encoded root the proof should lead to
+  - leaf: Base58 encoded leaf hash
+  - proof: Base58 encoded proof nodes, from the leaf level up
+  - index: Index of the leaf in the tree
+
+  ## Returns
+  - `{:ok, %{valid: boolean(), computed_root: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec verify_proof(
+          {String.t(), String.t(), [String.t()], non_neg_integer()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def verify_proof(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for verify_proof that takes individual arguments.
+  """
+  @spec verify_proof(
+          _root :: String.t(),
+          _leaf :: String.t(),
+          _proof :: [String.t()],
+          _index :: non_neg_integer()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def verify_proof(root, leaf, proof, index) do
+    verify_proof({root, leaf, proof, index})
+  end
+
+  @doc """
+  Computes how many leaves a Merkle tree holds and what its account costs.
+
+  ## Parameters
+  - max_depth: Maximum depth of the tree
+  - max_buffer_size: Maximum concurrent changes buffered by the tree
+  - canopy_depth: Depth of the cached canopy
+  - lamports_per_byte_year: Rent rate to compute with, or nil for the default rent
+  - rpc_url: RPC endpoint or connection to fetch the rent from, or nil to compute it locally
+
+  ## Returns
+  - `{:ok, %{"capacity" => integer(), "account_size" => integer(), "rent_exempt_lamports" => integer()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec calculate_tree_cost(
+          {non_neg_integer(), pos_integer(), non_neg_integer(), pos_integer() | nil,
+           String.t() | Connection.t() | nil}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def calculate_tree_cost(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for calculate_tree_cost that takes individual arguments.
+  """
+  @spec calculate_tree_cost(
+          _max_depth :: non_neg_integer(),
+          _max_buffer_size :: pos_integer(),
+          _canopy_depth :: non_neg_integer(),
+          _lamports_per_byte_year :: pos_integer() | nil,
+          _rpc_url :: String.t() | Connection.t() | nil
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def calculate_tree_cost(max_depth, max_buffer_size, canopy_depth, lamports_per_byte_year, rpc_url) do
+    calculate_tree_cost({max_depth, max_buffer_size, canopy_depth, lamports_per_byte_year, rpc_url})
+  end
+
+  @doc """
+  Suggests tree shapes that hold a number of NFTs, cheapest first.
+
+  ## Parameters
+  - nft_count: NFTs the tree has to hold
+  - concurrency: Changes to the tree expected within the same slot
+  - lamports_per_byte_year: Rent rate to compute with, or nil for the default rent
+  - rpc_url: RPC endpoint or connection to fetch the rent from, or nil to compute it locally
+
+  ## Returns
+  - `{:ok, %{"recommendations" => [SolanaBubblegum.Types.TreeRecommendation.t()]}}` on success
+  - `{:error, reason}` when no valid tree is large enough
+  """
+  @spec recommend_tree_config(
+          {pos_integer(), pos_integer(), pos_integer() | nil, String.t() | Connection.t() | nil}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def recommend_tree_config(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for recommend_tree_config that takes individual arguments.
+  """
+  @spec recommend_tree_config(
+          _nft_count :: pos_integer(),
+          _concurrency :: pos_integer(),
+          _lamports_per_byte_year :: pos_integer() | nil,
+          _rpc_url :: String.t() | Connection.t() | nil
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def recommend_tree_config(nft_count, concurrency, lamports_per_byte_year, rpc_url) do
+    recommend_tree_config({nft_count, concurrency, lamports_per_byte_year, rpc_url})
+  end
+
+  @doc """
+  Fetches a confirmed transaction and decodes the compression events logged through the noop program.
+
+  ## Parameters
+  - signature: Signature of the transaction
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t(), "slot" => integer(), "leaf_schema_events" => list(), "change_log_events" => list()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec parse_transaction_events(
+          {String.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def parse_transaction_events(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for parse_transaction_events that takes individual arguments.
+  """
+  @spec parse_transaction_events(
+          _signature :: String.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def parse_transaction_events(signature, retry, rpc_url) do
+    parse_transaction_events({signature, retry, rpc_url})
+  end
+
+  @doc """
+  Lists the confirmed transactions that touched an address, newest first.
+
+  ## Parameters
+  - address: Public key of the account, e.g. a Merkle tree
+  - before: Signature to start listing before, or nil to start at the newest transaction
+  - until: Signature to stop listing at, or nil
+  - limit: Maximum number of signatures, up to 1000, or nil for the RPC default of 1000
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %{"address" => String.t(), "signatures" => [SignatureInfo.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_signatures_for_address(
+          {String.t(), String.t() | nil, String.t() | nil, pos_integer() | nil, RetryPolicy.t(),
+           String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_signatures_for_address(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_signatures_for_address that takes individual arguments.
+  """
+  @spec get_signatures_for_address(
+          _address :: String.t(),
+          _before :: String.t() | nil,
+          _until :: String.t() | nil,
+          _limit :: pos_integer() | nil,
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_signatures_for_address(address, before, until, limit, retry, rpc_url) do
+    get_signatures_for_address({address, before, until, limit, retry, rpc_url})
+  end
+
+  @doc """
+  Fetches a confirmed transaction with a summary of its instructions and its compression events.
+
+  ## Parameters
+  - signature: Signature of the transaction
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t(), "slot" => integer(), "block_time" => integer() | nil, "fee" => integer(), "error" => Error.t() | nil, "logs" => [String.t()], "instructions" => [InstructionSummary.t()], "leaf_schema_events" => list(), "change_log_events" => list()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_transaction({String.t(), RetryPolicy.t(), String.t() | Connection.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def get_transaction(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_transaction that takes individual arguments.
+  """
+  @spec get_transaction(
+          _signature :: String.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_transaction(signature, retry, rpc_url) do
+    get_transaction({signature, retry, rpc_url})
+  end
+
+  @doc """
+  Fetches and decodes the Bubblegum tree config of a Merkle tree.
+
+  ## Parameters
+  - tree_pubkey: Public key of the Merkle tree
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.TreeConfigAccount{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_tree_config(
+          {String.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_tree_config(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_tree_config that takes individual arguments.
+  """
+  @spec get_tree_config(
+          _tree_pubkey :: String.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_tree_config(tree_pubkey, retry, rpc_url) do
+    get_tree_config({tree_pubkey, retry, rpc_url})
+  end
+
+  @doc """
+  Fetches and decodes the header and current state of a concurrent Merkle tree.
+
+  ## Parameters
+  - tree_pubkey: Public key of the Merkle tree
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %SolanaBubblegum.Types.MerkleTreeState{}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_merkle_tree_state(
+          {String.t(), RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_merkle_tree_state(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_merkle_tree_state that takes individual arguments.
+  """
+  @spec get_merkle_tree_state(
+          _tree_pubkey :: String.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_merkle_tree_state(tree_pubkey, retry, rpc_url) do
+    get_merkle_tree_state({tree_pubkey, retry, rpc_url})
+  end
+
+  @doc """
+  Requests an airdrop from the faucet of the cluster, on devnet and testnet.
+
+  ## Parameters
+  - pubkey: Public key of the wallet to fund
+  - lamports: Amount to request
+  - timeout_ms: How long to wait for the airdrop to be confirmed, 0 to not wait
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t(), "confirmed" => boolean(), "slot" => non_neg_integer() | nil}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec request_airdrop({String.t(), non_neg_integer(), non_neg_integer(), String.t() | Connection.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def request_airdrop(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for request_airdrop that takes individual arguments.
+  """
+  @spec request_airdrop(
+          _pubkey :: String.t(),
+          _lamports :: non_neg_integer(),
+          _timeout_ms :: non_neg_integer(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def request_airdrop(pubkey, lamports, timeout_ms, rpc_url) do
+    request_airdrop({pubkey, lamports, timeout_ms, rpc_url})
+  end
+
+  @doc """
+  Fetches the balance of an account.
+
+  ## Parameters
+  - pubkey: Public key of the account
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %{"pubkey" => String.t(), "lamports" => non_neg_integer()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_balance({String.t(), RetryPolicy.t(), String.t() | Connection.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def get_balance(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_balance that takes individual arguments.
+  """
+  @spec get_balance(
+          _pubkey :: String.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_balance(pubkey, retry, rpc_url) do
+    get_balance({pubkey, retry, rpc_url})
+  end
+
+  @doc """
+  Reads an arbitrary account.
+
+  ## Parameters
+  - pubkey: Public key of the account
+  - encoding: `:base64` to return the data Base64 encoded, `:binary` for the raw bytes
+  - retry: Retry policy for rate limits, server errors and timeouts
+  - rpc_url: URL of the Solana RPC endpoint, or a `SolanaBubblegum.Types.Connection`
+
+  ## Returns
+  - `{:ok, %{"pubkey" => String.t(), "lamports" => non_neg_integer(), "owner" => String.t(), "executable" => boolean(), "rent_epoch" => non_neg_integer(), "data" => binary()}}` on success
+  - `{:ok, nil}` if the account does not exist
+  - `{:error, reason}` on failure
+  """
+  @spec get_account_info({String.t(), :base64 | :binary, RetryPolicy.t(), String.t() | Connection.t()}) ::
+          {:ok, map() | nil} | {:error, Error.t()}
+  def get_account_info(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_account_info that takes individual arguments.
+  """
+  @spec get_account_info(
+          _pubkey :: String.t(),
+          _encoding :: :base64 | :binary,
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map() | nil} | {:error, Error.t()}
+  def get_account_info(pubkey, encoding, retry, rpc_url) do
+    get_account_info({pubkey, encoding, retry, rpc_url})
+  end
+
+  @doc """
+  Transfers SOL from a keypair to an account.
+
+  ## Parameters
+  - from_keypair_bs58: Base58 encoded keypair of the sender, who also pays the fees
+  - to: Public key of the recipient
+  - lamports: Amount to transfer, in lamports
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t(), "from" => String.t(), "to" => String.t(), "lamports" => pos_integer()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec transfer_sol(
+          {String.t(), String.t(), pos_integer(), TransactionOptions.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def transfer_sol(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for transfer_sol that takes individual arguments.
+  """
+  @spec transfer_sol(
+          _from_keypair_bs58 :: String.t(),
+          _to :: String.t(),
+          _lamports :: pos_integer(),
+          _tx_options :: TransactionOptions.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def transfer_sol(from_keypair_bs58, to, lamports, tx_options) do
+    transfer_sol({from_keypair_bs58, to, lamports, tx_options})
+  end
+
+  @doc """
+  Builds the instructions that allocate a Merkle tree account and create its tree config, without sending them.
+  The tree account keypair has to sign the resulting transaction as well as the payer.
+
+  ## Parameters
+  - payer: Public key of the payer, also used as the tree creator
+  - tree_pubkey: Public key of the new Merkle tree account
   - max_depth: Maximum depth of the Merkle tree
   - max_buffer_size: Maximum buffer size for the Merkle tree
-  - canopy_depth: Depth of the canopy
-  - public: Whether the tree is public
-  - rpc_url: URL of the Solana RPC endpoint
+  - canopy_depth: Canopy depth for the Merkle tree
+  - public: Whether the tree is public or not
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()], "tree_pubkey" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_create_tree_config(
+          {String.t(), String.t(), non_neg_integer(), non_neg_integer(), non_neg_integer(), boolean()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_create_tree_config(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_create_tree_config that takes individual arguments.
+  """
+  @spec build_create_tree_config(
+          _payer :: String.t(),
+          _tree_pubkey :: String.t(),
+          _max_depth :: non_neg_integer(),
+          _max_buffer_size :: non_neg_integer(),
+          _canopy_depth :: non_neg_integer(),
+          _public :: boolean()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_create_tree_config(payer, tree_pubkey, max_depth, max_buffer_size, canopy_depth, public) do
+    build_create_tree_config({payer, tree_pubkey, max_depth, max_buffer_size, canopy_depth, public})
+  end
+
+  @doc """
+  Builds the instruction that mints a compressed NFT without a collection.
+
+  ## Parameters
+  - payer: Public key of the payer, tree creator and leaf owner
+  - tree_pubkey: Public key of the Merkle tree
+  - metadata_args: Metadata for the NFT
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_mint_v1(
+          {String.t(), String.t(), MetadataArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_mint_v1(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_mint_v1 that takes individual arguments.
+  """
+  @spec build_mint_v1(
+          _payer :: String.t(),
+          _tree_pubkey :: String.t(),
+          _metadata_args :: MetadataArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_mint_v1(payer, tree_pubkey, metadata_args) do
+    build_mint_v1({payer, tree_pubkey, metadata_args})
+  end
+
+  @doc """
+  Builds the instruction that mints a compressed NFT into a collection.
+
+  ## Parameters
+  - payer: Public key of the payer, tree creator, collection authority and leaf owner
+  - tree_pubkey: Public key of the Merkle tree
+  - collection_pubkey: Public key of the collection mint
+  - metadata_args: Metadata for the NFT
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_mint_to_collection_v1(
+          {String.t(), String.t(), String.t(), MetadataArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_mint_to_collection_v1(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_mint_to_collection_v1 that takes individual arguments.
+  """
+  @spec build_mint_to_collection_v1(
+          _payer :: String.t(),
+          _tree_pubkey :: String.t(),
+          _collection_pubkey :: String.t(),
+          _metadata_args :: MetadataArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_mint_to_collection_v1(payer, tree_pubkey, collection_pubkey, metadata_args) do
+    build_mint_to_collection_v1({payer, tree_pubkey, collection_pubkey, metadata_args})
+  end
+
+  @doc """
+  Builds the instruction that transfers a compressed NFT.
+
+  ## Parameters
+  - authority: Public key of the signer, either the leaf owner or the leaf delegate
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf: Tuple of the leaf owner and the optional leaf delegate public keys
+  - new_owner: Public key of the new owner
+  - asset_proof: Merkle proof data for the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_transfer(
+          {String.t(), String.t(), {String.t(), String.t() | nil}, String.t(), AssetProof.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_transfer(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_transfer that takes individual arguments.
+  """
+  @spec build_transfer(
+          _authority :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf :: {String.t(), String.t() | nil},
+          _new_owner :: String.t(),
+          _asset_proof :: AssetProof.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_transfer(authority, tree_pubkey, leaf, new_owner, asset_proof) do
+    build_transfer({authority, tree_pubkey, leaf, new_owner, asset_proof})
+  end
+
+  @doc """
+  Builds the instruction that burns a compressed NFT.
+
+  ## Parameters
+  - authority: Public key of the signer, either the leaf owner or the leaf delegate
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf: Tuple of the leaf owner and the optional leaf delegate public keys
+  - asset_proof: Merkle proof data for the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_burn(
+          {String.t(), String.t(), {String.t(), String.t() | nil}, AssetProof.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_burn(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_burn that takes individual arguments.
+  """
+  @spec build_burn(
+          _authority :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf :: {String.t(), String.t() | nil},
+          _asset_proof :: AssetProof.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_burn(authority, tree_pubkey, leaf, asset_proof) do
+    build_burn({authority, tree_pubkey, leaf, asset_proof})
+  end
+
+  @doc """
+  Builds the instruction that sets the delegate of a compressed NFT.
+
+  ## Parameters
+  - leaf_owner: Public key of the leaf owner
+  - tree_pubkey: Public key of the Merkle tree
+  - previous_delegate: Public key of the current delegate, or nil when it is the owner
+  - new_delegate: Public key of the new delegate
+  - asset_proof: Merkle proof data for the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_delegate(
+          {String.t(), String.t(), String.t() | nil, String.t(), AssetProof.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_delegate(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_delegate that takes individual arguments.
+  """
+  @spec build_delegate(
+          _leaf_owner :: String.t(),
+          _tree_pubkey :: String.t(),
+          _previous_delegate :: String.t() | nil,
+          _new_delegate :: String.t(),
+          _asset_proof :: AssetProof.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_delegate(leaf_owner, tree_pubkey, previous_delegate, new_delegate, asset_proof) do
+    build_delegate({leaf_owner, tree_pubkey, previous_delegate, new_delegate, asset_proof})
+  end
+
+  @doc """
+  Builds the instruction that clears the delegate of a compressed NFT.
+
+  ## Parameters
+  - leaf_owner: Public key of the leaf owner
+  - tree_pubkey: Public key of the Merkle tree
+  - previous_delegate: Public key of the current delegate
+  - asset_proof: Merkle proof data for the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_cancel_delegate(
+          {String.t(), String.t(), String.t(), AssetProof.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_cancel_delegate(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_cancel_delegate that takes individual arguments.
+  """
+  @spec build_cancel_delegate(
+          _leaf_owner :: String.t(),
+          _tree_pubkey :: String.t(),
+          _previous_delegate :: String.t(),
+          _asset_proof :: AssetProof.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_cancel_delegate(leaf_owner, tree_pubkey, previous_delegate, asset_proof) do
+    build_cancel_delegate({leaf_owner, tree_pubkey, previous_delegate, asset_proof})
+  end
+
+  @doc """
+  Builds the instruction that redeems a compressed NFT into a voucher.
+
+  ## Parameters
+  - leaf_owner: Public key of the leaf owner
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf_delegate: Public key of the leaf delegate, or nil when it is the owner
+  - asset_proof: Merkle proof data for the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()], "voucher" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_redeem(
+          {String.t(), String.t(), String.t() | nil, AssetProof.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_redeem(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_redeem that takes individual arguments.
+  """
+  @spec build_redeem(
+          _leaf_owner :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf_delegate :: String.t() | nil,
+          _asset_proof :: AssetProof.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_redeem(leaf_owner, tree_pubkey, leaf_delegate, asset_proof) do
+    build_redeem({leaf_owner, tree_pubkey, leaf_delegate, asset_proof})
+  end
+
+  @doc """
+  Builds the instruction that cancels a redeem and puts the leaf back in the tree.
+
+  ## Parameters
+  - leaf_owner: Public key of the leaf owner
+  - tree_pubkey: Public key of the Merkle tree
+  - asset_proof: Merkle proof data for the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()], "voucher" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_cancel_redeem(
+          {String.t(), String.t(), AssetProof.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_cancel_redeem(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_cancel_redeem that takes individual arguments.
+  """
+  @spec build_cancel_redeem(
+          _leaf_owner :: String.t(),
+          _tree_pubkey :: String.t(),
+          _asset_proof :: AssetProof.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_cancel_redeem(leaf_owner, tree_pubkey, asset_proof) do
+    build_cancel_redeem({leaf_owner, tree_pubkey, asset_proof})
+  end
+
+  @doc """
+  Builds the instruction that decompresses a redeemed leaf into a regular NFT.
+
+  ## Parameters
+  - leaf_owner: Public key of the leaf owner
+  - tree_pubkey: Public key of the Merkle tree
+  - nonce: Nonce of the redeemed leaf
+  - metadata_args: Metadata of the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()], "mint" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_decompress_v1(
+          {String.t(), String.t(), non_neg_integer(), MetadataArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_decompress_v1(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_decompress_v1 that takes individual arguments.
+  """
+  @spec build_decompress_v1(
+          _leaf_owner :: String.t(),
+          _tree_pubkey :: String.t(),
+          _nonce :: non_neg_integer(),
+          _metadata_args :: MetadataArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_decompress_v1(leaf_owner, tree_pubkey, nonce, metadata_args) do
+    build_decompress_v1({leaf_owner, tree_pubkey, nonce, metadata_args})
+  end
+
+  @doc """
+  Builds the instruction that marks a creator of a compressed NFT as verified.
+
+  ## Parameters
+  - creator: Public key of the creator, who also pays the fees
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf: Tuple of the leaf owner and the optional leaf delegate public keys
+  - asset_proof: Merkle proof data for the leaf
+  - metadata_args: Current metadata of the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_verify_creator(
+          {String.t(), String.t(), {String.t(), String.t() | nil}, AssetProof.t(), MetadataArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_verify_creator(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_verify_creator that takes individual arguments.
+  """
+  @spec build_verify_creator(
+          _creator :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf :: {String.t(), String.t() | nil},
+          _asset_proof :: AssetProof.t(),
+          _metadata_args :: MetadataArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_verify_creator(creator, tree_pubkey, leaf, asset_proof, metadata_args) do
+    build_verify_creator({creator, tree_pubkey, leaf, asset_proof, metadata_args})
+  end
+
+  @doc """
+  Builds the instruction that removes the verified flag from a creator of a compressed NFT.
+
+  ## Parameters
+  - creator: Public key of the creator, who also pays the fees
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf: Tuple of the leaf owner and the optional leaf delegate public keys
+  - asset_proof: Merkle proof data for the leaf
+  - metadata_args: Current metadata of the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_unverify_creator(
+          {String.t(), String.t(), {String.t(), String.t() | nil}, AssetProof.t(), MetadataArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_unverify_creator(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_unverify_creator that takes individual arguments.
+  """
+  @spec build_unverify_creator(
+          _creator :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf :: {String.t(), String.t() | nil},
+          _asset_proof :: AssetProof.t(),
+          _metadata_args :: MetadataArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_unverify_creator(creator, tree_pubkey, leaf, asset_proof, metadata_args) do
+    build_unverify_creator({creator, tree_pubkey, leaf, asset_proof, metadata_args})
+  end
+
+  @doc """
+  Builds the instruction that verifies the collection of a compressed NFT.
+
+  ## Parameters
+  - collection_authority: Public key of the collection authority, who also pays the fees
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf: Tuple of the leaf owner and the optional leaf delegate public keys
+  - collection_mint: Public key of the collection mint
+  - asset_proof: Merkle proof data for the leaf
+  - metadata_args: Current metadata of the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_verify_collection(
+          {String.t(), String.t(), {String.t(), String.t() | nil}, String.t(), AssetProof.t(), MetadataArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_verify_collection(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_verify_collection that takes individual arguments.
+  """
+  @spec build_verify_collection(
+          _collection_authority :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf :: {String.t(), String.t() | nil},
+          _collection_mint :: String.t(),
+          _asset_proof :: AssetProof.t(),
+          _metadata_args :: MetadataArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_verify_collection(collection_authority, tree_pubkey, leaf, collection_mint, asset_proof, metadata_args) do
+    build_verify_collection({collection_authority, tree_pubkey, leaf, collection_mint, asset_proof, metadata_args})
+  end
+
+  @doc """
+  Builds the instruction that removes the verified flag from the collection of a compressed NFT.
+
+  ## Parameters
+  - collection_authority: Public key of the collection authority, who also pays the fees
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf: Tuple of the leaf owner and the optional leaf delegate public keys
+  - collection_mint: Public key of the collection mint
+  - asset_proof: Merkle proof data for the leaf
+  - metadata_args: Current metadata of the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_unverify_collection(
+          {String.t(), String.t(), {String.t(), String.t() | nil}, String.t(), AssetProof.t(), MetadataArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_unverify_collection(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_unverify_collection that takes individual arguments.
+  """
+  @spec build_unverify_collection(
+          _collection_authority :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf :: {String.t(), String.t() | nil},
+          _collection_mint :: String.t(),
+          _asset_proof :: AssetProof.t(),
+          _metadata_args :: MetadataArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_unverify_collection(collection_authority, tree_pubkey, leaf, collection_mint, asset_proof, metadata_args) do
+    build_unverify_collection({collection_authority, tree_pubkey, leaf, collection_mint, asset_proof, metadata_args})
+  end
+
+  @doc """
+  Builds the instruction that sets and verifies the collection of a compressed NFT.
+
+  ## Parameters
+  - collection_authority: Public key of the collection authority, who also pays the fees
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf: Tuple of the leaf owner and the optional leaf delegate public keys
+  - collection_mint: Public key of the collection mint
+  - asset_proof: Merkle proof data for the leaf
+  - metadata_args: Current metadata of the leaf
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_set_and_verify_collection(
+          {String.t(), String.t(), {String.t(), String.t() | nil}, String.t(), AssetProof.t(), MetadataArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_set_and_verify_collection(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_set_and_verify_collection that takes individual arguments.
+  """
+  @spec build_set_and_verify_collection(
+          _collection_authority :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf :: {String.t(), String.t() | nil},
+          _collection_mint :: String.t(),
+          _asset_proof :: AssetProof.t(),
+          _metadata_args :: MetadataArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_set_and_verify_collection(collection_authority, tree_pubkey, leaf, collection_mint, asset_proof, metadata_args) do
+    build_set_and_verify_collection({collection_authority, tree_pubkey, leaf, collection_mint, asset_proof, metadata_args})
+  end
+
+  @doc """
+  Builds the instruction that updates the metadata of a compressed NFT.
+
+  ## Parameters
+  - authority: Public key of the update authority, who also pays the fees
+  - tree_pubkey: Public key of the Merkle tree
+  - leaf: Tuple of the leaf owner and the optional leaf delegate public keys
+  - asset_proof: Merkle proof data for the leaf
+  - current_metadata: Current metadata of the leaf
+  - update_args: Metadata fields to change
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_update_metadata(
+          {String.t(), String.t(), {String.t(), String.t() | nil}, AssetProof.t(), MetadataArgs.t(), UpdateArgs.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_update_metadata(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_update_metadata that takes individual arguments.
+  """
+  @spec build_update_metadata(
+          _authority :: String.t(),
+          _tree_pubkey :: String.t(),
+          _leaf :: {String.t(), String.t() | nil},
+          _asset_proof :: AssetProof.t(),
+          _current_metadata :: MetadataArgs.t(),
+          _update_args :: UpdateArgs.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_update_metadata(authority, tree_pubkey, leaf, asset_proof, current_metadata, update_args) do
+    build_update_metadata({authority, tree_pubkey, leaf, asset_proof, current_metadata, update_args})
+  end
+
+  @doc """
+  Builds the instruction that sets the tree delegate.
+
+  ## Parameters
+  - tree_creator: Public key of the tree creator
+  - tree_pubkey: Public key of the Merkle tree
+  - new_delegate: Public key of the new tree delegate
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_set_tree_delegate(
+          {String.t(), String.t(), String.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_set_tree_delegate(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_set_tree_delegate that takes individual arguments.
+  """
+  @spec build_set_tree_delegate(
+          _tree_creator :: String.t(),
+          _tree_pubkey :: String.t(),
+          _new_delegate :: String.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_set_tree_delegate(tree_creator, tree_pubkey, new_delegate) do
+    build_set_tree_delegate({tree_creator, tree_pubkey, new_delegate})
+  end
+
+  @doc """
+  Builds the instruction that enables or disables decompression for a tree.
+
+  ## Parameters
+  - tree_creator: Public key of the tree creator
+  - tree_pubkey: Public key of the Merkle tree
+  - decompressible_state: Either :enabled or :disabled
+
+  ## Returns
+  - `{:ok, %{"instructions" => [Instruction.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_set_decompressible_state(
+          {String.t(), String.t(), :enabled | :disabled}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_set_decompressible_state(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_set_decompressible_state that takes individual arguments.
+  """
+  @spec build_set_decompressible_state(
+          _tree_creator :: String.t(),
+          _tree_pubkey :: String.t(),
+          _decompressible_state :: :enabled | :disabled
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_set_decompressible_state(tree_creator, tree_pubkey, decompressible_state) do
+    build_set_decompressible_state({tree_creator, tree_pubkey, decompressible_state})
+  end
+
+  @doc """
+  Assembles build-only instructions into an unsigned, Base64 encoded transaction.
+
+  ## Parameters
+  - instructions: Instructions returned by the build_* functions
+  - fee_payer: Public key of the fee payer
+  - blockhash: Recent blockhash to use, or nil to fetch one
+  - nonce: `{nonce_account, nonce_authority}` to use a durable nonce instead of a blockhash, or nil
+  - address_lookup_tables: Lookup table addresses; when non-empty a v0 transaction is built
+  - memo: Text to append as an SPL Memo instruction, or nil
+  - rpc_url: URL of, or connection to, the Solana RPC endpoint used to fetch the blockhash and lookup tables
+
+  ## Returns
+  - `{:ok, %{"transaction" => String.t(), "message" => String.t(), "blockhash" => String.t(), "signers" => [String.t()]}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec build_transaction(
+          {[Instruction.t()], String.t(), String.t() | nil, {String.t(), String.t()} | nil, [String.t()],
+           String.t() | nil, String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_transaction(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for build_transaction that takes individual arguments.
+  """
+  @spec build_transaction(
+          _instructions :: [Instruction.t()],
+          _fee_payer :: String.t(),
+          _blockhash :: String.t() | nil,
+          _nonce :: {String.t(), String.t()} | nil,
+          _address_lookup_tables :: [String.t()],
+          _memo :: String.t() | nil,
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def build_transaction(instructions, fee_payer, blockhash, nonce, address_lookup_tables, memo, rpc_url) do
+    build_transaction({instructions, fee_payer, blockhash, nonce, address_lookup_tables, memo, rpc_url})
+  end
+
+  @doc """
+  Submits a transaction that was serialized and signed outside of this library.
+
+  ## Parameters
+  - transaction: Serialized signed transaction bytes
+  - send_options: Preflight and confirmation settings
+  - timeout_ms: Milliseconds before the call gives up, or nil
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to it
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec send_raw_transaction(
+          {binary(), SendOptions.t(), pos_integer() | nil, String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def send_raw_transaction(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for send_raw_transaction that takes individual arguments.
+  """
+  @spec send_raw_transaction(
+          _transaction :: binary(),
+          _send_options :: SendOptions.t(),
+          _timeout_ms :: pos_integer() | nil,
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def send_raw_transaction(transaction, send_options, timeout_ms, rpc_url) do
+    send_raw_transaction({transaction, send_options, timeout_ms, rpc_url})
+  end
+
+  @doc """
+  Attaches signatures produced outside of this library to an unsigned transaction and submits it.
+
+  ## Parameters
+  - transaction: Serialized transaction bytes, as built by build_transaction
+  - signatures: `{signer_pubkey, signature}` pairs; signatures are raw 64 byte binaries or Base58 strings
+  - send_options: Preflight and confirmation settings
+  - timeout_ms: Milliseconds before the call gives up, or nil
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to it
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec attach_signatures_and_send(
+          {binary(), [{String.t(), binary()}], SendOptions.t(), pos_integer() | nil, String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def attach_signatures_and_send(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for attach_signatures_and_send that takes individual arguments.
+  """
+  @spec attach_signatures_and_send(
+          _transaction :: binary(),
+          _signatures :: [{String.t(), binary()}],
+          _send_options :: SendOptions.t(),
+          _timeout_ms :: pos_integer() | nil,
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def attach_signatures_and_send(transaction, signatures, send_options, timeout_ms, rpc_url) do
+    attach_signatures_and_send({transaction, signatures, send_options, timeout_ms, rpc_url})
+  end
+
+  @doc """
+  Simulates a serialized transaction without verifying its signatures.
+
+  ## Parameters
+  - transaction: Serialized transaction bytes, signed or not
+  - replace_recent_blockhash: Simulate with the latest blockhash instead of the transaction's own
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to it
 
   ## Returns
-  - `{:ok, %{tree_pubkey: String.t(), signature: String.t()}}` on success
+  - `{:ok, %{"logs" => [String.t()], "units_consumed" => non_neg_integer() | nil, "error" => String.t() | nil}}` on success
   - `{:error, reason}` on failure
   """
-  @spec create_tree_config(
-          {String.t(), non_neg_integer(), non_neg_integer(), non_neg_integer(), boolean(), String.t()}
-        ) :: {:ok, map()} | {:error, String.t()}
-  def create_tree_config(_args),
+  @spec simulate_transaction(
+          {binary(), boolean(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def simulate_transaction(_args),
     do: :erlang.nif_error(:nif_not_loaded)
 
   @doc """
-  Wrapper function for create_tree_config that takes individual arguments.
+  Wrapper function for simulate_transaction that takes individual arguments.
   """
-  @spec create_tree_config(
-          _payer_keypair_bs58 :: String.t(),
-          _max_depth :: non_neg_integer(),
-          _max_buffer_size :: non_neg_integer(),
-          _canopy_depth :: non_neg_integer(),
-          _public :: boolean(),
-          _rpc_url :: String.t()
-        ) :: {:ok, map()} | {:error, String.t()}
-  def create_tree_config(payer_keypair_bs58, max_depth, max_buffer_size, canopy_depth, public, rpc_url) do
-    create_tree_config({payer_keypair_bs58, max_depth, max_buffer_size, canopy_depth, public, rpc_url})
+  @spec simulate_transaction(
+          _transaction :: binary(),
+          _replace_recent_blockhash :: boolean(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def simulate_transaction(transaction, replace_recent_blockhash, rpc_url) do
+    simulate_transaction({transaction, replace_recent_blockhash, rpc_url})
   end
 
   @doc """
-  Mints a new compressed NFT to a collection.
+  Waits for a transaction signature to reach the given commitment.
 
   ## Parameters
-  - payer_keypair_bs58: Base58 encoded keypair of the payer
-  - tree_pubkey: Public key of the Merkle tree
-  - collection_pubkey: Public key of the collection
-  - metadata_args: Metadata for the NFT
-  - rpc_url: URL of the Solana RPC endpoint
+  - signature: Signature of the transaction
+  - commitment: Commitment level to wait for
+  - timeout_ms: How long to keep checking, in milliseconds; 0 checks once
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to it
+
+  ## Returns
+  - `{:ok, %{"signature" => String.t(), "confirmed" => boolean(), "slot" => non_neg_integer() | nil}}` on success
+  - `{:error, reason}` when the transaction failed or the status could not be fetched
+  """
+  @spec confirm_signature(
+          {String.t(), :processed | :confirmed | :finalized, non_neg_integer(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def confirm_signature(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for confirm_signature that takes individual arguments.
+  """
+  @spec confirm_signature(
+          _signature :: String.t(),
+          _commitment :: :processed | :confirmed | :finalized,
+          _timeout_ms :: non_neg_integer(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def confirm_signature(signature, commitment, timeout_ms, rpc_url) do
+    confirm_signature({signature, commitment, timeout_ms, rpc_url})
+  end
+
+  @doc """
+  Recommends a compute unit price from recent prioritization fees.
+
+  ## Parameters
+  - accounts: Public keys of the accounts the transaction writes to
+  - percentile: Percentile of recent fees to pick, or nil for the default of 75
+  - priority_fee_url: URL of a Helius endpoint to ask instead of the RPC node
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to it
+
+  ## Returns
+  - `{:ok, %{"micro_lamports" => non_neg_integer()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec get_priority_fee_estimate(
+          {[String.t()], non_neg_integer() | nil, String.t() | nil, String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_priority_fee_estimate(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for get_priority_fee_estimate that takes individual arguments.
+  """
+  @spec get_priority_fee_estimate(
+          _accounts :: [String.t()],
+          _percentile :: non_neg_integer() | nil,
+          _priority_fee_url :: String.t() | nil,
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def get_priority_fee_estimate(accounts, percentile, priority_fee_url, rpc_url) do
+    get_priority_fee_estimate({accounts, percentile, priority_fee_url, rpc_url})
+  end
+
+  @doc """
+  Estimates what a serialized transaction costs its fee payer.
+
+  ## Parameters
+  - transaction: Serialized transaction bytes, signed or not
+  - priority_fee: `:auto` or `{:micro_lamports, n}` to add a priority fee to a transaction that
+    sets no compute unit price, or nil
+  - percentile: Percentile of recent fees `:auto` picks, or nil for the default of 75
+  - priority_fee_url: URL of a Helius endpoint for `:auto` to ask instead of the RPC node
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to it
+
+  ## Returns
+  - `{:ok, %{"base_fee_lamports" => non_neg_integer(), "priority_fee_lamports" => non_neg_integer(), "rent_lamports" => non_neg_integer(), "total_lamports" => non_neg_integer(), ...}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec estimate_fee(
+          {binary(), :auto | {:micro_lamports, non_neg_integer()} | nil, non_neg_integer() | nil,
+           String.t() | nil, RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def estimate_fee(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for estimate_fee that takes individual arguments.
+  """
+  @spec estimate_fee(
+          _transaction :: binary(),
+          _priority_fee :: :auto | {:micro_lamports, non_neg_integer()} | nil,
+          _percentile :: non_neg_integer() | nil,
+          _priority_fee_url :: String.t() | nil,
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def estimate_fee(transaction, priority_fee, percentile, priority_fee_url, retry, rpc_url) do
+    estimate_fee({transaction, priority_fee, percentile, priority_fee_url, retry, rpc_url})
+  end
+
+  @doc """
+  Creates an address lookup table owned by the given authority.
+
+  ## Parameters
+  - authority_keypair_bs58: Base58 encoded keypair of the table authority, who also pays the fees
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t(), lookup_table: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec create_lookup_table(
+          {String.t(), TransactionOptions.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def create_lookup_table(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for create_lookup_table that takes individual arguments.
+  """
+  @spec create_lookup_table(
+          _authority_keypair_bs58 :: String.t(),
+          _tx_options :: TransactionOptions.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def create_lookup_table(authority_keypair_bs58, tx_options) do
+    create_lookup_table({authority_keypair_bs58, tx_options})
+  end
+
+  @doc """
+  Adds addresses to an address lookup table, in as many transactions as needed.
+
+  ## Parameters
+  - authority_keypair_bs58: Base58 encoded keypair of the table authority, who also pays the fees
+  - lookup_table: Address of the lookup table
+  - addresses: Addresses to add
+  - tx_options: RPC endpoint and compute budget settings for the transactions
+
+  ## Returns
+  - `{:ok, %{signatures: [String.t()], lookup_table: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec extend_lookup_table(
+          {String.t(), String.t(), [String.t()], TransactionOptions.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def extend_lookup_table(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for extend_lookup_table that takes individual arguments.
+  """
+  @spec extend_lookup_table(
+          _authority_keypair_bs58 :: String.t(),
+          _lookup_table :: String.t(),
+          _addresses :: [String.t()],
+          _tx_options :: TransactionOptions.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def extend_lookup_table(authority_keypair_bs58, lookup_table, addresses, tx_options) do
+    extend_lookup_table({authority_keypair_bs58, lookup_table, addresses, tx_options})
+  end
+
+  @doc """
+  Deactivates an address lookup table so it can be closed once the cooldown has passed.
+
+  ## Parameters
+  - authority_keypair_bs58: Base58 encoded keypair of the table authority, who also pays the fees
+  - lookup_table: Address of the lookup table
+  - tx_options: RPC endpoint and compute budget settings for the transaction
 
   ## Returns
   - `{:ok, %{signature: String.t()}}` on success
   - `{:error, reason}` on failure
   """
-  @spec mint_to_collection_v1(
-          {String.t(), String.t(), String.t(), MetadataArgs.t(), String.t()}
-        ) :: {:ok, map()} | {:error, String.t()}
-  def mint_to_collection_v1(_args),
+  @spec deactivate_lookup_table(
+          {String.t(), String.t(), TransactionOptions.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def deactivate_lookup_table(_args),
     do: :erlang.nif_error(:nif_not_loaded)
 
   @doc """
-  Wrapper function for mint_to_collection_v1 that takes individual arguments.
+  Wrapper function for deactivate_lookup_table that takes individual arguments.
   """
-  @spec mint_to_collection_v1(
-          _payer_keypair_bs58 :: String.t(),
+  @spec deactivate_lookup_table(
+          _authority_keypair_bs58 :: String.t(),
+          _lookup_table :: String.t(),
+          _tx_options :: TransactionOptions.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def deactivate_lookup_table(authority_keypair_bs58, lookup_table, tx_options) do
+    deactivate_lookup_table({authority_keypair_bs58, lookup_table, tx_options})
+  end
+
+  @doc """
+  Closes a deactivated address lookup table and reclaims its rent.
+
+  ## Parameters
+  - authority_keypair_bs58: Base58 encoded keypair of the table authority, who also pays the fees
+  - lookup_table: Address of the lookup table
+  - recipient: Account receiving the reclaimed rent, or nil for the authority
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec close_lookup_table(
+          {String.t(), String.t(), String.t() | nil, TransactionOptions.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def close_lookup_table(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for close_lookup_table that takes individual arguments.
+  """
+  @spec close_lookup_table(
+          _authority_keypair_bs58 :: String.t(),
+          _lookup_table :: String.t(),
+          _recipient :: String.t() | nil,
+          _tx_options :: TransactionOptions.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def close_lookup_table(authority_keypair_bs58, lookup_table, recipient, tx_options) do
+    close_lookup_table({authority_keypair_bs58, lookup_table, recipient, tx_options})
+  end
+
+  @doc """
+  Checks whether every leaf of a tree was burned or redeemed, and lists its vouchers.
+
+  ## Parameters
+  - tree_pubkey: Public key of the Merkle tree
+  - retry: Retry policy for rate limits, server errors and timeouts
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to it
+
+  ## Returns
+  - `{:ok, %{"tree" => String.t(), "num_minted" => non_neg_integer(), "empty" => boolean(), "vouchers" => [Voucher.t()], "locked_lamports" => non_neg_integer()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec check_tree_empty({String.t(), RetryPolicy.t(), String.t() | Connection.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def check_tree_empty(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for check_tree_empty that takes individual arguments.
+  """
+  @spec check_tree_empty(
           _tree_pubkey :: String.t(),
-          _collection_pubkey :: String.t(),
-          _metadata_args :: MetadataArgs.t(),
-          _rpc_url :: String.t()
-        ) :: {:ok, map()} | {:error, String.t()}
-  def mint_to_collection_v1(payer_keypair_bs58, tree_pubkey, collection_pubkey, metadata_args, rpc_url) do
-    mint_to_collection_v1({payer_keypair_bs58, tree_pubkey, collection_pubkey, metadata_args, rpc_url})
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def check_tree_empty(tree_pubkey, retry, rpc_url) do
+    check_tree_empty({tree_pubkey, retry, rpc_url})
   end
 
   @doc """
-  Transfers a compressed NFT to a new owner.
+  Takes a lookup table one step closer to returning its rent: deactivates it while it is
+  active, closes it once the cooldown has passed, and does nothing in between.
+
+  ## Parameters
+  - authority_keypair_bs58: Base58 encoded keypair of the table authority
+  - lookup_table: Address of the lookup table
+  - recipient: Public key that receives the rent, or nil for the authority
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{"lookup_table" => String.t(), "status" => :deactivated | :cooling_down | :closed, "closable_after_slot" => non_neg_integer() | nil, "reclaimed_lamports" => non_neg_integer()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec reclaim_lookup_table(
+          {String.t(), String.t(), String.t() | nil, TransactionOptions.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def reclaim_lookup_table(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for reclaim_lookup_table that takes individual arguments.
+  """
+  @spec reclaim_lookup_table(
+          _authority_keypair_bs58 :: String.t(),
+          _lookup_table :: String.t(),
+          _recipient :: String.t() | nil,
+          _tx_options :: TransactionOptions.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def reclaim_lookup_table(authority_keypair_bs58, lookup_table, recipient, tx_options) do
+    reclaim_lookup_table({authority_keypair_bs58, lookup_table, recipient, tx_options})
+  end
+
+  @doc """
+  Creates a durable nonce account funded by the payer.
 
   ## Parameters
   - payer_keypair_bs58: Base58 encoded keypair of the payer
+  - nonce_authority: Public key allowed to advance the nonce, or nil for the payer
+  - tx_options: RPC endpoint and compute budget settings for the transaction
+
+  ## Returns
+  - `{:ok, %{signature: String.t(), nonce_account: String.t(), nonce_authority: String.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec create_nonce_account(
+          {String.t(), String.t() | nil, TransactionOptions.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def create_nonce_account(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for create_nonce_account that takes individual arguments.
+  """
+  @spec create_nonce_account(
+          _payer_keypair_bs58 :: String.t(),
+          _nonce_authority :: String.t() | nil,
+          _tx_options :: TransactionOptions.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def create_nonce_account(payer_keypair_bs58, nonce_authority, tx_options) do
+    create_nonce_account({payer_keypair_bs58, nonce_authority, tx_options})
+  end
+
+  @doc """
+  Subscribes to the transactions that mention a Merkle tree over the Solana PubSub websocket.
+
+  ## Parameters
   - tree_pubkey: Public key of the Merkle tree
-  - leaf_owner: Public key of the current owner
-  - new_owner: Public key of the new owner
-  - asset_id: Asset ID of the NFT
+  - owner: Process that receives the tree events
+  - ws_url: URL of the websocket endpoint, or nil to derive it from rpc_url
+  - retry: Retry policy for fetching the transactions
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %{"subscription" => TreeSubscription.t(), "reference" => reference()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec subscribe_tree(
+          {String.t(), pid(), String.t() | nil, RetryPolicy.t(), String.t() | Connection.t()}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def subscribe_tree(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for subscribe_tree that takes individual arguments.
+  """
+  @spec subscribe_tree(
+          _tree_pubkey :: String.t(),
+          _owner :: pid(),
+          _ws_url :: String.t() | nil,
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def subscribe_tree(tree_pubkey, owner, ws_url, retry, rpc_url) do
+    subscribe_tree({tree_pubkey, owner, ws_url, retry, rpc_url})
+  end
+
+  @doc """
+  Stops a tree subscription.
+
+  ## Parameters
+  - subscription: Subscription returned by subscribe_tree
+
+  ## Returns
+  - `:ok`
+  """
+  @spec unsubscribe_tree(TreeSubscription.t()) :: :ok
+  def unsubscribe_tree(_subscription),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Waits in the background for a transaction to be finalized.
+
+  ## Parameters
+  - signature: Signature of the transaction
+  - timeout_ms: How long to wait, in milliseconds
+  - ws_url: URL of the websocket endpoint, or nil to derive it from rpc_url
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, ref}`, then `{:bubblegum_result, ref, {:ok, %{"signature" => String.t(), "finalized" => boolean(),
+    "slot" => integer() | nil, "error" => Error.t() | nil}}}` is sent to the caller
+  - `{:error, reason}` on an invalid signature
+  """
+  @spec await_finalized({String.t(), non_neg_integer(), String.t() | nil, String.t() | Connection.t()}) ::
+          {:ok, reference()} | {:error, Error.t()}
+  def await_finalized(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for await_finalized that takes individual arguments.
+  """
+  @spec await_finalized(
+          _signature :: String.t(),
+          _timeout_ms :: non_neg_integer(),
+          _ws_url :: String.t() | nil,
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, reference()} | {:error, Error.t()}
+  def await_finalized(signature, timeout_ms, ws_url, rpc_url) do
+    await_finalized({signature, timeout_ms, ws_url, rpc_url})
+  end
+
+  @doc """
+  Cancels an operation running in the background.
+
+  ## Parameters
+  - ref: Reference returned by a call with `async_reply` set, or by await_finalized
+
+  ## Returns
+  - `:ok`, then `{:bubblegum_result, ref, {:error, Error.t()}}` is sent to the caller in place of
+    the result, of kind `:cancelled` when nothing was sent yet or `:cancelled_unknown_outcome`
+    when a transaction was under way and may still land
+  - `{:error, reason}` when the operation had already replied
+  """
+  @spec cancel(reference()) :: :ok | {:error, Error.t()}
+  def cancel(_ref),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Opens a connection to a Solana RPC endpoint that other functions accept in place of its URL.
+
+  ## Parameters
   - rpc_url: URL of the Solana RPC endpoint
+  - commitment: Commitment level for requests, or nil for `:confirmed`
+  - blockhash_refresh_slots: Slots between background blockhash refreshes, or nil to fetch a
+    blockhash for every transaction
+  - rate_limit: Limit on the request rate of the connection, or nil for none
+  - http_options: Timeout, proxy and headers of the HTTP requests, or nil for the defaults
 
   ## Returns
-  - `{:ok, %{signature: String.t()}}` on success
+  - `{:ok, %{"connection" => Connection.t()}}` on success
   - `{:error, reason}` on failure
   """
-  @spec transfer(
-          {String.t(), String.t(), String.t(), String.t(), String.t(), String.t()}
-        ) :: {:ok, map()} | {:error, String.t()}
-  def transfer(_args),
+  @spec connect(
+          {String.t(), :processed | :confirmed | :finalized | nil, pos_integer() | nil,
+           RateLimit.t() | nil, HttpOptions.t() | nil}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def connect(_args),
     do: :erlang.nif_error(:nif_not_loaded)
 
   @doc """
-  Wrapper function for transfer that takes individual arguments.
+  Wrapper function for connect that takes individual arguments.
   """
-  @spec transfer(
-          _payer_keypair_bs58 :: String.t(),
+  @spec connect(
+          _rpc_url :: String.t(),
+          _commitment :: :processed | :confirmed | :finalized | nil,
+          _blockhash_refresh_slots :: pos_integer() | nil,
+          _rate_limit :: RateLimit.t() | nil,
+          _http_options :: HttpOptions.t() | nil
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def connect(rpc_url, commitment, blockhash_refresh_slots, rate_limit, http_options) do
+    connect({rpc_url, commitment, blockhash_refresh_slots, rate_limit, http_options})
+  end
+
+  @doc """
+  Opens a pool of Solana RPC endpoints that other functions accept in place of a URL.
+
+  ## Parameters
+  - endpoints: List of `{url, weight}` tuples, requests are spread over them by weight
+  - commitment: Commitment level for requests, or nil for `:confirmed`
+  - health_check_interval_ms: Interval between `getHealth` checks of every endpoint, or nil for none
+  - unhealthy_cooldown_ms: How long a failing endpoint is skipped, or nil for 30 seconds
+  - rate_limit: Limit on the request rate of each endpoint, or nil for none
+  - http_options: Timeout, proxy and headers of the HTTP requests to every endpoint, or nil for
+    the defaults
+
+  ## Returns
+  - `{:ok, %{"pool" => Connection.t()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec connect_pool(
+          {[{String.t(), pos_integer()}], :processed | :confirmed | :finalized | nil,
+           non_neg_integer() | nil, non_neg_integer() | nil, RateLimit.t() | nil, HttpOptions.t() | nil}
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def connect_pool(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for connect_pool that takes individual arguments.
+  """
+  @spec connect_pool(
+          _endpoints :: [{String.t(), pos_integer()}],
+          _commitment :: :processed | :confirmed | :finalized | nil,
+          _health_check_interval_ms :: non_neg_integer() | nil,
+          _unhealthy_cooldown_ms :: non_neg_integer() | nil,
+          _rate_limit :: RateLimit.t() | nil,
+          _http_options :: HttpOptions.t() | nil
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def connect_pool(endpoints, commitment, health_check_interval_ms, unhealthy_cooldown_ms, rate_limit, http_options) do
+    connect_pool({endpoints, commitment, health_check_interval_ms, unhealthy_cooldown_ms, rate_limit, http_options})
+  end
+
+  @doc """
+  Creates a proof cache that `transfer_with_das/2` consults before the DAS API.
+
+  ## Parameters
+  - capacity: Maximum number of assets kept, the least recently used is evicted first
+
+  ## Returns
+  - `{:ok, %{"proof_cache" => ProofCache.t()}}` on success
+  - `{:error, reason}` on a capacity of 0
+  """
+  @spec new_proof_cache(pos_integer()) :: {:ok, map()} | {:error, Error.t()}
+  def new_proof_cache(_capacity),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Reads the counters of a proof cache.
+
+  ## Parameters
+  - cache: Proof cache returned by `new_proof_cache/1`
+
+  ## Returns
+  - `{:ok, %{"hits" => integer(), "misses" => integer(), "size" => integer(), "capacity" => integer()}}`
+  """
+  @spec proof_cache_stats(ProofCache.t()) :: {:ok, map()}
+  def proof_cache_stats(_cache),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Creates a mint counter for a tree, starting at the `num_minted` of its tree config.
+
+  ## Parameters
+  - tree_pubkey: Public key of the Merkle tree
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %{"mint_counter" => MintCounter.t(), "next_leaf_index" => integer()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec new_mint_counter({String.t(), RetryPolicy.t(), String.t() | Connection.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def new_mint_counter(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for new_mint_counter that takes individual arguments.
+  """
+  @spec new_mint_counter(
           _tree_pubkey :: String.t(),
-          _leaf_owner :: String.t(),
-          _new_owner :: String.t(),
-          _asset_id :: String.t(),
-          _rpc_url :: String.t()
-        ) :: {:ok, map()} | {:error, String.t()}
-  def transfer(payer_keypair_bs58, tree_pubkey, leaf_owner, new_owner, asset_id, rpc_url) do
-    transfer({payer_keypair_bs58, tree_pubkey, leaf_owner, new_owner, asset_id, rpc_url})
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def new_mint_counter(tree_pubkey, retry, rpc_url) do
+    new_mint_counter({tree_pubkey, retry, rpc_url})
+  end
+
+  @doc """
+  Takes the next leaf index of a mint counter and derives its asset ID.
+
+  ## Parameters
+  - counter: Mint counter returned by `new_mint_counter/3`
+
+  ## Returns
+  - `{:ok, %{"index" => integer(), "asset_id" => String.t()}}`
+  """
+  @spec next_leaf_index(MintCounter.t()) :: {:ok, map()}
+  def next_leaf_index(_counter),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Resets a mint counter to the `num_minted` of its tree config.
+
+  ## Parameters
+  - counter: Mint counter returned by `new_mint_counter/3`
+  - retry: Retry policy for transient errors
+  - rpc_url: URL of the Solana RPC endpoint, or a connection to one
+
+  ## Returns
+  - `{:ok, %{"next_leaf_index" => integer(), "unused" => integer(), "minted_elsewhere" => integer()}}` on success
+  - `{:error, reason}` on failure
+  """
+  @spec reconcile_mint_counter({MintCounter.t(), RetryPolicy.t(), String.t() | Connection.t()}) ::
+          {:ok, map()} | {:error, Error.t()}
+  def reconcile_mint_counter(_args),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc """
+  Wrapper function for reconcile_mint_counter that takes individual arguments.
+  """
+  @spec reconcile_mint_counter(
+          _counter :: MintCounter.t(),
+          _retry :: RetryPolicy.t(),
+          _rpc_url :: String.t() | Connection.t()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def reconcile_mint_counter(counter, retry, rpc_url) do
+    reconcile_mint_counter({counter, retry, rpc_url})
   end
 end
@@ -0,0 +1,667 @@
+defmodule SolanaBubblegum.Build do
+  @moduledoc """
+  Build-only variants of the Bubblegum operations.
+
+  These functions take public keys instead of keypairs and return the unsigned instructions
+  as `SolanaBubblegum.Types.Instruction` structs, without contacting any RPC endpoint. Use them
+  when transactions are signed by a separate service, wallet or HSM.
+
+  `transaction/3` assembles the instructions into an unsigned transaction that can be handed
+  to the signer as is.
+  """
+
+  alias SolanaBubblegum.Bubblegum
+  alias SolanaBubblegum.Types.{AssetProof, Error, Instruction, MetadataArgs, UpdateArgs}
+
+  @default_rpc_url "https://api.devnet.solana.com"
+
+  @type result :: {:ok, %{required(String.t()) => [Instruction.t()] | String.t()}} | {:error, Error.t()}
+
+  @doc """
+  Builds the instructions that allocate a Merkle tree account and create its tree config.
+
+  Rent for the tree account is computed from the default rent parameters. The resulting
+  transaction must be signed by both the payer and the keypair of `tree_pubkey`.
+
+  ## Parameters
+
+  * `payer` - Public key of the payer, also used as the tree creator
+  * `tree_pubkey` - Public key of the new Merkle tree account
+  * `max_depth` - Maximum depth of the Merkle tree
+  * `max_buffer_size` - Maximum buffer size for the Merkle tree
+  * `canopy_depth` - Canopy depth for the Merkle tree, at most `max_depth`
+  * `public` - Whether the tree is public or not
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()], "tree_pubkey" => String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec create_tree_config(
+          payer :: String.t(),
+          tree_pubkey :: String.t(),
+          max_depth :: non_neg_integer(),
+          max_buffer_size :: non_neg_integer(),
+          canopy_depth :: non_neg_integer(),
+          public :: boolean()
+        ) :: result()
+  def create_tree_config(payer, tree_pubkey, max_depth, max_buffer_size, canopy_depth, public) do
+    payer
+    |> Bubblegum.build_create_tree_config(tree_pubkey, max_depth, max_buffer_size, canopy_depth, public)
+  end
+
+  @doc """
+  Builds the instruction that mints a compressed NFT without a collection.
+
+  ## Parameters
+
+  * `payer` - Public key of the payer, who is also the tree creator and the leaf owner
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `metadata_args` - Metadata for the NFT
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec mint(payer :: String.t(), tree_pubkey :: String.t(), metadata_args :: MetadataArgs.t()) :: result()
+  def mint(payer, tree_pubkey, metadata_args) do
+    payer
+    |> Bubblegum.build_mint_v1(tree_pubkey, metadata_args)
+  end
+
+  @doc """
+  Builds the instruction that mints a compressed NFT into a collection.
+
+  ## Parameters
+
+  * `payer` - Public key of the payer, who is also the tree creator, the collection authority
+    and the leaf owner
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `collection_pubkey` - Public key of the collection mint
+  * `metadata_args` - Metadata for the NFT
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec mint_to_collection(
+          payer :: String.t(),
+          tree_pubkey :: String.t(),
+          collection_pubkey :: String.t(),
+          metadata_args :: MetadataArgs.t()
+        ) :: result()
+  def mint_to_collection(payer, tree_pubkey, collection_pubkey, metadata_args) do
+    payer
+    |> Bubblegum.build_mint_to_collection_v1(tree_pubkey, collection_pubkey, metadata_args)
+  end
+
+  @doc """
+  Builds the instruction that transfers a compressed NFT.
+
+  ## Parameters
+
+  * `authority` - Public key of the signer, either the leaf owner or the leaf delegate
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the current leaf owner
+  * `new_owner` - Public key of the new owner
+  * `asset_proof` - Merkle proof data for the leaf
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec transfer(
+          authority :: String.t(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          new_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: result()
+  def transfer(authority, tree_pubkey, leaf_owner, new_owner, asset_proof, options \\ []) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    authority
+    |> Bubblegum.build_transfer(tree_pubkey, {leaf_owner, leaf_delegate}, new_owner, asset_proof)
+  end
+
+  @doc """
+  Builds the instruction that burns a compressed NFT.
+
+  ## Parameters
+
+  * `authority` - Public key of the signer, either the leaf owner or the leaf delegate
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `asset_proof` - Merkle proof data for the leaf
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec burn(
+          authority :: String.t(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: result()
+  def burn(authority, tree_pubkey, leaf_owner, asset_proof, options \\ []) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    authority
+    |> Bubblegum.build_burn(tree_pubkey, {leaf_owner, leaf_delegate}, asset_proof)
+  end
+
+  @doc """
+  Builds the instruction that sets the delegate of a compressed NFT.
+
+  ## Parameters
+
+  * `leaf_owner` - Public key of the leaf owner, who signs the transaction
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `new_delegate` - Public key of the new delegate
+  * `asset_proof` - Merkle proof data for the leaf
+  * `options` - Optional keyword list with additional parameters:
+    * `:previous_delegate` - Public key of the current delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec delegate(
+          leaf_owner :: String.t(),
+          tree_pubkey :: String.t(),
+          new_delegate :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: result()
+  def delegate(leaf_owner, tree_pubkey, new_delegate, asset_proof, options \\ []) do
+    previous_delegate = Keyword.get(options, :previous_delegate)
+
+    leaf_owner
+    |> Bubblegum.build_delegate(tree_pubkey, previous_delegate, new_delegate, asset_proof)
+  end
+
+  @doc """
+  Builds the instruction that clears the delegate of a compressed NFT.
+
+  ## Parameters
+
+  * `leaf_owner` - Public key of the leaf owner, who signs the transaction
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `previous_delegate` - Public key of the current delegate
+  * `asset_proof` - Merkle proof data for the leaf
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec cancel_delegate(
+          leaf_owner :: String.t(),
+          tree_pubkey :: String.t(),
+          previous_delegate :: String.t(),
+          asset_proof :: AssetProof.t()
+        ) :: result()
+  def cancel_delegate(leaf_owner, tree_pubkey, previous_delegate, asset_proof) do
+    leaf_owner
+    |> Bubblegum.build_cancel_delegate(tree_pubkey, previous_delegate, asset_proof)
+  end
+
+  @doc """
+  Builds the instruction that redeems a compressed NFT into a voucher.
+
+  ## Parameters
+
+  * `leaf_owner` - Public key of the leaf owner, who signs the transaction
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `asset_proof` - Merkle proof data for the leaf
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()], "voucher" => String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec redeem(
+          leaf_owner :: String.t(),
+          tree_pubkey :: String.t(),
+          asset_proof :: AssetProof.t(),
+          options :: keyword()
+        ) :: result()
+  def redeem(leaf_owner, tree_pubkey, asset_proof, options \\ []) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    leaf_owner
+    |> Bubblegum.build_redeem(tree_pubkey, leaf_delegate, asset_proof)
+  end
+
+  @doc """
+  Builds the instruction that cancels a redeem and puts the leaf back in the tree.
+
+  ## Parameters
+
+  * `leaf_owner` - Public key of the leaf owner, who signs the transaction
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `asset_proof` - Merkle proof data for the leaf
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()], "voucher" => String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec cancel_redeem(leaf_owner :: String.t(), tree_pubkey :: String.t(), asset_proof :: AssetProof.t()) ::
+          result()
+  def cancel_redeem(leaf_owner, tree_pubkey, asset_proof) do
+    leaf_owner
+    |> Bubblegum.build_cancel_redeem(tree_pubkey, asset_proof)
+  end
+
+  @doc """
+  Builds the instruction that decompresses a redeemed leaf into a regular NFT.
+
+  ## Parameters
+
+  * `leaf_owner` - Public key of the leaf owner, who signs the transaction
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `nonce` - Nonce of the redeemed leaf
+  * `metadata_args` - Metadata of the leaf, which must use the original token program
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()], "mint" => String.t()}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec decompress_v1(
+          leaf_owner :: String.t(),
+          tree_pubkey :: String.t(),
+          nonce :: non_neg_integer(),
+          metadata_args :: MetadataArgs.t()
+        ) :: result()
+  def decompress_v1(leaf_owner, tree_pubkey, nonce, metadata_args) do
+    leaf_owner
+    |> Bubblegum.build_decompress_v1(tree_pubkey, nonce, metadata_args)
+  end
+
+  @doc """
+  Builds the instruction that marks a creator of a compressed NFT as verified.
+
+  ## Parameters
+
+  * `creator` - Public key of the creator, who signs and pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec verify_creator(
+          creator :: String.t(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: result()
+  def verify_creator(creator, tree_pubkey, leaf_owner, asset_proof, metadata_args, options \\ []) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    creator
+    |> Bubblegum.build_verify_creator(tree_pubkey, {leaf_owner, leaf_delegate}, asset_proof, metadata_args)
+  end
+
+  @doc """
+  Builds the instruction that removes the verified flag from a creator of a compressed NFT.
+
+  ## Parameters
+
+  * `creator` - Public key of the creator, who signs and pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec unverify_creator(
+          creator :: String.t(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: result()
+  def unverify_creator(creator, tree_pubkey, leaf_owner, asset_proof, metadata_args, options \\ []) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    creator
+    |> Bubblegum.build_unverify_creator(tree_pubkey, {leaf_owner, leaf_delegate}, asset_proof, metadata_args)
+  end
+
+  @doc """
+  Builds the instruction that verifies the collection of a compressed NFT.
+
+  ## Parameters
+
+  * `collection_authority` - Public key of the collection authority, who signs and pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `collection_mint` - Public key of the collection mint
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec verify_collection(
+          collection_authority :: String.t(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          collection_mint :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: result()
+  def verify_collection(
+        collection_authority,
+        tree_pubkey,
+        leaf_owner,
+        collection_mint,
+        asset_proof,
+        metadata_args,
+        options \\ []
+      ) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    collection_authority
+    |> Bubblegum.build_verify_collection(
+      tree_pubkey,
+      {leaf_owner, leaf_delegate},
+      collection_mint,
+      asset_proof,
+      metadata_args
+    )
+  end
+
+  @doc """
+  Builds the instruction that removes the verified flag from the collection of a compressed NFT.
+
+  ## Parameters
+
+  * `collection_authority` - Public key of the collection authority, who signs and pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `collection_mint` - Public key of the collection mint
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec unverify_collection(
+          collection_authority :: String.t(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          collection_mint :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: result()
+  def unverify_collection(
+        collection_authority,
+        tree_pubkey,
+        leaf_owner,
+        collection_mint,
+        asset_proof,
+        metadata_args,
+        options \\ []
+      ) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    collection_authority
+    |> Bubblegum.build_unverify_collection(
+      tree_pubkey,
+      {leaf_owner, leaf_delegate},
+      collection_mint,
+      asset_proof,
+      metadata_args
+    )
+  end
+
+  @doc """
+  Builds the instruction that sets and verifies the collection of a compressed NFT.
+
+  ## Parameters
+
+  * `collection_authority` - Public key of the collection authority, who signs and pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `collection_mint` - Public key of the collection mint
+  * `asset_proof` - Merkle proof data for the leaf
+  * `metadata_args` - Current metadata of the leaf, as it is hashed in the tree
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec set_and_verify_collection(
+          collection_authority :: String.t(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          collection_mint :: String.t(),
+          asset_proof :: AssetProof.t(),
+          metadata_args :: MetadataArgs.t(),
+          options :: keyword()
+        ) :: result()
+  def set_and_verify_collection(
+        collection_authority,
+        tree_pubkey,
+        leaf_owner,
+        collection_mint,
+        asset_proof,
+        metadata_args,
+        options \\ []
+      ) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    collection_authority
+    |> Bubblegum.build_set_and_verify_collection(
+      tree_pubkey,
+      {leaf_owner, leaf_delegate},
+      collection_mint,
+      asset_proof,
+      metadata_args
+    )
+  end
+
+  @doc """
+  Builds the instruction that updates the metadata of a compressed NFT.
+
+  ## Parameters
+
+  * `authority` - Public key of the update authority, who signs and pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `leaf_owner` - Public key of the leaf owner
+  * `asset_proof` - Merkle proof data for the leaf
+  * `current_metadata` - Current metadata of the leaf, as it is hashed in the tree
+  * `update_args` - Metadata fields to change (see `SolanaBubblegum.Types.UpdateArgs`)
+  * `options` - Optional keyword list with additional parameters:
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec update_metadata(
+          authority :: String.t(),
+          tree_pubkey :: String.t(),
+          leaf_owner :: String.t(),
+          asset_proof :: AssetProof.t(),
+          current_metadata :: MetadataArgs.t(),
+          update_args :: UpdateArgs.t(),
+          options :: keyword()
+        ) :: result()
+  def update_metadata(
+        authority,
+        tree_pubkey,
+        leaf_owner,
+        asset_proof,
+        current_metadata,
+        update_args,
+        options \\ []
+      ) do
+    leaf_delegate = Keyword.get(options, :leaf_delegate)
+
+    authority
+    |> Bubblegum.build_update_metadata(
+      tree_pubkey,
+      {leaf_owner, leaf_delegate},
+      asset_proof,
+      current_metadata,
+      update_args
+    )
+  end
+
+  @doc """
+  Builds the instruction that sets the tree delegate.
+
+  ## Parameters
+
+  * `tree_creator` - Public key of the tree creator, who signs and pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `new_delegate` - Public key of the new tree delegate
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec set_tree_delegate(tree_creator :: String.t(), tree_pubkey :: String.t(), new_delegate :: String.t()) ::
+          result()
+  def set_tree_delegate(tree_creator, tree_pubkey, new_delegate) do
+    tree_creator
+    |> Bubblegum.build_set_tree_delegate(tree_pubkey, new_delegate)
+  end
+
+  @doc """
+  Builds the instruction that enables or disables decompression of the leaves of a tree.
+
+  ## Parameters
+
+  * `tree_creator` - Public key of the tree creator, who signs and pays the fees
+  * `tree_pubkey` - Public key of the Merkle tree
+  * `decompressible_state` - Either `:enabled` or `:disabled`
+
+  ## Returns
+
+  * `{:ok, %{"instructions" => [Instruction.t()]}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec set_decompressible_state(
+          tree_creator :: String.t(),
+          tree_pubkey :: String.t(),
+          decompressible_state :: :enabled | :disabled
+        ) :: result()
+  def set_decompressible_state(tree_creator, tree_pubkey, decompressible_state)
+      when decompressible_state in [:enabled, :disabled] do
+    tree_creator
+    |> Bubblegum.build_set_decompressible_state(tree_pubkey, decompressible_state)
+  end
+
+  @doc """
+  Assembles instructions into an unsigned transaction, serialized and Base64 encoded.
+
+  A legacy transaction is built unless `:address_lookup_tables` is given, in which case the
+  instructions are compiled into a v0 message that loads accounts from those tables.
+
+  Recent blockhashes expire after about a minute. When signing takes longer, pass
+  `nonce: {nonce_account, nonce_authority}` (see `SolanaBubblegum.create_nonce_account/2`):
+  the stored durable nonce is used instead of a blockhash and an `AdvanceNonceAccount`
+  instruction is prepended, which makes the nonce authority one of the signers.
+
+  The signature slots are left empty; `"signers"` lists the public keys that have to sign,
+  in order, starting with the fee payer. `"message"` holds the Base64 encoded bytes each of
+  them signs with ed25519, so a KMS, HSM or custody API can sign without ever handing over a
+  secret key. Pass the signatures to `SolanaBubblegum.attach_signatures_and_send/3`.
+
+  ## Parameters
+
+  * `instructions` - Instructions returned by the other functions of this module, or the
+    whole `{:ok, %{"instructions" => ...}}` result of one of them
+  * `fee_payer` - Public key of the fee payer
+  * `options` - Optional keyword list with additional parameters:
+    * `:blockhash` - Recent blockhash to use; when omitted a fresh one is fetched
+    * `:nonce` - `{nonce_account, nonce_authority}` of a durable nonce to use instead of a blockhash
+    * `:address_lookup_tables` - Addresses of lookup tables to compile the message against
+    * `:memo` - Text to append to the transaction as an SPL Memo instruction
+    * `:rpc_url` - URL of the Solana RPC endpoint used to fetch the blockhash and lookup tables
+      (defaults to Devnet)
+
+  ## Returns
+
+  * `{:ok, %{"transaction" => String.t(), "message" => String.t(), "blockhash" => String.t(), "signers" => [String.t()]}}` - On success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, built} = SolanaBubblegum.Build.transfer(owner, tree, owner, new_owner, asset_proof)
+      {:ok, %{"transaction" => transaction}} = SolanaBubblegum.Build.transaction(built, owner)
+
+  """
+  @spec transaction(
+          instructions :: [Instruction.t()] | result(),
+          fee_payer :: String.t(),
+          options :: keyword()
+        ) :: {:ok, map()} | {:error, Error.t()}
+  def transaction(instructions, fee_payer, options \\ [])
+
+  def transaction({:ok, %{"instructions" => instructions}}, fee_payer, options),
+    do: transaction(instructions, fee_payer, options)
+
+  def transaction({:error, _reason} = error, _fee_payer, _options), do: error
+
+  def transaction(instructions, fee_payer, options) when is_list(instructions) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    blockhash = Keyword.get(options, :blockhash)
+    nonce = Keyword.get(options, :nonce)
+    address_lookup_tables = Keyword.get(options, :address_lookup_tables, [])
+    memo = Keyword.get(options, :memo)
+
+    instructions
+    |> Bubblegum.build_transaction(fee_payer, blockhash, nonce, address_lookup_tables, memo, rpc_url)
+  end
+end
@@ -0,0 +1,395 @@
+defmodule SolanaBubblegum.DAS do
+  @moduledoc """
+  Read access to compressed NFTs through the Digital Asset Standard (DAS) API.
+
+  DAS is served by indexing RPC providers such as Helius or Triton; the default public
+  Solana endpoints do not support it, so pass a DAS-enabled `:rpc_url`.
+  """
+
+  alias SolanaBubblegum.Bubblegum
+  alias SolanaBubblegum.Types.{
+    Asset,
+    AssetList,
+    DasAssetProof,
+    Error,
+    Holder,
+    HolderSnapshot,
+    OwnershipCheck,
+    PageOptions,
+    RetryPolicy,
+    RoyaltyInfo,
+    SearchAssetsParams,
+    SnapshotOptions
+  }
+
+  @default_rpc_url "https://api.devnet.solana.com"
+
+  @doc """
+  Fetches an asset with the DAS `getAsset` method.
+
+  ## Parameters
+
+  * `asset_id` - Asset ID of the NFT
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.Asset{}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec get_asset(asset_id :: String.t(), options :: keyword()) :: {:ok, Asset.t()} | {:error, Error.t()}
+  def get_asset(asset_id, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    asset_id
+    |> Bubblegum.get_asset(retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Fetches the Merkle proof of an asset with the DAS `getAssetProof` method.
+
+  ## Parameters
+
+  * `asset_id` - Asset ID of the NFT
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.DasAssetProof{}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec get_asset_proof(asset_id :: String.t(), options :: keyword()) ::
+          {:ok, DasAssetProof.t()} | {:error, Error.t()}
+  def get_asset_proof(asset_id, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    asset_id
+    |> Bubblegum.get_asset_proof(retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Lists the assets owned by a wallet with the DAS `getAssetsByOwner` method.
+
+  ## Parameters
+
+  * `owner` - Public key of the owner
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+    * `:page` - Page to fetch, starting at 1 (defaults to 1)
+    * `:limit` - Items per page, at most 1000 (defaults to 1000)
+    * `:cursor` - Cursor to continue from, used instead of `:page`
+    * `:all` - Fetch every page and return them as one list (defaults to `false`)
+    * `:max_items` - With `:all`, most assets to collect; a query that returns more fails with
+      a `:das_error` instead of a partial list (defaults to 100000)
+    * `:sort_by` - `:created`, `:updated`, `:recent_action` or `:none`
+    * `:sort_direction` - `:asc` or `:desc` (defaults to `:asc`)
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.AssetList{}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec get_assets_by_owner(owner :: String.t(), options :: keyword()) ::
+          {:ok, AssetList.t()} | {:error, Error.t()}
+  def get_assets_by_owner(owner, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    owner
+    |> Bubblegum.get_assets_by_owner(page_options(options), retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Lists the assets in a group with the DAS `getAssetsByGroup` method.
+
+  ## Parameters
+
+  * `group_key` - Group key, usually `"collection"`
+  * `group_value` - Group value, e.g. the collection mint
+  * `options` - Optional keyword list, accepting the same options as `get_assets_by_owner/2`
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.AssetList{}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec get_assets_by_group(group_key :: String.t(), group_value :: String.t(), options :: keyword()) ::
+          {:ok, AssetList.t()} | {:error, Error.t()}
+  def get_assets_by_group(group_key, group_value, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    group_key
+    |> Bubblegum.get_assets_by_group(group_value, page_options(options), retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Lists the assets of a creator with the DAS `getAssetsByCreator` method.
+
+  ## Parameters
+
+  * `creator` - Public key of the creator
+  * `options` - Optional keyword list, accepting the same options as `get_assets_by_owner/2` and:
+    * `:only_verified` - Only return assets where the creator is verified (defaults to `true`)
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.AssetList{}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec get_assets_by_creator(creator :: String.t(), options :: keyword()) ::
+          {:ok, AssetList.t()} | {:error, Error.t()}
+  def get_assets_by_creator(creator, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    only_verified = Keyword.get(options, :only_verified, true)
+
+    creator
+    |> Bubblegum.get_assets_by_creator(only_verified, page_options(options), retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Searches assets with the DAS `searchAssets` method.
+
+  ## Parameters
+
+  * `search_params` - Filters to apply (see `SolanaBubblegum.Types.SearchAssetsParams`)
+  * `options` - Optional keyword list, accepting the same options as `get_assets_by_owner/2`
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.AssetList{}}` - On success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      # Compressed, unburnt assets of a wallet within one collection
+      SolanaBubblegum.DAS.search_assets(
+        %SolanaBubblegum.Types.SearchAssetsParams{
+          owner_address: "HXtBm8XZbxaTt41uqaKhwUAa6Z1aPyvJdsZVENiWsetg",
+          grouping: {"collection", "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"},
+          compressed: true,
+          burnt: false
+        },
+        rpc_url: das_rpc_url
+      )
+
+  """
+  @spec search_assets(search_params :: SearchAssetsParams.t(), options :: keyword()) ::
+          {:ok, AssetList.t()} | {:error, Error.t()}
+  def search_assets(%SearchAssetsParams{} = search_params, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    search_params
+    |> Bubblegum.search_assets(page_options(options), retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Fetches what a sale of an asset owes its creators with the DAS `getAsset` method.
+
+  ## Parameters
+
+  * `asset_id` - Asset ID of the NFT
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.RoyaltyInfo{}}` - On success
+  * `{:error, reason}` - On failure
+  """
+  @spec get_royalty_info(asset_id :: String.t(), options :: keyword()) ::
+          {:ok, RoyaltyInfo.t()} | {:error, Error.t()}
+  def get_royalty_info(asset_id, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    asset_id
+    |> Bubblegum.get_royalty_info(retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Splits the royalty of a secondary sale between the creators of an asset.
+
+  The royalty is `seller_fee_basis_points` of `sale_price`, rounded down, and every creator
+  receives its share of it, rounded down too. Amounts are in the unit of `sale_price`, e.g. lamports.
+
+  ## Examples
+
+      {:ok, royalty_info} = SolanaBubblegum.DAS.get_royalty_info(asset_id, rpc_url: das_rpc_url)
+      SolanaBubblegum.DAS.royalty_payouts(royalty_info, 2_000_000_000)
+      #=> [{"HXtBm8XZbxaTt41uqaKhwUAa6Z1aPyvJdsZVENiWsetg", 100_000_000}]
+
+  """
+  @spec royalty_payouts(royalty_info :: RoyaltyInfo.t(), sale_price :: non_neg_integer()) ::
+          [{String.t(), non_neg_integer()}]
+  def royalty_payouts(%RoyaltyInfo{} = royalty_info, sale_price) when is_integer(sale_price) and sale_price >= 0 do
+    royalty = div(sale_price * royalty_info.seller_fee_basis_points, 10_000)
+
+    Enum.map(royalty_info.creators, fn creator -> {creator.address, div(royalty * creator.share, 100)} end)
+  end
+
+  @doc """
+  Checks whether an asset belongs to an expected owner, e.g. to gate a feature on holding an NFT.
+
+  ## Parameters
+
+  * `asset_id` - Asset ID of the NFT
+  * `expected_owner` - Public key the asset should belong to
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+    * `:require_active` - Whether a burnt or frozen asset counts as not owned (defaults to `true`)
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.OwnershipCheck{}}` - Whether the asset is owned, along with
+    its ownership record, on success
+  * `{:error, reason}` - On failure, including an invalid `expected_owner`
+
+  ## Examples
+
+      {:ok, %{owned: true}} =
+        SolanaBubblegum.DAS.verify_ownership(asset_id, wallet, rpc_url: das_rpc_url)
+
+  """
+  @spec verify_ownership(asset_id :: String.t(), expected_owner :: String.t(), options :: keyword()) ::
+          {:ok, OwnershipCheck.t()} | {:error, Error.t()}
+  def verify_ownership(asset_id, expected_owner, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+    require_active = Keyword.get(options, :require_active, true)
+
+    asset_id
+    |> Bubblegum.verify_ownership(expected_owner, require_active, retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Takes a snapshot of the holders of a collection, e.g. to airdrop to them, by paging through
+  the DAS `getAssetsByGroup` method and counting the assets of every owner.
+
+  Rate limits are kept to with a connection made with `:rate_limit` and retried with `:retry`.
+  A snapshot limited with `:max_pages` returns the cursor it stopped at, which a later call
+  resumes from; `merge_snapshots/2` adds the parts up.
+
+  ## Parameters
+
+  * `collection_mint` - Mint of the collection NFT
+  * `options` - Optional keyword list with additional parameters:
+    * `:rpc_url` - URL of a DAS-enabled Solana RPC endpoint (defaults to Devnet)
+    * `:retry` - Retry policy for rate limits, server errors and timeouts, as a keyword list
+      or a `SolanaBubblegum.Types.RetryPolicy`
+    * `:cursor` - Cursor of an incomplete snapshot to resume from
+    * `:max_pages` - Pages of 1000 assets to fetch before returning (defaults to all)
+    * `:tree` - Only count assets in this Merkle tree
+    * `:include_burnt` - Whether burnt assets count for their last owner (defaults to `false`)
+
+  ## Returns
+
+  * `{:ok, %SolanaBubblegum.Types.HolderSnapshot{}}` - Holders, largest first, on success
+  * `{:error, reason}` - On failure
+
+  ## Examples
+
+      {:ok, %{holders: holders, complete: true}} =
+        SolanaBubblegum.DAS.snapshot_holders(collection_mint, rpc_url: das_rpc_url)
+
+  """
+  @spec snapshot_holders(collection_mint :: String.t(), options :: keyword()) ::
+          {:ok, HolderSnapshot.t()} | {:error, Error.t()}
+  def snapshot_holders(collection_mint, options \\ []) do
+    rpc_url = Keyword.get(options, :rpc_url, @default_rpc_url)
+
+    collection_mint
+    |> Bubblegum.snapshot_holders(snapshot_options(options), retry_policy(options), rpc_url)
+  end
+
+  @doc """
+  Streams the holders of a collection a page at a time, as `SolanaBubblegum.Types.HolderSnapshot`
+  parts of one page each. Accepts the same options as `snapshot_holders/2`, except `:max_pages`.
+
+  The stream raises when a page cannot be fetched; the `cursor` of the last part received
+  resumes it.
+
+  ## Examples
+
+      collection_mint
+      |> SolanaBubblegum.DAS.stream_holders(rpc_url: das_rpc_url)
+      |> Enum.reduce(&SolanaBubblegum.DAS.merge_snapshots/2)
+
+  """
+  @spec stream_holders(collection_mint :: String.t(), options :: keyword()) :: Enumerable.t()
+  def stream_holders(collection_mint, options \\ []) do
+    Stream.unfold(Keyword.get(options, :cursor, :start), fn
+      :done ->
+        nil
+
+      cursor ->
+        cursor = if cursor == :start, do: nil, else: cursor
+        options = Keyword.merge(options, cursor: cursor, max_pages: 1)
+
+        case snapshot_holders(collection_mint, options) do
+          {:ok, %HolderSnapshot{complete: true} = snapshot} -> {snapshot, :done}
+          {:ok, %HolderSnapshot{cursor: next} = snapshot} -> {snapshot, next}
+          {:error, error} -> raise "snapshot of #{collection_mint} failed: #{inspect(error)}"
+        end
+    end)
+  end
+
+  @doc """
+  Adds up two parts of a holder snapshot, such as the parts of a resumed snapshot or of
+  `stream_holders/2`. The cursor and completeness are taken from `later`.
+  """
+  @spec merge_snapshots(later :: HolderSnapshot.t(), earlier :: HolderSnapshot.t()) :: HolderSnapshot.t()
+  def merge_snapshots(%HolderSnapshot{} = later, %HolderSnapshot{} = earlier) do
+    holders =
+      (earlier.holders ++ later.holders)
+      |> Enum.reduce(%{}, fn %Holder{owner: owner, count: count}, counts ->
+        Map.update(counts, owner, count, &(&1 + count))
+      end)
+      |> Enum.map(fn {owner, count} -> %Holder{owner: owner, count: count} end)
+      |> Enum.sort_by(&{-&1.count, &1.owner})
+
+    %HolderSnapshot{
+      holders: holders,
+      assets: earlier.assets + later.assets,
+      pages: earlier.pages + later.pages,
+      cursor: later.cursor,
+      complete: later.complete
+    }
+  end
+
+  defp snapshot_options(options) do
+    %SnapshotOptions{
+      cursor: Keyword.get(options, :cursor),
+      max_pages: Keyword.get(options, :max_pages),
+      tree: Keyword.get(options, :tree),
+      include_burnt: Keyword.get(options, :include_burnt, false)
+    }
+  end
+
+  defp page_options(options) do
+    %PageOptions{
+      page: Keyword.get(options, :page),
+      limit: Keyword.get(options, :limit),
+      cursor: Keyword.get(options, :cursor),
+      all: Keyword.get(options, :all, false),
+      max_items: Keyword.get(options, :max_items),
+      sort_by: Keyword.get(options, :sort_by),
+      sort_direction: Keyword.get(options, :sort_direction)
+    }
+  end
+
+  defp retry_policy(options) do
+    case Keyword.get(options, :retry, []) do
+      %RetryPolicy{} = policy -> policy
+      retry -> struct(RetryPolicy, retry)
+    end
+  end
+end
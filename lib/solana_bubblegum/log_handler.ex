@@ -0,0 +1,60 @@
+defmodule SolanaBubblegum.LogHandler do
+  @moduledoc """
+  Logs the records of the native library with `Logger`, so retries, failovers and failed
+  transactions, with their program logs, show up where the rest of the application logs.
+
+  Add it to a supervision tree; it registers itself with `SolanaBubblegum.set_log_handler/1`:
+
+      children = [
+        {SolanaBubblegum.LogHandler, level: :info}
+      ]
+
+  Each record is logged with its fields appended as `key=value` and the Rust module that
+  logged it as the `:target` metadata.
+
+  ## Options
+
+  * `:level` - Lowest level to forward, see `SolanaBubblegum.set_log_level/1` (defaults to `:warning`)
+  * `:name` - Name to register the process under (defaults to the module name)
+  """
+  use GenServer
+
+  require Logger
+
+  @doc """
+  Starts the handler.
+  """
+  @spec start_link(keyword()) :: GenServer.on_start()
+  def start_link(options \\ []) do
+    {name, options} = Keyword.pop(options, :name, __MODULE__)
+    GenServer.start_link(__MODULE__, options, name: name)
+  end
+
+  @impl true
+  def init(options) do
+    # Stops the forwarding when shut down, instead of leaving it to a dead process
+    Process.flag(:trap_exit, true)
+    :ok = SolanaBubblegum.set_log_level(Keyword.get(options, :level, :warning))
+    :ok = SolanaBubblegum.set_log_handler(self())
+    {:ok, nil}
+  end
+
+  @impl true
+  def handle_info({:bubblegum_log, level, target, message, fields}, state) do
+    Logger.log(level, fn -> format(message, fields) end, target: target)
+    {:noreply, state}
+  end
+
+  def handle_info(_message, state), do: {:noreply, state}
+
+  @impl true
+  def terminate(_reason, _state) do
+    SolanaBubblegum.set_log_handler(nil)
+  end
+
+  defp format(message, fields) when map_size(fields) == 0, do: message
+
+  defp format(message, fields) do
+    Enum.reduce(fields, message, fn {key, value}, acc -> "#{acc} #{key}=#{value}" end)
+  end
+end
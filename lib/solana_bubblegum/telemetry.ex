@@ -0,0 +1,62 @@
+defmodule SolanaBubblegum.Telemetry do
+  @moduledoc """
+  Emits the RPC requests and transactions of the native library as `:telemetry` events, for
+  dashboards of minting throughput, latency and failure rates.
+
+  Add it to a supervision tree; it registers itself with `SolanaBubblegum.set_telemetry_handler/1`:
+
+      children = [
+        SolanaBubblegum.Telemetry
+      ]
+
+  Events:
+
+  * `[:solana_bubblegum, :rpc, :stop]` - An RPC or DAS request
+  * `[:solana_bubblegum, :transaction, :stop]` - A transaction, with all its retries
+
+  Measurements:
+
+  * `:duration` - How long it took, in native time units
+  * `:attempts` - Attempts the transaction took, or the attempt an RPC request was made in
+
+  Metadata:
+
+  * `:operation` - RPC method, e.g. `"getLatestBlockhash"`, or the first Bubblegum instruction of
+    a transaction, e.g. `"mint_v1"`
+  * `:endpoint` - URL of the RPC endpoint; `nil` for transactions sent through a pool
+  * `:result` - `:ok` or `:error`
+  * `:error_class` - Why it failed: a retry class such as `:rate_limited` or `:timeout`, otherwise
+    the kind of the error, such as `:transaction_error`; `nil` on success
+  """
+  use GenServer
+
+  @doc """
+  Starts the handler, registered under the module name unless a `:name` is given.
+  """
+  @spec start_link(keyword()) :: GenServer.on_start()
+  def start_link(options \\ []) do
+    GenServer.start_link(__MODULE__, :ok, Keyword.put_new(options, :name, __MODULE__))
+  end
+
+  @impl true
+  def init(:ok) do
+    # Stops the reports when shut down, instead of leaving them to a dead process
+    Process.flag(:trap_exit, true)
+    :ok = SolanaBubblegum.set_telemetry_handler(self())
+    {:ok, nil}
+  end
+
+  @impl true
+  def handle_info({:bubblegum_telemetry, event, measurements, metadata}, state) do
+    measurements = Map.update!(measurements, :duration, &System.convert_time_unit(&1, :microsecond, :native))
+    :telemetry.execute([:solana_bubblegum, event, :stop], measurements, metadata)
+    {:noreply, state}
+  end
+
+  def handle_info(_message, state), do: {:noreply, state}
+
+  @impl true
+  def terminate(_reason, _state) do
+    SolanaBubblegum.set_telemetry_handler(nil)
+  end
+end
@@ -3,6 +3,21 @@ defmodule SolanaBubblegum.Types do
   Type definitions for the SolanaBubblegum library.
   """
 
+  @typedoc """
+  A keypair: Base58 encoded, the JSON array `solana-keygen` writes as a string or a list of
+  bytes, or a raw 64 byte binary. A 32 byte seed is accepted in any of these formats too.
+  `{:encrypted, encrypted_keypair, passphrase}` is a keypair encrypted by
+  `SolanaBubblegum.encrypt_keypair/2`, a `SolanaBubblegum.Types.SecretKey` one loaded by
+  `SolanaBubblegum.load_secret_key/1`, and `{:signer, name}` one registered by
+  `SolanaBubblegum.register_signer/2`.
+  """
+  @type keypair ::
+          String.t()
+          | [byte()]
+          | {:encrypted, String.t(), String.t()}
+          | {:signer, atom() | String.t()}
+          | SolanaBubblegum.Types.SecretKey.t()
+
   defmodule TreeConfig do
     @moduledoc """
     Configuration for a Merkle tree used in compressed NFTs.
@@ -16,6 +31,299 @@ defmodule SolanaBubblegum.Types do
     }
   end
 
+  defmodule TreeConfigAccount do
+    @moduledoc """
+    The Bubblegum tree config of a Merkle tree, as read by `SolanaBubblegum.get_tree_config/2`.
+
+    * `:address` - Address of the tree config PDA, which is the authority of the Merkle tree
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:tree_creator` - Wallet that created the tree
+    * `:tree_delegate` - Wallet allowed to mint to a private tree, the creator unless delegated
+    * `:total_mint_capacity` - NFTs the tree holds
+    * `:num_minted` - NFTs minted so far
+    * `:is_public` - Whether anyone may mint to the tree
+    * `:is_decompressible` - `:enabled` or `:disabled`
+    """
+    defstruct [
+      :address,
+      :merkle_tree,
+      :tree_creator,
+      :tree_delegate,
+      :total_mint_capacity,
+      :num_minted,
+      :is_public,
+      :is_decompressible
+    ]
+
+    @type t :: %__MODULE__{
+      address: String.t(),
+      merkle_tree: String.t(),
+      tree_creator: String.t(),
+      tree_delegate: String.t(),
+      total_mint_capacity: non_neg_integer(),
+      num_minted: non_neg_integer(),
+      is_public: boolean(),
+      is_decompressible: :enabled | :disabled
+    }
+  end
+
+  defmodule LeafSchemaEvent do
+    @moduledoc """
+    A leaf written by Bubblegum, as logged through the noop program and decoded by
+    `SolanaBubblegum.parse_transaction_events/2`.
+
+    * `:asset_id` - ID of the compressed NFT held by the leaf
+    * `:owner` - Owner of the NFT
+    * `:delegate` - Delegate of the NFT, the owner when there is none
+    * `:nonce` - Nonce of the leaf, which is also its index in the tree
+    * `:data_hash` - Hash of the metadata, base58 encoded
+    * `:creator_hash` - Hash of the creators, base58 encoded
+    * `:leaf_hash` - Hash of the leaf, base58 encoded
+    """
+    defstruct [:asset_id, :owner, :delegate, :nonce, :data_hash, :creator_hash, :leaf_hash]
+
+    @type t :: %__MODULE__{
+      asset_id: String.t(),
+      owner: String.t(),
+      delegate: String.t(),
+      nonce: non_neg_integer(),
+      data_hash: String.t(),
+      creator_hash: String.t(),
+      leaf_hash: String.t()
+    }
+  end
+
+  defmodule ChangeLogEvent do
+    @moduledoc """
+    A change to a Merkle tree, as logged by spl-account-compression and decoded by
+    `SolanaBubblegum.parse_transaction_events/2`.
+
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:leaf_index` - Index of the changed leaf
+    * `:sequence_number` - Sequence number of the tree after the change
+    * `:root` - Root of the tree after the change, base58 encoded
+    """
+    defstruct [:merkle_tree, :leaf_index, :sequence_number, :root]
+
+    @type t :: %__MODULE__{
+      merkle_tree: String.t(),
+      leaf_index: non_neg_integer(),
+      sequence_number: non_neg_integer(),
+      root: String.t()
+    }
+  end
+
+  defmodule TreeEvent do
+    @moduledoc """
+    The compression events of a transaction that changed a subscribed tree, delivered by
+    `SolanaBubblegum.subscribe_tree/3` as `{:bubblegum_tree_event, ref, %TreeEvent{}}`.
+
+    * `:signature` - Signature of the transaction
+    * `:slot` - Slot the transaction landed in
+    * `:leaf_schema_events` - Leaves Bubblegum wrote, see `SolanaBubblegum.Types.LeafSchemaEvent`
+    * `:change_log_events` - Tree changes, see `SolanaBubblegum.Types.ChangeLogEvent`
+    """
+    defstruct [:signature, :slot, leaf_schema_events: [], change_log_events: []]
+
+    @type t :: %__MODULE__{
+      signature: String.t(),
+      slot: non_neg_integer(),
+      leaf_schema_events: [SolanaBubblegum.Types.LeafSchemaEvent.t()],
+      change_log_events: [SolanaBubblegum.Types.ChangeLogEvent.t()]
+    }
+  end
+
+  defmodule SignatureInfo do
+    @moduledoc """
+    A transaction that touched an address, as listed by
+    `SolanaBubblegum.get_signatures_for_address/2`.
+
+    * `:signature` - Signature of the transaction
+    * `:slot` - Slot the transaction landed in
+    * `:block_time` - Unix timestamp of the block, `nil` when the cluster did not record it
+    * `:memo` - Memo attached to the transaction, if any
+    * `:error` - Why the transaction failed, `nil` when it succeeded
+    """
+    defstruct [:signature, :slot, :block_time, :memo, :error]
+
+    @type t :: %__MODULE__{
+      signature: String.t(),
+      slot: non_neg_integer(),
+      block_time: integer() | nil,
+      memo: String.t() | nil,
+      error: String.t() | nil
+    }
+  end
+
+  defmodule InstructionSummary do
+    @moduledoc """
+    One instruction of a transaction fetched by `SolanaBubblegum.get_transaction/2`.
+
+    * `:index` - Index of the top-level instruction, shared by the instructions it invoked
+    * `:inner` - Whether the instruction was invoked by the top-level one rather than being part
+      of the transaction itself
+    * `:program_id` - Public key of the program
+    * `:program` - Name of the program, e.g. `:bubblegum`, `:account_compression`, `:noop`,
+      `:token_metadata`, `:token`, `:associated_token`, `:system` or `:compute_budget`; `nil`
+      for other programs
+    * `:instruction` - Name of the instruction, e.g. `:mint_v1` or `:transfer`; only Bubblegum
+      and spl-account-compression instructions are named
+    * `:accounts` - Public keys of the accounts the instruction was given
+    * `:data` - Instruction data, base58 encoded
+    """
+    defstruct [:index, :inner, :program_id, :program, :instruction, accounts: [], data: ""]
+
+    @type t :: %__MODULE__{
+      index: non_neg_integer(),
+      inner: boolean(),
+      program_id: String.t(),
+      program: atom() | nil,
+      instruction: atom() | nil,
+      accounts: [String.t()],
+      data: String.t()
+    }
+  end
+
+  defmodule TreeSubscription do
+    @moduledoc """
+    A websocket subscription to the changes of a Merkle tree, returned by
+    `SolanaBubblegum.subscribe_tree/3`. It runs until `SolanaBubblegum.unsubscribe_tree/1` is
+    called or it is garbage collected.
+    """
+    @type t :: reference()
+  end
+
+  defmodule MintCounter do
+    @moduledoc """
+    The leaf index the next mint to a tree gets, returned by `SolanaBubblegum.new_mint_counter/2`.
+
+    It starts at the tree config's `num_minted` and counts up locally, so the asset IDs of many
+    mints sent at once can be known before they confirm. Counters are safe to share between
+    processes and are freed when garbage collected.
+    """
+    @type t :: reference()
+  end
+
+  defmodule ProofCache do
+    @moduledoc """
+    Leaves and proofs of recently transferred assets, returned by `SolanaBubblegum.new_proof_cache/1`.
+
+    A cached proof is used while the sequence number of its tree is unchanged; any change to
+    the tree drops every proof cached for it. The least recently used asset is evicted once the
+    cache is full. Caches are safe to share between processes and are freed when garbage collected.
+    """
+    @type t :: reference()
+  end
+
+  defmodule SecretKey do
+    @moduledoc """
+    A keypair held by the native code, returned by `SolanaBubblegum.load_secret_key/1`. Its
+    bytes never exist as a BEAM binary and are wiped once it is garbage collected.
+    """
+    @type t :: reference()
+  end
+
+  defmodule Voucher do
+    @moduledoc """
+    A redeemed leaf waiting to be decompressed, as listed by `SolanaBubblegum.check_tree_empty/2`.
+    `SolanaBubblegum.cancel_redeem/4` closes it and puts the leaf back in the tree.
+
+    * `:address` - Address of the voucher account
+    * `:asset_id` - Asset ID of the redeemed leaf
+    * `:leaf_owner` - Public key of the leaf owner, who gets the rent back
+    * `:index` - Index of the leaf in the tree
+    * `:nonce` - Nonce of the leaf
+    * `:lamports` - Rent held by the voucher account
+    """
+    defstruct [:address, :asset_id, :leaf_owner, :index, :nonce, :lamports]
+
+    @type t :: %__MODULE__{
+      address: String.t(),
+      asset_id: String.t(),
+      leaf_owner: String.t(),
+      index: non_neg_integer(),
+      nonce: non_neg_integer(),
+      lamports: non_neg_integer()
+    }
+  end
+
+  defmodule MerkleTreeState do
+    @moduledoc """
+    The header and current state of a concurrent Merkle tree, as read by
+    `SolanaBubblegum.get_merkle_tree_state/2`.
+
+    * `:address` - Public key of the Merkle tree
+    * `:max_depth` - Depth of the tree
+    * `:max_buffer_size` - Slots in the change log buffer
+    * `:authority` - Authority of the tree, the tree config PDA for Bubblegum trees
+    * `:creation_slot` - Slot the tree was created in
+    * `:sequence_number` - Changes applied to the tree so far
+    * `:active_index` - Change log buffer slot holding the latest change
+    * `:buffer_size` - Change log buffer slots in use
+    * `:root` - Current root of the tree, base58 encoded
+    * `:rightmost_index` - Leaves appended so far, which is the index of the next leaf
+    * `:canopy_depth` - Levels of the tree cached on chain
+    """
+    defstruct [
+      :address,
+      :max_depth,
+      :max_buffer_size,
+      :authority,
+      :creation_slot,
+      :sequence_number,
+      :active_index,
+      :buffer_size,
+      :root,
+      :rightmost_index,
+      :canopy_depth
+    ]
+
+    @type t :: %__MODULE__{
+      address: String.t(),
+      max_depth: non_neg_integer(),
+      max_buffer_size: non_neg_integer(),
+      authority: String.t(),
+      creation_slot: non_neg_integer(),
+      sequence_number: non_neg_integer(),
+      active_index: non_neg_integer(),
+      buffer_size: non_neg_integer(),
+      root: String.t(),
+      rightmost_index: non_neg_integer(),
+      canopy_depth: non_neg_integer()
+    }
+  end
+
+  defmodule TreeRecommendation do
+    @moduledoc """
+    A tree shape suggested by `SolanaBubblegum.recommend_tree_config/2`.
+
+    * `:max_depth`, `:max_buffer_size` and `:canopy_depth` - Arguments for `SolanaBubblegum.create_tree_config/6`
+    * `:capacity` - NFTs the tree holds
+    * `:proof_length` - Proof nodes every transaction on the tree has to carry
+    * `:account_size` - Size of the tree account in bytes
+    * `:rent_exempt_lamports` - Rent the tree account needs
+    """
+    defstruct [
+      :max_depth,
+      :max_buffer_size,
+      :canopy_depth,
+      :capacity,
+      :proof_length,
+      :account_size,
+      :rent_exempt_lamports
+    ]
+
+    @type t :: %__MODULE__{
+      max_depth: non_neg_integer(),
+      max_buffer_size: pos_integer(),
+      canopy_depth: non_neg_integer(),
+      capacity: pos_integer(),
+      proof_length: non_neg_integer(),
+      account_size: pos_integer(),
+      rent_exempt_lamports: non_neg_integer()
+    }
+  end
+
   defmodule Creator do
     @moduledoc """
     Creator information for an NFT.
@@ -29,9 +337,35 @@ defmodule SolanaBubblegum.Types do
     }
   end
 
+  defmodule Uses do
+    @moduledoc """
+    How often an NFT can be used, as defined by the Token Metadata standard.
+
+    * `:use_method` - `:burn` (the NFT is burnt when used up), `:multiple` or `:single`
+    * `:remaining` - Uses left, at most `:total`
+    * `:total` - Uses in total; exactly 1 for `:single`
+    """
+    defstruct [:remaining, :total, use_method: :multiple]
+
+    @type t :: %__MODULE__{
+      use_method: :burn | :multiple | :single,
+      remaining: non_neg_integer(),
+      total: non_neg_integer()
+    }
+  end
+
   defmodule MetadataArgs do
     @moduledoc """
     Metadata arguments for an NFT.
+
+    `:token_program_version` (`:original` or `:token2022`) and `:token_standard`
+    (`:non_fungible`, `:fungible_asset`, `:fungible` or `:non_fungible_edition`) default to
+    `:original` and `:non_fungible` when left as `nil`.
+
+    `:collection_verified` tells whether the collection of an existing leaf is verified. The
+    leaf's data hash covers it, so set it to `true` when passing the metadata of a leaf minted
+    with `mint_to_collection_v1` or verified later, e.g. to `unverify_collection` or
+    `update_metadata`. Leave it `false` when minting.
     """
     defstruct [
       :name,
@@ -43,7 +377,10 @@ defmodule SolanaBubblegum.Types do
       :edition_nonce,
       :creators,
       :collection,
-      :uses
+      :uses,
+      :token_program_version,
+      :token_standard,
+      collection_verified: false
     ]
 
     @type t :: %__MODULE__{
@@ -56,7 +393,1768 @@ defmodule SolanaBubblegum.Types do
       edition_nonce: non_neg_integer() | nil,
       creators: [Creator.t()],
       collection: String.t() | nil,
-      uses: non_neg_integer() | nil
+      collection_verified: boolean(),
+      uses: SolanaBubblegum.Types.Uses.t() | nil,
+      token_program_version: :original | :token2022 | nil,
+      token_standard: :non_fungible | :fungible_asset | :fungible | :non_fungible_edition | nil
+    }
+  end
+
+  defmodule OffchainFile do
+    @moduledoc """
+    A file of an NFT listed in the `properties` of its off-chain metadata.
+
+    * `:uri` - Where the file is stored
+    * `:mime_type` - MIME type of the file, e.g. `"image/png"`; `type` in the JSON
+    * `:cdn` - Whether the file is served from a CDN, `nil` to leave it out
+    """
+    @enforce_keys [:uri, :mime_type]
+    defstruct [:uri, :mime_type, cdn: nil]
+
+    @type t :: %__MODULE__{
+      uri: String.t(),
+      mime_type: String.t(),
+      cdn: boolean() | nil
+    }
+  end
+
+  defmodule OffchainProperties do
+    @moduledoc """
+    The `properties` of off-chain metadata.
+
+    * `:files` - Every file of the NFT, the image included
+    * `:category` - `"image"`, `"video"`, `"audio"`, `"vr"` or `"html"`
+    """
+    defstruct files: [], category: nil
+
+    @type t :: %__MODULE__{
+      files: [SolanaBubblegum.Types.OffchainFile.t()],
+      category: String.t() | nil
+    }
+  end
+
+  defmodule OffchainAttribute do
+    @moduledoc """
+    A trait of an NFT, as marketplaces show it.
+
+    * `:trait_type` - Name of the trait; each appears once per NFT
+    * `:value` - Its value, a string or a number
+    * `:display_type` - How a numeric value is rendered, e.g. `"number"` or `"boost_percentage"`
+    """
+    @enforce_keys [:trait_type, :value]
+    defstruct [:trait_type, :value, display_type: nil]
+
+    @type t :: %__MODULE__{
+      trait_type: String.t(),
+      value: String.t() | integer() | float(),
+      display_type: String.t() | nil
+    }
+  end
+
+  defmodule OffchainMetadata do
+    @moduledoc """
+    The JSON document the `uri` of a `SolanaBubblegum.Types.MetadataArgs` points to, laid out as
+    the Metaplex token standard describes it.
+
+    * `:name` - Name of the NFT; at most 32 bytes, like the on-chain name it should match
+    * `:symbol` - Symbol of the collection, at most 10 bytes
+    * `:description` - Description of the NFT
+    * `:seller_fee_basis_points` - Royalty, at most 10000
+    * `:image` - URI of the image, over http(s), Arweave (`ar://`) or IPFS (`ipfs://`)
+    * `:animation_url` - URI of a video, audio or 3D file
+    * `:external_url` - Web page of the NFT
+    * `:attributes` - Traits, see `SolanaBubblegum.Types.OffchainAttribute`
+    * `:properties` - Files and category, see `SolanaBubblegum.Types.OffchainProperties`
+
+    Fields left as `nil` are omitted from the JSON.
+    """
+    @enforce_keys [:name, :image]
+    defstruct [
+      :name,
+      :image,
+      symbol: nil,
+      description: nil,
+      seller_fee_basis_points: nil,
+      animation_url: nil,
+      external_url: nil,
+      attributes: [],
+      properties: nil
+    ]
+
+    @type t :: %__MODULE__{
+      name: String.t(),
+      image: String.t(),
+      symbol: String.t() | nil,
+      description: String.t() | nil,
+      seller_fee_basis_points: non_neg_integer() | nil,
+      animation_url: String.t() | nil,
+      external_url: String.t() | nil,
+      attributes: [SolanaBubblegum.Types.OffchainAttribute.t()],
+      properties: SolanaBubblegum.Types.OffchainProperties.t() | nil
+    }
+  end
+
+  defmodule UploadRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.upload_metadata/2`, which stores data, usually
+    off-chain metadata JSON, permanently and returns its URI.
+
+    * `:payer_keypair` - Base58 encoded keypair that signs the upload and pays for it
+    * `:data` - What to store
+    * `:content_type` - MIME type of the data (defaults to `"application/json"`)
+    * `:provider` - Where to store it; `:irys` stores it on Arweave through an Irys node, paid in SOL
+    * `:node_url` - URL of the node to upload to (defaults to the Irys devnet node, whose uploads
+      are removed after a while; use `"https://node1.irys.xyz"` on mainnet)
+    * `:gateway_url` - Gateway the returned URI points at (defaults to `"https://gateway.irys.xyz"`)
+    * `:fund` - Top up the payer's balance on the node from its SOL when it is below the price
+      of the upload (defaults to `true`)
+    * `:tags` - Extra `{name, value}` tags stored with the data
+    """
+    @enforce_keys [:payer_keypair, :data]
+    defstruct [
+      :payer_keypair,
+      :data,
+      content_type: "application/json",
+      provider: :irys,
+      node_url: "https://devnet.irys.xyz",
+      gateway_url: "https://gateway.irys.xyz",
+      fund: true,
+      tags: []
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      data: String.t(),
+      content_type: String.t(),
+      provider: :irys,
+      node_url: String.t(),
+      gateway_url: String.t(),
+      fund: boolean(),
+      tags: [{String.t(), String.t()}]
+    }
+  end
+
+  defmodule MintCompleteRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.mint_complete/2`, which uploads the off-chain metadata
+    of an NFT and mints the NFT against it. The on-chain name, symbol and royalty are taken from
+    the off-chain metadata.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer, who also pays for the upload
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:collection_mint` - Mint of the collection NFT, or `nil` to mint without a collection
+    * `:metadata` - A `SolanaBubblegum.Types.OffchainMetadata`
+    * `:creators` - Creators of the NFT, see `SolanaBubblegum.Types.Creator`
+    * `:is_mutable` - Whether the metadata can be updated later (defaults to `true`)
+    * `:leaf_owner`, `:leaf_delegate`, `:tree_creator_or_delegate_keypair`,
+      `:collection_authority_keypair` and `:collection_authority_record` - As for
+      `SolanaBubblegum.Types.MintToCollectionRequest`; without a collection the payer must be
+      the tree creator or delegate
+    * `:provider`, `:node_url`, `:gateway_url` and `:fund` - Where the JSON is uploaded, as for
+      `SolanaBubblegum.Types.UploadRequest`
+    """
+    @enforce_keys [:payer_keypair, :merkle_tree, :metadata]
+    defstruct [
+      :payer_keypair,
+      :merkle_tree,
+      :metadata,
+      collection_mint: nil,
+      creators: [],
+      is_mutable: true,
+      leaf_owner: nil,
+      leaf_delegate: nil,
+      tree_creator_or_delegate_keypair: nil,
+      collection_authority_keypair: nil,
+      collection_authority_record: nil,
+      provider: :irys,
+      node_url: "https://devnet.irys.xyz",
+      gateway_url: "https://gateway.irys.xyz",
+      fund: true
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      metadata: SolanaBubblegum.Types.OffchainMetadata.t(),
+      collection_mint: String.t() | nil,
+      creators: [SolanaBubblegum.Types.Creator.t()],
+      is_mutable: boolean(),
+      leaf_owner: String.t() | nil,
+      leaf_delegate: String.t() | nil,
+      tree_creator_or_delegate_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_record: String.t() | nil,
+      provider: :irys,
+      node_url: String.t(),
+      gateway_url: String.t(),
+      fund: boolean()
+    }
+  end
+
+  defmodule UpdateArgs do
+    @moduledoc """
+    Metadata fields to change on an existing compressed NFT.
+    Fields left as `nil` keep their current value.
+    """
+    defstruct [
+      :name,
+      :symbol,
+      :uri,
+      :creators,
+      :seller_fee_basis_points,
+      :primary_sale_happened,
+      :is_mutable
+    ]
+
+    @type t :: %__MODULE__{
+      name: String.t() | nil,
+      symbol: String.t() | nil,
+      uri: String.t() | nil,
+      creators: [Creator.t()] | nil,
+      seller_fee_basis_points: non_neg_integer() | nil,
+      primary_sale_happened: boolean() | nil,
+      is_mutable: boolean() | nil
+    }
+  end
+
+  defmodule AssetProof do
+    @moduledoc """
+    Merkle proof data for a compressed NFT leaf, as returned by a DAS `getAssetProof` call.
+    Hashes and proof nodes are Base58 encoded.
+
+    `proof` lists the nodes from the leaf up. Set `canopy_depth` to the canopy depth of the
+    tree to leave out the top nodes the canopy already holds on chain.
+    """
+    defstruct [:root, :data_hash, :creator_hash, :nonce, :index, proof: [], canopy_depth: nil]
+
+    @type t :: %__MODULE__{
+      root: String.t(),
+      data_hash: String.t(),
+      creator_hash: String.t(),
+      nonce: non_neg_integer(),
+      index: non_neg_integer(),
+      proof: [String.t()],
+      canopy_depth: non_neg_integer() | nil
+    }
+  end
+
+  defmodule AssetMetadata do
+    @moduledoc """
+    On-chain metadata of an asset as reported by the DAS API.
+    """
+    defstruct [:name, :symbol, :description]
+
+    @type t :: %__MODULE__{
+      name: String.t(),
+      symbol: String.t(),
+      description: String.t() | nil
+    }
+  end
+
+  defmodule AssetContent do
+    @moduledoc """
+    Content of an asset as reported by the DAS API.
+    """
+    defstruct [:json_uri, :metadata]
+
+    @type t :: %__MODULE__{
+      json_uri: String.t(),
+      metadata: SolanaBubblegum.Types.AssetMetadata.t()
+    }
+  end
+
+  defmodule AssetCompression do
+    @moduledoc """
+    Compression information of an asset as reported by the DAS API.
+    """
+    defstruct [:eligible, :compressed, :data_hash, :creator_hash, :asset_hash, :tree, :seq, :leaf_id]
+
+    @type t :: %__MODULE__{
+      eligible: boolean(),
+      compressed: boolean(),
+      data_hash: String.t(),
+      creator_hash: String.t(),
+      asset_hash: String.t(),
+      tree: String.t(),
+      seq: non_neg_integer(),
+      leaf_id: non_neg_integer()
+    }
+  end
+
+  defmodule AssetOwnership do
+    @moduledoc """
+    Ownership information of an asset as reported by the DAS API.
+    """
+    defstruct [:owner, :delegate, :delegated, :frozen, :ownership_model]
+
+    @type t :: %__MODULE__{
+      owner: String.t(),
+      delegate: String.t() | nil,
+      delegated: boolean(),
+      frozen: boolean(),
+      ownership_model: String.t()
+    }
+  end
+
+  defmodule AssetGrouping do
+    @moduledoc """
+    A group (e.g. a collection) an asset belongs to.
+    """
+    defstruct [:group_key, :group_value]
+
+    @type t :: %__MODULE__{
+      group_key: String.t(),
+      group_value: String.t()
+    }
+  end
+
+  defmodule Asset do
+    @moduledoc """
+    An asset as returned by the DAS `getAsset` method.
+    """
+    defstruct [:id, :interface, :content, :compression, :ownership, :mutable, :burnt, grouping: []]
+
+    @type t :: %__MODULE__{
+      id: String.t(),
+      interface: String.t(),
+      content: SolanaBubblegum.Types.AssetContent.t(),
+      compression: SolanaBubblegum.Types.AssetCompression.t(),
+      ownership: SolanaBubblegum.Types.AssetOwnership.t(),
+      grouping: [SolanaBubblegum.Types.AssetGrouping.t()],
+      mutable: boolean(),
+      burnt: boolean()
     }
   end
+
+  defmodule DasAssetProof do
+    @moduledoc """
+    Merkle proof of an asset as returned by the DAS `getAssetProof` method.
+    """
+    defstruct [:root, :node_index, :leaf, :tree_id, proof: []]
+
+    @type t :: %__MODULE__{
+      root: String.t(),
+      proof: [String.t()],
+      node_index: non_neg_integer(),
+      leaf: String.t(),
+      tree_id: String.t()
+    }
+  end
+
+  defmodule AssetList do
+    @moduledoc """
+    A page of assets returned by a DAS query, with its pagination metadata.
+    `page` and `cursor` are `nil` when all pages were fetched at once.
+    """
+    defstruct [:total, :limit, :page, :cursor, items: []]
+
+    @type t :: %__MODULE__{
+      total: non_neg_integer(),
+      limit: non_neg_integer(),
+      page: pos_integer() | nil,
+      cursor: String.t() | nil,
+      items: [SolanaBubblegum.Types.Asset.t()]
+    }
+  end
+
+  defmodule RoyaltyInfo do
+    @moduledoc """
+    What a sale of an asset owes its creators, as reported by the DAS API.
+
+    * `:seller_fee_basis_points` - Royalty on every sale, in hundredths of a percent
+    * `:primary_sale_happened` - Whether the first sale already happened; marketplaces commonly
+      pay the whole price of a primary sale to the creators
+    * `:royalty_model` - `"creators"`, `"fanout"` or `"single"`
+    * `:locked` - Whether the royalty can no longer be changed
+    * `:creators` - Creators and their share of the royalty, in percent
+    """
+    defstruct [:asset_id, :seller_fee_basis_points, :primary_sale_happened, :royalty_model, :locked, creators: []]
+
+    @type t :: %__MODULE__{
+      asset_id: String.t(),
+      seller_fee_basis_points: non_neg_integer(),
+      primary_sale_happened: boolean(),
+      royalty_model: String.t(),
+      locked: boolean(),
+      creators: [SolanaBubblegum.Types.Creator.t()]
+    }
+  end
+
+  defmodule OwnershipCheck do
+    @moduledoc """
+    Result of `SolanaBubblegum.DAS.verify_ownership/3`. `owned` is `true` when the asset belongs
+    to the expected owner and, unless the check was told otherwise, is neither burnt nor frozen.
+    """
+    defstruct [:asset_id, :owned, :burnt, :ownership]
+
+    @type t :: %__MODULE__{
+      asset_id: String.t(),
+      owned: boolean(),
+      burnt: boolean(),
+      ownership: SolanaBubblegum.Types.AssetOwnership.t()
+    }
+  end
+
+  defmodule PageOptions do
+    @moduledoc """
+    Pagination and sorting options for DAS queries.
+
+    With `:all`, every page is fetched; `:max_items` caps how many assets that may collect
+    before the query fails (defaults to 100000 when `nil`).
+    """
+    defstruct [:page, :limit, :cursor, :sort_by, :sort_direction, :max_items, all: false]
+
+    @type t :: %__MODULE__{
+      page: pos_integer() | nil,
+      limit: pos_integer() | nil,
+      cursor: String.t() | nil,
+      all: boolean(),
+      max_items: pos_integer() | nil,
+      sort_by: :created | :updated | :recent_action | :none | nil,
+      sort_direction: :asc | :desc | nil
+    }
+  end
+
+  defmodule SnapshotOptions do
+    @moduledoc """
+    How much of a collection `SolanaBubblegum.DAS.snapshot_holders/2` covers and which assets count.
+
+    * `:cursor` - Cursor an incomplete snapshot stopped at, to resume from
+    * `:max_pages` - Pages of 1000 assets to fetch before returning, or `nil` for the whole collection
+    * `:tree` - Only count assets in this Merkle tree
+    * `:include_burnt` - Whether burnt assets count for their last owner (defaults to `false`)
+    """
+    defstruct [cursor: nil, max_pages: nil, tree: nil, include_burnt: false]
+
+    @type t :: %__MODULE__{
+      cursor: String.t() | nil,
+      max_pages: pos_integer() | nil,
+      tree: String.t() | nil,
+      include_burnt: boolean()
+    }
+  end
+
+  defmodule Holder do
+    @moduledoc """
+    An owner in a holder snapshot and how many of the counted assets it holds.
+    """
+    defstruct [:owner, :count]
+
+    @type t :: %__MODULE__{
+      owner: String.t(),
+      count: pos_integer()
+    }
+  end
+
+  defmodule HolderSnapshot do
+    @moduledoc """
+    Holders of a collection, largest first, as counted over the pages fetched.
+    When `complete` is `false`, `cursor` is where the next snapshot resumes.
+    """
+    defstruct [:assets, :pages, :cursor, :complete, holders: []]
+
+    @type t :: %__MODULE__{
+      holders: [SolanaBubblegum.Types.Holder.t()],
+      assets: non_neg_integer(),
+      pages: non_neg_integer(),
+      cursor: String.t() | nil,
+      complete: boolean()
+    }
+  end
+
+  defmodule SearchAssetsParams do
+    @moduledoc """
+    Filters for the DAS `searchAssets` method. Fields left as `nil` are not filtered on.
+    `condition_type` controls whether all (`:all`, the default) or any (`:any`) filter must match.
+    """
+    defstruct [
+      :condition_type,
+      :interface,
+      :owner_address,
+      :creator_address,
+      :creator_verified,
+      :authority_address,
+      :grouping,
+      :delegate,
+      :frozen,
+      :supply_mint,
+      :compressed,
+      :compressible,
+      :royalty_target_type,
+      :royalty_target,
+      :burnt,
+      :json_uri
+    ]
+
+    @type t :: %__MODULE__{
+      condition_type: :all | :any | nil,
+      interface: String.t() | nil,
+      owner_address: String.t() | nil,
+      creator_address: String.t() | nil,
+      creator_verified: boolean() | nil,
+      authority_address: String.t() | nil,
+      grouping: {String.t(), String.t()} | nil,
+      delegate: String.t() | nil,
+      frozen: boolean() | nil,
+      supply_mint: String.t() | nil,
+      compressed: boolean() | nil,
+      compressible: boolean() | nil,
+      royalty_target_type: String.t() | nil,
+      royalty_target: String.t() | nil,
+      burnt: boolean() | nil,
+      json_uri: String.t() | nil
+    }
+  end
+
+  defmodule AccountMeta do
+    @moduledoc """
+    An account referenced by an instruction, with its signer and writable flags.
+    """
+    defstruct [:pubkey, is_signer: false, is_writable: false]
+
+    @type t :: %__MODULE__{
+      pubkey: String.t(),
+      is_signer: boolean(),
+      is_writable: boolean()
+    }
+  end
+
+  defmodule Instruction do
+    @moduledoc """
+    An unsigned Solana instruction as returned by the build-only functions.
+    `data` holds the raw instruction data.
+    """
+    defstruct [:program_id, :data, accounts: []]
+
+    @type t :: %__MODULE__{
+      program_id: String.t(),
+      accounts: [SolanaBubblegum.Types.AccountMeta.t()],
+      data: binary()
+    }
+  end
+
+  defmodule Connection do
+    @moduledoc """
+    A connection to a Solana RPC endpoint, returned by `SolanaBubblegum.connect/2`, or to a
+    pool of endpoints, returned by `SolanaBubblegum.connect_pool/2`.
+
+    It wraps native RPC clients whose HTTP connections are kept open and reused, and can be
+    passed as `:rpc_url` to every function that talks to the network. Connections are safe to
+    share between processes and are closed when garbage collected.
+    """
+    @type t :: reference()
+  end
+
+  defmodule RateLimit do
+    @moduledoc """
+    Request rate a connection keeps to, so public endpoints do not answer with HTTP 429.
+
+    * `:requests_per_second` - Average rate of RPC and DAS requests
+    * `:burst` - Requests allowed at once after the connection was idle (defaults to one
+      second worth of requests)
+
+    Requests over the limit wait for their turn instead of failing.
+    """
+    @enforce_keys [:requests_per_second]
+    defstruct [:requests_per_second, burst: nil]
+
+    @type t :: %__MODULE__{
+      requests_per_second: pos_integer(),
+      burst: pos_integer() | nil
+    }
+  end
+
+  defmodule HttpOptions do
+    @moduledoc """
+    How a connection makes its RPC and DAS requests over HTTP.
+
+    * `:timeout` - Milliseconds before a request is abandoned (defaults to 30 seconds)
+    * `:proxy` - URL of an HTTP or HTTPS proxy every request goes through (defaults to none)
+    * `:headers` - List of `{name, value}` headers added to every request, such as an API key
+      the provider expects in `x-api-key` (defaults to none)
+    """
+    defstruct [:timeout, :proxy, headers: []]
+
+    @type t :: %__MODULE__{
+      timeout: pos_integer() | nil,
+      proxy: String.t() | nil,
+      headers: [{String.t(), String.t()}]
+    }
+  end
+
+  defmodule SendOptions do
+    @moduledoc """
+    Options for submitting a transaction.
+
+    * `:skip_preflight` - Skip the preflight simulation (defaults to `false`)
+    * `:preflight_commitment` - `:processed`, `:confirmed` or `:finalized` commitment for the
+      preflight simulation
+    * `:max_retries` - How often the RPC node retries sending the transaction to the leader;
+      when unset the node keeps retrying until the blockhash expires
+    * `:min_context_slot` - Minimum slot the node has to have reached to run the preflight
+    * `:confirm` - Wait until the transaction is confirmed (defaults to `true`)
+    * `:commitment` - `:processed`, `:confirmed` or `:finalized` commitment the blockhash is
+      fetched at and the transaction is confirmed to (defaults to the commitment of the
+      connection, `:confirmed` for a URL)
+    """
+    defstruct [:preflight_commitment, :max_retries, :min_context_slot, :commitment, skip_preflight: false, confirm: true]
+
+    @type t :: %__MODULE__{
+      skip_preflight: boolean(),
+      preflight_commitment: :processed | :confirmed | :finalized | nil,
+      max_retries: non_neg_integer() | nil,
+      min_context_slot: non_neg_integer() | nil,
+      confirm: boolean(),
+      commitment: :processed | :confirmed | :finalized | nil
+    }
+  end
+
+  defmodule RetryPolicy do
+    @moduledoc """
+    How RPC and DAS calls are retried after transient errors.
+
+    * `:max_attempts` - Attempts in total, including the first one (defaults to 3)
+    * `:base_delay_ms` - Delay before the second attempt; it doubles after every further
+      attempt (defaults to 500)
+    * `:max_delay_ms` - Upper bound of the delay between attempts (defaults to 5 seconds)
+    * `:jitter` - Wait a random time between half and all of the delay, so concurrent callers
+      do not retry in lockstep (defaults to `true`)
+    * `:retry_on` - Error classes to retry: `:rate_limited` (HTTP 429), `:server_error`
+      (HTTP 5xx), `:timeout`, `:blockhash_not_found` and `:node_behind` (defaults to all)
+
+    A transaction that failed because its blockhash expired is signed again with a fresh
+    blockhash; after any other error the same signed transaction is resent.
+    """
+    defstruct max_attempts: 3,
+              base_delay_ms: 500,
+              max_delay_ms: 5_000,
+              jitter: true,
+              retry_on: [:rate_limited, :server_error, :timeout, :blockhash_not_found, :node_behind]
+
+    @type error_class :: :rate_limited | :server_error | :timeout | :blockhash_not_found | :node_behind
+
+    @type t :: %__MODULE__{
+      max_attempts: pos_integer(),
+      base_delay_ms: non_neg_integer(),
+      max_delay_ms: non_neg_integer(),
+      jitter: boolean(),
+      retry_on: [error_class()]
+    }
+  end
+
+  defmodule TransactionOptions do
+    @moduledoc """
+    Per-call settings for functions that sign and send a transaction.
+
+    * `:rpc_url` - URL of the Solana RPC endpoint, or a `SolanaBubblegum.Types.Connection` to it
+    * `:compute_unit_limit` - Compute unit limit for the transaction
+    * `:compute_unit_price_micro_lamports` - Priority fee per compute unit, in micro-lamports
+    * `:priority_fee` - `:auto` to estimate the compute unit price from recent prioritization
+      fees of the accounts the transaction writes to, or `{:micro_lamports, n}`; takes
+      precedence over `:compute_unit_price_micro_lamports`
+    * `:priority_fee_percentile` - Percentile of recent fees picked by `:auto` (defaults to 75)
+    * `:priority_fee_url` - Helius endpoint whose `getPriorityFeeEstimate` method `:auto` uses
+      instead of `getRecentPrioritizationFees`
+    * `:address_lookup_tables` - Addresses of address lookup tables to compile the transaction
+      against; when any are given a v0 transaction is sent instead of a legacy one, which lets
+      transfers with deep proofs fit in a single transaction
+    * `:simulate_only` - Simulate the signed transaction instead of sending it; the result then
+      holds a `simulation` map with `logs`, `units_consumed` and `error` in place of `signature`
+    * `:dry_run` - Build the transaction without signing or sending it and without contacting
+      the RPC endpoint; the result then holds a `plan` map with the `instructions`, `accounts`,
+      `signers`, estimated `compute_units` and estimated fees in place of `signature`.
+      Operations that read on-chain state first, such as fetching a proof, still do so
+    * `:idempotency_key` - Key that makes retrying the call safe. It is recorded on chain as a
+      memo derived from the key, and before sending, the latest transactions of the fee payer
+      are searched for it; when an earlier transaction with the key succeeded, its signature is
+      returned with `duplicate: true` instead of sending another. Calls that send several
+      transactions derive a key for each of them
+    * `:memo` - Text appended to the transaction as an SPL Memo instruction, such as an order ID
+      to find the transaction by later; calls that send several transactions add it to each
+    * `:send_options` - Preflight, retry and confirmation settings, see
+      `SolanaBubblegum.Types.SendOptions`; the functions of `SolanaBubblegum` accept its keys
+      directly as options
+    * `:async_reply` - Return `{:ok, ref}` immediately and do the work on a background thread;
+      the calling process then receives `{:bubblegum_result, ref, {:ok, map} | {:error, reason}}`.
+      The functions of `SolanaBubblegum` take it as the `:async` option
+    * `:timeout_ms` - Milliseconds before the call gives up and returns, or replies with, an
+      error (no timeout by default). The work sends no further transaction after that. The
+      error is of kind `:timeout` when nothing was sent, and of kind `:timeout_unknown_outcome`
+      when a transaction was already under way and may still land
+    * `:retry` - Retry policy for transient errors, see `SolanaBubblegum.Types.RetryPolicy`;
+      the result reports how many `attempts` the transaction took
+
+    When a limit or price is set, `SetComputeUnitLimit` and `SetComputeUnitPrice` instructions
+    are prepended to the transaction.
+    """
+    defstruct [
+      :rpc_url,
+      :compute_unit_limit,
+      :compute_unit_price_micro_lamports,
+      :priority_fee,
+      :priority_fee_percentile,
+      :priority_fee_url,
+      address_lookup_tables: [],
+      simulate_only: false,
+      dry_run: false,
+      idempotency_key: nil,
+      memo: nil,
+      send_options: %SolanaBubblegum.Types.SendOptions{},
+      async_reply: false,
+      timeout_ms: nil,
+      retry: %SolanaBubblegum.Types.RetryPolicy{}
+    ]
+
+    @type t :: %__MODULE__{
+      rpc_url: String.t() | SolanaBubblegum.Types.Connection.t(),
+      compute_unit_limit: pos_integer() | nil,
+      compute_unit_price_micro_lamports: non_neg_integer() | nil,
+      priority_fee: :auto | {:micro_lamports, non_neg_integer()} | nil,
+      priority_fee_percentile: 0..100 | nil,
+      priority_fee_url: String.t() | nil,
+      address_lookup_tables: [String.t()],
+      simulate_only: boolean(),
+      dry_run: boolean(),
+      idempotency_key: String.t() | nil,
+      memo: String.t() | nil,
+      send_options: SolanaBubblegum.Types.SendOptions.t(),
+      async_reply: boolean(),
+      timeout_ms: pos_integer() | nil,
+      retry: SolanaBubblegum.Types.RetryPolicy.t()
+    }
+  end
+
+  defmodule CreateTreeConfigRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.create_tree_config/2`.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer
+    * `:max_depth` - Maximum depth of the Merkle tree
+    * `:max_buffer_size` - Maximum buffer size of the Merkle tree
+    * `:canopy_depth` - Depth of the canopy, at most `max_depth` (defaults to 0)
+    * `:public` - Whether anyone may mint to the tree (defaults to `false`)
+    * `:tree_keypair` - Base58 encoded keypair of the tree account; when `nil` a new one is
+      generated and returned as `tree_keypair` in the result
+    * `:tree_creator_keypair` - Base58 encoded keypair of the tree creator, who owns the tree
+      and may mint to it or delegate that; defaults to the payer
+    """
+    @enforce_keys [:payer_keypair, :max_depth, :max_buffer_size]
+    defstruct [
+      :payer_keypair,
+      :max_depth,
+      :max_buffer_size,
+      canopy_depth: 0,
+      public: false,
+      tree_keypair: nil,
+      tree_creator_keypair: nil
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      max_depth: non_neg_integer(),
+      max_buffer_size: non_neg_integer(),
+      canopy_depth: non_neg_integer(),
+      public: boolean(),
+      tree_keypair: SolanaBubblegum.Types.keypair() | nil,
+      tree_creator_keypair: SolanaBubblegum.Types.keypair() | nil
+    }
+  end
+
+  defmodule UriCheckOptions do
+    @moduledoc """
+    How metadata URIs are checked before minting.
+
+    Each URI gets a HEAD request, to fail fast on missing files, and a GET of its JSON, which
+    must have a non-empty `name` and `image`. `ar://` URIs are fetched through arweave.net and
+    `ipfs://` URIs through ipfs.io; other schemes than these and http(s) are rejected.
+
+    * `:timeout_ms` - Timeout of each request (defaults to 10 seconds)
+    * `:concurrency` - URIs checked at the same time (defaults to 8)
+    """
+    defstruct timeout_ms: 10_000, concurrency: 8
+
+    @type t :: %__MODULE__{
+      timeout_ms: pos_integer(),
+      concurrency: pos_integer()
+    }
+  end
+
+  defmodule UriCheck do
+    @moduledoc """
+    How one metadata URI fared in `SolanaBubblegum.check_metadata_uris/2`.
+
+    * `:uri` - The URI, as given
+    * `:error` - Why it failed, e.g. an HTTP status or a missing field; `nil` when it is
+      reachable and holds valid metadata JSON
+    """
+    defstruct [:uri, :error]
+
+    @type t :: %__MODULE__{
+      uri: String.t(),
+      error: String.t() | nil
+    }
+  end
+
+  defmodule MintRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.mint_v1/2`.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:metadata` - Metadata of the NFT
+    * `:leaf_owner` - Public key of the owner of the new NFT (defaults to the payer)
+    * `:leaf_delegate` - Public key of the delegate of the new NFT (defaults to the leaf owner)
+    * `:validate_uris` - A `SolanaBubblegum.Types.UriCheckOptions` to fetch the metadata URI
+      and check its JSON before minting, `nil` to skip the check
+    """
+    @enforce_keys [:payer_keypair, :merkle_tree, :metadata]
+    defstruct [:payer_keypair, :merkle_tree, :metadata, leaf_owner: nil, leaf_delegate: nil, validate_uris: nil]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      metadata: SolanaBubblegum.Types.MetadataArgs.t(),
+      leaf_owner: String.t() | nil,
+      leaf_delegate: String.t() | nil,
+      validate_uris: SolanaBubblegum.Types.UriCheckOptions.t() | nil
+    }
+  end
+
+  defmodule MintToCollectionRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.mint_to_collection_v1/2`.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:collection_mint` - Mint of the collection NFT
+    * `:metadata` - Metadata of the NFT
+    * `:leaf_owner` - Public key of the owner of the new NFT (defaults to the payer)
+    * `:leaf_delegate` - Public key of the delegate of the new NFT (defaults to the leaf owner)
+    * `:tree_creator_or_delegate_keypair` - Base58 encoded keypair of the tree creator or tree
+      delegate (defaults to the payer)
+    * `:collection_authority_keypair` - Base58 encoded keypair of the collection update
+      authority or a delegated collection authority (defaults to the payer)
+    * `:collection_authority_record` - Collection authority record PDA, when the collection
+      authority is a delegate
+    * `:validate_uris` - A `SolanaBubblegum.Types.UriCheckOptions` to fetch the metadata URI
+      and check its JSON before minting, `nil` to skip the check
+    """
+    @enforce_keys [:payer_keypair, :merkle_tree, :collection_mint, :metadata]
+    defstruct [
+      :payer_keypair,
+      :merkle_tree,
+      :collection_mint,
+      :metadata,
+      leaf_owner: nil,
+      leaf_delegate: nil,
+      tree_creator_or_delegate_keypair: nil,
+      collection_authority_keypair: nil,
+      collection_authority_record: nil,
+      validate_uris: nil
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      collection_mint: String.t(),
+      metadata: SolanaBubblegum.Types.MetadataArgs.t(),
+      leaf_owner: String.t() | nil,
+      leaf_delegate: String.t() | nil,
+      tree_creator_or_delegate_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_record: String.t() | nil,
+      validate_uris: SolanaBubblegum.Types.UriCheckOptions.t() | nil
+    }
+  end
+
+  defmodule MintBatchToCollectionRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.mint_batch_to_collection_v1/2`. Takes the same
+    accounts as `SolanaBubblegum.Types.MintToCollectionRequest`, with a list of metadata, one
+    per NFT. As many mints as fit the transaction size and compute limits share a transaction.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer, who becomes the leaf owner
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:collection_mint` - Mint of the collection NFT
+    * `:metadata` - Metadata of each NFT, in minting order
+    * `:tree_creator_or_delegate_keypair` - Base58 encoded keypair of the tree creator or tree
+      delegate (defaults to the payer)
+    * `:collection_authority_keypair` - Base58 encoded keypair of the collection update
+      authority or a delegated collection authority (defaults to the payer)
+    * `:collection_authority_record` - Collection authority record PDA, when the collection
+      authority is a delegate
+    """
+    @enforce_keys [:payer_keypair, :merkle_tree, :collection_mint, :metadata]
+    defstruct [
+      :payer_keypair,
+      :merkle_tree,
+      :collection_mint,
+      :metadata,
+      tree_creator_or_delegate_keypair: nil,
+      collection_authority_keypair: nil,
+      collection_authority_record: nil
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      collection_mint: String.t(),
+      metadata: [SolanaBubblegum.Types.MetadataArgs.t()],
+      tree_creator_or_delegate_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_record: String.t() | nil
+    }
+  end
+
+  defmodule BulkMintRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.bulk_mint/2`, for airdrops of thousands of NFTs.
+    The mints are packed into transactions as by `mint_batch_to_collection_v1/2` and sent by a
+    pool of workers in the background.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer, who becomes the leaf owner
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:collection_mint` - Mint of the collection NFT, or `nil` to mint without a collection
+    * `:metadata` - Metadata of each NFT
+    * `:tree_creator_or_delegate_keypair` - Base58 encoded keypair of the tree creator or tree
+      delegate (defaults to the payer)
+    * `:collection_authority_keypair` - Base58 encoded keypair of the collection update
+      authority or a delegated collection authority (defaults to the payer)
+    * `:collection_authority_record` - Collection authority record PDA, when the collection
+      authority is a delegate
+    * `:workers` - Transactions sent at the same time (defaults to 8)
+    * `:max_transactions_per_second` - Upper bound on the send rate of all workers together,
+      `nil` for no bound
+    * `:validate_uris` - A `SolanaBubblegum.Types.UriCheckOptions` to fetch every metadata URI
+      and check its JSON before the job starts, `nil` to skip the check. An NFT whose URI fails
+      is reported like one with invalid metadata
+    """
+    @enforce_keys [:payer_keypair, :merkle_tree, :metadata]
+    defstruct [
+      :payer_keypair,
+      :merkle_tree,
+      :metadata,
+      collection_mint: nil,
+      tree_creator_or_delegate_keypair: nil,
+      collection_authority_keypair: nil,
+      collection_authority_record: nil,
+      workers: 8,
+      max_transactions_per_second: nil,
+      validate_uris: nil
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      collection_mint: String.t() | nil,
+      metadata: [SolanaBubblegum.Types.MetadataArgs.t()],
+      tree_creator_or_delegate_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_record: String.t() | nil,
+      workers: pos_integer(),
+      max_transactions_per_second: pos_integer() | nil,
+      validate_uris: SolanaBubblegum.Types.UriCheckOptions.t() | nil
+    }
+  end
+
+  defmodule AirdropRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.airdrop_to_owners/2`, which mints one NFT of the
+    same metadata to every recipient through the bulk mint pipeline.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:collection_mint` - Mint of the collection NFT, or `nil` to mint without a collection
+    * `:metadata` - Metadata every NFT is minted with
+    * `:recipients` - Public keys of the wallets that receive an NFT each, as its owner and delegate
+    * `:tree_creator_or_delegate_keypair`, `:collection_authority_keypair`,
+      `:collection_authority_record`, `:workers` and `:max_transactions_per_second` - As for
+      `SolanaBubblegum.Types.BulkMintRequest`
+    * `:validate_uris` - A `SolanaBubblegum.Types.UriCheckOptions` to fetch the metadata URI
+      and check its JSON before the job starts, `nil` to skip the check
+    """
+    @enforce_keys [:payer_keypair, :merkle_tree, :metadata, :recipients]
+    defstruct [
+      :payer_keypair,
+      :merkle_tree,
+      :metadata,
+      :recipients,
+      collection_mint: nil,
+      tree_creator_or_delegate_keypair: nil,
+      collection_authority_keypair: nil,
+      collection_authority_record: nil,
+      workers: 8,
+      max_transactions_per_second: nil,
+      validate_uris: nil
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      collection_mint: String.t() | nil,
+      metadata: SolanaBubblegum.Types.MetadataArgs.t(),
+      recipients: [String.t()],
+      tree_creator_or_delegate_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_record: String.t() | nil,
+      workers: pos_integer(),
+      max_transactions_per_second: pos_integer() | nil,
+      validate_uris: SolanaBubblegum.Types.UriCheckOptions.t() | nil
+    }
+  end
+
+  defmodule AirdropItem do
+    @moduledoc """
+    How the NFT of one airdrop recipient fared.
+
+    * `:index` - Position of the recipient in the request
+    * `:recipient` - Public key of the recipient, as given
+    * `:signature` - Signature of the transaction that minted the NFT, `nil` when simulated or failed
+    * `:asset_id` - Asset ID of the NFT, read from the events of the confirmed transaction;
+      `nil` when the transaction was not confirmed or its events could not be read
+    * `:error` - Why the NFT was not minted, see `SolanaBubblegum.Types.Error`. An invalid
+      recipient fails only its own NFT, a failed transaction every NFT it carried
+    """
+    defstruct [:index, :recipient, :signature, :asset_id, :error]
+
+    @type t :: %__MODULE__{
+      index: non_neg_integer(),
+      recipient: String.t(),
+      signature: String.t() | nil,
+      asset_id: String.t() | nil,
+      error: SolanaBubblegum.Types.Error.t() | nil
+    }
+  end
+
+  defmodule MetadataRowError do
+    @moduledoc """
+    A row of a metadata file that could not be loaded.
+
+    * `:line` - Line of the row in the file, counting from 1
+    * `:error` - Why the row was left out, see `SolanaBubblegum.Types.Error`: malformed rows
+      give `:serialization_error`, rows that parse but fail validation `:invalid_metadata` or
+      `:invalid_public_key`
+    """
+    defstruct [:line, :error]
+
+    @type t :: %__MODULE__{
+      line: pos_integer(),
+      error: SolanaBubblegum.Types.Error.t()
+    }
+  end
+
+  defmodule BatchMintItem do
+    @moduledoc """
+    How one NFT of a batch or bulk mint fared.
+
+    * `:index` - Position of its metadata in the request
+    * `:signature` - Signature of the transaction that minted it, `nil` when simulated or failed
+    * `:error` - Why it was not minted, see `SolanaBubblegum.Types.Error`. Invalid metadata
+      fails only its own NFT, a failed transaction every NFT it carried
+    """
+    defstruct [:index, :signature, :error]
+
+    @type t :: %__MODULE__{
+      index: non_neg_integer(),
+      signature: String.t() | nil,
+      error: SolanaBubblegum.Types.Error.t() | nil
+    }
+  end
+
+  defmodule TransferRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.transfer/2`.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer, who must be the leaf owner or delegate
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:leaf_owner` - Public key of the current owner
+    * `:leaf_delegate` - Public key of the current delegate (defaults to the leaf owner)
+    * `:new_owner` - Public key of the new owner
+    * `:asset_proof` - Merkle proof of the leaf
+    """
+    @enforce_keys [:payer_keypair, :merkle_tree, :leaf_owner, :new_owner, :asset_proof]
+    defstruct [
+      :payer_keypair,
+      :merkle_tree,
+      :leaf_owner,
+      :new_owner,
+      :asset_proof,
+      leaf_delegate: nil
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      leaf_owner: String.t(),
+      leaf_delegate: String.t() | nil,
+      new_owner: String.t(),
+      asset_proof: SolanaBubblegum.Types.AssetProof.t()
+    }
+  end
+
+  defmodule BurnRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.burn/2`.
+
+    * `:leaf_owner_keypair` - Base58 encoded keypair of the leaf owner
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:asset_proof` - Merkle proof of the leaf
+    """
+    @enforce_keys [:leaf_owner_keypair, :merkle_tree, :asset_proof]
+    defstruct [:leaf_owner_keypair, :merkle_tree, :asset_proof]
+
+    @type t :: %__MODULE__{
+      leaf_owner_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      asset_proof: SolanaBubblegum.Types.AssetProof.t()
+    }
+  end
+
+  defmodule DelegateRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.delegate/2`.
+
+    * `:leaf_owner_keypair` - Base58 encoded keypair of the leaf owner
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:previous_delegate` - Public key of the current delegate (defaults to the leaf owner)
+    * `:new_delegate` - Public key of the new delegate
+    * `:asset_proof` - Merkle proof of the leaf
+    """
+    @enforce_keys [:leaf_owner_keypair, :merkle_tree, :new_delegate, :asset_proof]
+    defstruct [
+      :leaf_owner_keypair,
+      :merkle_tree,
+      :new_delegate,
+      :asset_proof,
+      previous_delegate: nil
+    ]
+
+    @type t :: %__MODULE__{
+      leaf_owner_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      previous_delegate: String.t() | nil,
+      new_delegate: String.t(),
+      asset_proof: SolanaBubblegum.Types.AssetProof.t()
+    }
+  end
+
+  defmodule CancelDelegateRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.cancel_delegate/2`.
+
+    * `:leaf_owner_keypair` - Base58 encoded keypair of the leaf owner
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:previous_delegate` - Public key of the delegate to remove
+    * `:asset_proof` - Merkle proof of the leaf
+    """
+    @enforce_keys [:leaf_owner_keypair, :merkle_tree, :previous_delegate, :asset_proof]
+    defstruct [:leaf_owner_keypair, :merkle_tree, :previous_delegate, :asset_proof]
+
+    @type t :: %__MODULE__{
+      leaf_owner_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      previous_delegate: String.t(),
+      asset_proof: SolanaBubblegum.Types.AssetProof.t()
+    }
+  end
+
+  defmodule RedeemRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.redeem/2`.
+
+    * `:leaf_owner_keypair` - Base58 encoded keypair of the leaf owner
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:leaf_delegate` - Public key of the current delegate (defaults to the leaf owner)
+    * `:asset_proof` - Merkle proof of the leaf
+    """
+    @enforce_keys [:leaf_owner_keypair, :merkle_tree, :asset_proof]
+    defstruct [:leaf_owner_keypair, :merkle_tree, :asset_proof, leaf_delegate: nil]
+
+    @type t :: %__MODULE__{
+      leaf_owner_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      leaf_delegate: String.t() | nil,
+      asset_proof: SolanaBubblegum.Types.AssetProof.t()
+    }
+  end
+
+  defmodule CancelRedeemRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.cancel_redeem/2`.
+
+    * `:leaf_owner_keypair` - Base58 encoded keypair of the leaf owner
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:asset_proof` - Merkle proof of the leaf to put back
+    """
+    @enforce_keys [:leaf_owner_keypair, :merkle_tree, :asset_proof]
+    defstruct [:leaf_owner_keypair, :merkle_tree, :asset_proof]
+
+    @type t :: %__MODULE__{
+      leaf_owner_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      asset_proof: SolanaBubblegum.Types.AssetProof.t()
+    }
+  end
+
+  defmodule DecompressRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.decompress_v1/2`.
+
+    * `:leaf_owner_keypair` - Base58 encoded keypair of the leaf owner
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:nonce` - Nonce of the redeemed leaf
+    * `:metadata` - Metadata of the NFT
+    """
+    @enforce_keys [:leaf_owner_keypair, :merkle_tree, :nonce, :metadata]
+    defstruct [:leaf_owner_keypair, :merkle_tree, :nonce, :metadata]
+
+    @type t :: %__MODULE__{
+      leaf_owner_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      nonce: non_neg_integer(),
+      metadata: SolanaBubblegum.Types.MetadataArgs.t()
+    }
+  end
+
+  defmodule VerifyCreatorRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.verify_creator/2` and
+    `SolanaBubblegum.Bubblegum.unverify_creator/2`.
+
+    * `:creator_keypair` - Base58 encoded keypair of the creator
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:leaf_owner` - Public key of the leaf owner
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+    * `:asset_proof` - Merkle proof of the leaf
+    * `:metadata` - Current metadata of the NFT
+    """
+    @enforce_keys [:creator_keypair, :merkle_tree, :leaf_owner, :asset_proof, :metadata]
+    defstruct [
+      :creator_keypair,
+      :merkle_tree,
+      :leaf_owner,
+      :asset_proof,
+      :metadata,
+      leaf_delegate: nil
+    ]
+
+    @type t :: %__MODULE__{
+      creator_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      leaf_owner: String.t(),
+      leaf_delegate: String.t() | nil,
+      asset_proof: SolanaBubblegum.Types.AssetProof.t(),
+      metadata: SolanaBubblegum.Types.MetadataArgs.t()
+    }
+  end
+
+  defmodule VerifyCollectionRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.verify_collection/2`,
+    `SolanaBubblegum.Bubblegum.unverify_collection/2` and
+    `SolanaBubblegum.Bubblegum.set_and_verify_collection/2`.
+
+    * `:collection_authority_keypair` - Base58 encoded keypair of the collection authority
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:leaf_owner` - Public key of the leaf owner
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+    * `:collection_mint` - Mint of the collection NFT
+    * `:asset_proof` - Merkle proof of the leaf
+    * `:metadata` - Current metadata of the NFT
+    """
+    @enforce_keys [
+      :collection_authority_keypair,
+      :merkle_tree,
+      :leaf_owner,
+      :collection_mint,
+      :asset_proof,
+      :metadata
+    ]
+    defstruct [
+      :collection_authority_keypair,
+      :merkle_tree,
+      :leaf_owner,
+      :collection_mint,
+      :asset_proof,
+      :metadata,
+      leaf_delegate: nil
+    ]
+
+    @type t :: %__MODULE__{
+      collection_authority_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      leaf_owner: String.t(),
+      leaf_delegate: String.t() | nil,
+      collection_mint: String.t(),
+      asset_proof: SolanaBubblegum.Types.AssetProof.t(),
+      metadata: SolanaBubblegum.Types.MetadataArgs.t()
+    }
+  end
+
+  defmodule UpdateMetadataRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.update_metadata/2`.
+
+    * `:authority_keypair` - Base58 encoded keypair of the update authority
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:leaf_owner` - Public key of the leaf owner
+    * `:leaf_delegate` - Public key of the leaf delegate (defaults to the leaf owner)
+    * `:asset_proof` - Merkle proof of the leaf
+    * `:current_metadata` - Current metadata of the NFT
+    * `:update_args` - Fields to change
+    """
+    @enforce_keys [
+      :authority_keypair,
+      :merkle_tree,
+      :leaf_owner,
+      :asset_proof,
+      :current_metadata,
+      :update_args
+    ]
+    defstruct [
+      :authority_keypair,
+      :merkle_tree,
+      :leaf_owner,
+      :asset_proof,
+      :current_metadata,
+      :update_args,
+      leaf_delegate: nil
+    ]
+
+    @type t :: %__MODULE__{
+      authority_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      leaf_owner: String.t(),
+      leaf_delegate: String.t() | nil,
+      asset_proof: SolanaBubblegum.Types.AssetProof.t(),
+      current_metadata: SolanaBubblegum.Types.MetadataArgs.t(),
+      update_args: SolanaBubblegum.Types.UpdateArgs.t()
+    }
+  end
+
+  defmodule SetTreeDelegateRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.set_tree_delegate/2`.
+
+    * `:tree_creator_keypair` - Base58 encoded keypair of the tree creator
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:new_delegate` - Public key of the new tree delegate
+    """
+    @enforce_keys [:tree_creator_keypair, :merkle_tree, :new_delegate]
+    defstruct [:tree_creator_keypair, :merkle_tree, :new_delegate]
+
+    @type t :: %__MODULE__{
+      tree_creator_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      new_delegate: String.t()
+    }
+  end
+
+  defmodule SetDecompressibleStateRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.set_decompressible_state/2`.
+
+    * `:tree_creator_keypair` - Base58 encoded keypair of the tree creator
+    * `:merkle_tree` - Public key of the Merkle tree
+    * `:decompressible_state` - Whether leaves of the tree may be decompressed
+    """
+    @enforce_keys [:tree_creator_keypair, :merkle_tree, :decompressible_state]
+    defstruct [:tree_creator_keypair, :merkle_tree, :decompressible_state]
+
+    @type t :: %__MODULE__{
+      tree_creator_keypair: SolanaBubblegum.Types.keypair(),
+      merkle_tree: String.t(),
+      decompressible_state: :enabled | :disabled
+    }
+  end
+
+  defmodule TransferWithDasRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.transfer_with_das/2`. The leaf, its owner and
+    its proof are fetched from the DAS API.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer, who must be the leaf owner or delegate
+    * `:asset_id` - Id of the compressed NFT
+    * `:new_owner` - Public key of the new owner
+    * `:canopy_depth` - Canopy depth of the tree, or nil to read it from the tree account
+    * `:proof_cache` - A `SolanaBubblegum.Types.ProofCache` to consult before DAS, or nil
+    """
+    @enforce_keys [:payer_keypair, :asset_id, :new_owner]
+    defstruct [:payer_keypair, :asset_id, :new_owner, canopy_depth: nil, proof_cache: nil]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      asset_id: String.t(),
+      new_owner: String.t(),
+      canopy_depth: non_neg_integer() | nil,
+      proof_cache: SolanaBubblegum.Types.ProofCache.t() | nil
+    }
+  end
+
+  defmodule TransferBatchRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.transfer_batch/2`. The leaves, their owners and their
+    proofs are fetched from the DAS API.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer, who must be the owner or delegate of every asset
+    * `:transfers` - `{asset_id, new_owner}` pairs
+    * `:canopy_depth` - Canopy depth of every tree, or nil to read it from each tree account
+    * `:workers` - Proofs fetched and transactions sent at the same time (defaults to 8)
+    """
+    @enforce_keys [:payer_keypair, :transfers]
+    defstruct [:payer_keypair, :transfers, canopy_depth: nil, workers: 8]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      transfers: [{String.t(), String.t()}],
+      canopy_depth: non_neg_integer() | nil,
+      workers: pos_integer()
+    }
+  end
+
+  defmodule TransferBatchItem do
+    @moduledoc """
+    How one asset of a batch transfer fared.
+
+    * `:index` - Position of its transfer in the request
+    * `:asset_id` - Id of the compressed NFT
+    * `:signature` - Signature of the transaction that transferred it, `nil` when simulated or failed
+    * `:error` - Why it was not transferred, see `SolanaBubblegum.Types.Error`. An asset DAS
+      cannot serve fails only itself, a failed transaction every transfer it carried
+    """
+    defstruct [:index, :asset_id, :signature, :error]
+
+    @type t :: %__MODULE__{
+      index: non_neg_integer(),
+      asset_id: String.t(),
+      signature: String.t() | nil,
+      error: SolanaBubblegum.Types.Error.t() | nil
+    }
+  end
+
+  defmodule BurnWithDasRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.burn_with_das/2`. The leaf, its owner and its
+    proof are fetched from the DAS API.
+
+    * `:payer_keypair` - Base58 encoded keypair of the payer, who must be the leaf owner or delegate
+    * `:asset_id` - Id of the compressed NFT
+    * `:canopy_depth` - Canopy depth of the tree, or nil to read it from the tree account
+    """
+    @enforce_keys [:payer_keypair, :asset_id]
+    defstruct [:payer_keypair, :asset_id, canopy_depth: nil]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      asset_id: String.t(),
+      canopy_depth: non_neg_integer() | nil
+    }
+  end
+
+  defmodule CoreCreateRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.create_core_asset/2`, which creates an uncompressed
+    Metaplex Core asset.
+
+    * `:payer_keypair` - Keypair of the payer
+    * `:name` - Name of the asset
+    * `:uri` - URI of the asset's off-chain metadata
+    * `:owner` - Owner of the asset (defaults to the payer)
+    * `:collection` - Core collection to create the asset in, or nil
+    * `:update_authority` - Update authority of an asset outside a collection (defaults to the payer)
+    * `:asset_keypair` - Keypair of the asset account, generated when nil
+    * `:authority_keypair` - Keypair of the collection's update authority, when it is not the payer
+    """
+    @enforce_keys [:payer_keypair, :name, :uri]
+    defstruct [
+      :payer_keypair,
+      :name,
+      :uri,
+      owner: nil,
+      collection: nil,
+      update_authority: nil,
+      asset_keypair: nil,
+      authority_keypair: nil
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      name: String.t(),
+      uri: String.t(),
+      owner: String.t() | nil,
+      collection: String.t() | nil,
+      update_authority: String.t() | nil,
+      asset_keypair: SolanaBubblegum.Types.keypair() | nil,
+      authority_keypair: SolanaBubblegum.Types.keypair() | nil
+    }
+  end
+
+  defmodule CoreTransferRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.transfer_core_asset/2`.
+
+    * `:payer_keypair` - Keypair of the payer, who is the owner unless `:authority_keypair` is given
+    * `:asset` - Address of the Core asset
+    * `:new_owner` - Public key of the new owner
+    * `:collection` - Core collection of the asset; required when it is in one
+    * `:authority_keypair` - Keypair of the owner or a transfer delegate, when it is not the payer
+    """
+    @enforce_keys [:payer_keypair, :asset, :new_owner]
+    defstruct [:payer_keypair, :asset, :new_owner, collection: nil, authority_keypair: nil]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      asset: String.t(),
+      new_owner: String.t(),
+      collection: String.t() | nil,
+      authority_keypair: SolanaBubblegum.Types.keypair() | nil
+    }
+  end
+
+  defmodule CoreBurnRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.burn_core_asset/2`.
+
+    * `:payer_keypair` - Keypair of the payer, who is the owner unless `:authority_keypair` is given
+    * `:asset` - Address of the Core asset
+    * `:collection` - Core collection of the asset; required when it is in one
+    * `:authority_keypair` - Keypair of the owner or a burn delegate, when it is not the payer
+    """
+    @enforce_keys [:payer_keypair, :asset]
+    defstruct [:payer_keypair, :asset, collection: nil, authority_keypair: nil]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      asset: String.t(),
+      collection: String.t() | nil,
+      authority_keypair: SolanaBubblegum.Types.keypair() | nil
+    }
+  end
+
+  defmodule CreateCollectionRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.create_collection_nft/2`.
+
+    * `:payer_keypair` - Keypair of the payer
+    * `:name` - Name of the collection
+    * `:symbol` - Symbol of the collection
+    * `:uri` - URI of the collection's off-chain metadata
+    * `:seller_fee_basis_points` - Royalties, in basis points
+    * `:creators` - Creators of the collection; only the update authority is verified
+    * `:is_mutable` - Whether the metadata can be updated later
+    * `:mint_keypair` - Keypair of the collection mint, generated when nil
+    * `:update_authority_keypair` - Keypair of the update authority, which also receives the
+      collection NFT, when it is not the payer
+    * `:token_program_version` - `:original` or `:token2022`, the token program of the collection
+      mint (defaults to `:original`)
+    """
+    @enforce_keys [:payer_keypair, :name, :uri]
+    defstruct [
+      :payer_keypair,
+      :name,
+      :uri,
+      :mint_keypair,
+      :update_authority_keypair,
+      symbol: "",
+      seller_fee_basis_points: 0,
+      creators: [],
+      is_mutable: true,
+      token_program_version: :original
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      name: String.t(),
+      symbol: String.t(),
+      uri: String.t(),
+      seller_fee_basis_points: non_neg_integer(),
+      creators: [SolanaBubblegum.Types.Creator.t()],
+      is_mutable: boolean(),
+      mint_keypair: SolanaBubblegum.Types.keypair() | nil,
+      update_authority_keypair: SolanaBubblegum.Types.keypair() | nil,
+      token_program_version: :original | :token2022
+    }
+  end
+
+  defmodule UpdateCollectionRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.update_collection_metadata/2`. Fields left nil keep
+    their current value.
+
+    * `:payer_keypair` - Keypair of the payer
+    * `:collection_mint` - Mint address of the collection NFT
+    * `:name` - New name
+    * `:symbol` - New symbol
+    * `:uri` - New URI of the off-chain metadata
+    * `:seller_fee_basis_points` - New royalties, in basis points
+    * `:creators` - New creators; creators verified before and the update authority are verified
+    * `:primary_sale_happened` - Whether the primary sale happened
+    * `:is_mutable` - `false` to make the metadata immutable for good
+    * `:new_update_authority` - Public key of a new update authority
+    * `:update_authority_keypair` - Keypair of the current update authority, when it is not
+      the payer
+    """
+    @enforce_keys [:payer_keypair, :collection_mint]
+    defstruct [
+      :payer_keypair,
+      :collection_mint,
+      :name,
+      :symbol,
+      :uri,
+      :seller_fee_basis_points,
+      :creators,
+      :primary_sale_happened,
+      :is_mutable,
+      :new_update_authority,
+      :update_authority_keypair
+    ]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      collection_mint: String.t(),
+      name: String.t() | nil,
+      symbol: String.t() | nil,
+      uri: String.t() | nil,
+      seller_fee_basis_points: non_neg_integer() | nil,
+      creators: [SolanaBubblegum.Types.Creator.t()] | nil,
+      primary_sale_happened: boolean() | nil,
+      is_mutable: boolean() | nil,
+      new_update_authority: String.t() | nil,
+      update_authority_keypair: SolanaBubblegum.Types.keypair() | nil
+    }
+  end
+
+  defmodule ApproveCollectionAuthorityRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.approve_collection_authority/2`.
+
+    * `:payer_keypair` - Keypair of the payer
+    * `:collection_mint` - Mint address of the collection NFT
+    * `:new_collection_authority` - Public key of the wallet to delegate collection authority to
+    * `:update_authority_keypair` - Keypair of the update authority, when it is not the payer
+    """
+    @enforce_keys [:payer_keypair, :collection_mint, :new_collection_authority]
+    defstruct [:payer_keypair, :collection_mint, :new_collection_authority, :update_authority_keypair]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      collection_mint: String.t(),
+      new_collection_authority: String.t(),
+      update_authority_keypair: SolanaBubblegum.Types.keypair() | nil
+    }
+  end
+
+  defmodule RevokeCollectionAuthorityRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.revoke_collection_authority/2`.
+
+    * `:payer_keypair` - Keypair of the payer
+    * `:collection_mint` - Mint address of the collection NFT
+    * `:delegate_authority` - Public key of the delegated collection authority
+    * `:revoke_authority_keypair` - Keypair of the update authority or of the delegate itself,
+      when it is not the payer
+    """
+    @enforce_keys [:payer_keypair, :collection_mint, :delegate_authority]
+    defstruct [:payer_keypair, :collection_mint, :delegate_authority, :revoke_authority_keypair]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      collection_mint: String.t(),
+      delegate_authority: String.t(),
+      revoke_authority_keypair: SolanaBubblegum.Types.keypair() | nil
+    }
+  end
+
+  defmodule SetCollectionSizeRequest do
+    @moduledoc """
+    Arguments of `SolanaBubblegum.Bubblegum.set_collection_size/2`.
+
+    * `:payer_keypair` - Keypair of the payer
+    * `:collection_mint` - Mint address of the collection NFT
+    * `:size` - Number of NFTs already verified into the collection
+    * `:collection_authority_keypair` - Keypair of the update authority or a delegated
+      collection authority, when it is not the payer
+    * `:collection_authority_record` - Collection authority record PDA of a delegated
+      collection authority
+    """
+    @enforce_keys [:payer_keypair, :collection_mint, :size]
+    defstruct [:payer_keypair, :collection_mint, :size, :collection_authority_keypair, :collection_authority_record]
+
+    @type t :: %__MODULE__{
+      payer_keypair: SolanaBubblegum.Types.keypair(),
+      collection_mint: String.t(),
+      size: non_neg_integer(),
+      collection_authority_keypair: SolanaBubblegum.Types.keypair() | nil,
+      collection_authority_record: String.t() | nil
+    }
+  end
+
+  defmodule Error do
+    @moduledoc """
+    Why an operation failed.
+
+    * `:kind` - Cause of the failure: `:invalid_public_key`, `:invalid_keypair`,
+      `:invalid_signature`, `:invalid_hash`, `:invalid_tree_config`, `:invalid_argument`,
+      `:invalid_metadata`, `:rpc_error`, `:transaction_error`, `:serialization_error`, `:das_error`,
+      `:priority_fee_error`, `:upload_error`, `:timeout`, `:cancelled`, `:timeout_unknown_outcome`
+      or `:cancelled_unknown_outcome`. The last two mean a transaction was under way when the
+      call gave up, so it may still land
+    * `:message` - Human readable description
+    * `:program_error_code` - Custom error code returned by the failing program, if any
+    * `:program_error` - Name of the custom error when the Bubblegum or spl-account-compression
+      program raised it, e.g. `:leaf_authority_must_sign`, `:invalid_proof` or `:tree_full`
+    * `:program_id` - Program that raised the custom error, if known
+    * `:instruction_index` - Index of the failing instruction in the transaction, if known
+    * `:logs` - Program logs of the failed preflight simulation, if any
+    * `:violations` - For `:invalid_metadata`, every field that breaks a Token Metadata limit
+      with what is wrong with it, e.g. `[{:name, "is 40 bytes, at most 32 are allowed"}]`
+
+    It converts to its message with `to_string/1`, so it can be interpolated like the plain
+    error strings returned before.
+    """
+    defstruct [
+      :kind,
+      :message,
+      :program_error_code,
+      :program_error,
+      :program_id,
+      :instruction_index,
+      logs: [],
+      violations: []
+    ]
+
+    @type kind ::
+            :invalid_public_key
+            | :invalid_keypair
+            | :invalid_signature
+            | :invalid_hash
+            | :invalid_tree_config
+            | :invalid_argument
+            | :invalid_metadata
+            | :rpc_error
+            | :transaction_error
+            | :serialization_error
+            | :das_error
+            | :priority_fee_error
+            | :upload_error
+            | :timeout
+            | :cancelled
+            | :timeout_unknown_outcome
+            | :cancelled_unknown_outcome
+
+    @type t :: %__MODULE__{
+      kind: kind(),
+      message: String.t(),
+      program_error_code: non_neg_integer() | nil,
+      program_error: atom() | nil,
+      program_id: String.t() | nil,
+      instruction_index: non_neg_integer() | nil,
+      logs: [String.t()],
+      violations: [{atom(), String.t()}]
+    }
+
+    defimpl String.Chars do
+      def to_string(%{message: message}), do: message
+    end
+  end
 end
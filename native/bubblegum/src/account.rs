@@ -0,0 +1,206 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use rustler::{Encoder, Env, NifUnitEnum, OwnedBinary, Term};
+use solana_sdk::{commitment_config::CommitmentConfig, signer::Signer, system_instruction};
+use std::time::Duration;
+
+use crate::{
+    atoms, decode_keypair,
+    keypair::KeypairNif,
+    parse_pubkey, reply,
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+    send_transaction,
+    transaction::{confirm_signature_status, TransactionOptionsNif},
+    BubblegumError,
+};
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn request_airdrop(
+    env: Env,
+    args: (String, u64, u64, RpcEndpoint),
+) -> Term {
+    let (pubkey_str, lamports, timeout_ms, rpc_url) = args;
+    
+    let pubkey = match parse_pubkey(&pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Not retried: a faucet that timed out may still have paid, and rate limits the wallet
+    let result = rpc_url.with_failover(|connection| {
+        let signature = connection
+            .client
+            .request_airdrop(&pubkey, lamports)
+            .map_err(BubblegumError::from_client_error)?;
+        
+        // The funds can only be spent once the airdrop is confirmed; a timeout of 0 skips waiting
+        let slot = match timeout_ms {
+            0 => None,
+            _ => confirm_signature_status(
+                &connection.client,
+                &signature,
+                CommitmentConfig::confirmed(),
+                Duration::from_millis(timeout_ms),
+            )?,
+        };
+        
+        Ok((signature, slot))
+    });
+    
+    match result {
+        Ok((signature, slot)) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("signature".encode(env), signature.to_string().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("confirmed".encode(env), slot.is_some().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("slot".encode(env), slot.encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_balance(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (pubkey_str, retry, rpc_url) = args;
+    
+    let pubkey = match parse_pubkey(&pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let result = retry.run(|| {
+        rpc_url.with_failover(|connection| {
+            connection
+                .client
+                .get_balance(&pubkey)
+                .map_err(BubblegumError::from_client_error)
+        })
+    });
+    
+    match result {
+        Ok((lamports, _attempts)) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("pubkey".encode(env), pubkey_str.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("lamports".encode(env), lamports.encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[derive(NifUnitEnum)]
+pub enum AccountEncodingNif {
+    Base64,
+    Binary,
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_account_info(
+    env: Env,
+    args: (String, AccountEncodingNif, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (pubkey_str, encoding, retry, rpc_url) = args;
+    
+    let pubkey = match parse_pubkey(&pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Unlike get_account, this reports a missing account as None instead of an RPC error
+    let result = retry.run(|| {
+        rpc_url.with_failover(|connection| {
+            connection
+                .client
+                .get_account_with_commitment(&pubkey, connection.client.commitment())
+                .map(|response| response.value)
+                .map_err(BubblegumError::from_client_error)
+        })
+    });
+    
+    let account = match result {
+        Ok((Some(account), _attempts)) => account,
+        Ok((None, _attempts)) => return (atoms::ok(), None::<Term>).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let data = match encoding {
+        AccountEncodingNif::Base64 => BASE64.encode(&account.data).encode(env),
+        AccountEncodingNif::Binary => {
+            let mut binary = match OwnedBinary::new(account.data.len()) {
+                Some(binary) => binary,
+                None => {
+                    let e = BubblegumError::SerializationError("Could not allocate account data".to_string());
+                    return (atoms::error(), e).encode(env);
+                },
+            };
+            binary.as_mut_slice().copy_from_slice(&account.data);
+            binary.release(env).encode(env)
+        },
+    };
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("pubkey".encode(env), pubkey_str.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("lamports".encode(env), account.lamports.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("owner".encode(env), account.owner.to_string().encode(env)).unwrap();
+    let ok_map = ok_map.map_put("executable".encode(env), account.executable.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("rent_epoch".encode(env), account.rent_epoch.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("data".encode(env), data).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn transfer_sol(
+    env: Env,
+    args: (KeypairNif, String, u64, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.3.reply_options(), move |env| transfer_sol_impl(env, args))
+}
+
+fn transfer_sol_impl(
+    env: Env,
+    args: (KeypairNif, String, u64, TransactionOptionsNif),
+) -> Term {
+    let (from_keypair_bs58, to_str, lamports, tx_options) = args;
+    
+    // Decode the sender, who also pays the fees
+    let from = match decode_keypair(&from_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let to = match parse_pubkey(&to_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    if lamports == 0 {
+        let e = BubblegumError::InvalidArgument("lamports must be greater than 0".to_string());
+        return (atoms::error(), e).encode(env);
+    }
+    
+    let transfer_ix = system_instruction::transfer(&from.pubkey(), &to, lamports);
+    
+    // Send the transaction
+    match send_transaction(vec![transfer_ix], &from, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            let ok_map = ok_map.map_put("from".encode(env), from.pubkey().to_string().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("to".encode(env), to_str.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("lamports".encode(env), lamports.encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
@@ -0,0 +1,225 @@
+use rustler::{Encoder, Env, NifStruct, Term};
+use solana_sdk::{instruction::Instruction, message::Message, packet::PACKET_DATA_SIZE, pubkey::Pubkey, signer::Signer};
+
+use crate::{
+    atoms, convert_metadata_args, decode_keypair, error::ErrorNif, instructions, parse_pubkey, reply,
+    idempotency, request::MintBatchToCollectionRequestNif, send_transaction_part,
+    transaction::{self, TransactionOptionsNif, TransactionOutcome},
+};
+
+// Rough compute cost of one mint to a collection, including its Token Metadata CPI
+pub(crate) const MINT_TO_COLLECTION_COMPUTE_UNITS: u32 = 120_000;
+
+// Most compute units a transaction can request
+pub(crate) const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+// How one NFT of a batch fared: minted in the transaction with `signature`, or not, with `error`
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.BatchMintItem"]
+pub struct BatchMintItemNif {
+    pub index: usize,
+    pub signature: Option<String>,
+    pub error: Option<ErrorNif>,
+}
+
+// Size of the signed transaction, with room for the compute budget instructions
+// `send_transaction` may prepend and the memos it may append
+fn transaction_size(payer: &Pubkey, instructions: &[Instruction], memo: Option<&str>) -> usize {
+    let mut all_instructions = transaction::compute_budget_instructions(Some(MAX_COMPUTE_UNIT_LIMIT), Some(u64::MAX));
+    all_instructions.extend_from_slice(instructions);
+    all_instructions.extend(memo.map(instructions::memo));
+    all_instructions.push(instructions::memo(&idempotency::memo("")));
+    
+    let message = Message::new(&all_instructions, Some(payer));
+    let signatures = message.header.num_required_signatures as usize;
+    let message_size = bincode::serialized_size(&message).map_or(usize::MAX, |size| size as usize);
+    
+    // Compact length of the signatures, the signatures and the message
+    1 + signatures * 64 + message_size
+}
+
+// Packs the instructions, such as mints or transfers, in order into as few transactions as fit
+// the packet size and compute limits. One too large to share a transaction still gets its own
+pub(crate) fn pack_instructions(
+    payer: &Pubkey,
+    indexed_instructions: Vec<(usize, Instruction)>,
+    max_per_transaction: usize,
+    memo: Option<&str>,
+) -> Vec<Vec<(usize, Instruction)>> {
+    let mut batches: Vec<Vec<(usize, Instruction)>> = Vec::new();
+    let mut current: Vec<(usize, Instruction)> = Vec::new();
+    
+    for indexed_instruction in indexed_instructions {
+        current.push(indexed_instruction);
+        
+        let instructions: Vec<Instruction> = current.iter().map(|(_, instruction)| instruction.clone()).collect();
+        let fits = current.len() <= max_per_transaction
+            && transaction_size(payer, &instructions, memo) <= PACKET_DATA_SIZE;
+        
+        if !fits && current.len() > 1 {
+            let overflow = current.pop().unwrap();
+            batches.push(std::mem::take(&mut current));
+            current.push(overflow);
+        }
+    }
+    
+    if !current.is_empty() {
+        batches.push(current);
+    }
+    batches
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn mint_batch_to_collection_v1(
+    env: Env,
+    args: (MintBatchToCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| mint_batch_to_collection_v1_impl(env, args))
+}
+
+fn mint_batch_to_collection_v1_impl(
+    env: Env,
+    args: (MintBatchToCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let MintBatchToCollectionRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        collection_mint: collection_pubkey_str,
+        metadata: metadata_list,
+        tree_creator_or_delegate_keypair: tree_creator_or_delegate_keypair_bs58,
+        collection_authority_keypair: collection_authority_keypair_bs58,
+        collection_authority_record: collection_authority_record_str,
+    } = request;
+    
+    // Decode the keypairs; the tree and collection authorities default to the payer
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_creator_or_delegate = match tree_creator_or_delegate_keypair_bs58 {
+        Some(keypair_bs58) => match decode_keypair(&keypair_bs58) {
+            Ok(keypair) => Some(keypair),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => None,
+    };
+    
+    let collection_authority = match collection_authority_keypair_bs58 {
+        Some(keypair_bs58) => match decode_keypair(&keypair_bs58) {
+            Ok(keypair) => Some(keypair),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => None,
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection_pubkey = match parse_pubkey(&collection_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection_authority_record = match collection_authority_record_str {
+        Some(record_str) => match parse_pubkey(&record_str) {
+            Ok(pubkey) => Some(pubkey),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => None,
+    };
+    
+    let tree_creator_or_delegate_pubkey = tree_creator_or_delegate.as_ref().unwrap_or(&payer).pubkey();
+    let collection_authority_pubkey = collection_authority.as_ref().unwrap_or(&payer).pubkey();
+    
+    // Metadata that does not convert fails on its own, without holding up the rest
+    let mut items: Vec<BatchMintItemNif> = Vec::with_capacity(metadata_list.len());
+    let mut mints = Vec::with_capacity(metadata_list.len());
+    for (index, metadata_args) in metadata_list.iter().enumerate() {
+        match convert_metadata_args(metadata_args) {
+            Ok(metadata) => mints.push((
+                index,
+                instructions::mint_to_collection_v1(
+                    &payer.pubkey(),
+                    &tree_pubkey,
+                    &payer.pubkey(),
+                    &payer.pubkey(),
+                    &tree_creator_or_delegate_pubkey,
+                    &collection_authority_pubkey,
+                    collection_authority_record,
+                    &collection_pubkey,
+                    metadata,
+                ),
+            )),
+            Err(e) => items.push(BatchMintItemNif {
+                index,
+                signature: None,
+                error: Some(e.to_nif(env)),
+            }),
+        }
+    }
+    
+    // An explicit compute unit limit applies to every transaction of the batch
+    let compute_unit_limit = tx_options.compute_unit_limit.unwrap_or(MAX_COMPUTE_UNIT_LIMIT);
+    let max_mints_per_transaction = (compute_unit_limit / MINT_TO_COLLECTION_COMPUTE_UNITS).max(1) as usize;
+    
+    // Send the batches one after another; a failed transaction only fails its own mints
+    let mut signatures = Vec::new();
+    let mut simulations = Vec::new();
+    let mut plans = Vec::new();
+    let mut attempts = 0;
+    for batch in pack_instructions(&payer.pubkey(), mints, max_mints_per_transaction, tx_options.memo.as_deref()) {
+        let (indexes, batch_instructions): (Vec<usize>, Vec<Instruction>) = batch.into_iter().unzip();
+        let signers = tree_creator_or_delegate.iter().chain(collection_authority.iter()).collect();
+        
+        // Batches are named after their first mint, which a retried call packs the same way
+        let part = indexes[0];
+        match send_transaction_part(batch_instructions, &payer, signers, &tx_options, part) {
+            Ok(sent) => {
+                attempts += sent.attempts;
+                let signature = match &sent.outcome {
+                    TransactionOutcome::Sent(signature) => {
+                        signatures.push(signature.to_string());
+                        Some(signature.to_string())
+                    },
+                    TransactionOutcome::Simulated(simulation) => {
+                        simulations.push(simulation.encode(env));
+                        None
+                    },
+                    TransactionOutcome::Planned(plan) => {
+                        plans.push(plan.encode(env));
+                        None
+                    },
+                };
+                items.extend(indexes.into_iter().map(|index| BatchMintItemNif {
+                    index,
+                    signature: signature.clone(),
+                    error: None,
+                }));
+            },
+            Err(e) => {
+                items.extend(indexes.into_iter().map(|index| BatchMintItemNif {
+                    index,
+                    signature: None,
+                    error: Some(e.to_nif(env)),
+                }));
+            },
+        }
+    }
+    
+    items.sort_by_key(|item| item.index);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("signatures".encode(env), signatures.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("simulations".encode(env), simulations.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("plans".encode(env), plans.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("items".encode(env), items.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("attempts".encode(env), attempts.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
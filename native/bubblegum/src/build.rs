@@ -0,0 +1,853 @@
+use rustler::{Binary, Encoder, Env, NifStruct, OwnedBinary, Term};
+use solana_sdk::{instruction::Instruction, rent::Rent};
+
+use crate::{
+    atoms, convert_asset_proof, convert_decompressible_state, convert_metadata_args, convert_update_args,
+    instructions, merkle_tree_account_size, parse_pubkey, validate_tree_depths, AssetProofNif, BubblegumError,
+    DecompressibleStateNif, MetadataArgsNif, UpdateArgsNif,
+};
+
+// Build-only variants of the Bubblegum operations. They take public keys instead of
+// keypairs and return the unsigned instructions, so signing can happen elsewhere.
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.AccountMeta"]
+pub struct AccountMetaNif {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.Instruction"]
+pub struct InstructionNif<'a> {
+    pub program_id: String,
+    pub accounts: Vec<AccountMetaNif>,
+    pub data: Binary<'a>,
+}
+
+pub(crate) fn encode_instruction<'a>(env: Env<'a>, instruction: &Instruction) -> Result<InstructionNif<'a>, BubblegumError> {
+    let mut data = OwnedBinary::new(instruction.data.len())
+        .ok_or_else(|| BubblegumError::SerializationError("Could not allocate instruction data".to_string()))?;
+    data.as_mut_slice().copy_from_slice(&instruction.data);
+    
+    Ok(InstructionNif {
+        program_id: instruction.program_id.to_string(),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|account| AccountMetaNif {
+                pubkey: account.pubkey.to_string(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: data.release(env),
+    })
+}
+
+// Wraps the instructions, plus any derived addresses, as `{:ok, %{"instructions" => [...], ...}}`
+fn instructions_result<'a>(env: Env<'a>, instructions: &[Instruction], addresses: &[(&str, String)]) -> Term<'a> {
+    let mut encoded = Vec::with_capacity(instructions.len());
+    for instruction in instructions {
+        match encode_instruction(env, instruction) {
+            Ok(instruction) => encoded.push(instruction),
+            Err(e) => {
+                return (atoms::error(), e).encode(env);
+            },
+        }
+    }
+    
+    let ok_map = Term::map_new(env);
+    
+    let mut ok_map = ok_map.map_put("instructions".encode(env), encoded.encode(env)).unwrap();
+    for (key, address) in addresses {
+        ok_map = ok_map.map_put(key.encode(env), address.encode(env)).unwrap();
+    }
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn build_create_tree_config(
+    env: Env,
+    args: (String, String, u32, u32, u32, bool),
+) -> Term {
+    let (payer_str, tree_pubkey_str, max_depth, max_buffer_size, canopy_depth, public) = args;
+    
+    // Parse the pubkeys
+    let payer = match parse_pubkey(&payer_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The canopy can cache at most the whole tree
+    if let Err(e) = validate_tree_depths(max_depth, canopy_depth) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    // Rent is computed locally from the default rent parameters instead of asking the cluster
+    let tree_account_size = merkle_tree_account_size(max_depth, max_buffer_size, canopy_depth);
+    let tree_account_lamports = Rent::default().minimum_balance(tree_account_size);
+    
+    // Create the tree account and tree config instructions
+    let create_tree_ixs = instructions::create_tree_config(
+        &payer,
+        &tree_pubkey,
+        &payer,
+        max_depth,
+        max_buffer_size,
+        canopy_depth,
+        public,
+        tree_account_lamports,
+    );
+    
+    instructions_result(env, &create_tree_ixs, &[("tree_pubkey", tree_pubkey.to_string())])
+}
+
+#[rustler::nif]
+pub fn build_mint_v1(
+    env: Env,
+    args: (String, String, MetadataArgsNif),
+) -> Term {
+    let (payer_str, tree_pubkey_str, metadata_args) = args;
+    
+    // Parse the pubkeys
+    let payer = match parse_pubkey(&payer_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the metadata args
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the mint instruction
+    let mint_ix = instructions::mint_v1(&payer, &tree_pubkey, &payer, &payer, metadata);
+    
+    instructions_result(env, &[mint_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_mint_to_collection_v1(
+    env: Env,
+    args: (String, String, String, MetadataArgsNif),
+) -> Term {
+    let (payer_str, tree_pubkey_str, collection_pubkey_str, metadata_args) = args;
+    
+    // Parse the pubkeys
+    let payer = match parse_pubkey(&payer_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection_pubkey = match parse_pubkey(&collection_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the metadata args
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the mint instruction
+    let mint_ix = instructions::mint_to_collection_v1(
+        &payer,
+        &tree_pubkey,
+        &payer,
+        &payer,
+        &payer,
+        &payer,
+        None,
+        &collection_pubkey,
+        metadata,
+    );
+    
+    instructions_result(env, &[mint_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_transfer(
+    env: Env,
+    args: (String, String, (String, Option<String>), String, AssetProofNif),
+) -> Term {
+    let (authority_str, tree_pubkey_str, (leaf_owner_str, leaf_delegate_str), new_owner_str, asset_proof) = args;
+    
+    // Parse the pubkeys
+    let authority = match parse_pubkey(&authority_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    let new_owner = match parse_pubkey(&new_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the transfer instruction; the authority signs as either the owner or the delegate
+    let transfer_ix = instructions::transfer(&authority, &tree_pubkey, &leaf_owner, &leaf_delegate, &new_owner, &asset_proof);
+    
+    instructions_result(env, &[transfer_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_burn(
+    env: Env,
+    args: (String, String, (String, Option<String>), AssetProofNif),
+) -> Term {
+    let (authority_str, tree_pubkey_str, (leaf_owner_str, leaf_delegate_str), asset_proof) = args;
+    
+    // Parse the pubkeys
+    let authority = match parse_pubkey(&authority_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the burn instruction; the authority signs as either the owner or the delegate
+    let burn_ix = instructions::burn(&authority, &tree_pubkey, &leaf_owner, &leaf_delegate, &asset_proof);
+    
+    instructions_result(env, &[burn_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_delegate(
+    env: Env,
+    args: (String, String, Option<String>, String, AssetProofNif),
+) -> Term {
+    let (leaf_owner_str, tree_pubkey_str, previous_delegate_str, new_delegate_str, asset_proof) = args;
+    
+    // Parse the pubkeys
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let previous_delegate = match previous_delegate_str {
+        Some(previous_delegate_str) => match parse_pubkey(&previous_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    let new_delegate = match parse_pubkey(&new_delegate_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the delegate instruction
+    let delegate_ix = instructions::delegate(&tree_pubkey, &leaf_owner, &previous_delegate, &new_delegate, &asset_proof);
+    
+    instructions_result(env, &[delegate_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_cancel_delegate(
+    env: Env,
+    args: (String, String, String, AssetProofNif),
+) -> Term {
+    let (leaf_owner_str, tree_pubkey_str, previous_delegate_str, asset_proof) = args;
+    
+    // Parse the pubkeys
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let previous_delegate = match parse_pubkey(&previous_delegate_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Delegating back to the owner clears the delegation
+    let delegate_ix = instructions::delegate(&tree_pubkey, &leaf_owner, &previous_delegate, &leaf_owner, &asset_proof);
+    
+    instructions_result(env, &[delegate_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_redeem(
+    env: Env,
+    args: (String, String, Option<String>, AssetProofNif),
+) -> Term {
+    let (leaf_owner_str, tree_pubkey_str, leaf_delegate_str, asset_proof) = args;
+    
+    // Parse the pubkeys
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the redeem instruction
+    let (redeem_ix, voucher) = instructions::redeem(&tree_pubkey, &leaf_owner, &leaf_delegate, &asset_proof);
+    
+    instructions_result(env, &[redeem_ix], &[("voucher", voucher.to_string())])
+}
+
+#[rustler::nif]
+pub fn build_cancel_redeem(
+    env: Env,
+    args: (String, String, AssetProofNif),
+) -> Term {
+    let (leaf_owner_str, tree_pubkey_str, asset_proof) = args;
+    
+    // Parse the pubkeys
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the cancel redeem instruction
+    let (cancel_redeem_ix, voucher) = instructions::cancel_redeem(&tree_pubkey, &leaf_owner, &asset_proof);
+    
+    instructions_result(env, &[cancel_redeem_ix], &[("voucher", voucher.to_string())])
+}
+
+#[rustler::nif]
+pub fn build_decompress_v1(
+    env: Env,
+    args: (String, String, u64, MetadataArgsNif),
+) -> Term {
+    let (leaf_owner_str, tree_pubkey_str, nonce, metadata_args) = args;
+    
+    // Parse the pubkeys
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the metadata args
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the decompress instruction
+    let (decompress_ix, mint) = match instructions::decompress_v1(&tree_pubkey, &leaf_owner, nonce, metadata) {
+        Ok(decompress) => decompress,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    instructions_result(env, &[decompress_ix], &[("mint", mint.to_string())])
+}
+
+#[rustler::nif]
+pub fn build_verify_creator(
+    env: Env,
+    args: (String, String, (String, Option<String>), AssetProofNif, MetadataArgsNif),
+) -> Term {
+    let (creator_str, tree_pubkey_str, (leaf_owner_str, leaf_delegate_str), asset_proof, metadata_args) = args;
+    
+    // Parse the pubkeys
+    let creator = match parse_pubkey(&creator_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the verify creator instruction
+    let verify_creator_ix = instructions::verify_creator(&creator, &tree_pubkey, &leaf_owner, &leaf_delegate, &asset_proof, metadata);
+    
+    instructions_result(env, &[verify_creator_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_unverify_creator(
+    env: Env,
+    args: (String, String, (String, Option<String>), AssetProofNif, MetadataArgsNif),
+) -> Term {
+    let (creator_str, tree_pubkey_str, (leaf_owner_str, leaf_delegate_str), asset_proof, metadata_args) = args;
+    
+    // Parse the pubkeys
+    let creator = match parse_pubkey(&creator_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the unverify creator instruction
+    let unverify_creator_ix = instructions::unverify_creator(&creator, &tree_pubkey, &leaf_owner, &leaf_delegate, &asset_proof, metadata);
+    
+    instructions_result(env, &[unverify_creator_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_verify_collection(
+    env: Env,
+    args: (String, String, (String, Option<String>), String, AssetProofNif, MetadataArgsNif),
+) -> Term {
+    let (collection_authority_str, tree_pubkey_str, (leaf_owner_str, leaf_delegate_str), collection_mint_str, asset_proof, metadata_args) = args;
+    
+    // Parse the pubkeys
+    let collection_authority = match parse_pubkey(&collection_authority_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    let collection_mint = match parse_pubkey(&collection_mint_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the verify collection instruction
+    let verify_collection_ix = instructions::verify_collection(
+        &collection_authority,
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &collection_mint,
+        &asset_proof,
+        metadata,
+    );
+    
+    instructions_result(env, &[verify_collection_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_unverify_collection(
+    env: Env,
+    args: (String, String, (String, Option<String>), String, AssetProofNif, MetadataArgsNif),
+) -> Term {
+    let (collection_authority_str, tree_pubkey_str, (leaf_owner_str, leaf_delegate_str), collection_mint_str, asset_proof, metadata_args) = args;
+    
+    // Parse the pubkeys
+    let collection_authority = match parse_pubkey(&collection_authority_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    let collection_mint = match parse_pubkey(&collection_mint_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the unverify collection instruction
+    let unverify_collection_ix = instructions::unverify_collection(
+        &collection_authority,
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &collection_mint,
+        &asset_proof,
+        metadata,
+    );
+    
+    instructions_result(env, &[unverify_collection_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_set_and_verify_collection(
+    env: Env,
+    args: (String, String, (String, Option<String>), String, AssetProofNif, MetadataArgsNif),
+) -> Term {
+    let (collection_authority_str, tree_pubkey_str, (leaf_owner_str, leaf_delegate_str), collection_mint_str, asset_proof, metadata_args) = args;
+    
+    // Parse the pubkeys
+    let collection_authority = match parse_pubkey(&collection_authority_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    let collection_mint = match parse_pubkey(&collection_mint_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the set and verify collection instruction
+    let set_and_verify_collection_ix = instructions::set_and_verify_collection(
+        &collection_authority,
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &collection_mint,
+        &asset_proof,
+        metadata,
+    );
+    
+    instructions_result(env, &[set_and_verify_collection_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_update_metadata(
+    env: Env,
+    args: (String, String, (String, Option<String>), AssetProofNif, MetadataArgsNif, UpdateArgsNif),
+) -> Term {
+    let (authority_str, tree_pubkey_str, (leaf_owner_str, leaf_delegate_str), asset_proof, metadata_args, update_args) = args;
+    
+    // Parse the pubkeys
+    let authority = match parse_pubkey(&authority_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    // Convert the asset proof, current metadata and update args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let current_metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let update_args = match convert_update_args(&update_args) {
+        Ok(update_args) => update_args,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the update metadata instruction
+    let update_metadata_ix = instructions::update_metadata(
+        &authority,
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &asset_proof,
+        current_metadata,
+        update_args,
+    );
+    
+    instructions_result(env, &[update_metadata_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_set_tree_delegate(
+    env: Env,
+    args: (String, String, String),
+) -> Term {
+    let (tree_creator_str, tree_pubkey_str, new_delegate_str) = args;
+    
+    // Parse the pubkeys
+    let tree_creator = match parse_pubkey(&tree_creator_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let new_delegate = match parse_pubkey(&new_delegate_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the set tree delegate instruction
+    let set_tree_delegate_ix = instructions::set_tree_delegate(&tree_creator, &tree_pubkey, &new_delegate);
+    
+    instructions_result(env, &[set_tree_delegate_ix], &[])
+}
+
+#[rustler::nif]
+pub fn build_set_decompressible_state(
+    env: Env,
+    args: (String, String, DecompressibleStateNif),
+) -> Term {
+    let (tree_creator_str, tree_pubkey_str, decompressible_state) = args;
+    
+    // Parse the pubkeys
+    let tree_creator = match parse_pubkey(&tree_creator_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the set decompressible state instruction
+    let set_decompressible_state_ix = instructions::set_decompressible_state(
+        &tree_creator,
+        &tree_pubkey,
+        convert_decompressible_state(&decompressible_state),
+    );
+    
+    instructions_result(env, &[set_decompressible_state_ix], &[])
+}
@@ -0,0 +1,762 @@
+use mpl_bubblegum::types::MetadataArgs;
+use rustler::{env::SavedTerm, Encoder, Env, LocalPid, NifStruct, NifUnitEnum, OwnedEnv, Term};
+use serde::Deserialize;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader, Read},
+    sync::{mpsc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    atoms,
+    batch::{self, BatchMintItemNif},
+    convert_metadata_args, decode_keypair,
+    error::ErrorNif,
+    events, instructions,
+    keypair::KeypairNif,
+    parse_pubkey, reply,
+    request::{AirdropRequestNif, BulkMintRequestNif},
+    send_transaction_part,
+    transaction::{SendResult, TransactionOptionsNif, TransactionOutcome},
+    uri_check, BubblegumError, CreatorNif, MetadataArgsNif,
+};
+
+// Rough compute cost of one mint without a collection
+const MINT_COMPUTE_UNITS: u32 = 40_000;
+
+// The keypairs a bulk mint signs with and the accounts it mints to
+struct BulkMintAccounts {
+    payer: Keypair,
+    tree_creator_or_delegate: Option<Keypair>,
+    collection_authority: Option<Keypair>,
+    tree: Pubkey,
+    collection: Option<Pubkey>,
+    collection_authority_record: Option<Pubkey>,
+}
+
+// A bulk mint with its keypairs decoded and its metadata turned into mint instructions
+struct BulkMintJob {
+    payer: Keypair,
+    tree_creator_or_delegate: Option<Keypair>,
+    collection_authority: Option<Keypair>,
+    tree: Pubkey,
+    // Transactions still to send, each with the item indexes of its mints
+    queue: Mutex<VecDeque<Vec<(usize, Instruction)>>>,
+    // Items that failed to convert and are not minted
+    invalid: Vec<(usize, BubblegumError)>,
+    total: usize,
+    workers: usize,
+    pacer: Pacer,
+}
+
+// Spaces out transactions so all workers together send at most the given number per second
+struct Pacer {
+    interval: Option<Duration>,
+    next: Mutex<Instant>,
+}
+
+// How the NFT of one airdrop recipient fared
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.AirdropItem"]
+pub struct AirdropItemNif {
+    pub index: usize,
+    pub recipient: String,
+    pub signature: Option<String>,
+    pub asset_id: Option<String>,
+    pub error: Option<ErrorNif>,
+}
+
+// What a worker reports after sending one transaction of the job
+type BatchResult = (Vec<usize>, Result<SendResult, BubblegumError>);
+
+impl Pacer {
+    fn new(max_transactions_per_second: Option<u32>) -> Self {
+        Pacer {
+            interval: max_transactions_per_second
+                .filter(|rate| *rate > 0)
+                .map(|rate| Duration::from_secs(1) / rate),
+            next: Mutex::new(Instant::now()),
+        }
+    }
+    
+    // Blocks until the caller's turn to send comes up
+    fn wait(&self) {
+        let interval = match self.interval {
+            Some(interval) => interval,
+            None => return,
+        };
+        
+        let delay = {
+            let mut next = self.next.lock().unwrap();
+            let now = Instant::now();
+            let slot = (*next).max(now);
+            *next = slot + interval;
+            slot - now
+        };
+        thread::sleep(delay);
+    }
+}
+
+impl BulkMintAccounts {
+    // Decodes the keypairs and parses the pubkeys; the tree and collection authorities default to
+    // the payer, and without a collection the NFTs are minted with mint_v1
+    fn decode(
+        payer_keypair: &KeypairNif,
+        tree: &str,
+        collection: Option<&str>,
+        tree_creator_or_delegate_keypair: Option<&KeypairNif>,
+        collection_authority_keypair: Option<&KeypairNif>,
+        collection_authority_record: Option<&str>,
+    ) -> Result<Self, BubblegumError> {
+        Ok(BulkMintAccounts {
+            payer: decode_keypair(payer_keypair)?,
+            tree_creator_or_delegate: tree_creator_or_delegate_keypair.map(decode_keypair).transpose()?,
+            collection_authority: collection_authority_keypair.map(decode_keypair).transpose()?,
+            tree: parse_pubkey(tree)?,
+            collection: collection.map(parse_pubkey).transpose()?,
+            collection_authority_record: collection_authority_record.map(parse_pubkey).transpose()?,
+        })
+    }
+}
+
+impl BulkMintJob {
+    fn from_request(request: BulkMintRequestNif, tx_options: &TransactionOptionsNif) -> Result<Self, BubblegumError> {
+        let accounts = BulkMintAccounts::decode(
+            &request.payer_keypair,
+            &request.merkle_tree,
+            request.collection_mint.as_deref(),
+            request.tree_creator_or_delegate_keypair.as_ref(),
+            request.collection_authority_keypair.as_ref(),
+            request.collection_authority_record.as_deref(),
+        )?;
+        
+        // A bad URI fails only its own NFT, like other invalid metadata
+        let uri_checks = match &request.validate_uris {
+            Some(options) => uri_check::validate_metadata_uris(&request.metadata, options),
+            None => request.metadata.iter().map(|_| Ok(())).collect(),
+        };
+        
+        // Every NFT goes to the payer
+        let payer_pubkey = accounts.payer.pubkey();
+        let items = request
+            .metadata
+            .iter()
+            .zip(uri_checks)
+            .map(|(metadata_args, uri_check)| {
+                let metadata = convert_metadata_args(metadata_args)?;
+                uri_check.map(|_| (metadata, payer_pubkey))
+            })
+            .collect();
+        
+        BulkMintJob::new(accounts, items, request.workers, request.max_transactions_per_second, tx_options)
+    }
+    
+    fn from_airdrop(request: &AirdropRequestNif, tx_options: &TransactionOptionsNif) -> Result<Self, BubblegumError> {
+        let accounts = BulkMintAccounts::decode(
+            &request.payer_keypair,
+            &request.merkle_tree,
+            request.collection_mint.as_deref(),
+            request.tree_creator_or_delegate_keypair.as_ref(),
+            request.collection_authority_keypair.as_ref(),
+            request.collection_authority_record.as_deref(),
+        )?;
+        
+        // The template is shared by every recipient, so bad metadata fails the whole airdrop;
+        // a bad recipient fails only its own NFT
+        let metadata = convert_metadata_args(&request.metadata)?;
+        if let Some(options) = &request.validate_uris {
+            uri_check::validate_metadata_uri(&request.metadata, options)?;
+        }
+        
+        let items = request
+            .recipients
+            .iter()
+            .map(|recipient| parse_pubkey(recipient).map(|leaf_owner| (metadata.clone(), leaf_owner)))
+            .collect();
+        
+        BulkMintJob::new(accounts, items, request.workers, request.max_transactions_per_second, tx_options)
+    }
+    
+    // Turns every item, its metadata and leaf owner, into a mint instruction and packs them
+    fn new(
+        accounts: BulkMintAccounts,
+        items: Vec<Result<(MetadataArgs, Pubkey), BubblegumError>>,
+        workers: u32,
+        max_transactions_per_second: Option<u32>,
+        tx_options: &TransactionOptionsNif,
+    ) -> Result<Self, BubblegumError> {
+        let BulkMintAccounts {
+            payer,
+            tree_creator_or_delegate,
+            collection_authority,
+            tree: tree_pubkey,
+            collection: collection_pubkey,
+            collection_authority_record,
+        } = accounts;
+        
+        if workers == 0 {
+            return Err(BubblegumError::InvalidArgument("A bulk mint needs at least one worker".to_string()));
+        }
+        
+        let tree_creator_or_delegate_pubkey = tree_creator_or_delegate.as_ref().unwrap_or(&payer).pubkey();
+        let collection_authority_pubkey = collection_authority.as_ref().unwrap_or(&payer).pubkey();
+        
+        let total = items.len();
+        let mut invalid = Vec::new();
+        let mut mints = Vec::with_capacity(total);
+        for (index, item) in items.into_iter().enumerate() {
+            let (metadata, leaf_owner) = match item {
+                Ok(item) => item,
+                Err(e) => {
+                    invalid.push((index, e));
+                    continue;
+                },
+            };
+            
+            let mint_ix = match &collection_pubkey {
+                Some(collection_pubkey) => instructions::mint_to_collection_v1(
+                    &payer.pubkey(),
+                    &tree_pubkey,
+                    &leaf_owner,
+                    &leaf_owner,
+                    &tree_creator_or_delegate_pubkey,
+                    &collection_authority_pubkey,
+                    collection_authority_record,
+                    collection_pubkey,
+                    metadata,
+                ),
+                None => instructions::mint_v1(&payer.pubkey(), &tree_pubkey, &leaf_owner, &leaf_owner, metadata),
+            };
+            mints.push((index, mint_ix));
+        }
+        
+        // Pack the mints the same way mint_batch_to_collection_v1 does
+        let mint_compute_units = match collection_pubkey {
+            Some(_) => batch::MINT_TO_COLLECTION_COMPUTE_UNITS,
+            None => MINT_COMPUTE_UNITS,
+        };
+        let compute_unit_limit = tx_options.compute_unit_limit.unwrap_or(batch::MAX_COMPUTE_UNIT_LIMIT);
+        let max_mints_per_transaction = (compute_unit_limit / mint_compute_units).max(1) as usize;
+        let queue = batch::pack_instructions(&payer.pubkey(), mints, max_mints_per_transaction, tx_options.memo.as_deref());
+        
+        Ok(BulkMintJob {
+            payer,
+            tree_creator_or_delegate,
+            collection_authority,
+            tree: tree_pubkey,
+            queue: Mutex::new(queue.into()),
+            invalid,
+            total,
+            workers: workers as usize,
+            pacer: Pacer::new(max_transactions_per_second),
+        })
+    }
+    
+    // Takes transactions off the queue and sends them until it is empty. Retries, and signing
+    // again after an expired blockhash, follow the retry policy of the transaction options
+    fn run_worker(&self, tx_options: &TransactionOptionsNif, results: mpsc::Sender<BatchResult>) {
+        loop {
+            let batch = match self.queue.lock().unwrap().pop_front() {
+                Some(batch) => batch,
+                None => return,
+            };
+            
+            self.pacer.wait();
+            
+            let (indexes, batch_instructions): (Vec<usize>, Vec<Instruction>) = batch.into_iter().unzip();
+            let signers = self.tree_creator_or_delegate.iter().chain(self.collection_authority.iter()).collect();
+            let part = indexes[0];
+            let result = send_transaction_part(batch_instructions, &self.payer, signers, tx_options, part);
+            
+            if results.send((indexes, result)).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+// Sends `{:bulk_mint_progress, ref, done, total, failures}` to the caller
+fn send_progress(pid: &LocalPid, reference_env: &OwnedEnv, reference: &SavedTerm, done: usize, total: usize, failures: usize) {
+    let mut message_env = OwnedEnv::new();
+    message_env.send_and_clear(pid, |env| {
+        let reference = reference_env.run(|reference_env| reference.load(reference_env).in_env(env));
+        (atoms::bulk_mint_progress(), reference, done, total, failures).encode(env)
+    });
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn bulk_mint(
+    env: Env,
+    args: (BulkMintRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    
+    // Bad keypairs and accounts fail right away, before the job starts
+    let job = match BulkMintJob::from_request(request, &tx_options) {
+        Ok(job) => job,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    run_in_background(env, job, tx_options, |env, job, _tx_options, results| encode_result(env, job.invalid, results))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn airdrop_to_owners(
+    env: Env,
+    args: (AirdropRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    
+    // Bad keypairs, accounts and metadata fail right away, before the job starts
+    let job = match BulkMintJob::from_airdrop(&request, &tx_options) {
+        Ok(job) => job,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let recipients = request.recipients;
+    run_in_background(env, job, tx_options, move |env, job, tx_options, results| {
+        encode_airdrop_result(env, &job, tx_options, &recipients, results)
+    })
+}
+
+// Runs the job from its workers in the background. The caller receives progress messages and,
+// once every transaction is sent, `{:bubblegum_result, ref, result}` with what `encode` makes of
+// the results
+fn run_in_background<F>(env: Env, job: BulkMintJob, tx_options: TransactionOptionsNif, encode: F) -> Term
+where
+    F: for<'a> FnOnce(Env<'a>, BulkMintJob, &TransactionOptionsNif, Vec<BatchResult>) -> Term<'a> + Send + 'static,
+{
+    let pid = env.pid();
+    let reference = reply::make_ref(env);
+    
+    let mut reference_env = OwnedEnv::new();
+    let saved_reference = reference_env.save(reference.encode(env));
+    
+    thread::spawn(move || {
+        let (sender, receiver) = mpsc::channel();
+        let mut results: Vec<BatchResult> = Vec::new();
+        
+        thread::scope(|scope| {
+            for _ in 0..job.workers {
+                let sender = sender.clone();
+                let job = &job;
+                let tx_options = &tx_options;
+                scope.spawn(move || job.run_worker(tx_options, sender));
+            }
+            drop(sender);
+            
+            let mut done = job.invalid.len();
+            let mut failures = job.invalid.len();
+            if done > 0 {
+                send_progress(&pid, &reference_env, &saved_reference, done, job.total, failures);
+            }
+            
+            for (indexes, result) in receiver {
+                done += indexes.len();
+                if result.is_err() {
+                    failures += indexes.len();
+                }
+                send_progress(&pid, &reference_env, &saved_reference, done, job.total, failures);
+                results.push((indexes, result));
+            }
+        });
+        
+        reference_env.send_and_clear(&pid, |env| {
+            let result = encode(env, job, &tx_options, results);
+            (atoms::bubblegum_result(), saved_reference.load(env), result).encode(env)
+        });
+    });
+    
+    (atoms::ok(), reference).encode(env)
+}
+
+// Same shape as the result of mint_batch_to_collection_v1
+fn encode_result<'a>(env: Env<'a>, invalid: Vec<(usize, BubblegumError)>, results: Vec<BatchResult>) -> Term<'a> {
+    let mut items: Vec<BatchMintItemNif> = invalid
+        .into_iter()
+        .map(|(index, e)| BatchMintItemNif {
+            index,
+            signature: None,
+            error: Some(e.to_nif(env)),
+        })
+        .collect();
+    
+    let mut signatures = Vec::new();
+    let mut simulations = Vec::new();
+    let mut plans = Vec::new();
+    let mut attempts = 0;
+    for (indexes, result) in results {
+        match result {
+            Ok(sent) => {
+                attempts += sent.attempts;
+                let signature = match &sent.outcome {
+                    TransactionOutcome::Sent(signature) => {
+                        signatures.push(signature.to_string());
+                        Some(signature.to_string())
+                    },
+                    TransactionOutcome::Simulated(simulation) => {
+                        simulations.push(simulation.encode(env));
+                        None
+                    },
+                    TransactionOutcome::Planned(plan) => {
+                        plans.push(plan.encode(env));
+                        None
+                    },
+                };
+                items.extend(indexes.into_iter().map(|index| BatchMintItemNif {
+                    index,
+                    signature: signature.clone(),
+                    error: None,
+                }));
+            },
+            Err(e) => {
+                items.extend(indexes.into_iter().map(|index| BatchMintItemNif {
+                    index,
+                    signature: None,
+                    error: Some(e.to_nif(env)),
+                }));
+            },
+        }
+    }
+    
+    items.sort_by_key(|item| item.index);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("signatures".encode(env), signatures.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("simulations".encode(env), simulations.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("plans".encode(env), plans.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("items".encode(env), items.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("attempts".encode(env), attempts.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+// Per recipient, in the order given: the transaction that minted its NFT and the asset ID,
+// read from the events of the transaction once it is confirmed
+fn encode_airdrop_result<'a>(
+    env: Env<'a>,
+    job: &BulkMintJob,
+    tx_options: &TransactionOptionsNif,
+    recipients: &[String],
+    results: Vec<BatchResult>,
+) -> Term<'a> {
+    let item = |index: usize, signature: Option<String>, asset_id: Option<String>, error: Option<&BubblegumError>| AirdropItemNif {
+        index,
+        recipient: recipients[index].clone(),
+        signature,
+        asset_id,
+        error: error.map(|e| e.to_nif(env)),
+    };
+    
+    let mut items: Vec<AirdropItemNif> = job.invalid.iter().map(|(index, e)| item(*index, None, None, Some(e))).collect();
+    
+    let mut signatures = Vec::new();
+    let mut simulations = Vec::new();
+    let mut plans = Vec::new();
+    let mut attempts = 0;
+    for (indexes, result) in results {
+        match result {
+            Ok(sent) => {
+                attempts += sent.attempts;
+                match &sent.outcome {
+                    TransactionOutcome::Sent(signature) => {
+                        signatures.push(signature.to_string());
+                        
+                        // The mints of a transaction log their leaves in the order they were packed
+                        let asset_ids = if tx_options.send_options.confirm {
+                            events::fetch_transaction_events(&tx_options.rpc_url, &tx_options.retry, signature)
+                                .map(|events| events::minted_asset_ids(&job.tree, &events))
+                                .unwrap_or_default()
+                        } else {
+                            Vec::new()
+                        };
+                        let asset_ids: Vec<Option<String>> = if asset_ids.len() == indexes.len() {
+                            asset_ids.into_iter().map(Some).collect()
+                        } else {
+                            vec![None; indexes.len()]
+                        };
+                        
+                        items.extend(
+                            indexes
+                                .into_iter()
+                                .zip(asset_ids)
+                                .map(|(index, asset_id)| item(index, Some(signature.to_string()), asset_id, None)),
+                        );
+                    },
+                    TransactionOutcome::Simulated(simulation) => {
+                        simulations.push(simulation.encode(env));
+                        items.extend(indexes.into_iter().map(|index| item(index, None, None, None)));
+                    },
+                    TransactionOutcome::Planned(plan) => {
+                        plans.push(plan.encode(env));
+                        items.extend(indexes.into_iter().map(|index| item(index, None, None, None)));
+                    },
+                }
+            },
+            Err(e) => {
+                items.extend(indexes.into_iter().map(|index| item(index, None, None, Some(&e))));
+            },
+        }
+    }
+    
+    items.sort_by_key(|item| item.index);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("signatures".encode(env), signatures.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("simulations".encode(env), simulations.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("plans".encode(env), plans.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("items".encode(env), items.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("attempts".encode(env), attempts.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+// Layout of a metadata file for `load_metadata_file`
+#[derive(NifUnitEnum, Clone, Copy)]
+pub enum MetadataFileFormatNif {
+    Jsonl,
+    Csv,
+}
+
+// A row of a metadata file that did not make valid metadata, with its line in the file
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.MetadataRowError"]
+pub struct MetadataRowErrorNif {
+    pub line: u64,
+    pub error: ErrorNif,
+}
+
+fn default_is_mutable() -> bool {
+    true
+}
+
+// One JSONL line; creators are objects like those of MetadataArgs
+#[derive(Deserialize)]
+struct MetadataRow {
+    name: String,
+    #[serde(default)]
+    symbol: String,
+    uri: String,
+    #[serde(default)]
+    seller_fee_basis_points: u16,
+    #[serde(default)]
+    primary_sale_happened: bool,
+    #[serde(default = "default_is_mutable")]
+    is_mutable: bool,
+    #[serde(default)]
+    edition_nonce: Option<u8>,
+    #[serde(default)]
+    creators: Vec<CreatorRow>,
+    #[serde(default)]
+    collection: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CreatorRow {
+    address: String,
+    #[serde(default)]
+    verified: bool,
+    share: u8,
+}
+
+// One CSV record; creators are `address:share` pairs separated by `;` in a single column
+#[derive(Deserialize)]
+struct CsvMetadataRow {
+    name: String,
+    #[serde(default)]
+    symbol: String,
+    uri: String,
+    #[serde(default)]
+    seller_fee_basis_points: u16,
+    #[serde(default)]
+    primary_sale_happened: bool,
+    #[serde(default = "default_is_mutable")]
+    is_mutable: bool,
+    #[serde(default)]
+    edition_nonce: Option<u8>,
+    #[serde(default)]
+    creators: Option<String>,
+    #[serde(default)]
+    collection: Option<String>,
+}
+
+impl CsvMetadataRow {
+    fn into_row(self) -> Result<MetadataRow, BubblegumError> {
+        let creators = self
+            .creators
+            .iter()
+            .flat_map(|creators| creators.split(';'))
+            .map(str::trim)
+            .filter(|creator| !creator.is_empty())
+            .map(|creator| match creator.split_once(':') {
+                Some((address, share)) => share
+                    .trim()
+                    .parse()
+                    .map(|share| CreatorRow {
+                        address: address.trim().to_string(),
+                        verified: false,
+                        share,
+                    })
+                    .map_err(|e| BubblegumError::SerializationError(format!("creator {}: share {}", creator, e))),
+                None => Err(BubblegumError::SerializationError(format!("creator {} is not address:share", creator))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(MetadataRow {
+            name: self.name,
+            symbol: self.symbol,
+            uri: self.uri,
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            primary_sale_happened: self.primary_sale_happened,
+            is_mutable: self.is_mutable,
+            edition_nonce: self.edition_nonce,
+            creators,
+            collection: self.collection.filter(|collection| !collection.is_empty()),
+        })
+    }
+}
+
+impl MetadataRow {
+    // Checked the same way every mint checks its metadata, so rows that load also mint
+    fn into_metadata_args(self) -> Result<MetadataArgsNif, BubblegumError> {
+        let metadata = MetadataArgsNif {
+            name: self.name,
+            symbol: self.symbol,
+            uri: self.uri,
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            primary_sale_happened: self.primary_sale_happened,
+            is_mutable: self.is_mutable,
+            edition_nonce: self.edition_nonce,
+            creators: self
+                .creators
+                .into_iter()
+                .map(|creator| CreatorNif {
+                    address: creator.address,
+                    verified: creator.verified,
+                    share: creator.share,
+                })
+                .collect(),
+            collection: self.collection,
+            collection_verified: false,
+            uses: None,
+            token_program_version: None,
+            token_standard: None,
+        };
+        convert_metadata_args(&metadata)?;
+        Ok(metadata)
+    }
+}
+
+// A row of a metadata file with its line number, or why the row could not be read
+type NumberedRow = (u64, Result<MetadataRow, BubblegumError>);
+
+// Reads one line at a time; blank lines are skipped
+fn jsonl_rows(reader: impl BufRead) -> Result<Vec<NumberedRow>, BubblegumError> {
+    let mut rows = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| BubblegumError::SerializationError(format!("line {}: {}", index + 1, e)))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        
+        let row = serde_json::from_str(&line).map_err(|e| BubblegumError::SerializationError(e.to_string()));
+        rows.push((index as u64 + 1, row));
+    }
+    Ok(rows)
+}
+
+// Reads one record at a time; the first line names the columns
+fn csv_rows(reader: impl Read) -> Result<Vec<NumberedRow>, BubblegumError> {
+    let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+    let headers = reader
+        .headers()
+        .map_err(|e| BubblegumError::SerializationError(format!("header: {}", e)))?
+        .clone();
+    
+    let mut rows = Vec::new();
+    let mut record = csv::StringRecord::new();
+    loop {
+        match reader.read_record(&mut record) {
+            Ok(true) => {},
+            Ok(false) => break,
+            // A record that cannot be read, such as one with too many fields, fails on its own
+            Err(e) => match e.position() {
+                Some(position) if !e.is_io_error() => {
+                    rows.push((position.line(), Err(BubblegumError::SerializationError(e.to_string()))));
+                    continue;
+                },
+                _ => return Err(BubblegumError::SerializationError(e.to_string())),
+            },
+        }
+        
+        let line = record.position().map_or(0, |position| position.line());
+        let row = record
+            .deserialize::<CsvMetadataRow>(Some(&headers))
+            .map_err(|e| BubblegumError::SerializationError(e.to_string()))
+            .and_then(CsvMetadataRow::into_row);
+        rows.push((line, row));
+    }
+    Ok(rows)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn load_metadata_file(
+    env: Env,
+    args: (String, Option<MetadataFileFormatNif>),
+) -> Term {
+    let (path, format) = args;
+    
+    // Unless given, the format follows the extension
+    let format = format.unwrap_or(if path.to_lowercase().ends_with(".csv") {
+        MetadataFileFormatNif::Csv
+    } else {
+        MetadataFileFormatNif::Jsonl
+    });
+    
+    let file = match File::open(&path) {
+        Ok(file) => file,
+        Err(e) => return (atoms::error(), BubblegumError::InvalidArgument(format!("{}: {}", path, e))).encode(env),
+    };
+    
+    // The file is read through a buffer rather than at once, so only the parsed rows stay in memory
+    let reader = BufReader::new(file);
+    let rows = match format {
+        MetadataFileFormatNif::Jsonl => jsonl_rows(reader),
+        MetadataFileFormatNif::Csv => csv_rows(reader),
+    };
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // A row that does not make valid metadata is reported with its line and left out
+    let mut metadata = Vec::new();
+    let mut lines = Vec::new();
+    let mut errors = Vec::new();
+    for (line, row) in rows {
+        match row.and_then(MetadataRow::into_metadata_args) {
+            Ok(row_metadata) => {
+                metadata.push(row_metadata);
+                lines.push(line);
+            },
+            Err(e) => errors.push(MetadataRowErrorNif {
+                line,
+                error: e.to_nif(env),
+            }),
+        }
+    }
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("metadata".encode(env), metadata.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("lines".encode(env), lines.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("errors".encode(env), errors.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
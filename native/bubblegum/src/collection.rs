@@ -0,0 +1,536 @@
+use mpl_token_metadata::{
+    accounts::{CollectionAuthorityRecord, Metadata},
+    instructions::{
+        ApproveCollectionAuthorityBuilder, CreateV1Builder, MintV1Builder, RevokeCollectionAuthorityBuilder,
+        SetCollectionSizeBuilder, UpdateV1Builder,
+    },
+    types::{
+        CollectionDetails, CollectionDetailsToggle, CollectionToggle, Creator as TokenMetadataCreator, Data,
+        PrintSupply, RuleSetToggle, SetCollectionSizeArgs, TokenStandard, UsesToggle,
+    },
+};
+use rustler::{Encoder, Env, Term};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use std::str::FromStr;
+
+use crate::{
+    associated_token_address, atoms, convert_token_program_version, decode_keypair,
+    keypair::KeypairNif,
+    master_edition_pda, metadata_pda, metadata_violations, parse_pubkey, reply,
+    request::{
+        ApproveCollectionAuthorityRequestNif, CreateCollectionRequestNif, RevokeCollectionAuthorityRequestNif,
+        SetCollectionSizeRequestNif, UpdateCollectionRequestNif,
+    },
+    send_transaction,
+    transaction::TransactionOptionsNif,
+    tree::fetch_account_data,
+    token_program_id, BubblegumError, CreatorNif,
+};
+
+// Decodes an optional keypair that defaults to the payer
+fn decode_optional_keypair(keypair: Option<KeypairNif>) -> Result<Option<Keypair>, BubblegumError> {
+    keypair.as_ref().map(decode_keypair).transpose()
+}
+
+// Token Metadata creators; only the update authority signs, so only it can be verified
+fn convert_creators(creators: &[CreatorNif], update_authority: &Pubkey) -> Result<Vec<TokenMetadataCreator>, BubblegumError> {
+    convert_creators_keeping(creators, update_authority, &[])
+}
+
+// Like convert_creators, but creators in `verified` stay verified, as Token Metadata only lets
+// a creator be verified by its own signature
+fn convert_creators_keeping(
+    creators: &[CreatorNif],
+    update_authority: &Pubkey,
+    verified: &[Pubkey],
+) -> Result<Vec<TokenMetadataCreator>, BubblegumError> {
+    creators
+        .iter()
+        .enumerate()
+        .map(|(index, c)| {
+            let address = Pubkey::from_str(&c.address).map_err(|e| {
+                BubblegumError::InvalidPublicKey(format!("creator {} address {}: {}", index, c.address, e))
+            })?;
+            
+            Ok(TokenMetadataCreator {
+                address,
+                verified: address == *update_authority || verified.contains(&address),
+                share: c.share,
+            })
+        })
+        .collect()
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn create_collection_nft(
+    env: Env,
+    args: (CreateCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| create_collection_nft_impl(env, args))
+}
+
+fn create_collection_nft_impl(
+    env: Env,
+    args: (CreateCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let CreateCollectionRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points,
+        creators,
+        is_mutable,
+        mint_keypair: mint_keypair_bs58,
+        update_authority_keypair: update_authority_keypair_bs58,
+        token_program_version,
+    } = request;
+    
+    // Check the metadata before any RPC call
+    let violations = metadata_violations(&name, &symbol, &uri, seller_fee_basis_points, &creators);
+    if !violations.is_empty() {
+        return (atoms::error(), BubblegumError::InvalidMetadata(violations)).encode(env);
+    }
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Use the caller's mint keypair, or generate one
+    let mint = match mint_keypair_bs58 {
+        Some(mint_keypair_bs58) => match decode_keypair(&mint_keypair_bs58) {
+            Ok(keypair) => keypair,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => Keypair::new(),
+    };
+    
+    // The update authority defaults to the payer
+    let update_authority = match decode_optional_keypair(update_authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    let authority = update_authority.as_ref().unwrap_or(&payer);
+    
+    let creators = match convert_creators(&creators, &authority.pubkey()) {
+        Ok(creators) => creators,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = metadata_pda(&mint.pubkey());
+    let master_edition = master_edition_pda(&mint.pubkey());
+    // A Token-2022 collection has its mint and token account owned by the Token-2022 program
+    let token_program = token_program_id(&convert_token_program_version(token_program_version.as_ref()));
+    let token_account = associated_token_address(&authority.pubkey(), &mint.pubkey(), &token_program);
+    
+    // Create the mint with its metadata and master edition; a sized collection starts empty
+    let mut create_builder = CreateV1Builder::new();
+    create_builder
+        .metadata(metadata)
+        .master_edition(Some(master_edition))
+        .mint(mint.pubkey(), true)
+        .authority(authority.pubkey())
+        .payer(payer.pubkey())
+        .update_authority(authority.pubkey(), true)
+        .spl_token_program(Some(token_program))
+        .name(name)
+        .symbol(symbol)
+        .uri(uri)
+        .seller_fee_basis_points(seller_fee_basis_points)
+        .primary_sale_happened(false)
+        .is_mutable(is_mutable)
+        .token_standard(TokenStandard::NonFungible)
+        .collection_details(CollectionDetails::V1 { size: 0 })
+        .print_supply(PrintSupply::Zero);
+    if !creators.is_empty() {
+        create_builder.creators(creators);
+    }
+    let create_ix = create_builder.instruction();
+    
+    // Mint the single token to the update authority, creating its token account
+    let mint_ix = MintV1Builder::new()
+        .token(token_account)
+        .token_owner(Some(authority.pubkey()))
+        .metadata(metadata)
+        .master_edition(Some(master_edition))
+        .mint(mint.pubkey())
+        .authority(authority.pubkey())
+        .payer(payer.pubkey())
+        .spl_token_program(token_program)
+        .amount(1)
+        .instruction();
+    
+    // Send the transaction
+    match send_transaction(vec![create_ix, mint_ix], &payer, vec![&mint, authority], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("collection_mint".encode(env), mint.pubkey().to_string().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("metadata".encode(env), metadata.to_string().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("master_edition".encode(env), master_edition.to_string().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("token_account".encode(env), token_account.to_string().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("token_program".encode(env), token_program.to_string().encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+// Token Metadata pads name, symbol and URI with NUL bytes up to their maximum length
+fn unpadded(value: &str) -> String {
+    value.trim_end_matches('\0').to_string()
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn update_collection_metadata(
+    env: Env,
+    args: (UpdateCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| update_collection_metadata_impl(env, args))
+}
+
+fn update_collection_metadata_impl(
+    env: Env,
+    args: (UpdateCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let UpdateCollectionRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        collection_mint: collection_mint_str,
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points,
+        creators,
+        primary_sale_happened,
+        is_mutable,
+        new_update_authority: new_update_authority_str,
+        update_authority_keypair: update_authority_keypair_bs58,
+    } = request;
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The update authority defaults to the payer
+    let update_authority = match decode_optional_keypair(update_authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    let authority = update_authority.as_ref().unwrap_or(&payer);
+    
+    let collection_mint = match parse_pubkey(&collection_mint_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let new_update_authority = match new_update_authority_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // UpdateV1 replaces name, symbol, URI, royalties and creators together, so the fields left
+    // nil are filled in from the current metadata
+    let updates_data = name.is_some()
+        || symbol.is_some()
+        || uri.is_some()
+        || seller_fee_basis_points.is_some()
+        || creators.is_some();
+    if !updates_data && primary_sale_happened.is_none() && is_mutable.is_none() && new_update_authority.is_none() {
+        return (atoms::error(), BubblegumError::InvalidArgument("Nothing to update".to_string())).encode(env);
+    }
+    
+    let metadata = metadata_pda(&collection_mint);
+    let master_edition = master_edition_pda(&collection_mint);
+    
+    let mut update_builder = UpdateV1Builder::new();
+    update_builder
+        .authority(authority.pubkey())
+        .metadata(metadata)
+        .mint(collection_mint)
+        .edition(Some(master_edition))
+        .payer(payer.pubkey())
+        .collection(CollectionToggle::None)
+        .collection_details(CollectionDetailsToggle::None)
+        .uses(UsesToggle::None)
+        .rule_set(RuleSetToggle::None);
+    
+    if updates_data {
+        let current = match fetch_account_data(&tx_options.rpc_url, &tx_options.retry, &metadata)
+            .and_then(|data| {
+                Metadata::safe_deserialize(&data)
+                    .map_err(|e| BubblegumError::SerializationError(format!("metadata {}: {}", metadata, e)))
+            }) {
+            Ok(current) => current,
+            Err(e) => return (atoms::error(), e).encode(env),
+        };
+        
+        let name = name.unwrap_or_else(|| unpadded(&current.name));
+        let symbol = symbol.unwrap_or_else(|| unpadded(&current.symbol));
+        let uri = uri.unwrap_or_else(|| unpadded(&current.uri));
+        let seller_fee_basis_points = seller_fee_basis_points.unwrap_or(current.seller_fee_basis_points);
+        
+        let creators = match creators {
+            Some(creators) => {
+                let violations = metadata_violations(&name, &symbol, &uri, seller_fee_basis_points, &creators);
+                if !violations.is_empty() {
+                    return (atoms::error(), BubblegumError::InvalidMetadata(violations)).encode(env);
+                }
+                
+                let verified: Vec<Pubkey> = current
+                    .creators
+                    .iter()
+                    .flatten()
+                    .filter(|creator| creator.verified)
+                    .map(|creator| creator.address)
+                    .collect();
+                match convert_creators_keeping(&creators, &authority.pubkey(), &verified) {
+                    Ok(creators) if creators.is_empty() => None,
+                    Ok(creators) => Some(creators),
+                    Err(e) => return (atoms::error(), e).encode(env),
+                }
+            },
+            None => {
+                let violations = metadata_violations(&name, &symbol, &uri, seller_fee_basis_points, &[]);
+                if !violations.is_empty() {
+                    return (atoms::error(), BubblegumError::InvalidMetadata(violations)).encode(env);
+                }
+                current.creators
+            },
+        };
+        
+        update_builder.data(Data {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points,
+            creators,
+        });
+    }
+    
+    if let Some(primary_sale_happened) = primary_sale_happened {
+        update_builder.primary_sale_happened(primary_sale_happened);
+    }
+    if let Some(is_mutable) = is_mutable {
+        update_builder.is_mutable(is_mutable);
+    }
+    if let Some(new_update_authority) = new_update_authority {
+        update_builder.new_update_authority(new_update_authority);
+    }
+    
+    let update_ix = update_builder.instruction();
+    
+    // Send the transaction
+    match send_transaction(vec![update_ix], &payer, vec![authority], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("metadata".encode(env), metadata.to_string().encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn approve_collection_authority(
+    env: Env,
+    args: (ApproveCollectionAuthorityRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| approve_collection_authority_impl(env, args))
+}
+
+fn approve_collection_authority_impl(
+    env: Env,
+    args: (ApproveCollectionAuthorityRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let ApproveCollectionAuthorityRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        collection_mint: collection_mint_str,
+        new_collection_authority: new_collection_authority_str,
+        update_authority_keypair: update_authority_keypair_bs58,
+    } = request;
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The update authority defaults to the payer
+    let update_authority = match decode_optional_keypair(update_authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    let authority = update_authority.as_ref().unwrap_or(&payer);
+    
+    let (collection_mint, new_collection_authority) =
+        match (parse_pubkey(&collection_mint_str), parse_pubkey(&new_collection_authority_str)) {
+            (Ok(collection_mint), Ok(new_collection_authority)) => (collection_mint, new_collection_authority),
+            (Err(e), _) | (_, Err(e)) => return (atoms::error(), e).encode(env),
+        };
+    
+    let record = CollectionAuthorityRecord::find_pda(&collection_mint, &new_collection_authority).0;
+    
+    let approve_ix = ApproveCollectionAuthorityBuilder::new()
+        .collection_authority_record(record)
+        .new_collection_authority(new_collection_authority)
+        .update_authority(authority.pubkey())
+        .payer(payer.pubkey())
+        .metadata(metadata_pda(&collection_mint))
+        .mint(collection_mint)
+        .instruction();
+    
+    // Send the transaction
+    match send_transaction(vec![approve_ix], &payer, vec![authority], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("collection_authority_record".encode(env), record.to_string().encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn revoke_collection_authority(
+    env: Env,
+    args: (RevokeCollectionAuthorityRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| revoke_collection_authority_impl(env, args))
+}
+
+fn revoke_collection_authority_impl(
+    env: Env,
+    args: (RevokeCollectionAuthorityRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let RevokeCollectionAuthorityRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        collection_mint: collection_mint_str,
+        delegate_authority: delegate_authority_str,
+        revoke_authority_keypair: revoke_authority_keypair_bs58,
+    } = request;
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Either the update authority or the delegate itself revokes; defaults to the payer
+    let revoke_authority = match decode_optional_keypair(revoke_authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    let authority = revoke_authority.as_ref().unwrap_or(&payer);
+    
+    let (collection_mint, delegate_authority) =
+        match (parse_pubkey(&collection_mint_str), parse_pubkey(&delegate_authority_str)) {
+            (Ok(collection_mint), Ok(delegate_authority)) => (collection_mint, delegate_authority),
+            (Err(e), _) | (_, Err(e)) => return (atoms::error(), e).encode(env),
+        };
+    
+    let record = CollectionAuthorityRecord::find_pda(&collection_mint, &delegate_authority).0;
+    
+    // Closing the record refunds its rent to the revoking authority
+    let revoke_ix = RevokeCollectionAuthorityBuilder::new()
+        .collection_authority_record(record)
+        .delegate_authority(delegate_authority)
+        .revoke_authority(authority.pubkey())
+        .metadata(metadata_pda(&collection_mint))
+        .mint(collection_mint)
+        .instruction();
+    
+    // Send the transaction
+    match send_transaction(vec![revoke_ix], &payer, vec![authority], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("collection_authority_record".encode(env), record.to_string().encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn set_collection_size(
+    env: Env,
+    args: (SetCollectionSizeRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| set_collection_size_impl(env, args))
+}
+
+fn set_collection_size_impl(
+    env: Env,
+    args: (SetCollectionSizeRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let SetCollectionSizeRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        collection_mint: collection_mint_str,
+        size,
+        collection_authority_keypair: collection_authority_keypair_bs58,
+        collection_authority_record: collection_authority_record_str,
+    } = request;
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The collection authority defaults to the payer
+    let collection_authority = match decode_optional_keypair(collection_authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    let authority = collection_authority.as_ref().unwrap_or(&payer);
+    
+    let collection_mint = match parse_pubkey(&collection_mint_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection_authority_record = match collection_authority_record_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(record) => record,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Turns an unsized collection into a sized one that starts counting from `size`
+    let set_size_ix = SetCollectionSizeBuilder::new()
+        .collection_metadata(metadata_pda(&collection_mint))
+        .collection_authority(authority.pubkey())
+        .collection_mint(collection_mint)
+        .collection_authority_record(collection_authority_record)
+        .set_collection_size_args(SetCollectionSizeArgs { size })
+        .instruction();
+    
+    // Send the transaction
+    match send_transaction(vec![set_size_ix], &payer, vec![authority], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
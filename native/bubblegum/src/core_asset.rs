@@ -0,0 +1,242 @@
+use mpl_core::{
+    instructions::{BurnV1Builder, CreateV1Builder, TransferV1Builder},
+    types::DataState,
+};
+use rustler::{Encoder, Env, Term};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::{
+    atoms, decode_keypair, parse_pubkey, reply,
+    request::{CoreBurnRequestNif, CoreCreateRequestNif, CoreTransferRequestNif},
+    send_transaction,
+    transaction::TransactionOptionsNif,
+    BubblegumError,
+};
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn create_core_asset(
+    env: Env,
+    args: (CoreCreateRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| create_core_asset_impl(env, args))
+}
+
+fn create_core_asset_impl(
+    env: Env,
+    args: (CoreCreateRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let CoreCreateRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        name,
+        uri,
+        owner: owner_str,
+        collection: collection_str,
+        update_authority: update_authority_str,
+        asset_keypair: asset_keypair_bs58,
+        authority_keypair: authority_keypair_bs58,
+    } = request;
+    
+    // Core has no length limits of its own, but an empty name or URI is never meant
+    let mut violations = Vec::new();
+    if name.is_empty() {
+        violations.push(("name", "is empty".to_string()));
+    }
+    if uri.is_empty() {
+        violations.push(("uri", "is empty".to_string()));
+    }
+    if !violations.is_empty() {
+        return (atoms::error(), BubblegumError::InvalidMetadata(violations)).encode(env);
+    }
+    
+    // Decode the keypairs; the asset keypair is generated when missing
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let asset = match asset_keypair_bs58.as_ref().map(decode_keypair).transpose() {
+        Ok(keypair) => keypair.unwrap_or_else(Keypair::new),
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Signs for the collection's update authority, when it is not the payer
+    let authority = match authority_keypair_bs58.as_ref().map(decode_keypair).transpose() {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let owner = match owner_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection = match collection_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // An asset in a collection takes the collection's update authority instead of its own
+    let update_authority = match update_authority_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(Some(_)) if collection.is_some() => {
+            let e = BubblegumError::InvalidArgument("an asset in a collection cannot have its own update authority".to_string());
+            return (atoms::error(), e).encode(env);
+        },
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The owner and update authority default to the payer
+    let create_ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection)
+        .authority(authority.as_ref().map(|authority| authority.pubkey()))
+        .payer(payer.pubkey())
+        .owner(owner)
+        .update_authority(update_authority)
+        .data_state(DataState::AccountState)
+        .name(name)
+        .uri(uri)
+        .instruction();
+    
+    let signers = std::iter::once(&asset).chain(authority.iter()).collect();
+    match send_transaction(vec![create_ix], &payer, signers, &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("asset".encode(env), asset.pubkey().to_string().encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn transfer_core_asset(
+    env: Env,
+    args: (CoreTransferRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| transfer_core_asset_impl(env, args))
+}
+
+fn transfer_core_asset_impl(
+    env: Env,
+    args: (CoreTransferRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let CoreTransferRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        asset: asset_str,
+        new_owner: new_owner_str,
+        collection: collection_str,
+        authority_keypair: authority_keypair_bs58,
+    } = request;
+    
+    // Decode the keypairs; the owner or a transfer delegate signs, which defaults to the payer
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let authority = match authority_keypair_bs58.as_ref().map(decode_keypair).transpose() {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let asset = match parse_pubkey(&asset_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let new_owner = match parse_pubkey(&new_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The program checks the collection's plugins too, so an asset in one needs it passed
+    let collection = match collection_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset)
+        .collection(collection)
+        .payer(payer.pubkey())
+        .authority(authority.as_ref().map(|authority| authority.pubkey()))
+        .new_owner(new_owner)
+        .instruction();
+    
+    match send_transaction(vec![transfer_ix], &payer, authority.iter().collect(), &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn burn_core_asset(
+    env: Env,
+    args: (CoreBurnRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| burn_core_asset_impl(env, args))
+}
+
+fn burn_core_asset_impl(
+    env: Env,
+    args: (CoreBurnRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let CoreBurnRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        asset: asset_str,
+        collection: collection_str,
+        authority_keypair: authority_keypair_bs58,
+    } = request;
+    
+    // Decode the keypairs; the owner or a burn delegate signs, which defaults to the payer
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let authority = match authority_keypair_bs58.as_ref().map(decode_keypair).transpose() {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let asset = match parse_pubkey(&asset_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection = match collection_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset)
+        .collection(collection)
+        .payer(payer.pubkey())
+        .authority(authority.as_ref().map(|authority| authority.pubkey()))
+        .instruction();
+    
+    match send_transaction(vec![burn_ix], &payer, authority.iter().collect(), &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
@@ -0,0 +1,625 @@
+use rustler::{Encoder, Env, NifStruct, NifUnitEnum, Term};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+use std::time::Instant;
+
+use crate::{
+    atoms, parse_hash, parse_pubkey,
+    retry::{self, RetryPolicyNif},
+    rpc::RpcEndpoint,
+    telemetry::{self, ErrorClass, TelemetryEvent, TelemetryEventNif},
+    AssetProof, BubblegumError, CreatorNif,
+};
+
+// DAS caps every page at 1000 items
+const MAX_PAGE_LIMIT: u32 = 1000;
+
+// Most assets a query with `all` collects unless the caller allows more
+const DEFAULT_MAX_ITEMS: u32 = 100_000;
+
+#[derive(Default, Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.AssetMetadata"]
+pub struct AssetMetadataNif {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.AssetContent"]
+pub struct AssetContentNif {
+    #[serde(default)]
+    pub json_uri: String,
+    #[serde(default)]
+    pub metadata: AssetMetadataNif,
+}
+
+#[derive(Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.AssetCompression"]
+pub struct AssetCompressionNif {
+    pub eligible: bool,
+    pub compressed: bool,
+    pub data_hash: String,
+    pub creator_hash: String,
+    pub asset_hash: String,
+    pub tree: String,
+    pub seq: u64,
+    pub leaf_id: u64,
+}
+
+#[derive(Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.AssetOwnership"]
+pub struct AssetOwnershipNif {
+    pub owner: String,
+    pub delegate: Option<String>,
+    pub delegated: bool,
+    pub frozen: bool,
+    pub ownership_model: String,
+}
+
+#[derive(Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.AssetGrouping"]
+pub struct AssetGroupingNif {
+    pub group_key: String,
+    pub group_value: String,
+}
+
+#[derive(Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.Asset"]
+pub struct AssetNif {
+    pub id: String,
+    pub interface: String,
+    pub content: AssetContentNif,
+    pub compression: AssetCompressionNif,
+    pub ownership: AssetOwnershipNif,
+    #[serde(default)]
+    pub grouping: Vec<AssetGroupingNif>,
+    pub mutable: bool,
+    pub burnt: bool,
+}
+
+#[derive(Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.DasAssetProof"]
+pub struct DasAssetProofNif {
+    pub root: String,
+    pub proof: Vec<String>,
+    pub node_index: u64,
+    pub leaf: String,
+    pub tree_id: String,
+}
+
+#[derive(Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.AssetList"]
+pub struct AssetListNif {
+    pub total: u32,
+    pub limit: u32,
+    #[serde(default)]
+    pub page: Option<u32>,
+    #[serde(default)]
+    pub cursor: Option<String>,
+    pub items: Vec<AssetNif>,
+}
+
+// Whether an asset belongs to the expected owner, with the ownership record it was decided on
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.OwnershipCheck"]
+pub struct OwnershipCheckNif {
+    pub asset_id: String,
+    pub owned: bool,
+    pub burnt: bool,
+    pub ownership: AssetOwnershipNif,
+}
+
+// The royalty and creator fields of a `getAsset` result, which AssetNif leaves out
+#[derive(Deserialize)]
+struct DasRoyaltyAsset {
+    id: String,
+    royalty: DasRoyalty,
+    #[serde(default)]
+    creators: Vec<DasCreator>,
+}
+
+#[derive(Deserialize)]
+struct DasRoyalty {
+    royalty_model: String,
+    basis_points: u16,
+    primary_sale_happened: bool,
+    #[serde(default)]
+    locked: bool,
+}
+
+#[derive(Deserialize)]
+struct DasCreator {
+    address: String,
+    share: u8,
+    verified: bool,
+}
+
+// What a sale of an asset owes its creators
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.RoyaltyInfo"]
+pub struct RoyaltyInfoNif {
+    pub asset_id: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    // "creators", "fanout" or "single"
+    pub royalty_model: String,
+    pub locked: bool,
+    pub creators: Vec<CreatorNif>,
+}
+
+#[derive(NifUnitEnum)]
+pub enum SortByNif {
+    Created,
+    Updated,
+    RecentAction,
+    None,
+}
+
+#[derive(NifUnitEnum)]
+pub enum SortDirectionNif {
+    Asc,
+    Desc,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.PageOptions"]
+pub struct PageOptionsNif {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+    pub all: bool,
+    // Most assets `all` may collect before the query fails instead of returning a partial list
+    pub max_items: Option<u32>,
+    pub sort_by: Option<SortByNif>,
+    pub sort_direction: Option<SortDirectionNif>,
+}
+
+impl SortByNif {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortByNif::Created => "created",
+            SortByNif::Updated => "updated",
+            SortByNif::RecentAction => "recent_action",
+            SortByNif::None => "none",
+        }
+    }
+}
+
+impl SortDirectionNif {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortDirectionNif::Asc => "asc",
+            SortDirectionNif::Desc => "desc",
+        }
+    }
+}
+
+#[derive(NifUnitEnum)]
+pub enum ConditionTypeNif {
+    All,
+    Any,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.SearchAssetsParams"]
+pub struct SearchAssetsParamsNif {
+    pub condition_type: Option<ConditionTypeNif>,
+    pub interface: Option<String>,
+    pub owner_address: Option<String>,
+    pub creator_address: Option<String>,
+    pub creator_verified: Option<bool>,
+    pub authority_address: Option<String>,
+    pub grouping: Option<(String, String)>,
+    pub delegate: Option<String>,
+    pub frozen: Option<bool>,
+    pub supply_mint: Option<String>,
+    pub compressed: Option<bool>,
+    pub compressible: Option<bool>,
+    pub royalty_target_type: Option<String>,
+    pub royalty_target: Option<String>,
+    pub burnt: Option<bool>,
+    pub json_uri: Option<String>,
+}
+
+impl SearchAssetsParamsNif {
+    // Only filters that are set are sent, so DAS applies no constraint for the rest
+    fn to_params(&self) -> serde_json::Value {
+        let mut params = json!({});
+        
+        if let Some(condition_type) = &self.condition_type {
+            params["conditionType"] = json!(match condition_type {
+                ConditionTypeNif::All => "all",
+                ConditionTypeNif::Any => "any",
+            });
+        }
+        if let Some(interface) = &self.interface {
+            params["interface"] = json!(interface);
+        }
+        if let Some(owner_address) = &self.owner_address {
+            params["ownerAddress"] = json!(owner_address);
+        }
+        if let Some(creator_address) = &self.creator_address {
+            params["creatorAddress"] = json!(creator_address);
+        }
+        if let Some(creator_verified) = self.creator_verified {
+            params["creatorVerified"] = json!(creator_verified);
+        }
+        if let Some(authority_address) = &self.authority_address {
+            params["authorityAddress"] = json!(authority_address);
+        }
+        if let Some((group_key, group_value)) = &self.grouping {
+            params["grouping"] = json!([group_key, group_value]);
+        }
+        if let Some(delegate) = &self.delegate {
+            params["delegate"] = json!(delegate);
+        }
+        if let Some(frozen) = self.frozen {
+            params["frozen"] = json!(frozen);
+        }
+        if let Some(supply_mint) = &self.supply_mint {
+            params["supplyMint"] = json!(supply_mint);
+        }
+        if let Some(compressed) = self.compressed {
+            params["compressed"] = json!(compressed);
+        }
+        if let Some(compressible) = self.compressible {
+            params["compressible"] = json!(compressible);
+        }
+        if let Some(royalty_target_type) = &self.royalty_target_type {
+            params["royaltyTargetType"] = json!(royalty_target_type);
+        }
+        if let Some(royalty_target) = &self.royalty_target {
+            params["royaltyTarget"] = json!(royalty_target);
+        }
+        if let Some(burnt) = self.burnt {
+            params["burnt"] = json!(burnt);
+        }
+        if let Some(json_uri) = &self.json_uri {
+            params["jsonUri"] = json!(json_uri);
+        }
+        
+        params
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<JsonRpcError>,
+}
+
+// Calls a DAS JSON-RPC method and deserializes its `result`
+pub(crate) fn das_request<T: DeserializeOwned>(
+    rpc_url: &RpcEndpoint,
+    method: &str,
+    params: serde_json::Value,
+    retry: &RetryPolicyNif,
+) -> Result<T, BubblegumError> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": "solana-bubblegum",
+        "method": method,
+        "params": params,
+    });
+    
+    // Errors inside the JSON-RPC response, such as an overloaded indexer, are retried as well
+    let (result, _attempts) = retry.run(|| {
+        let response: JsonRpcResponse<T> = rpc_url.with_failover(|connection| {
+            connection.throttle();
+            
+            let started = Instant::now();
+            let response = connection
+                .http
+                .post(&connection.url)
+                .json(&body)
+                .send()
+                .and_then(|response| response.error_for_status())
+                .map_err(|e| BubblegumError::DasError(e.to_string()))
+                .and_then(|response| {
+                    response
+                        .json()
+                        .map_err(|e| BubblegumError::SerializationError(e.to_string()))
+                });
+            
+            telemetry::emit(TelemetryEvent {
+                event: TelemetryEventNif::Rpc,
+                operation: Some(method.to_string()),
+                endpoint: Some(connection.url.clone()),
+                duration: started.elapsed(),
+                attempts: retry::current_attempt(),
+                error: response.as_ref().err().map(ErrorClass::of),
+            });
+            
+            response
+        })?;
+        
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(BubblegumError::DasError(format!("{} (code {})", error.message, error.code))),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(BubblegumError::DasError(format!("{} returned no result", method))),
+        }
+    })?;
+    
+    Ok(result)
+}
+
+// Runs a paginated asset query. With `all` set, pages of MAX_PAGE_LIMIT items are
+// fetched until a short page comes back and returned as a single list. A query that would
+// go past `max_items` fails, so a returned list is always complete.
+pub(crate) fn fetch_asset_pages(
+    rpc_url: &RpcEndpoint,
+    method: &str,
+    params: serde_json::Value,
+    options: &PageOptionsNif,
+    retry: &RetryPolicyNif,
+) -> Result<AssetListNif, BubblegumError> {
+    let mut params = params;
+    
+    if let Some(sort_by) = &options.sort_by {
+        let sort_direction = options.sort_direction.as_ref().unwrap_or(&SortDirectionNif::Asc);
+        params["sortBy"] = json!({
+            "sortBy": sort_by.as_str(),
+            "sortDirection": sort_direction.as_str(),
+        });
+    }
+    
+    if !options.all {
+        params["limit"] = json!(options.limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT));
+        match &options.cursor {
+            Some(cursor) => params["cursor"] = json!(cursor),
+            None => params["page"] = json!(options.page.unwrap_or(1)),
+        }
+        return das_request(rpc_url, method, params, retry);
+    }
+    
+    let max_items = options.max_items.unwrap_or(DEFAULT_MAX_ITEMS);
+    let mut items = Vec::new();
+    let mut total: u32 = 0;
+    let mut page = 1;
+    loop {
+        params["limit"] = json!(MAX_PAGE_LIMIT);
+        params["page"] = json!(page);
+        
+        let list: AssetListNif = das_request(rpc_url, method, params.clone(), retry)?;
+        let page_len = list.items.len();
+        total = total.saturating_add(list.total);
+        items.extend(list.items);
+        
+        if items.len() > max_items as usize {
+            return Err(BubblegumError::DasError(format!(
+                "{} returned more than {} assets; raise max_items or page through them with a cursor",
+                method, max_items
+            )));
+        }
+        if page_len < MAX_PAGE_LIMIT as usize {
+            break;
+        }
+        page += 1;
+    }
+    
+    // The totals DAS reported for each page, and the page size the list was fetched with
+    Ok(AssetListNif {
+        total,
+        limit: MAX_PAGE_LIMIT,
+        page: None,
+        cursor: None,
+        items,
+    })
+}
+
+// Everything a proof-requiring instruction needs about a leaf, fetched from DAS
+#[derive(Clone)]
+pub(crate) struct DasLeaf {
+    pub tree: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub asset_proof: AssetProof,
+}
+
+pub(crate) fn fetch_leaf(
+    rpc_url: &RpcEndpoint,
+    asset_id: &str,
+    retry: &RetryPolicyNif,
+) -> Result<DasLeaf, BubblegumError> {
+    let asset: AssetNif = das_request(rpc_url, "getAsset", json!({ "id": asset_id }), retry)?;
+    let proof: DasAssetProofNif = das_request(rpc_url, "getAssetProof", json!({ "id": asset_id }), retry)?;
+    
+    let owner = parse_pubkey(&asset.ownership.owner)?;
+    let delegate = match &asset.ownership.delegate {
+        Some(delegate) => parse_pubkey(delegate)?,
+        None => owner,
+    };
+    
+    // node_index counts from the root, so the leaf index is its offset from the first leaf. A
+    // malformed response must not overflow the shift or the subtraction
+    let index = u32::try_from(proof.proof.len())
+        .ok()
+        .and_then(|depth| 1u64.checked_shl(depth))
+        .and_then(|first_leaf| proof.node_index.checked_sub(first_leaf))
+        .and_then(|index| u32::try_from(index).ok())
+        .ok_or_else(|| {
+            BubblegumError::SerializationError(format!(
+                "node_index {} does not match a proof of {} nodes",
+                proof.node_index,
+                proof.proof.len()
+            ))
+        })?;
+    
+    let proof_accounts = proof
+        .proof
+        .iter()
+        .map(|node| parse_pubkey(node).map(|pubkey| AccountMeta::new_readonly(pubkey, false)))
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(DasLeaf {
+        tree: parse_pubkey(&proof.tree_id)?,
+        owner,
+        delegate,
+        asset_proof: AssetProof {
+            root: parse_hash(&proof.root)?,
+            data_hash: parse_hash(asset.compression.data_hash.trim())?,
+            creator_hash: parse_hash(asset.compression.creator_hash.trim())?,
+            nonce: asset.compression.leaf_id,
+            index,
+            proof: proof_accounts,
+        },
+    })
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_asset(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (asset_id, retry, rpc_url) = args;
+    
+    match das_request::<AssetNif>(&rpc_url, "getAsset", json!({ "id": asset_id }), &retry) {
+        Ok(asset) => (atoms::ok(), asset).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_asset_proof(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (asset_id, retry, rpc_url) = args;
+    
+    match das_request::<DasAssetProofNif>(&rpc_url, "getAssetProof", json!({ "id": asset_id }), &retry) {
+        Ok(proof) => (atoms::ok(), proof).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_assets_by_owner(
+    env: Env,
+    args: (String, PageOptionsNif, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (owner, page_options, retry, rpc_url) = args;
+    
+    let params = json!({ "ownerAddress": owner });
+    
+    match fetch_asset_pages(&rpc_url, "getAssetsByOwner", params, &page_options, &retry) {
+        Ok(assets) => (atoms::ok(), assets).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_assets_by_group(
+    env: Env,
+    args: (String, String, PageOptionsNif, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (group_key, group_value, page_options, retry, rpc_url) = args;
+    
+    let params = json!({ "groupKey": group_key, "groupValue": group_value });
+    
+    match fetch_asset_pages(&rpc_url, "getAssetsByGroup", params, &page_options, &retry) {
+        Ok(assets) => (atoms::ok(), assets).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_assets_by_creator(
+    env: Env,
+    args: (String, bool, PageOptionsNif, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (creator, only_verified, page_options, retry, rpc_url) = args;
+    
+    let params = json!({ "creatorAddress": creator, "onlyVerified": only_verified });
+    
+    match fetch_asset_pages(&rpc_url, "getAssetsByCreator", params, &page_options, &retry) {
+        Ok(assets) => (atoms::ok(), assets).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn search_assets(
+    env: Env,
+    args: (SearchAssetsParamsNif, PageOptionsNif, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (search_params, page_options, retry, rpc_url) = args;
+    
+    match fetch_asset_pages(&rpc_url, "searchAssets", search_params.to_params(), &page_options, &retry) {
+        Ok(assets) => (atoms::ok(), assets).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_royalty_info(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (asset_id, retry, rpc_url) = args;
+    
+    let asset = match das_request::<DasRoyaltyAsset>(&rpc_url, "getAsset", json!({ "id": asset_id }), &retry) {
+        Ok(asset) => asset,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let royalty_info = RoyaltyInfoNif {
+        asset_id: asset.id,
+        seller_fee_basis_points: asset.royalty.basis_points,
+        primary_sale_happened: asset.royalty.primary_sale_happened,
+        royalty_model: asset.royalty.royalty_model,
+        locked: asset.royalty.locked,
+        creators: asset
+            .creators
+            .into_iter()
+            .map(|creator| CreatorNif {
+                address: creator.address,
+                verified: creator.verified,
+                share: creator.share,
+            })
+            .collect(),
+    };
+    
+    (atoms::ok(), royalty_info).encode(env)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn verify_ownership(
+    env: Env,
+    args: (String, String, bool, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (asset_id, expected_owner_str, require_active, retry, rpc_url) = args;
+    
+    let expected_owner = match parse_pubkey(&expected_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let asset = match das_request::<AssetNif>(&rpc_url, "getAsset", json!({ "id": asset_id }), &retry) {
+        Ok(asset) => asset,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // DAS keeps reporting the last owner of a burnt asset, so a burn only counts when asked to
+    let owner_matches = parse_pubkey(&asset.ownership.owner).is_ok_and(|owner| owner == expected_owner);
+    let active = !asset.burnt && !asset.ownership.frozen;
+    
+    let check = OwnershipCheckNif {
+        owned: owner_matches && (active || !require_active),
+        burnt: asset.burnt,
+        asset_id: asset.id,
+        ownership: asset.ownership,
+    };
+    
+    (atoms::ok(), check).encode(env)
+}
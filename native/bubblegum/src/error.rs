@@ -0,0 +1,173 @@
+use rustler::{Atom, Encoder, Env, NifStruct, NifUnitEnum, Term};
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_request::{RpcError, RpcResponseErrorData},
+};
+use solana_sdk::{instruction::InstructionError, message::VersionedMessage, transaction::TransactionError};
+
+use crate::{program_error, BubblegumError};
+
+#[derive(NifUnitEnum)]
+pub enum ErrorKindNif {
+    InvalidPublicKey,
+    InvalidKeypair,
+    InvalidSignature,
+    InvalidHash,
+    InvalidTreeConfig,
+    InvalidArgument,
+    InvalidMetadata,
+    RpcError,
+    TransactionError,
+    SerializationError,
+    DasError,
+    PriorityFeeError,
+    UploadError,
+    Timeout,
+    Cancelled,
+    TimeoutUnknownOutcome,
+    CancelledUnknownOutcome,
+}
+
+// What every NIF returns as the reason of an error
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.Error"]
+pub struct ErrorNif {
+    pub kind: ErrorKindNif,
+    pub message: String,
+    pub program_error_code: Option<u32>,
+    pub program_error: Option<Atom>,
+    pub program_id: Option<String>,
+    pub instruction_index: Option<u8>,
+    pub logs: Vec<String>,
+    pub violations: Vec<(Atom, String)>,
+}
+
+impl BubblegumError {
+    // Keeps the failing instruction, its custom program error and the preflight logs, which
+    // the client error only mentions in its message
+    pub(crate) fn from_client_error(error: ClientError) -> Self {
+        let logs = match error.kind() {
+            ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                data: RpcResponseErrorData::SendTransactionPreflightFailure(simulation),
+                ..
+            }) => simulation.logs.clone().unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        
+        match error.get_transaction_error() {
+            Some(transaction_error) => Self::from_transaction_error(&transaction_error, error.to_string(), logs),
+            None => BubblegumError::TransactionError(error.to_string()),
+        }
+    }
+    
+    pub(crate) fn from_transaction_error(error: &TransactionError, message: String, logs: Vec<String>) -> Self {
+        let (instruction_index, program_error_code) = match error {
+            TransactionError::InstructionError(index, InstructionError::Custom(code)) => (Some(*index), Some(*code)),
+            TransactionError::InstructionError(index, _) => (Some(*index), None),
+            _ => (None, None),
+        };
+        
+        BubblegumError::TransactionFailed {
+            message,
+            instruction_index,
+            program_id: program_error::failing_program(&logs),
+            program_error_code,
+            logs,
+        }
+    }
+    
+    // Without logs naming the failing program, it is taken to be the program of the failing
+    // instruction of the transaction that was sent
+    pub(crate) fn with_program_id(self, message: &VersionedMessage) -> Self {
+        match self {
+            BubblegumError::TransactionFailed {
+                message: error_message,
+                instruction_index: Some(index),
+                program_id: None,
+                program_error_code,
+                logs,
+            } => BubblegumError::TransactionFailed {
+                program_id: message
+                    .instructions()
+                    .get(index as usize)
+                    .and_then(|instruction| message.static_account_keys().get(instruction.program_id_index as usize))
+                    .copied(),
+                message: error_message,
+                instruction_index: Some(index),
+                program_error_code,
+                logs,
+            },
+            error => error,
+        }
+    }
+    
+    pub(crate) fn kind(&self) -> ErrorKindNif {
+        match self {
+            BubblegumError::InvalidPublicKey(_) => ErrorKindNif::InvalidPublicKey,
+            BubblegumError::InvalidKeypair(_) => ErrorKindNif::InvalidKeypair,
+            BubblegumError::InvalidSignature(_) => ErrorKindNif::InvalidSignature,
+            BubblegumError::InvalidHash(_) => ErrorKindNif::InvalidHash,
+            BubblegumError::InvalidTreeConfig(_) => ErrorKindNif::InvalidTreeConfig,
+            BubblegumError::InvalidArgument(_) => ErrorKindNif::InvalidArgument,
+            BubblegumError::InvalidMetadata(_) => ErrorKindNif::InvalidMetadata,
+            BubblegumError::SolanaClientError(_) => ErrorKindNif::RpcError,
+            BubblegumError::TransactionError(_) | BubblegumError::TransactionFailed { .. } => {
+                ErrorKindNif::TransactionError
+            },
+            BubblegumError::SerializationError(_) => ErrorKindNif::SerializationError,
+            BubblegumError::DasError(_) => ErrorKindNif::DasError,
+            BubblegumError::PriorityFeeError(_) => ErrorKindNif::PriorityFeeError,
+            BubblegumError::UploadError(_) => ErrorKindNif::UploadError,
+            BubblegumError::Timeout(_) => ErrorKindNif::Timeout,
+            BubblegumError::Cancelled => ErrorKindNif::Cancelled,
+            BubblegumError::TimeoutUnknownOutcome(_) => ErrorKindNif::TimeoutUnknownOutcome,
+            BubblegumError::CancelledUnknownOutcome => ErrorKindNif::CancelledUnknownOutcome,
+        }
+    }
+    
+    pub(crate) fn to_nif(&self, env: Env) -> ErrorNif {
+        let (program_error_code, program_id, instruction_index, logs) = match self {
+            BubblegumError::TransactionFailed {
+                program_error_code,
+                program_id,
+                instruction_index,
+                logs,
+                ..
+            } => (*program_error_code, *program_id, *instruction_index, logs.clone()),
+            _ => (None, None, None, Vec::new()),
+        };
+        
+        // Bubblegum and spl-account-compression errors are named, other programs' are left as codes
+        let program_error = match (program_id, program_error_code) {
+            (Some(program_id), Some(code)) => program_error::decode(&program_id, code, &logs)
+                .and_then(|name| Atom::from_str(env, name).ok()),
+            _ => None,
+        };
+        
+        // Fields of invalid metadata become atoms, each with the problem found with it
+        let violations = match self {
+            BubblegumError::InvalidMetadata(violations) => violations
+                .iter()
+                .filter_map(|(field, problem)| Atom::from_str(env, field).ok().map(|field| (field, problem.clone())))
+                .collect(),
+            _ => Vec::new(),
+        };
+        
+        ErrorNif {
+            kind: self.kind(),
+            message: self.to_string(),
+            program_error_code,
+            program_error,
+            program_id: program_id.map(|program_id| program_id.to_string()),
+            instruction_index,
+            logs,
+            violations,
+        }
+    }
+}
+
+impl Encoder for BubblegumError {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        self.to_nif(env).encode(env)
+    }
+}
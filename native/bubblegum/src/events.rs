@@ -0,0 +1,343 @@
+use borsh::BorshDeserialize;
+use rustler::{Encoder, Env, NifStruct, Term};
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::{commitment_config::CommitmentConfig, message::VersionedMessage, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{
+    UiInnerInstructions, UiInstruction, UiLoadedAddresses, UiTransactionEncoding, UiTransactionStatusMeta,
+};
+use std::str::FromStr;
+
+use crate::{
+    atoms, parse_pubkey,
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+    transaction::{TransactionOptionsNif, TransactionOutcome},
+    BubblegumError, SPL_NOOP_PROGRAM_ID,
+};
+
+// What spl-account-compression and Bubblegum log through the noop program, mirrored here
+// so they can be decoded without depending on spl-account-compression
+#[derive(BorshDeserialize)]
+enum AccountCompressionEvent {
+    ChangeLog(ChangeLogEvent),
+    ApplicationData(ApplicationDataEvent),
+}
+
+#[derive(BorshDeserialize)]
+enum ChangeLogEvent {
+    V1(ChangeLogEventV1),
+}
+
+#[derive(BorshDeserialize)]
+struct ChangeLogEventV1 {
+    id: Pubkey,
+    // Nodes from the changed leaf up to the new root, which comes last
+    path: Vec<PathNode>,
+    seq: u64,
+    index: u32,
+}
+
+#[derive(BorshDeserialize)]
+struct PathNode {
+    node: [u8; 32],
+    _index: u32,
+}
+
+#[derive(BorshDeserialize)]
+enum ApplicationDataEvent {
+    V1(ApplicationDataEventV1),
+}
+
+#[derive(BorshDeserialize)]
+struct ApplicationDataEventV1 {
+    application_data: Vec<u8>,
+}
+
+// The application data Bubblegum logs for every leaf it writes
+#[derive(BorshDeserialize)]
+struct LeafSchemaEvent {
+    event_type: BubblegumEventType,
+    // Only V1 exists
+    _version: u8,
+    schema: LeafSchema,
+    leaf_hash: [u8; 32],
+}
+
+#[derive(BorshDeserialize, PartialEq)]
+enum BubblegumEventType {
+    Uninitialized,
+    LeafSchemaEvent,
+}
+
+#[derive(BorshDeserialize)]
+enum LeafSchema {
+    V1 {
+        id: Pubkey,
+        owner: Pubkey,
+        delegate: Pubkey,
+        nonce: u64,
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+    },
+}
+
+// A leaf written by Bubblegum: the asset it holds and the hashes it was built from
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.LeafSchemaEvent"]
+pub struct LeafSchemaEventNif {
+    pub asset_id: String,
+    pub owner: String,
+    pub delegate: String,
+    pub nonce: u64,
+    pub data_hash: String,
+    pub creator_hash: String,
+    pub leaf_hash: String,
+}
+
+// A change to a Merkle tree: the leaf it touched and the root it left the tree with
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.ChangeLogEvent"]
+pub struct ChangeLogEventNif {
+    pub merkle_tree: String,
+    pub leaf_index: u32,
+    pub sequence_number: u64,
+    pub root: String,
+}
+
+// The compression events of one transaction, in the order they were logged
+pub(crate) struct TransactionEvents {
+    pub slot: u64,
+    pub leaf_schema_events: Vec<LeafSchemaEventNif>,
+    pub change_log_events: Vec<ChangeLogEventNif>,
+}
+
+impl TransactionEvents {
+    // Decodes the data of one noop instruction; data logged by other programs is skipped
+    fn push(&mut self, data: &[u8]) {
+        match AccountCompressionEvent::try_from_slice(data) {
+            Ok(AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(event))) => {
+                self.change_log_events.push(ChangeLogEventNif {
+                    merkle_tree: event.id.to_string(),
+                    leaf_index: event.index,
+                    sequence_number: event.seq,
+                    root: event
+                        .path
+                        .last()
+                        .map(|root| bs58::encode(root.node).into_string())
+                        .unwrap_or_default(),
+                });
+            },
+            Ok(AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1(event))) => {
+                let leaf_event = match LeafSchemaEvent::try_from_slice(&event.application_data) {
+                    Ok(leaf_event) if leaf_event.event_type == BubblegumEventType::LeafSchemaEvent => leaf_event,
+                    _ => return,
+                };
+                
+                let LeafSchema::V1 { id, owner, delegate, nonce, data_hash, creator_hash } = leaf_event.schema;
+                self.leaf_schema_events.push(LeafSchemaEventNif {
+                    asset_id: id.to_string(),
+                    owner: owner.to_string(),
+                    delegate: delegate.to_string(),
+                    nonce,
+                    data_hash: bs58::encode(data_hash).into_string(),
+                    creator_hash: bs58::encode(creator_hash).into_string(),
+                    leaf_hash: bs58::encode(leaf_event.leaf_hash).into_string(),
+                });
+            },
+            Err(_) => {},
+        }
+    }
+}
+
+// A confirmed transaction with the account keys its instructions index into
+pub(crate) struct FetchedTransaction {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub message: VersionedMessage,
+    pub meta: UiTransactionStatusMeta,
+    // The static keys followed by the keys loaded from lookup tables
+    pub account_keys: Vec<Pubkey>,
+}
+
+impl FetchedTransaction {
+    pub(crate) fn inner_instructions(&self) -> Vec<UiInnerInstructions> {
+        let inner_instructions: Option<Vec<UiInnerInstructions>> = self.meta.inner_instructions.clone().into();
+        inner_instructions.unwrap_or_default()
+    }
+    
+    // Decodes the events the inner noop instructions carry
+    pub(crate) fn events(&self) -> TransactionEvents {
+        let mut events = TransactionEvents {
+            slot: self.slot,
+            leaf_schema_events: Vec::new(),
+            change_log_events: Vec::new(),
+        };
+        
+        let inner_instructions = self.inner_instructions();
+        for instruction in inner_instructions.iter().flat_map(|inner| inner.instructions.iter()) {
+            let compiled = match instruction {
+                UiInstruction::Compiled(compiled) => compiled,
+                UiInstruction::Parsed(_) => continue,
+            };
+            
+            if self.account_keys.get(compiled.program_id_index as usize) != Some(&SPL_NOOP_PROGRAM_ID) {
+                continue;
+            }
+            
+            if let Ok(data) = bs58::decode(&compiled.data).into_vec() {
+                events.push(&data);
+            }
+        }
+        
+        events
+    }
+}
+
+// Fetches a confirmed transaction
+pub(crate) fn fetch_transaction(
+    rpc_url: &RpcEndpoint,
+    retry: &RetryPolicyNif,
+    signature: &Signature,
+) -> Result<FetchedTransaction, BubblegumError> {
+    let (transaction, _attempts) = retry.run(|| {
+        rpc_url.with_failover(|connection| {
+            // Transactions can only be fetched once confirmed
+            let commitment = match connection.client.commitment() {
+                commitment if commitment.is_at_least_confirmed() => commitment,
+                _ => CommitmentConfig::confirmed(),
+            };
+            let config = RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(commitment),
+                max_supported_transaction_version: Some(0),
+            };
+            
+            connection
+                .client
+                .get_transaction_with_config(signature, config)
+                .map_err(BubblegumError::from_client_error)
+        })
+    })?;
+    
+    let versioned = transaction
+        .transaction
+        .transaction
+        .decode()
+        .ok_or_else(|| BubblegumError::SerializationError(format!("transaction {} did not decode", signature)))?;
+    let meta = transaction
+        .transaction
+        .meta
+        .ok_or_else(|| BubblegumError::SerializationError(format!("transaction {} has no status meta", signature)))?;
+    
+    // Inner instructions index into the static keys followed by the keys loaded from lookup tables
+    let mut account_keys = versioned.message.static_account_keys().to_vec();
+    let loaded_addresses: Option<UiLoadedAddresses> = meta.loaded_addresses.clone().into();
+    if let Some(loaded) = loaded_addresses {
+        for address in loaded.writable.iter().chain(loaded.readonly.iter()) {
+            account_keys.push(parse_pubkey(address)?);
+        }
+    }
+    
+    Ok(FetchedTransaction {
+        slot: transaction.slot,
+        block_time: transaction.block_time,
+        message: versioned.message,
+        meta,
+        account_keys,
+    })
+}
+
+// Fetches a confirmed transaction and decodes the events its inner noop instructions carry
+pub(crate) fn fetch_transaction_events(
+    rpc_url: &RpcEndpoint,
+    retry: &RetryPolicyNif,
+    signature: &Signature,
+) -> Result<TransactionEvents, BubblegumError> {
+    fetch_transaction(rpc_url, retry, signature).map(|transaction| transaction.events())
+}
+
+// Adds the asset ID, leaf index and nonce of a confirmed mint to its success map, read from the
+// events of the mint transaction. They are nil when the mint was only simulated, not confirmed,
+// or its events could not be fetched; the mint itself still succeeded
+pub(crate) fn put_minted_leaf<'a>(
+    env: Env<'a>,
+    map: Term<'a>,
+    tree: &Pubkey,
+    outcome: &TransactionOutcome,
+    tx_options: &TransactionOptionsNif,
+) -> Term<'a> {
+    let minted = match outcome {
+        TransactionOutcome::Sent(signature) if tx_options.send_options.confirm => {
+            fetch_transaction_events(&tx_options.rpc_url, &tx_options.retry, signature)
+                .ok()
+                .and_then(|events| minted_leaf(tree, &events))
+        },
+        _ => None,
+    };
+    
+    let (asset_id, leaf_index, nonce) = match minted {
+        Some((asset_id, leaf_index, nonce)) => (Some(asset_id), Some(leaf_index), Some(nonce)),
+        None => (None, None, None),
+    };
+    
+    let map = map.map_put("asset_id".encode(env), asset_id.encode(env)).unwrap();
+    let map = map.map_put("leaf_index".encode(env), leaf_index.encode(env)).unwrap();
+    map.map_put("nonce".encode(env), nonce.encode(env)).unwrap()
+}
+
+// Asset IDs of every leaf a transaction minted to the tree, in the order its mints ran
+pub(crate) fn minted_asset_ids(tree: &Pubkey, events: &TransactionEvents) -> Vec<String> {
+    if !events.leaf_schema_events.is_empty() {
+        return events.leaf_schema_events.iter().map(|leaf| leaf.asset_id.clone()).collect();
+    }
+    
+    let tree_str = tree.to_string();
+    events
+        .change_log_events
+        .iter()
+        .filter(|event| event.merkle_tree == tree_str)
+        .map(|event| mpl_bubblegum::utils::get_asset_id(tree, event.leaf_index as u64).to_string())
+        .collect()
+}
+
+// The leaf a mint appended to the tree. The change log gives the leaf index; the asset ID and
+// nonce come from the leaf schema, or are derived from the index when it was not logged
+fn minted_leaf(tree: &Pubkey, events: &TransactionEvents) -> Option<(String, u32, u64)> {
+    let tree_str = tree.to_string();
+    let change_log = events.change_log_events.iter().find(|event| event.merkle_tree == tree_str)?;
+    
+    match events.leaf_schema_events.first() {
+        Some(leaf) => Some((leaf.asset_id.clone(), change_log.leaf_index, leaf.nonce)),
+        None => {
+            let nonce = change_log.leaf_index as u64;
+            Some((mpl_bubblegum::utils::get_asset_id(tree, nonce).to_string(), change_log.leaf_index, nonce))
+        },
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn parse_transaction_events(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (signature_str, retry, rpc_url) = args;
+    
+    let signature = match Signature::from_str(&signature_str) {
+        Ok(signature) => signature,
+        Err(e) => return (atoms::error(), BubblegumError::InvalidSignature(e.to_string())).encode(env),
+    };
+    
+    let events = match fetch_transaction_events(&rpc_url, &retry, &signature) {
+        Ok(events) => events,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("signature".encode(env), signature_str.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("slot".encode(env), events.slot.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("leaf_schema_events".encode(env), events.leaf_schema_events.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("change_log_events".encode(env), events.change_log_events.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
@@ -0,0 +1,361 @@
+use rustler::{Binary, Encoder, Env, NifTaggedEnum, Term};
+use serde::Deserialize;
+use serde_json::json;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction,
+    message::VersionedMessage,
+    pubkey::Pubkey,
+    system_instruction::SystemInstruction,
+    transaction::VersionedTransaction,
+};
+
+use crate::{
+    atoms,
+    das::das_request,
+    history, parse_pubkey,
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+    transaction::{self, TransactionOptionsNif},
+    BubblegumError,
+};
+
+// Percentile of recent fees used when the caller does not pick one
+const DEFAULT_PERCENTILE: u8 = 75;
+
+// getRecentPrioritizationFees accepts at most this many accounts
+const MAX_FEE_ACCOUNTS: usize = 128;
+
+// Tags of the compute budget instructions that set the limit and the price
+const SET_COMPUTE_UNIT_LIMIT_TAG: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE_TAG: u8 = 3;
+
+// Sizes of the accounts Bubblegum creates: the tree config of create_tree_config, and the mint,
+// token account, metadata and master edition of decompress_v1
+const TREE_CONFIG_ACCOUNT_SIZE: usize = 96;
+const MINT_ACCOUNT_SIZE: usize = 82;
+const TOKEN_ACCOUNT_SIZE: usize = 165;
+const METADATA_ACCOUNT_SIZE: usize = 679;
+const MASTER_EDITION_ACCOUNT_SIZE: usize = 282;
+
+#[derive(NifTaggedEnum)]
+pub enum PriorityFeeNif {
+    Auto,
+    MicroLamports(u64),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HeliusPriorityFeeEstimate {
+    priority_fee_estimate: f64,
+}
+
+// Writable accounts are the ones whose fee markets matter for a transaction
+fn writable_accounts(instructions: &[Instruction]) -> Vec<Pubkey> {
+    let mut accounts: Vec<Pubkey> = Vec::new();
+    for account in instructions.iter().flat_map(|instruction| &instruction.accounts) {
+        if account.is_writable && !accounts.contains(&account.pubkey) {
+            accounts.push(account.pubkey);
+        }
+    }
+    accounts.truncate(MAX_FEE_ACCOUNTS);
+    accounts
+}
+
+// Nearest-rank percentile of the fees; no fees at all means no competition, so zero
+fn percentile(mut fees: Vec<u64>, percentile: u8) -> u64 {
+    if fees.is_empty() {
+        return 0;
+    }
+    fees.sort_unstable();
+    let rank = (fees.len() * percentile.min(100) as usize).div_ceil(100);
+    fees[rank.saturating_sub(1)]
+}
+
+// Helius names its estimates after priority levels rather than percentiles
+fn helius_priority_level(percentile: u8) -> &'static str {
+    match percentile {
+        0 => "Min",
+        1..=25 => "Low",
+        26..=50 => "Medium",
+        51..=75 => "High",
+        76..=99 => "VeryHigh",
+        _ => "UnsafeMax",
+    }
+}
+
+pub(crate) fn estimate_priority_fee(
+    client: &RpcClient,
+    accounts: &[Pubkey],
+    percentile_value: Option<u8>,
+    helius_url: Option<&str>,
+) -> Result<u64, BubblegumError> {
+    let percentile_value = percentile_value.unwrap_or(DEFAULT_PERCENTILE);
+    
+    if let Some(helius_url) = helius_url {
+        let account_keys: Vec<String> = accounts.iter().map(|pubkey| pubkey.to_string()).collect();
+        let estimate: HeliusPriorityFeeEstimate = das_request(
+            &RpcEndpoint::Url(helius_url.to_string()),
+            "getPriorityFeeEstimate",
+            json!([{
+                "accountKeys": account_keys,
+                "options": { "priorityLevel": helius_priority_level(percentile_value) },
+            }]),
+            &RetryPolicyNif::single_attempt(),
+        )
+        .map_err(|e| BubblegumError::PriorityFeeError(e.to_string()))?;
+        
+        return Ok(estimate.priority_fee_estimate.ceil() as u64);
+    }
+    
+    let fees = client
+        .get_recent_prioritization_fees(accounts)
+        .map_err(|e| BubblegumError::PriorityFeeError(e.to_string()))?;
+    
+    Ok(percentile(fees.iter().map(|fee| fee.prioritization_fee).collect(), percentile_value))
+}
+
+// Compute unit price to attach to a transaction: an explicit `priority_fee` wins over
+// `compute_unit_price_micro_lamports`, and `:auto` asks the cluster for an estimate
+pub(crate) fn compute_unit_price(
+    client: &RpcClient,
+    tx_options: &TransactionOptionsNif,
+    instructions: &[Instruction],
+) -> Result<Option<u64>, BubblegumError> {
+    match &tx_options.priority_fee {
+        Some(PriorityFeeNif::MicroLamports(micro_lamports)) => Ok(Some(*micro_lamports)),
+        Some(PriorityFeeNif::Auto) => estimate_priority_fee(
+            client,
+            &writable_accounts(instructions),
+            tx_options.priority_fee_percentile,
+            tx_options.priority_fee_url.as_deref(),
+        )
+        .map(Some),
+        None => Ok(tx_options.compute_unit_price_micro_lamports),
+    }
+}
+
+// The compute unit limit and price a message sets with compute budget instructions
+fn message_compute_budget(message: &VersionedMessage) -> (Option<u32>, Option<u64>) {
+    let account_keys = message.static_account_keys();
+    let mut limit = None;
+    let mut price = None;
+    
+    for instruction in message.instructions() {
+        if account_keys.get(instruction.program_id_index as usize) != Some(&solana_sdk::compute_budget::ID) {
+            continue;
+        }
+        match instruction.data.split_first() {
+            Some((&SET_COMPUTE_UNIT_LIMIT_TAG, units)) => {
+                limit = units.try_into().ok().map(u32::from_le_bytes);
+            },
+            Some((&SET_COMPUTE_UNIT_PRICE_TAG, micro_lamports)) => {
+                price = micro_lamports.try_into().ok().map(u64::from_le_bytes);
+            },
+            _ => {},
+        }
+    }
+    
+    (limit, price)
+}
+
+// Lamports a message moves into new accounts: what its system program instructions fund them
+// with, and the sizes of the accounts its Bubblegum instructions create, to be priced as rent
+fn message_rent(message: &VersionedMessage) -> (u64, Vec<usize>) {
+    let account_keys = message.static_account_keys();
+    let mut funded = 0;
+    let mut account_sizes = Vec::new();
+    
+    for instruction in message.instructions() {
+        let program_id = match account_keys.get(instruction.program_id_index as usize) {
+            Some(program_id) => program_id,
+            None => continue,
+        };
+        
+        if *program_id == solana_sdk::system_program::ID {
+            match bincode::deserialize(&instruction.data) {
+                Ok(SystemInstruction::CreateAccount { lamports, .. })
+                | Ok(SystemInstruction::CreateAccountWithSeed { lamports, .. }) => funded += lamports,
+                _ => {},
+            }
+            continue;
+        }
+        
+        match history::instruction_name(program_id, &instruction.data) {
+            Some("create_tree") => account_sizes.push(TREE_CONFIG_ACCOUNT_SIZE),
+            Some("decompress_v1") => account_sizes.extend([
+                MINT_ACCOUNT_SIZE,
+                TOKEN_ACCOUNT_SIZE,
+                METADATA_ACCOUNT_SIZE,
+                MASTER_EDITION_ACCOUNT_SIZE,
+            ]),
+            _ => {},
+        }
+    }
+    
+    (funded, account_sizes)
+}
+
+// Accounts a message may write to, for the fee markets they take part in
+fn writable_message_accounts(message: &VersionedMessage) -> Vec<Pubkey> {
+    let mut accounts: Vec<Pubkey> = message
+        .static_account_keys()
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| message.is_maybe_writable(*index))
+        .map(|(_, pubkey)| *pubkey)
+        .collect();
+    accounts.truncate(MAX_FEE_ACCOUNTS);
+    accounts
+}
+
+// What a transaction is expected to cost its fee payer, in lamports
+struct FeeEstimate {
+    compute_units: u32,
+    compute_unit_price: Option<u64>,
+    base_fee: u64,
+    priority_fee: u64,
+    rent: u64,
+}
+
+impl Encoder for FeeEstimate {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        let map = Term::map_new(env);
+        
+        let map = map.map_put("compute_units".encode(env), self.compute_units.encode(env)).unwrap();
+        let map = map.map_put("compute_unit_price_micro_lamports".encode(env), self.compute_unit_price.encode(env)).unwrap();
+        let map = map.map_put("base_fee_lamports".encode(env), self.base_fee.encode(env)).unwrap();
+        let map = map.map_put("priority_fee_lamports".encode(env), self.priority_fee.encode(env)).unwrap();
+        let map = map.map_put("rent_lamports".encode(env), self.rent.encode(env)).unwrap();
+        let total = self.base_fee + self.priority_fee + self.rent;
+        map.map_put("total_lamports".encode(env), total.encode(env)).unwrap()
+    }
+}
+
+fn estimate_transaction_fee(
+    client: &RpcClient,
+    mut message: VersionedMessage,
+    priority_fee: Option<&PriorityFeeNif>,
+    percentile_value: Option<u8>,
+    helius_url: Option<&str>,
+) -> Result<FeeEstimate, BubblegumError> {
+    let (compute_unit_limit, own_price) = message_compute_budget(&message);
+    let compute_units = compute_unit_limit.unwrap_or_else(|| {
+        let budget_instructions = message
+            .instructions()
+            .iter()
+            .filter(|instruction| {
+                message.static_account_keys().get(instruction.program_id_index as usize)
+                    == Some(&solana_sdk::compute_budget::ID)
+            })
+            .count();
+        transaction::default_compute_units(message.instructions().len() - budget_instructions)
+    });
+    
+    // The fee does not depend on the blockhash, but the cluster only prices messages with a recent one
+    let blockhash = client
+        .get_latest_blockhash()
+        .map_err(BubblegumError::from_client_error)?;
+    message.set_recent_blockhash(blockhash);
+    
+    let fee = match &message {
+        VersionedMessage::Legacy(message) => client.get_fee_for_message(message),
+        VersionedMessage::V0(message) => client.get_fee_for_message(message),
+    }
+    .map_err(BubblegumError::from_client_error)?;
+    
+    // The cluster's fee already includes the priority fee of a price the message sets; only
+    // messages without one get the requested priority fee added
+    let compute_unit_price = match (own_price, priority_fee) {
+        (Some(price), _) => Some(price),
+        (None, Some(PriorityFeeNif::MicroLamports(micro_lamports))) => Some(*micro_lamports),
+        (None, Some(PriorityFeeNif::Auto)) => Some(estimate_priority_fee(
+            client,
+            &writable_message_accounts(&message),
+            percentile_value,
+            helius_url,
+        )?),
+        (None, None) => None,
+    };
+    let priority_fee = compute_unit_price
+        .map(|price| transaction::priority_fee_lamports(price, compute_units))
+        .unwrap_or(0);
+    let base_fee = match own_price {
+        Some(_) => fee.saturating_sub(priority_fee),
+        None => fee,
+    };
+    
+    let (funded, account_sizes) = message_rent(&message);
+    let mut rent = funded;
+    for account_size in account_sizes {
+        rent += client
+            .get_minimum_balance_for_rent_exemption(account_size)
+            .map_err(BubblegumError::from_client_error)?;
+    }
+    
+    Ok(FeeEstimate {
+        compute_units,
+        compute_unit_price,
+        base_fee,
+        priority_fee,
+        rent,
+    })
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn estimate_fee<'a>(
+    env: Env<'a>,
+    args: (Binary<'a>, Option<PriorityFeeNif>, Option<u8>, Option<String>, RetryPolicyNif, RpcEndpoint),
+) -> Term<'a> {
+    let (transaction_bytes, priority_fee, percentile_value, helius_url, retry, rpc_url) = args;
+    
+    let transaction: VersionedTransaction = match bincode::deserialize(transaction_bytes.as_slice()) {
+        Ok(transaction) => transaction,
+        Err(e) => return (atoms::error(), BubblegumError::SerializationError(e.to_string())).encode(env),
+    };
+    
+    let result = retry.run(|| {
+        rpc_url.with_failover(|connection| {
+            estimate_transaction_fee(
+                &connection.client,
+                transaction.message.clone(),
+                priority_fee.as_ref(),
+                percentile_value,
+                helius_url.as_deref(),
+            )
+        })
+    });
+    
+    match result {
+        Ok((estimate, _attempts)) => (atoms::ok(), estimate).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_priority_fee_estimate(
+    env: Env,
+    args: (Vec<String>, Option<u8>, Option<String>, RpcEndpoint),
+) -> Term {
+    let (account_strs, percentile_value, helius_url, rpc_url) = args;
+    
+    // Parse the accounts
+    let accounts = match account_strs.iter().map(|account| parse_pubkey(account)).collect::<Result<Vec<_>, _>>() {
+        Ok(accounts) => accounts,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Connect to Solana
+    let client = rpc_url.client();
+    
+    match estimate_priority_fee(&client, &accounts, percentile_value, helius_url.as_deref()) {
+        Ok(micro_lamports) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("micro_lamports".encode(env), micro_lamports.encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
@@ -0,0 +1,168 @@
+use rustler::{Encoder, Env, Term};
+use solana_sdk::keccak;
+
+use crate::{atoms, convert_creators, convert_metadata_args, parse_hash, parse_pubkey, BubblegumError, CreatorNif, MetadataArgsNif};
+
+// Leaf schema version byte hashed in front of every V1 leaf
+const LEAF_SCHEMA_V1: u8 = 1;
+
+// Hash of a V1 leaf exactly as the Bubblegum program stores it in the tree
+pub(crate) fn leaf_hash(
+    asset_id: &[u8; 32],
+    owner: &[u8; 32],
+    delegate: &[u8; 32],
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    keccak::hashv(&[
+        &[LEAF_SCHEMA_V1],
+        asset_id,
+        owner,
+        delegate,
+        &nonce.to_le_bytes(),
+        data_hash,
+        creator_hash,
+    ])
+    .to_bytes()
+}
+
+// Recomputes the root from a leaf and its proof path the way spl-account-compression
+// does: at each level the index bit decides whether the node is the left or right child
+pub(crate) fn compute_root(leaf: &[u8; 32], proof: &[[u8; 32]], index: u32) -> [u8; 32] {
+    let mut node = *leaf;
+    for (level, sibling) in proof.iter().enumerate() {
+        node = if (index >> level) & 1 == 0 {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        };
+    }
+    node
+}
+
+#[rustler::nif]
+pub fn hash_metadata(
+    env: Env,
+    metadata_args: MetadataArgsNif,
+) -> Term {
+    // Convert the metadata args
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let data_hash = match mpl_bubblegum::hash::hash_metadata(&metadata) {
+        Ok(data_hash) => data_hash,
+        Err(e) => return (atoms::error(), BubblegumError::SerializationError(e.to_string())).encode(env),
+    };
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("data_hash".encode(env), bs58::encode(data_hash).into_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn hash_creators(
+    env: Env,
+    creators: Vec<CreatorNif>,
+) -> Term {
+    // Convert the creators
+    let creators = match convert_creators(&creators) {
+        Ok(creators) => creators,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let creator_hash = mpl_bubblegum::hash::hash_creators(&creators);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("creator_hash".encode(env), bs58::encode(creator_hash).into_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn compute_leaf_hash(
+    env: Env,
+    args: (String, String, String, u64, String, String),
+) -> Term {
+    let (asset_id_str, owner_str, delegate_str, nonce, data_hash_str, creator_hash_str) = args;
+    
+    // Parse the pubkeys
+    let asset_id = match parse_pubkey(&asset_id_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let owner = match parse_pubkey(&owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let delegate = match parse_pubkey(&delegate_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the hashes
+    let data_hash = match parse_hash(&data_hash_str) {
+        Ok(hash) => hash,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let creator_hash = match parse_hash(&creator_hash_str) {
+        Ok(hash) => hash,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_hash = leaf_hash(
+        &asset_id.to_bytes(),
+        &owner.to_bytes(),
+        &delegate.to_bytes(),
+        nonce,
+        &data_hash,
+        &creator_hash,
+    );
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("leaf_hash".encode(env), bs58::encode(leaf_hash).into_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn verify_proof(
+    env: Env,
+    args: (String, String, Vec<String>, u32),
+) -> Term {
+    let (root_str, leaf_str, proof_strs, index) = args;
+    
+    // Parse the hashes
+    let root = match parse_hash(&root_str) {
+        Ok(hash) => hash,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf = match parse_hash(&leaf_str) {
+        Ok(hash) => hash,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let proof = match proof_strs.iter().map(|node| parse_hash(node)).collect::<Result<Vec<_>, _>>() {
+        Ok(proof) => proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let computed_root = compute_root(&leaf, &proof, index);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("valid".encode(env), (computed_root == root).encode(env)).unwrap();
+    let ok_map = ok_map.map_put("computed_root".encode(env), bs58::encode(computed_root).into_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
@@ -0,0 +1,301 @@
+use rustler::{Atom, Encoder, Env, NifStruct, Term};
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_sdk::{commitment_config::CommitmentConfig, hash::hashv, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::UiInstruction;
+use std::str::FromStr;
+
+use crate::{
+    atoms,
+    events::{fetch_transaction, FetchedTransaction},
+    parse_pubkey,
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+    BubblegumError, ASSOCIATED_TOKEN_PROGRAM_ID, SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, SPL_MEMO_PROGRAM_ID,
+    SPL_NOOP_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_METADATA_PROGRAM_ID, TOKEN_PROGRAM_ID,
+};
+
+// The most signatures a single getSignaturesForAddress call returns
+const MAX_SIGNATURES_LIMIT: usize = 1000;
+
+// Instructions of the Bubblegum program, by the method names their Anchor discriminators are
+// derived from; create_tree_config is `create_tree`
+const BUBBLEGUM_INSTRUCTIONS: &[&str] = &[
+    "burn",
+    "cancel_redeem",
+    "compress",
+    "create_tree",
+    "decompress_v1",
+    "delegate",
+    "mint_to_collection_v1",
+    "mint_v1",
+    "redeem",
+    "set_and_verify_collection",
+    "set_decompressible_state",
+    "set_decompressable_state",
+    "set_tree_delegate",
+    "transfer",
+    "unverify_collection",
+    "unverify_creator",
+    "update_metadata",
+    "verify_collection",
+    "verify_creator",
+];
+
+// Instructions of spl-account-compression, which Bubblegum invokes to change its trees
+const ACCOUNT_COMPRESSION_INSTRUCTIONS: &[&str] = &[
+    "init_empty_merkle_tree",
+    "replace_leaf",
+    "transfer_authority",
+    "verify_leaf",
+    "append",
+    "insert_or_append",
+    "close_empty_tree",
+];
+
+// A transaction that touched an address, newest first
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.SignatureInfo"]
+pub struct SignatureInfoNif {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub memo: Option<String>,
+    pub error: Option<String>,
+}
+
+// One instruction of a transaction, with the program and instruction named where they are known
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.InstructionSummary"]
+pub struct InstructionSummaryNif {
+    pub index: u8,
+    pub inner: bool,
+    pub program_id: String,
+    pub program: Option<Atom>,
+    pub instruction: Option<Atom>,
+    pub accounts: Vec<String>,
+    pub data: String,
+}
+
+pub(crate) fn program_name(program_id: &Pubkey) -> Option<&'static str> {
+    match *program_id {
+        id if id == mpl_bubblegum::ID => Some("bubblegum"),
+        id if id == SPL_ACCOUNT_COMPRESSION_PROGRAM_ID => Some("account_compression"),
+        id if id == SPL_NOOP_PROGRAM_ID => Some("noop"),
+        id if id == SPL_MEMO_PROGRAM_ID => Some("memo"),
+        id if id == TOKEN_METADATA_PROGRAM_ID => Some("token_metadata"),
+        id if id == TOKEN_PROGRAM_ID => Some("token"),
+        id if id == TOKEN_2022_PROGRAM_ID => Some("token_2022"),
+        id if id == ASSOCIATED_TOKEN_PROGRAM_ID => Some("associated_token"),
+        id if id == solana_sdk::system_program::ID => Some("system"),
+        id if id == solana_sdk::compute_budget::ID => Some("compute_budget"),
+        _ => None,
+    }
+}
+
+// Anchor prefixes instruction data with the first 8 bytes of the SHA-256 of `global:<method>`
+pub(crate) fn instruction_name(program_id: &Pubkey, data: &[u8]) -> Option<&'static str> {
+    let names = match *program_id {
+        id if id == mpl_bubblegum::ID => BUBBLEGUM_INSTRUCTIONS,
+        id if id == SPL_ACCOUNT_COMPRESSION_PROGRAM_ID => ACCOUNT_COMPRESSION_INSTRUCTIONS,
+        _ => return None,
+    };
+    let discriminator = data.get(..8)?;
+    
+    names
+        .iter()
+        .find(|name| &hashv(&[b"global:", name.as_bytes()]).to_bytes()[..8] == discriminator)
+        .copied()
+}
+
+// Summarizes an instruction whose program and accounts index into `account_keys`
+pub(crate) fn summarize(
+    env: Env,
+    account_keys: &[Pubkey],
+    index: u8,
+    inner: bool,
+    program_id_index: u8,
+    accounts: &[u8],
+    data: &[u8],
+) -> InstructionSummaryNif {
+    let program_id = account_keys
+        .get(program_id_index as usize)
+        .copied()
+        .unwrap_or_default();
+    let atom = |name: Option<&str>| name.and_then(|name| Atom::from_str(env, name).ok());
+    
+    InstructionSummaryNif {
+        index,
+        inner,
+        program_id: program_id.to_string(),
+        program: atom(program_name(&program_id)),
+        instruction: atom(instruction_name(&program_id, data)),
+        accounts: accounts
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|account| account.to_string())
+            .collect(),
+        data: bs58::encode(data).into_string(),
+    }
+}
+
+// The top-level instructions in order, each followed by the instructions it invoked
+fn instruction_summaries(env: Env, transaction: &FetchedTransaction) -> Vec<InstructionSummaryNif> {
+    let inner_instructions = transaction.inner_instructions();
+    let mut summaries = Vec::new();
+    
+    for (index, instruction) in transaction.message.instructions().iter().enumerate() {
+        let index = index as u8;
+        summaries.push(summarize(
+            env,
+            &transaction.account_keys,
+            index,
+            false,
+            instruction.program_id_index,
+            &instruction.accounts,
+            &instruction.data,
+        ));
+        
+        let invoked = inner_instructions
+            .iter()
+            .filter(|inner| inner.index == index)
+            .flat_map(|inner| inner.instructions.iter());
+        for instruction in invoked {
+            let compiled = match instruction {
+                UiInstruction::Compiled(compiled) => compiled,
+                UiInstruction::Parsed(_) => continue,
+            };
+            let data = bs58::decode(&compiled.data).into_vec().unwrap_or_default();
+            
+            summaries.push(summarize(
+                env,
+                &transaction.account_keys,
+                index,
+                true,
+                compiled.program_id_index,
+                &compiled.accounts,
+                &data,
+            ));
+        }
+    }
+    
+    summaries
+}
+
+fn parse_signature(signature: &str) -> Result<Signature, BubblegumError> {
+    Signature::from_str(signature).map_err(|e| BubblegumError::InvalidSignature(e.to_string()))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_signatures_for_address(
+    env: Env,
+    args: (String, Option<String>, Option<String>, Option<usize>, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (address_str, before, until, limit, retry, rpc_url) = args;
+    
+    let address = match parse_pubkey(&address_str) {
+        Ok(address) => address,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let before = match before.as_deref().map(parse_signature).transpose() {
+        Ok(before) => before,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let until = match until.as_deref().map(parse_signature).transpose() {
+        Ok(until) => until,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    if let Some(limit) = limit.filter(|limit| *limit == 0 || *limit > MAX_SIGNATURES_LIMIT) {
+        let message = format!("limit must be between 1 and {}, got {}", MAX_SIGNATURES_LIMIT, limit);
+        return (atoms::error(), BubblegumError::InvalidArgument(message)).encode(env);
+    }
+    
+    let result = retry.run(|| {
+        rpc_url.with_failover(|connection| {
+            // Processed transactions are not listed
+            let commitment = match connection.client.commitment() {
+                commitment if commitment.is_at_least_confirmed() => commitment,
+                _ => CommitmentConfig::confirmed(),
+            };
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit,
+                commitment: Some(commitment),
+            };
+            
+            connection
+                .client
+                .get_signatures_for_address_with_config(&address, config)
+                .map_err(BubblegumError::from_client_error)
+        })
+    });
+    
+    let statuses = match result {
+        Ok((statuses, _attempts)) => statuses,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let signatures: Vec<SignatureInfoNif> = statuses
+        .into_iter()
+        .map(|status| SignatureInfoNif {
+            signature: status.signature,
+            slot: status.slot,
+            block_time: status.block_time,
+            memo: status.memo,
+            error: status.err.map(|err| err.to_string()),
+        })
+        .collect();
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("address".encode(env), address_str.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("signatures".encode(env), signatures.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_transaction(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (signature_str, retry, rpc_url) = args;
+    
+    let signature = match parse_signature(&signature_str) {
+        Ok(signature) => signature,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let transaction = match fetch_transaction(&rpc_url, &retry, &signature) {
+        Ok(transaction) => transaction,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let logs: Option<Vec<String>> = transaction.meta.log_messages.clone().into();
+    let logs = logs.unwrap_or_default();
+    
+    // A failed transaction is still returned, with the error it failed with
+    let error = transaction.meta.err.as_ref().map(|err| {
+        BubblegumError::from_transaction_error(err, err.to_string(), logs.clone()).with_program_id(&transaction.message)
+    });
+    
+    let instructions = instruction_summaries(env, &transaction);
+    let events = transaction.events();
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("signature".encode(env), signature_str.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("slot".encode(env), transaction.slot.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("block_time".encode(env), transaction.block_time.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("fee".encode(env), transaction.meta.fee.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("error".encode(env), error.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("logs".encode(env), logs.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("instructions".encode(env), instructions.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("leaf_schema_events".encode(env), events.leaf_schema_events.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("change_log_events".encode(env), events.change_log_events.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
@@ -0,0 +1,77 @@
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Proxy,
+};
+use rustler::NifStruct;
+use std::time::Duration;
+
+use crate::BubblegumError;
+
+// Same timeout the RPC client gives its own HTTP client
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+// How a connection talks HTTP: providers that want an API key in a header, or networks that
+// only reach out through a proxy. Applies to RPC and DAS requests alike
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.HttpOptions"]
+pub struct HttpOptionsNif {
+    // Milliseconds before a request is abandoned
+    pub timeout: Option<u64>,
+    pub proxy: Option<String>,
+    pub headers: Vec<(String, String)>,
+}
+
+// The RPC client sends through an async HTTP client, DAS requests through a blocking one.
+// Both are built from the same options
+pub(crate) struct HttpClients {
+    pub rpc: reqwest::Client,
+    pub das: reqwest::blocking::Client,
+}
+
+impl Default for HttpClients {
+    fn default() -> Self {
+        HttpClients {
+            rpc: reqwest::Client::builder().timeout(DEFAULT_TIMEOUT).build().unwrap(),
+            das: reqwest::blocking::Client::builder().timeout(DEFAULT_TIMEOUT).build().unwrap(),
+        }
+    }
+}
+
+impl HttpClients {
+    pub(crate) fn new(options: &HttpOptionsNif) -> Result<Self, BubblegumError> {
+        let timeout = options.timeout.map(Duration::from_millis).unwrap_or(DEFAULT_TIMEOUT);
+        let headers = header_map(&options.headers)?;
+        let proxy = options
+            .proxy
+            .as_deref()
+            .map(Proxy::all)
+            .transpose()
+            .map_err(|e| BubblegumError::InvalidArgument(format!("Invalid proxy URL: {}", e)))?;
+        
+        let mut rpc = reqwest::Client::builder().timeout(timeout).default_headers(headers.clone());
+        let mut das = reqwest::blocking::Client::builder().timeout(timeout).default_headers(headers);
+        if let Some(proxy) = proxy {
+            rpc = rpc.proxy(proxy.clone());
+            das = das.proxy(proxy);
+        }
+        
+        Ok(HttpClients {
+            rpc: rpc.build().map_err(|e| BubblegumError::InvalidArgument(e.to_string()))?,
+            das: das.build().map_err(|e| BubblegumError::InvalidArgument(e.to_string()))?,
+        })
+    }
+}
+
+// Header values often carry API keys, so they are marked sensitive and left out of debug output
+fn header_map(headers: &[(String, String)]) -> Result<HeaderMap, BubblegumError> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| BubblegumError::InvalidArgument(format!("Invalid HTTP header name: {}", name)))?;
+        let mut value = HeaderValue::from_str(value)
+            .map_err(|_| BubblegumError::InvalidArgument(format!("Invalid value for HTTP header {}", name)))?;
+        value.set_sensitive(true);
+        map.insert(name, value);
+    }
+    Ok(map)
+}
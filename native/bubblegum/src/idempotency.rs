@@ -0,0 +1,55 @@
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_sdk::{commitment_config::CommitmentConfig, hash::hashv, pubkey::Pubkey, signature::Signature};
+use std::str::FromStr;
+
+use crate::BubblegumError;
+
+// Memos that carry idempotency keys start with this, so they are told apart from other memos
+const MEMO_PREFIX: &str = "bubblegum-idempotency:";
+
+// How many of the fee payer's latest transactions are searched for an earlier submission
+const SEARCH_LIMIT: usize = 1000;
+
+// The memo a key is recorded on chain as. Keys are hashed, so order IDs and the like stay private
+// and memos stay short whatever the key
+pub(crate) fn memo(key: &str) -> String {
+    format!("{}{}", MEMO_PREFIX, hashv(&[key.as_bytes()]))
+}
+
+// The key of one of the transactions of a call that sends several, such as a batch
+pub(crate) fn part_key(key: &str, part: usize) -> String {
+    format!("{}:{}", key, part)
+}
+
+// The signature of a successful transaction of the fee payer that carries the memo, if any.
+// Failed transactions changed nothing, so they do not count as submissions
+pub(crate) fn find_prior_submission(
+    client: &RpcClient,
+    payer: &Pubkey,
+    memo: &str,
+) -> Result<Option<Signature>, BubblegumError> {
+    let config = GetConfirmedSignaturesForAddress2Config {
+        before: None,
+        until: None,
+        limit: Some(SEARCH_LIMIT),
+        commitment: Some(CommitmentConfig::confirmed()),
+    };
+    let statuses = client
+        .get_signatures_for_address_with_config(payer, config)
+        .map_err(BubblegumError::from_client_error)?;
+    
+    // The RPC node lists the memos of a transaction as `[length] memo`, joined with `; `
+    statuses
+        .into_iter()
+        .filter(|status| status.err.is_none())
+        .find(|status| {
+            status
+                .memo
+                .as_deref()
+                .is_some_and(|memos| memos.split("; ").any(|listed| listed.ends_with(memo)))
+        })
+        .map(|status| {
+            Signature::from_str(&status.signature).map_err(|e| BubblegumError::InvalidSignature(e.to_string()))
+        })
+        .transpose()
+}
@@ -0,0 +1,457 @@
+use mpl_bubblegum::{
+    instructions::{
+        BurnBuilder, CancelRedeemBuilder, CreateTreeConfigBuilder, DecompressV1Builder,
+        DelegateBuilder, MintToCollectionV1Builder, MintV1Builder, RedeemBuilder,
+        SetAndVerifyCollectionBuilder, SetDecompressibleStateBuilder, SetTreeDelegateBuilder,
+        TransferBuilder, UnverifyCollectionBuilder, UnverifyCreatorBuilder, UpdateMetadataBuilder,
+        VerifyCollectionBuilder, VerifyCreatorBuilder,
+    },
+    types::{DecompressibleState, MetadataArgs, TokenProgramVersion, UpdateArgs},
+};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_instruction};
+
+use crate::{
+    associated_token_address, master_edition_pda, merkle_tree_account_size, metadata_pda,
+    mint_authority_pda, tree_config_pda, voucher_pda, AssetProof, BubblegumError,
+    SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, SPL_MEMO_PROGRAM_ID, TOKEN_PROGRAM_ID,
+};
+
+// Instruction constructors shared by the sending NIFs and the build-only NIFs.
+// They only take public keys, so nothing here needs a keypair or the network.
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_tree_config(
+    payer: &Pubkey,
+    tree: &Pubkey,
+    tree_creator: &Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+    canopy_depth: u32,
+    public: bool,
+    tree_account_lamports: u64,
+) -> Vec<Instruction> {
+    // Allocate the Merkle tree account, owned by the account compression program
+    let tree_account_size = merkle_tree_account_size(max_depth, max_buffer_size, canopy_depth);
+    
+    let create_account_ix = system_instruction::create_account(
+        payer,
+        tree,
+        tree_account_lamports,
+        tree_account_size as u64,
+        &SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+    );
+    
+    let create_tree_ix = CreateTreeConfigBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .payer(*payer)
+        .merkle_tree(*tree)
+        .tree_creator(*tree_creator)
+        .max_depth(max_depth)
+        .max_buffer_size(max_buffer_size)
+        .public(public)
+        .instruction();
+    
+    vec![create_account_ix, create_tree_ix]
+}
+
+pub(crate) fn mint_v1(
+    payer: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    metadata: MetadataArgs,
+) -> Instruction {
+    MintV1Builder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .merkle_tree(*tree)
+        .payer(*payer)
+        .tree_creator_or_delegate(*payer)
+        .metadata(metadata)
+        .instruction()
+}
+
+// The tree creator or delegate and the collection authority both sign; a collection
+// authority that was delegated authority proves it with its authority record
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn mint_to_collection_v1(
+    payer: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    tree_creator_or_delegate: &Pubkey,
+    collection_authority: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+    collection_mint: &Pubkey,
+    metadata: MetadataArgs,
+) -> Instruction {
+    MintToCollectionV1Builder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .merkle_tree(*tree)
+        .payer(*payer)
+        .tree_creator_or_delegate(*tree_creator_or_delegate)
+        .collection_authority(*collection_authority)
+        .collection_authority_record_pda(collection_authority_record)
+        .collection_mint(*collection_mint)
+        .collection_metadata(metadata_pda(collection_mint))
+        .collection_edition(master_edition_pda(collection_mint))
+        .metadata(metadata)
+        .instruction()
+}
+
+// The authority signs as either the owner or the delegate
+pub(crate) fn transfer(
+    authority: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    new_owner: &Pubkey,
+    asset_proof: &AssetProof,
+) -> Instruction {
+    TransferBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner, authority == leaf_owner)
+        .leaf_delegate(*leaf_delegate, authority == leaf_delegate && leaf_delegate != leaf_owner)
+        .new_leaf_owner(*new_owner)
+        .merkle_tree(*tree)
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+// The authority signs as either the owner or the delegate
+pub(crate) fn burn(
+    authority: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    asset_proof: &AssetProof,
+) -> Instruction {
+    BurnBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner, authority == leaf_owner)
+        .leaf_delegate(*leaf_delegate, authority == leaf_delegate && leaf_delegate != leaf_owner)
+        .merkle_tree(*tree)
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+// Delegating back to the owner clears the delegation
+pub(crate) fn delegate(
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    previous_delegate: &Pubkey,
+    new_delegate: &Pubkey,
+    asset_proof: &AssetProof,
+) -> Instruction {
+    DelegateBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .previous_leaf_delegate(*previous_delegate)
+        .new_leaf_delegate(*new_delegate)
+        .merkle_tree(*tree)
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+// Returns the instruction together with the voucher PDA it creates
+pub(crate) fn redeem(
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    asset_proof: &AssetProof,
+) -> (Instruction, Pubkey) {
+    let voucher = voucher_pda(tree, asset_proof.nonce);
+    
+    let redeem_ix = RedeemBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .merkle_tree(*tree)
+        .voucher(voucher)
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction();
+    
+    (redeem_ix, voucher)
+}
+
+// Returns the instruction together with the voucher PDA it closes
+pub(crate) fn cancel_redeem(
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    asset_proof: &AssetProof,
+) -> (Instruction, Pubkey) {
+    let voucher = voucher_pda(tree, asset_proof.nonce);
+    
+    let cancel_redeem_ix = CancelRedeemBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .merkle_tree(*tree)
+        .voucher(voucher)
+        .root(asset_proof.root)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction();
+    
+    (cancel_redeem_ix, voucher)
+}
+
+// Returns the instruction together with the mint of the resulting NFT
+pub(crate) fn decompress_v1(
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    nonce: u64,
+    metadata: MetadataArgs,
+) -> Result<(Instruction, Pubkey), BubblegumError> {
+    // DecompressV1 only takes the original token program, so a Token-2022 leaf would fail on chain
+    if metadata.token_program_version == TokenProgramVersion::Token2022 {
+        return Err(BubblegumError::InvalidArgument(
+            "Bubblegum only decompresses into original token program mints; Token-2022 leaves cannot be decompressed"
+                .to_string(),
+        ));
+    }
+    
+    let voucher = voucher_pda(tree, nonce);
+    let mint = mpl_bubblegum::utils::get_asset_id(tree, nonce);
+    
+    let decompress_ix = DecompressV1Builder::new()
+        .voucher(voucher)
+        .leaf_owner(*leaf_owner)
+        .token_account(associated_token_address(leaf_owner, &mint, &TOKEN_PROGRAM_ID))
+        .mint(mint)
+        .mint_authority(mint_authority_pda(&mint))
+        .metadata_account(metadata_pda(&mint))
+        .master_edition(master_edition_pda(&mint))
+        .metadata(metadata)
+        .instruction();
+    
+    Ok((decompress_ix, mint))
+}
+
+pub(crate) fn verify_creator(
+    creator: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    asset_proof: &AssetProof,
+    metadata: MetadataArgs,
+) -> Instruction {
+    VerifyCreatorBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .merkle_tree(*tree)
+        .payer(*creator)
+        .creator(*creator)
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .metadata(metadata)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+pub(crate) fn unverify_creator(
+    creator: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    asset_proof: &AssetProof,
+    metadata: MetadataArgs,
+) -> Instruction {
+    UnverifyCreatorBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .merkle_tree(*tree)
+        .payer(*creator)
+        .creator(*creator)
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .metadata(metadata)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+pub(crate) fn verify_collection(
+    collection_authority: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    collection_mint: &Pubkey,
+    asset_proof: &AssetProof,
+    metadata: MetadataArgs,
+) -> Instruction {
+    VerifyCollectionBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .merkle_tree(*tree)
+        .payer(*collection_authority)
+        .tree_creator_or_delegate(*collection_authority)
+        .collection_authority(*collection_authority)
+        .collection_mint(*collection_mint)
+        .collection_metadata(metadata_pda(collection_mint))
+        .collection_edition(master_edition_pda(collection_mint))
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .metadata(metadata)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+pub(crate) fn unverify_collection(
+    collection_authority: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    collection_mint: &Pubkey,
+    asset_proof: &AssetProof,
+    metadata: MetadataArgs,
+) -> Instruction {
+    UnverifyCollectionBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .merkle_tree(*tree)
+        .payer(*collection_authority)
+        .tree_creator_or_delegate(*collection_authority)
+        .collection_authority(*collection_authority)
+        .collection_mint(*collection_mint)
+        .collection_metadata(metadata_pda(collection_mint))
+        .collection_edition(master_edition_pda(collection_mint))
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .metadata(metadata)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+pub(crate) fn set_and_verify_collection(
+    collection_authority: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    collection_mint: &Pubkey,
+    asset_proof: &AssetProof,
+    metadata: MetadataArgs,
+) -> Instruction {
+    SetAndVerifyCollectionBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .merkle_tree(*tree)
+        .payer(*collection_authority)
+        // The collection authority signs as the tree creator or delegate of a private tree
+        .tree_creator_or_delegate(*collection_authority, true)
+        .collection_authority(*collection_authority)
+        .collection_mint(*collection_mint)
+        .collection_metadata(metadata_pda(collection_mint))
+        .collection_edition(master_edition_pda(collection_mint))
+        .root(asset_proof.root)
+        .data_hash(asset_proof.data_hash)
+        .creator_hash(asset_proof.creator_hash)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .metadata(metadata)
+        .collection(*collection_mint)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+// Leaves in a verified collection are updated by the collection authority, which the program
+// checks against the collection accounts; other leaves by the tree creator or delegate
+pub(crate) fn update_metadata(
+    authority: &Pubkey,
+    tree: &Pubkey,
+    leaf_owner: &Pubkey,
+    leaf_delegate: &Pubkey,
+    asset_proof: &AssetProof,
+    current_metadata: MetadataArgs,
+    update_args: UpdateArgs,
+) -> Instruction {
+    let collection_mint = current_metadata
+        .collection
+        .as_ref()
+        .filter(|collection| collection.verified)
+        .map(|collection| collection.key);
+    
+    UpdateMetadataBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .authority(*authority)
+        .collection_mint(collection_mint)
+        .collection_metadata(collection_mint.as_ref().map(metadata_pda))
+        .leaf_owner(*leaf_owner)
+        .leaf_delegate(*leaf_delegate)
+        .payer(*authority)
+        .merkle_tree(*tree)
+        .root(asset_proof.root)
+        .nonce(asset_proof.nonce)
+        .index(asset_proof.index)
+        .current_metadata(current_metadata)
+        .update_args(update_args)
+        .add_remaining_accounts(&asset_proof.proof)
+        .instruction()
+}
+
+pub(crate) fn set_tree_delegate(tree_creator: &Pubkey, tree: &Pubkey, new_delegate: &Pubkey) -> Instruction {
+    SetTreeDelegateBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .tree_creator(*tree_creator)
+        .new_tree_delegate(*new_delegate)
+        .merkle_tree(*tree)
+        .instruction()
+}
+
+pub(crate) fn set_decompressible_state(
+    tree_creator: &Pubkey,
+    tree: &Pubkey,
+    decompressible_state: DecompressibleState,
+) -> Instruction {
+    SetDecompressibleStateBuilder::new()
+        .tree_config(tree_config_pda(tree))
+        .tree_creator(*tree_creator)
+        .decompressable_state(decompressible_state)
+        .instruction()
+}
+
+// An SPL Memo instruction; without accounts it needs no signatures of its own
+pub(crate) fn memo(text: &str) -> Instruction {
+    Instruction {
+        program_id: SPL_MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data: text.as_bytes().to_vec(),
+    }
+}
@@ -0,0 +1,281 @@
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use bip39::{Language, Mnemonic, Seed};
+use chacha20poly1305::{
+    aead::{Aead, NewAead},
+    Key, XChaCha20Poly1305, XNonce,
+};
+use rand::{rngs::OsRng, RngCore};
+use rustler::{Atom, Binary, Decoder, Encoder, Env, NifResult, OwnedBinary, ResourceArc, Term};
+use solana_sdk::{
+    derivation_path::DerivationPath,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::{keypair as solana_keypair, Signer},
+};
+
+use zeroize::Zeroizing;
+
+use crate::{atoms, parse_keypair, signer, BubblegumError};
+
+// Version byte of the encrypted keypair format, followed by the Argon2id salt, the
+// XChaCha20-Poly1305 nonce and the sealed 64 byte keypair
+const ENCRYPTED_KEYPAIR_VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+// Secret key bytes held by the native code, so they never exist as a BEAM binary. The bytes are
+// wiped when the BEAM garbage collects the last reference
+pub struct SecretKeyResource {
+    bytes: Zeroizing<Vec<u8>>,
+    pub pubkey: Pubkey,
+}
+
+impl SecretKeyResource {
+    pub(crate) fn new(keypair: &Keypair) -> Self {
+        let keypair_bytes = Zeroizing::new(keypair.to_bytes());
+        SecretKeyResource {
+            bytes: Zeroizing::new(keypair_bytes.to_vec()),
+            pubkey: keypair.pubkey(),
+        }
+    }
+    
+    // A copy of the keypair bytes, wiped when dropped
+    pub(crate) fn secret_bytes(&self) -> Zeroizing<Vec<u8>> {
+        self.bytes.clone()
+    }
+}
+
+// A keypair argument as the caller gave it: a Base58 string, the JSON array `solana-keygen`
+// writes, either as a string or as a list of bytes, or raw bytes. Each holds a 64 byte keypair
+// or a 32 byte seed. `{:encrypted, blob, passphrase}` holds a keypair sealed by encrypt_keypair,
+// a secret key resource one loaded by load_secret_key, and `{:signer, name}` one registered
+// by register_signer
+pub enum KeypairNif {
+    Plain(Zeroizing<Vec<u8>>),
+    Encrypted(String, Zeroizing<String>),
+    Resource(ResourceArc<SecretKeyResource>),
+    Signer(String),
+}
+
+impl<'a> Decoder<'a> for KeypairNif {
+    fn decode(term: Term<'a>) -> NifResult<Self> {
+        if let Ok(resource) = term.decode::<ResourceArc<SecretKeyResource>>() {
+            return Ok(KeypairNif::Resource(resource));
+        }
+        if let Ok(binary) = term.decode::<Binary>() {
+            return Ok(KeypairNif::Plain(Zeroizing::new(binary.as_slice().to_vec())));
+        }
+        if let Ok((tag, blob, passphrase)) = term.decode::<(Atom, String, String)>() {
+            if tag == atoms::encrypted() {
+                return Ok(KeypairNif::Encrypted(blob, Zeroizing::new(passphrase)));
+            }
+        }
+        if let Ok((tag, name)) = term.decode::<(Atom, Term)>() {
+            if tag == atoms::signer() {
+                return Ok(KeypairNif::Signer(signer::decode_name(name)?));
+            }
+        }
+        Ok(KeypairNif::Plain(Zeroizing::new(term.decode::<Vec<u8>>()?)))
+    }
+}
+
+impl Encoder for KeypairNif {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        match self {
+            KeypairNif::Plain(bytes) => {
+                let mut binary = OwnedBinary::new(bytes.len()).expect("Could not allocate keypair");
+                binary.as_mut_slice().copy_from_slice(bytes);
+                binary.release(env).encode(env)
+            },
+            KeypairNif::Encrypted(blob, passphrase) => (atoms::encrypted(), blob, passphrase.as_str()).encode(env),
+            KeypairNif::Resource(resource) => resource.encode(env),
+            KeypairNif::Signer(name) => (atoms::signer(), name).encode(env),
+        }
+    }
+}
+
+impl KeypairNif {
+    // The keypair or seed bytes, whatever the format. Raw keypairs and seeds are told apart
+    // from Base58 by their length, which no valid Base58 keypair or seed has. The copy is wiped
+    // when dropped
+    pub(crate) fn secret_bytes(&self) -> Result<Zeroizing<Vec<u8>>, BubblegumError> {
+        let bytes = match self {
+            KeypairNif::Plain(bytes) => bytes.as_slice(),
+            KeypairNif::Encrypted(blob, passphrase) => return decrypt(blob, passphrase),
+            KeypairNif::Resource(resource) => return Ok(resource.secret_bytes()),
+            KeypairNif::Signer(name) => return signer::registered(name),
+        };
+        
+        if bytes.first() == Some(&b'[') {
+            return serde_json::from_slice(bytes)
+                .map(Zeroizing::new)
+                .map_err(|e| BubblegumError::InvalidKeypair(format!("Invalid JSON keypair: {}", e)));
+        }
+        if bytes.len() == 64 || bytes.len() == 32 {
+            return Ok(Zeroizing::new(bytes.to_vec()));
+        }
+        
+        bs58::decode(bytes)
+            .into_vec()
+            .map(Zeroizing::new)
+            .map_err(|e| BubblegumError::InvalidKeypair(format!("Invalid bs58 encoding: {}", e)))
+    }
+}
+
+// 32 byte XChaCha20-Poly1305 key stretched from the passphrase with Argon2id
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Zeroizing<[u8; 32]>, BubblegumError> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut_slice())
+        .map_err(|e| BubblegumError::InvalidArgument(format!("Could not derive key: {}", e)))?;
+    Ok(key)
+}
+
+// Seals a keypair under a passphrase, with a fresh salt and nonce each time. Returns Base64
+fn encrypt(keypair: &Keypair, passphrase: &str) -> Result<String, BubblegumError> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    
+    let key = derive_key(passphrase, &salt)?;
+    let keypair_bytes = Zeroizing::new(keypair.to_bytes());
+    let ciphertext = XChaCha20Poly1305::new(Key::from_slice(key.as_slice()))
+        .encrypt(XNonce::from_slice(&nonce), keypair_bytes.as_slice())
+        .map_err(|e| BubblegumError::InvalidKeypair(format!("Could not encrypt keypair: {}", e)))?;
+    
+    let mut blob = Vec::with_capacity(1 + SALT_LEN + NONCE_LEN + ciphertext.len());
+    blob.push(ENCRYPTED_KEYPAIR_VERSION);
+    blob.extend_from_slice(&salt);
+    blob.extend_from_slice(&nonce);
+    blob.extend_from_slice(&ciphertext);
+    
+    Ok(BASE64.encode(blob))
+}
+
+// Opens a blob sealed by encrypt. A wrong passphrase and a tampered blob fail alike
+fn decrypt(blob: &str, passphrase: &str) -> Result<Zeroizing<Vec<u8>>, BubblegumError> {
+    let blob = BASE64
+        .decode(blob.trim())
+        .map_err(|e| BubblegumError::InvalidKeypair(format!("Invalid encrypted keypair: {}", e)))?;
+    
+    if blob.len() <= 1 + SALT_LEN + NONCE_LEN || blob[0] != ENCRYPTED_KEYPAIR_VERSION {
+        return Err(BubblegumError::InvalidKeypair("Invalid encrypted keypair".to_string()));
+    }
+    let (salt, rest) = blob[1..].split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    
+    let key = derive_key(passphrase, salt)?;
+    XChaCha20Poly1305::new(Key::from_slice(key.as_slice()))
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map(Zeroizing::new)
+        .map_err(|_| BubblegumError::InvalidKeypair("Could not decrypt keypair: wrong passphrase or corrupted data".to_string()))
+}
+
+// `{:ok, %{"keypair" => bs58, "pubkey" => bs58}}`, with the keypair encoded the way every
+// `*_keypair` argument expects it
+fn encode_keypair<'a>(env: Env<'a>, keypair: &Keypair) -> Term<'a> {
+    let ok_map = Term::map_new(env);
+    
+    let keypair_bytes = Zeroizing::new(keypair.to_bytes());
+    let ok_map = ok_map
+        .map_put("keypair".encode(env), bs58::encode(keypair_bytes.as_slice()).into_string().encode(env))
+        .unwrap();
+    let ok_map = ok_map.map_put("pubkey".encode(env), keypair.pubkey().to_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn generate_keypair(env: Env) -> Term {
+    encode_keypair(env, &Keypair::new())
+}
+
+#[rustler::nif]
+pub fn keypair_from_seed<'a>(
+    env: Env<'a>,
+    seed: Binary<'a>,
+) -> Term<'a> {
+    // The first 32 bytes of the seed become the secret key
+    match solana_keypair::keypair_from_seed(seed.as_slice()) {
+        Ok(keypair) => encode_keypair(env, &keypair),
+        Err(e) => (atoms::error(), BubblegumError::InvalidArgument(format!("Invalid seed: {}", e))).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn keypair_from_mnemonic(
+    env: Env,
+    args: (String, String, u32, Option<u32>),
+) -> Term {
+    let (phrase, passphrase, account, change) = args;
+    
+    // The phrase has to be English BIP39 words with a valid checksum
+    let mnemonic = match Mnemonic::from_phrase(phrase.trim(), Language::English) {
+        Ok(mnemonic) => mnemonic,
+        Err(e) => return (atoms::error(), BubblegumError::InvalidArgument(format!("Invalid mnemonic: {}", e))).encode(env),
+    };
+    
+    // BIP39 seed, then SLIP-0010 derivation along m/44'/501'/account'/change', as wallets do
+    let seed = Seed::new(&mnemonic, &passphrase);
+    let derivation_path = DerivationPath::new_bip44(Some(account), change);
+    
+    match solana_keypair::keypair_from_seed_and_derivation_path(seed.as_bytes(), Some(derivation_path)) {
+        Ok(keypair) => encode_keypair(env, &keypair),
+        Err(e) => (atoms::error(), BubblegumError::InvalidArgument(format!("Invalid derivation: {}", e))).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn encrypt_keypair(
+    env: Env,
+    args: (KeypairNif, String),
+) -> Term {
+    let (keypair, passphrase) = args;
+    
+    // Parse first, so only valid keypairs get sealed, always as the full 64 bytes
+    let keypair = match keypair.secret_bytes().and_then(|bytes| parse_keypair(&bytes)) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    match encrypt(&keypair, &passphrase) {
+        Ok(blob) => (atoms::ok(), blob).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn decrypt_keypair(
+    env: Env,
+    args: (String, String),
+) -> Term {
+    let (blob, passphrase) = args;
+    
+    match decrypt(&blob, &passphrase).and_then(|bytes| parse_keypair(&bytes)) {
+        Ok(keypair) => encode_keypair(env, &keypair),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn load_secret_key(
+    env: Env,
+    keypair: KeypairNif,
+) -> Term {
+    // Stored as the parsed 64 byte keypair, so seeds and encrypted keypairs are resolved once
+    let keypair = match keypair.secret_bytes().and_then(|bytes| parse_keypair(&bytes)) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let resource = ResourceArc::new(SecretKeyResource::new(&keypair));
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("secret_key".encode(env), resource.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("pubkey".encode(env), keypair.pubkey().to_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
@@ -1,26 +1,97 @@
-use rustler::{Encoder, Env, NifStruct, Term};
-use mpl_bubblegum::{
-    instructions::{
-        CreateTreeConfigBuilder, MintToCollectionV1Builder, TransferBuilder,
-    },
-    types::{MetadataArgs, TokenProgramVersion, TokenStandard, Creator, Collection, Uses, UseMethod},
+use rustler::{Encoder, Env, NifStruct, NifUnitEnum, Term};
+use mpl_bubblegum::types::{
+    MetadataArgs, TokenProgramVersion, TokenStandard, Creator, Collection, Uses, UseMethod, UpdateArgs,
+    DecompressibleState,
 };
 use solana_sdk::{
     commitment_config::CommitmentConfig,
-    instruction::Instruction,
+    instruction::{AccountMeta, Instruction},
+    pubkey,
     pubkey::Pubkey,
     signature::{Keypair, Signature},
-    signer::Signer,
-    transaction::Transaction,
+    signer::{keypair::keypair_from_seed, Signer},
+    transaction::VersionedTransaction,
 };
-use solana_client::rpc_client::RpcClient;
-use std::str::FromStr;
+use std::{str::FromStr, time::Instant};
 use thiserror::Error;
+use zeroize::Zeroizing;
+
+mod account;
+mod batch;
+mod build;
+mod bulk;
+mod collection;
+mod core_asset;
+mod das;
+mod error;
+mod events;
+mod fees;
+mod hash;
+mod history;
+mod http;
+mod idempotency;
+mod instructions;
+mod keypair;
+mod logging;
+mod lookup_table;
+mod mint_complete;
+mod mint_counter;
+mod nonce;
+mod offchain_metadata;
+mod program_error;
+mod proof_cache;
+mod rate_limit;
+mod reclaim;
+mod reply;
+mod request;
+mod retry;
+mod rpc;
+mod signer;
+mod snapshot;
+mod subscription;
+mod telemetry;
+mod transaction;
+mod transfer_batch;
+mod tree;
+mod upload;
+mod uri_check;
+
+use keypair::KeypairNif;
+use request::{
+    BurnRequestNif, BurnWithDasRequestNif, CancelDelegateRequestNif, CancelRedeemRequestNif, CreateTreeConfigRequestNif,
+    DecompressRequestNif, DelegateRequestNif, MintRequestNif, MintToCollectionRequestNif, RedeemRequestNif,
+    SetDecompressibleStateRequestNif, SetTreeDelegateRequestNif, TransferRequestNif, TransferWithDasRequestNif,
+    UpdateMetadataRequestNif, VerifyCollectionRequestNif, VerifyCreatorRequestNif,
+};
+use telemetry::{ErrorClass, TelemetryEvent, TelemetryEventNif};
+use transaction::{SendResult, TransactionOptionsNif, TransactionOutcome};
+
+const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+const SPL_NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const SPL_MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
 
 mod atoms {
     rustler::atoms! {
         ok,
-        error
+        error,
+        bubblegum_result,
+        bulk_mint_progress,
+        bubblegum_tree_event,
+        bubblegum_tree_error,
+        encrypted,
+        signer,
+        bubblegum_telemetry,
+        duration,
+        attempts,
+        operation,
+        endpoint,
+        result,
+        error_class,
+        bubblegum_log
     }
 }
 
@@ -32,14 +103,71 @@ pub enum BubblegumError {
     #[error("Invalid keypair: {0}")]
     InvalidKeypair(String),
     
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+    
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    
+    // Every field of the metadata that breaks a Token Metadata limit, with what is wrong with it
+    #[error("Invalid metadata: {}", describe_violations(.0))]
+    InvalidMetadata(Vec<(&'static str, String)>),
+    
     #[error("Solana client error: {0}")]
     SolanaClientError(String),
     
     #[error("Transaction error: {0}")]
     TransactionError(String),
     
+    // A failed transaction, with the instruction, program and custom program error it failed on
+    #[error("Transaction error: {message}")]
+    TransactionFailed {
+        message: String,
+        instruction_index: Option<u8>,
+        program_id: Option<Pubkey>,
+        program_error_code: Option<u32>,
+        logs: Vec<String>,
+    },
+    
     #[error("Serialization error: {0}")]
     SerializationError(String),
+    
+    #[error("Invalid hash: {0}")]
+    InvalidHash(String),
+    
+    #[error("Invalid tree config: {0}")]
+    InvalidTreeConfig(String),
+    
+    #[error("DAS API error: {0}")]
+    DasError(String),
+    
+    #[error("Priority fee estimation error: {0}")]
+    PriorityFeeError(String),
+    
+    #[error("Upload error: {0}")]
+    UploadError(String),
+    
+    // Given up on before anything was sent
+    #[error("Timed out after {0} ms")]
+    Timeout(u64),
+    
+    #[error("Cancelled")]
+    Cancelled,
+    
+    // Given up on while a transaction was being sent, so it may still land
+    #[error("Timed out after {0} ms while sending a transaction; it may still land")]
+    TimeoutUnknownOutcome(u64),
+    
+    #[error("Cancelled while sending a transaction; it may still land")]
+    CancelledUnknownOutcome,
+}
+
+fn describe_violations(violations: &[(&'static str, String)]) -> String {
+    violations
+        .iter()
+        .map(|(field, problem)| format!("{} {}", field, problem))
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 #[derive(NifStruct)]
@@ -70,35 +198,332 @@ pub struct MetadataArgsNif {
     pub edition_nonce: Option<u8>,
     pub creators: Vec<CreatorNif>,
     pub collection: Option<String>,
-    pub uses: Option<u64>,
+    // Whether the collection of an existing leaf is verified; the data hash covers the flag
+    pub collection_verified: bool,
+    pub uses: Option<UsesNif>,
+    pub token_program_version: Option<TokenProgramVersionNif>,
+    pub token_standard: Option<TokenStandardNif>,
+}
+
+#[derive(NifUnitEnum)]
+pub enum TokenProgramVersionNif {
+    Original,
+    Token2022,
+}
+
+#[derive(NifUnitEnum)]
+pub enum TokenStandardNif {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+
+#[derive(NifUnitEnum)]
+pub enum UseMethodNif {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.Uses"]
+pub struct UsesNif {
+    pub use_method: UseMethodNif,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(NifUnitEnum)]
+pub enum DecompressibleStateNif {
+    Enabled,
+    Disabled,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.UpdateArgs"]
+pub struct UpdateArgsNif {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+    pub creators: Option<Vec<CreatorNif>>,
+    pub seller_fee_basis_points: Option<u16>,
+    pub primary_sale_happened: Option<bool>,
+    pub is_mutable: Option<bool>,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.AssetProof"]
+pub struct AssetProofNif {
+    pub root: String,
+    pub data_hash: String,
+    pub creator_hash: String,
+    pub nonce: u64,
+    pub index: u32,
+    pub proof: Vec<String>,
+    pub canopy_depth: Option<u32>,
+}
+
+// Decoded form of AssetProofNif; proof nodes become read-only remaining accounts
+#[derive(Clone)]
+struct AssetProof {
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+    proof: Vec<AccountMeta>,
+}
+
+impl AssetProof {
+    // The canopy of a tree caches its top `canopy_depth` levels on chain, so the nodes nearest the
+    // root can be left out; proofs list their nodes from the leaf up
+    fn truncate_to_canopy(&mut self, canopy_depth: u32) {
+        let length = self.proof.len().saturating_sub(canopy_depth as usize);
+        self.proof.truncate(length);
+    }
 }
 
 fn parse_pubkey(pubkey_str: &str) -> Result<Pubkey, BubblegumError> {
     Pubkey::from_str(pubkey_str).map_err(|e| BubblegumError::InvalidPublicKey(e.to_string()))
 }
 
+// A 64 byte keypair, or the keypair of a 32 byte seed. The Keypair wipes its secret key on drop
 fn parse_keypair(keypair_bytes: &[u8]) -> Result<Keypair, BubblegumError> {
+    if keypair_bytes.len() == 32 {
+        return keypair_from_seed(keypair_bytes).map_err(|e| BubblegumError::InvalidKeypair(e.to_string()));
+    }
+    
     let keypair = Keypair::from_bytes(keypair_bytes)
         .map_err(|e| BubblegumError::InvalidKeypair(e.to_string()))?;
     Ok(keypair)
 }
 
-fn convert_metadata_args(args: &MetadataArgsNif) -> Result<MetadataArgs, BubblegumError> {
-    let creators = args.creators.iter().map(|c| {
-        Creator {
-            address: parse_pubkey(&c.address).unwrap(),
-            verified: c.verified,
-            share: c.share,
+// Decodes a keypair argument in any of the formats it accepts and parses it
+fn decode_keypair(keypair: &KeypairNif) -> Result<Keypair, BubblegumError> {
+    parse_keypair(&keypair.secret_bytes()?)
+}
+
+fn parse_hash(hash_str: &str) -> Result<[u8; 32], BubblegumError> {
+    let bytes = bs58::decode(hash_str)
+        .into_vec()
+        .map_err(|e| BubblegumError::InvalidHash(e.to_string()))?;
+    bytes
+        .try_into()
+        .map_err(|_| BubblegumError::InvalidHash(format!("{} is not 32 bytes", hash_str)))
+}
+
+// Size of a spl-account-compression ConcurrentMerkleTree account: the V1 header
+// (account type, header version and 54 bytes of header data) followed by the
+// tree itself (sequence number, active index, buffer size, change log buffer and
+// rightmost proof) and the canopy, which caches the top `canopy_depth` levels of
+// nodes below the root.
+fn merkle_tree_account_size(max_depth: u32, max_buffer_size: u32, canopy_depth: u32) -> usize {
+    let max_depth = max_depth as usize;
+    let max_buffer_size = max_buffer_size as usize;
+    
+    let header_size = 2 + 54;
+    let change_log_size = 32 + 32 * max_depth + 4 + 4;
+    let rightmost_path_size = 32 * max_depth + 32 + 4 + 4;
+    let tree_size = 8 + 8 + 8 + max_buffer_size * change_log_size + rightmost_path_size;
+    let canopy_size = ((1usize << (canopy_depth + 1)) - 2) * 32;
+    
+    header_size + tree_size + canopy_size
+}
+
+fn validate_tree_depths(max_depth: u32, canopy_depth: u32) -> Result<(), BubblegumError> {
+    if max_depth > 30 {
+        return Err(BubblegumError::InvalidTreeConfig(format!(
+            "max_depth {} exceeds the supported maximum of 30",
+            max_depth
+        )));
+    }
+    if canopy_depth > max_depth {
+        return Err(BubblegumError::InvalidTreeConfig(format!(
+            "canopy_depth {} exceeds max_depth {}",
+            canopy_depth, max_depth
+        )));
+    }
+    Ok(())
+}
+
+fn tree_config_pda(merkle_tree: &Pubkey) -> Pubkey {
+    mpl_bubblegum::accounts::TreeConfig::find_pda(merkle_tree).0
+}
+
+fn voucher_pda(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    mpl_bubblegum::accounts::Voucher::find_pda(merkle_tree, nonce).0
+}
+
+fn mint_authority_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[mint.as_ref()], &mpl_bubblegum::ID).0
+}
+
+fn metadata_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+fn master_edition_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref(), b"edition"],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+// The token account address depends on the token program that owns the mint
+fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+fn token_program_id(version: &TokenProgramVersion) -> Pubkey {
+    match version {
+        TokenProgramVersion::Original => TOKEN_PROGRAM_ID,
+        TokenProgramVersion::Token2022 => TOKEN_2022_PROGRAM_ID,
+    }
+}
+
+// Unless set, NFTs use the original token program
+fn convert_token_program_version(version: Option<&TokenProgramVersionNif>) -> TokenProgramVersion {
+    match version {
+        Some(TokenProgramVersionNif::Token2022) => TokenProgramVersion::Token2022,
+        Some(TokenProgramVersionNif::Original) | None => TokenProgramVersion::Original,
+    }
+}
+
+// Owner and delegate of a leaf about to be minted: the payer unless an owner is given, and the
+// owner unless a delegate is given
+fn parse_leaf_recipient(
+    payer: &Keypair,
+    leaf_owner: Option<String>,
+    leaf_delegate: Option<String>,
+) -> Result<(Pubkey, Pubkey), BubblegumError> {
+    let leaf_owner = match leaf_owner {
+        Some(leaf_owner) => parse_pubkey(&leaf_owner)?,
+        None => payer.pubkey(),
+    };
+    let leaf_delegate = match leaf_delegate {
+        Some(leaf_delegate) => parse_pubkey(&leaf_delegate)?,
+        None => leaf_owner,
+    };
+    Ok((leaf_owner, leaf_delegate))
+}
+
+fn convert_asset_proof(args: &AssetProofNif) -> Result<AssetProof, BubblegumError> {
+    let proof = args
+        .proof
+        .iter()
+        .map(|node| parse_pubkey(node).map(|pubkey| AccountMeta::new_readonly(pubkey, false)))
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut asset_proof = AssetProof {
+        root: parse_hash(&args.root)?,
+        data_hash: parse_hash(&args.data_hash)?,
+        creator_hash: parse_hash(&args.creator_hash)?,
+        nonce: args.nonce,
+        index: args.index,
+        proof,
+    };
+    if let Some(canopy_depth) = args.canopy_depth {
+        asset_proof.truncate_to_canopy(canopy_depth);
+    }
+    
+    Ok(asset_proof)
+}
+
+// Limits the Token Metadata program puts on metadata
+const MAX_NAME_LENGTH: usize = 32;
+const MAX_SYMBOL_LENGTH: usize = 10;
+const MAX_URI_LENGTH: usize = 200;
+const MAX_SELLER_FEE_BASIS_POINTS: u16 = 10000;
+const MAX_CREATOR_LIMIT: usize = 5;
+
+// Checks the metadata against the Token Metadata limits and reports every violation at once,
+// so a bad mint fails before any RPC call instead of in the program
+fn validate_metadata_args(args: &MetadataArgsNif) -> Result<(), BubblegumError> {
+    let mut violations =
+        metadata_violations(&args.name, &args.symbol, &args.uri, args.seller_fee_basis_points, &args.creators);
+    
+    if let Some(uses) = &args.uses {
+        if uses.remaining > uses.total {
+            violations.push(("uses", format!("remaining {} exceeds total {}", uses.remaining, uses.total)));
+        }
+        if matches!(uses.use_method, UseMethodNif::Single) && uses.total != 1 {
+            violations.push(("uses", format!("total is {}, a single use allows exactly 1", uses.total)));
         }
-    }).collect();
+    }
     
-    let collection = if let Some(collection_str) = &args.collection {
-        Some(Collection {
-            key: parse_pubkey(collection_str).unwrap(),
-            verified: false, // Will be verified by the program
-        })
+    if violations.is_empty() {
+        Ok(())
     } else {
-        None
+        Err(BubblegumError::InvalidMetadata(violations))
+    }
+}
+
+// Violations of the limits every Token Metadata account has, compressed or not
+fn metadata_violations(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    seller_fee_basis_points: u16,
+    creators: &[CreatorNif],
+) -> Vec<(&'static str, String)> {
+    let mut violations = Vec::new();
+    
+    let lengths = [
+        ("name", name.len(), MAX_NAME_LENGTH),
+        ("symbol", symbol.len(), MAX_SYMBOL_LENGTH),
+        ("uri", uri.len(), MAX_URI_LENGTH),
+    ];
+    for (field, length, max_length) in lengths {
+        if length > max_length {
+            violations.push((field, format!("is {} bytes, at most {} are allowed", length, max_length)));
+        }
+    }
+    
+    if seller_fee_basis_points > MAX_SELLER_FEE_BASIS_POINTS {
+        violations.push((
+            "seller_fee_basis_points",
+            format!("is {}, at most {} are allowed", seller_fee_basis_points, MAX_SELLER_FEE_BASIS_POINTS),
+        ));
+    }
+    
+    if creators.len() > MAX_CREATOR_LIMIT {
+        violations.push((
+            "creators",
+            format!("has {} entries, at most {} are allowed", creators.len(), MAX_CREATOR_LIMIT),
+        ));
+    }
+    
+    let total_share: u32 = creators.iter().map(|c| c.share as u32).sum();
+    if !creators.is_empty() && total_share != 100 {
+        violations.push(("creators", format!("shares add up to {}, they must add up to 100", total_share)));
+    }
+    
+    violations
+}
+
+fn convert_metadata_args(args: &MetadataArgsNif) -> Result<MetadataArgs, BubblegumError> {
+    validate_metadata_args(args)?;
+    
+    let creators = convert_creators(&args.creators)?;
+    
+    let collection = match &args.collection {
+        Some(collection_str) => Some(Collection {
+            key: Pubkey::from_str(collection_str).map_err(|e| {
+                BubblegumError::InvalidPublicKey(format!("collection {}: {}", collection_str, e))
+            })?,
+            verified: args.collection_verified,
+        }),
+        None => None,
     };
     
     Ok(MetadataArgs {
@@ -111,234 +536,1939 @@ fn convert_metadata_args(args: &MetadataArgsNif) -> Result<MetadataArgs, Bubbleg
         edition_nonce: args.edition_nonce,
         creators,
         collection,
-        uses: args.uses.map(|uses| Uses {
-            use_method: UseMethod::Multiple,
-            remaining: uses,
-            total: uses,
+        uses: args.uses.as_ref().map(convert_uses),
+        // Unless set, compressed NFTs are non-fungible and use the original token program
+        token_program_version: convert_token_program_version(args.token_program_version.as_ref()),
+        token_standard: Some(match args.token_standard {
+            Some(TokenStandardNif::FungibleAsset) => TokenStandard::FungibleAsset,
+            Some(TokenStandardNif::Fungible) => TokenStandard::Fungible,
+            Some(TokenStandardNif::NonFungibleEdition) => TokenStandard::NonFungibleEdition,
+            Some(TokenStandardNif::NonFungible) | None => TokenStandard::NonFungible,
         }),
-        token_program_version: TokenProgramVersion::Original,
-        token_standard: Some(TokenStandard::NonFungible),
+    })
+}
+
+fn convert_uses(uses: &UsesNif) -> Uses {
+    Uses {
+        use_method: match uses.use_method {
+            UseMethodNif::Burn => UseMethod::Burn,
+            UseMethodNif::Multiple => UseMethod::Multiple,
+            UseMethodNif::Single => UseMethod::Single,
+        },
+        remaining: uses.remaining,
+        total: uses.total,
+    }
+}
+
+fn convert_decompressible_state(state: &DecompressibleStateNif) -> DecompressibleState {
+    match state {
+        DecompressibleStateNif::Enabled => DecompressibleState::Enabled,
+        DecompressibleStateNif::Disabled => DecompressibleState::Disabled,
+    }
+}
+
+// Names the offending creator by its position, so a bad address is easy to find
+fn convert_creators(creators: &[CreatorNif]) -> Result<Vec<Creator>, BubblegumError> {
+    creators
+        .iter()
+        .enumerate()
+        .map(|(index, c)| {
+            Ok(Creator {
+                address: Pubkey::from_str(&c.address).map_err(|e| {
+                    BubblegumError::InvalidPublicKey(format!("creator {} address {}: {}", index, c.address, e))
+                })?,
+                verified: c.verified,
+                share: c.share,
+            })
+        })
+        .collect()
+}
+
+fn convert_update_args(args: &UpdateArgsNif) -> Result<UpdateArgs, BubblegumError> {
+    let creators = match &args.creators {
+        Some(creators) => Some(convert_creators(creators)?),
+        None => None,
+    };
+    
+    Ok(UpdateArgs {
+        name: args.name.clone(),
+        symbol: args.symbol.clone(),
+        uri: args.uri.clone(),
+        creators,
+        seller_fee_basis_points: args.seller_fee_basis_points,
+        primary_sale_happened: args.primary_sale_happened,
+        is_mutable: args.is_mutable,
     })
 }
 
 fn send_transaction(
-    client: &RpcClient,
     instructions: Vec<Instruction>,
     payer: &Keypair,
     signers: Vec<&Keypair>,
-) -> Result<Signature, BubblegumError> {
-    let recent_blockhash = client
-        .get_latest_blockhash()
-        .map_err(|e| BubblegumError::SolanaClientError(e.to_string()))?;
+    tx_options: &TransactionOptionsNif,
+) -> Result<SendResult, BubblegumError> {
+    send_keyed_transaction(instructions, payer, signers, tx_options, tx_options.idempotency_key.as_deref())
+}
+
+// Sends one of several transactions of a call; each gets its own idempotency key, derived from
+// the key of the call and `part`, which must name the same transaction when the call is retried
+fn send_transaction_part(
+    instructions: Vec<Instruction>,
+    payer: &Keypair,
+    signers: Vec<&Keypair>,
+    tx_options: &TransactionOptionsNif,
+    part: usize,
+) -> Result<SendResult, BubblegumError> {
+    let idempotency_key = tx_options
+        .idempotency_key
+        .as_deref()
+        .map(|key| idempotency::part_key(key, part));
+    send_keyed_transaction(instructions, payer, signers, tx_options, idempotency_key.as_deref())
+}
+
+fn send_keyed_transaction(
+    mut instructions: Vec<Instruction>,
+    payer: &Keypair,
+    signers: Vec<&Keypair>,
+    tx_options: &TransactionOptionsNif,
+    idempotency_key: Option<&str>,
+) -> Result<SendResult, BubblegumError> {
+    // The caller's memo follows the instructions of the operation
+    if let Some(text) = &tx_options.memo {
+        instructions.push(instructions::memo(text));
+    }
     
-    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    // The key goes on chain as a memo too, which is how a retried call finds what it sent before
+    let memo = idempotency_key.map(idempotency::memo);
+    if let Some(memo) = &memo {
+        instructions.push(instructions::memo(memo));
+    }
     
+    // A keypair given for several roles signs only once
     let mut all_signers = vec![payer];
-    all_signers.extend(signers);
+    for signer in signers {
+        if !all_signers.iter().any(|added| added.pubkey() == signer.pubkey()) {
+            all_signers.push(signer);
+        }
+    }
+    
+    // A dry run stops at the compiled message, before anything is fetched or signed
+    if tx_options.dry_run {
+        let signer_pubkeys = all_signers.iter().map(|signer| signer.pubkey()).collect();
+        return transaction::plan_transaction(&payer.pubkey(), &instructions, signer_pubkeys, tx_options)
+            .map(|plan| SendResult {
+                outcome: TransactionOutcome::Planned(plan),
+                attempts: 0,
+                duplicate: false,
+            });
+    }
+    
+    // A transaction with the same key that already landed is returned instead of sending another
+    if let Some(memo) = memo.as_deref().filter(|_| !tx_options.simulate_only) {
+        let (prior, _) = tx_options.retry.run(|| {
+            tx_options
+                .rpc_url
+                .with_failover(|connection| idempotency::find_prior_submission(&connection.client, &payer.pubkey(), memo))
+        })?;
+        if let Some(signature) = prior {
+            tracing::info!(%signature, "Transaction already submitted with this idempotency key");
+            return Ok(SendResult {
+                outcome: TransactionOutcome::Sent(signature),
+                attempts: 0,
+                duplicate: true,
+            });
+        }
+    }
+    
+    // Build and sign once; failing over or retrying resends the very same transaction, so it
+    // can never land twice. A node that reports the blockhash not found may only lag behind the
+    // cluster, so the transaction is re-signed only once its blockhash has expired without it landing
+    let mut signed: Option<(VersionedTransaction, u64)> = None;
+    let mut attempts = 0;
+    let started = Instant::now();
+    let result = tx_options.retry.run(|| {
+        attempts += 1;
+        let (transaction, last_valid_block_height) = match signed.take() {
+            Some(signed) => signed,
+            None => tx_options
+                .rpc_url
+                .with_failover(|connection| sign_transaction(connection, &instructions, &all_signers, tx_options))?,
+        };
+        
+        // An operation that timed out or was cancelled sends nothing more
+        if !tx_options.simulate_only {
+            reply::before_send()?;
+        }
+        
+        let result = submit_transaction(&transaction, tx_options);
+        match &result {
+            Err(e) if retry::classify(e) == Some(retry::RetryableErrorNif::BlockhashNotFound) => {
+                match prior_attempt(&transaction, last_valid_block_height, tx_options) {
+                    Ok(PriorAttempt::Landed(signature)) => return Ok(TransactionOutcome::Sent(signature)),
+                    Ok(PriorAttempt::Expired) => tx_options.rpc_url.invalidate_blockhash(),
+                    // Still valid, or impossible to tell; resending the same transaction is safe either way
+                    Ok(PriorAttempt::Pending) | Err(_) => signed = Some((transaction, last_valid_block_height)),
+                }
+            },
+            _ => signed = Some((transaction, last_valid_block_height)),
+        }
+        result
+    });
+    
+    let operation = telemetry::transaction_operation(&instructions);
+    let operation_name = operation.as_deref().unwrap_or("unknown");
+    match &result {
+        Ok((TransactionOutcome::Sent(signature), _)) => {
+            tracing::debug!(operation = operation_name, %signature, attempts, "Transaction sent");
+        },
+        // Dry runs returned before anything was sent, so only simulations remain
+        Ok(_) => {
+            tracing::debug!(operation = operation_name, attempts, "Transaction simulated");
+        },
+        Err(e) => {
+            // The program logs are what explains most failures
+            let logs: &[String] = match e {
+                BubblegumError::TransactionFailed { logs, .. } => logs.as_slice(),
+                _ => &[],
+            };
+            tracing::error!(operation = operation_name, attempts, error = %e, logs = ?logs, "Transaction failed");
+        },
+    }
     
-    transaction.sign(&all_signers, recent_blockhash);
+    telemetry::emit(TelemetryEvent {
+        event: TelemetryEventNif::Transaction,
+        operation,
+        endpoint: tx_options.rpc_url.url(),
+        duration: started.elapsed(),
+        attempts,
+        error: result.as_ref().err().map(ErrorClass::of),
+    });
     
-    client
-        .send_and_confirm_transaction_with_spinner(&transaction)
+    let (outcome, attempts) = result?;
+    Ok(SendResult {
+        outcome,
+        attempts,
+        duplicate: false,
+    })
+}
+
+// Simulates, submits, or submits and confirms a signed transaction, as the options ask
+fn submit_transaction(
+    transaction: &VersionedTransaction,
+    tx_options: &TransactionOptionsNif,
+) -> Result<TransactionOutcome, BubblegumError> {
+    if tx_options.simulate_only {
+        return tx_options
+            .rpc_url
+            .with_failover(|connection| transaction::simulate(&connection.client, transaction, true, false))
+            .map(TransactionOutcome::Simulated);
+    }
+    
+    // Without confirmation the signature is returned as soon as the node accepts the transaction
+    if !tx_options.send_options.confirm {
+        return tx_options
+            .rpc_url
+            .with_failover(|connection| {
+                transaction::send_transaction_async(&connection.client, transaction, &tx_options.send_options)
+            })
+            .map(TransactionOutcome::Sent)
+            .map_err(|e| e.with_program_id(&transaction.message));
+    }
+    
+    tx_options
+        .rpc_url
+        .with_failover(|connection| {
+            connection
+                .client
+                .send_and_confirm_transaction_with_spinner_and_config(
+                    transaction,
+                    tx_options.send_options.commitment_config(&connection.client),
+                    tx_options.send_options.to_config(),
+                )
+                .map_err(BubblegumError::from_client_error)
+        })
+        .map(TransactionOutcome::Sent)
+        .map_err(|e| e.with_program_id(&transaction.message))
+}
+
+// What became of a transaction sent before whose blockhash a node did not find
+enum PriorAttempt {
+    // The cluster has a status for its signature
+    Landed(Signature),
+    // Its blockhash is still valid, so it may land yet
+    Pending,
+    // Its blockhash expired and it never landed, so it never will
+    Expired,
+}
+
+// Checks the block height before the signature status, so a transaction that landed just before
+// its blockhash expired is still found
+fn prior_attempt(
+    transaction: &VersionedTransaction,
+    last_valid_block_height: u64,
+    tx_options: &TransactionOptionsNif,
+) -> Result<PriorAttempt, BubblegumError> {
+    let signature = transaction.signatures[0];
+    tx_options.rpc_url.with_failover(|connection| {
+        let client = &connection.client;
+        let block_height = client
+            .get_block_height_with_commitment(CommitmentConfig::processed())
+            .map_err(BubblegumError::from_client_error)?;
+        let status = client
+            .get_signature_statuses(&[signature])
+            .map_err(BubblegumError::from_client_error)?
+            .value
+            .into_iter()
+            .next()
+            .flatten();
+        
+        Ok(match status {
+            Some(_) => PriorAttempt::Landed(signature),
+            None if block_height > last_valid_block_height => PriorAttempt::Expired,
+            None => PriorAttempt::Pending,
+        })
+    })
+}
+
+// Prices, compiles and signs a transaction against the latest blockhash; the first signer pays the
+// fees. Returns the last block height the transaction can land at along with it
+fn sign_transaction(
+    connection: &rpc::Connection,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+    tx_options: &TransactionOptionsNif,
+) -> Result<(VersionedTransaction, u64), BubblegumError> {
+    let client = &connection.client;
+    let commitment = tx_options.send_options.commitment_config(client);
+    let (recent_blockhash, last_valid_block_height) = connection.latest_blockhash(Some(commitment))?;
+    
+    // Compute budget instructions have to come before the instructions they apply to
+    let compute_unit_price = fees::compute_unit_price(client, tx_options, instructions)?;
+    let mut all_instructions = transaction::compute_budget_instructions(tx_options.compute_unit_limit, compute_unit_price);
+    all_instructions.extend_from_slice(instructions);
+    
+    // Large proofs only fit when their accounts come from lookup tables
+    let lookup_tables = transaction::fetch_lookup_tables(client, &tx_options.address_lookup_tables)?;
+    let message = transaction::compile_message(&signers[0].pubkey(), &all_instructions, &lookup_tables, recent_blockhash)?;
+    
+    VersionedTransaction::try_new(message, signers)
+        .map(|transaction| (transaction, last_valid_block_height))
         .map_err(|e| BubblegumError::TransactionError(e.to_string()))
 }
 
-#[rustler::nif]
+#[rustler::nif(schedule = "DirtyIo")]
 fn create_tree_config(
     env: Env,
-    args: (String, u32, u32, u32, bool, String),
+    args: (CreateTreeConfigRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| create_tree_config_impl(env, args))
+}
+
+fn create_tree_config_impl(
+    env: Env,
+    args: (CreateTreeConfigRequestNif, TransactionOptionsNif),
 ) -> Term {
-    let (payer_keypair_bs58, max_depth, max_buffer_size, _canopy_depth, public, rpc_url) = args;
+    let (request, tx_options) = args;
+    let CreateTreeConfigRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        max_depth,
+        max_buffer_size,
+        canopy_depth,
+        public,
+        tree_keypair: tree_keypair_bs58,
+        tree_creator_keypair: tree_creator_keypair_bs58,
+    } = request;
     
     // Decode the payer keypair
-    let payer_bytes = match bs58::decode(payer_keypair_bs58).into_vec() {
-        Ok(bytes) => bytes,
-        Err(e) => return (atoms::error(), format!("Invalid bs58 encoding: {}", e)).encode(env),
-    };
-    
-    let payer = match parse_keypair(&payer_bytes) {
+    let payer = match decode_keypair(&payer_keypair_bs58) {
         Ok(keypair) => keypair,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
     };
     
-    // Create a new keypair for the tree
-    let tree_keypair = Keypair::new();
+    // The canopy can cache at most the whole tree
+    if let Err(e) = validate_tree_depths(max_depth, canopy_depth) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    // Use the given tree keypair, or create a new one and hand its secret key back
+    let (tree_keypair, generated) = match tree_keypair_bs58 {
+        Some(tree_keypair_bs58) => match decode_keypair(&tree_keypair_bs58) {
+            Ok(keypair) => (keypair, false),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => (Keypair::new(), true),
+    };
     let tree_pubkey = tree_keypair.pubkey();
     
-    // Connect to Solana
-    let client = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+    // The tree creator owns the tree and signs for it; the payer unless another keypair is given
+    let tree_creator = match tree_creator_keypair_bs58 {
+        Some(tree_creator_keypair_bs58) => match decode_keypair(&tree_creator_keypair_bs58) {
+            Ok(keypair) => Some(keypair),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => None,
+    };
+    let tree_creator_pubkey = tree_creator.as_ref().map_or(payer.pubkey(), |tree_creator| tree_creator.pubkey());
+    
+    // Rent for the Merkle tree account, owned by the account compression program
+    let tree_account_size = merkle_tree_account_size(max_depth, max_buffer_size, canopy_depth);
+    let tree_account_lamports = match tx_options.rent_exempt_lamports(tree_account_size) {
+        Ok(lamports) => lamports,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the tree account and tree config instructions
+    let create_tree_ixs = instructions::create_tree_config(
+        &payer.pubkey(),
+        &tree_pubkey,
+        &tree_creator_pubkey,
+        max_depth,
+        max_buffer_size,
+        canopy_depth,
+        public,
+        tree_account_lamports,
+    );
     
-    // Create the tree config instruction
-    let create_tree_ix = CreateTreeConfigBuilder::new()
-        .payer(payer.pubkey())
-        .merkle_tree(tree_pubkey)
-        .tree_creator(payer.pubkey())
-        .max_depth(max_depth)
-        .max_buffer_size(max_buffer_size)
-        .public(public)
-        .instruction();
+    let mut signers = vec![&tree_keypair];
+    signers.extend(tree_creator.as_ref());
     
     // Send the transaction
-    match send_transaction(&client, vec![create_tree_ix], &payer, vec![&tree_keypair]) {
-        Ok(signature) => {
+    match send_transaction(create_tree_ixs, &payer, signers, &tx_options) {
+        Ok(outcome) => {
             let tree_pubkey_str = tree_pubkey.to_string();
-            let signature_str = signature.to_string();
             
-            let result = Term::map_new(env);
             let ok_map = Term::map_new(env);
             
             let ok_map = ok_map.map_put("tree_pubkey".encode(env), tree_pubkey_str.encode(env)).unwrap();
-            let ok_map = ok_map.map_put("signature".encode(env), signature_str.encode(env)).unwrap();
+            let ok_map = if generated {
+                let tree_keypair_bytes = Zeroizing::new(tree_keypair.to_bytes());
+                let tree_keypair_str = bs58::encode(tree_keypair_bytes.as_slice()).into_string();
+                ok_map.map_put("tree_keypair".encode(env), tree_keypair_str.encode(env)).unwrap()
+            } else {
+                ok_map
+            };
+            let ok_map = outcome.put_into(env, ok_map);
             
-            result.map_put(atoms::ok().encode(env), ok_map).unwrap()
-        },
-        Err(e) => {
-            let result = Term::map_new(env);
-            let error_term = e.to_string().encode(env);
-            result.map_put(atoms::error().encode(env), error_term).unwrap()
+            (atoms::ok(), ok_map).encode(env)
         },
+        Err(e) => (atoms::error(), e).encode(env),
     }
 }
 
-#[rustler::nif]
+#[rustler::nif(schedule = "DirtyIo")]
 fn mint_to_collection_v1(
     env: Env,
-    args: (String, String, String, MetadataArgsNif, String),
+    args: (MintToCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| mint_to_collection_v1_impl(env, args))
+}
+
+fn mint_to_collection_v1_impl(
+    env: Env,
+    args: (MintToCollectionRequestNif, TransactionOptionsNif),
 ) -> Term {
-    let (payer_keypair_bs58, tree_pubkey_str, collection_pubkey_str, metadata_args, rpc_url) = args;
+    let (request, tx_options) = args;
+    let MintToCollectionRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        collection_mint: collection_pubkey_str,
+        metadata: metadata_args,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        tree_creator_or_delegate_keypair: tree_creator_or_delegate_keypair_bs58,
+        collection_authority_keypair: collection_authority_keypair_bs58,
+        collection_authority_record: collection_authority_record_str,
+        validate_uris: uri_check_options,
+    } = request;
     
     // Decode the payer keypair
-    let payer_bytes = match bs58::decode(payer_keypair_bs58).into_vec() {
-        Ok(bytes) => bytes,
-        Err(e) => return (atoms::error(), format!("Invalid bs58 encoding: {}", e)).encode(env),
-    };
-    
-    let payer = match parse_keypair(&payer_bytes) {
+    let payer = match decode_keypair(&payer_keypair_bs58) {
         Ok(keypair) => keypair,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
     };
     
     // Parse the tree and collection pubkeys
     let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
         Ok(pubkey) => pubkey,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
     };
     
     let collection_pubkey = match parse_pubkey(&collection_pubkey_str) {
         Ok(pubkey) => pubkey,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The leaf goes to the payer unless another owner is given
+    let (leaf_owner, leaf_delegate) = match parse_leaf_recipient(&payer, leaf_owner_str, leaf_delegate_str) {
+        Ok(recipient) => recipient,
+        Err(e) => return (atoms::error(), e).encode(env),
     };
     
     // Convert the metadata args
     let metadata = match convert_metadata_args(&metadata_args) {
         Ok(metadata) => metadata,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // A URI that is unreachable or does not hold metadata JSON would break the NFT for good
+    if let Some(options) = &uri_check_options {
+        if let Err(e) = uri_check::validate_metadata_uri(&metadata_args, options) {
+            return (atoms::error(), e).encode(env);
+        }
+    }
+    
+    // The tree and collection authorities sign as well when they are not the payer
+    let tree_creator_or_delegate = match tree_creator_or_delegate_keypair_bs58 {
+        Some(keypair_bs58) => match decode_keypair(&keypair_bs58) {
+            Ok(keypair) => Some(keypair),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => None,
     };
     
-    // Connect to Solana
-    let client = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+    let collection_authority = match collection_authority_keypair_bs58 {
+        Some(keypair_bs58) => match decode_keypair(&keypair_bs58) {
+            Ok(keypair) => Some(keypair),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => None,
+    };
+    
+    let collection_authority_record = match collection_authority_record_str {
+        Some(record_str) => match parse_pubkey(&record_str) {
+            Ok(pubkey) => Some(pubkey),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => None,
+    };
     
     // Create the mint instruction
-    let mint_ix = MintToCollectionV1Builder::new()
-        .payer(payer.pubkey())
-        .merkle_tree(tree_pubkey)
-        .tree_creator_or_delegate(payer.pubkey())
-        .collection_mint(collection_pubkey)
-        .collection_authority(payer.pubkey())
-        .metadata(metadata)
-        .instruction();
+    let mint_ix = instructions::mint_to_collection_v1(
+        &payer.pubkey(),
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &tree_creator_or_delegate.as_ref().unwrap_or(&payer).pubkey(),
+        &collection_authority.as_ref().unwrap_or(&payer).pubkey(),
+        collection_authority_record,
+        &collection_pubkey,
+        metadata,
+    );
+    
+    let signers = tree_creator_or_delegate.iter().chain(collection_authority.iter()).collect();
     
     // Send the transaction
-    match send_transaction(&client, vec![mint_ix], &payer, vec![]) {
-        Ok(signature) => {
-            let signature_str = signature.to_string();
-            
-            let result = Term::map_new(env);
+    match send_transaction(vec![mint_ix], &payer, signers, &tx_options) {
+        Ok(outcome) => {
             let ok_map = Term::map_new(env);
             
-            let ok_map = ok_map.map_put("signature".encode(env), signature_str.encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            let ok_map = events::put_minted_leaf(env, ok_map, &tree_pubkey, &outcome.outcome, &tx_options);
             
-            result.map_put(atoms::ok().encode(env), ok_map).unwrap()
-        },
-        Err(e) => {
-            let result = Term::map_new(env);
-            let error_term = e.to_string().encode(env);
-            result.map_put(atoms::error().encode(env), error_term).unwrap()
+            (atoms::ok(), ok_map).encode(env)
         },
+        Err(e) => (atoms::error(), e).encode(env),
     }
 }
 
-#[rustler::nif]
+#[rustler::nif(schedule = "DirtyIo")]
 fn transfer(
     env: Env,
-    args: (String, String, String, String, String, String),
+    args: (TransferRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| transfer_impl(env, args))
+}
+
+fn transfer_impl(
+    env: Env,
+    args: (TransferRequestNif, TransactionOptionsNif),
 ) -> Term {
-    let (payer_keypair_bs58, tree_pubkey_str, leaf_owner_str, new_owner_str, asset_id_str, rpc_url) = args;
+    let (request, tx_options) = args;
+    let TransferRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        new_owner: new_owner_str,
+        asset_proof,
+    } = request;
     
     // Decode the payer keypair
-    let payer_bytes = match bs58::decode(payer_keypair_bs58).into_vec() {
-        Ok(bytes) => bytes,
-        Err(e) => return (atoms::error(), format!("Invalid bs58 encoding: {}", e)).encode(env),
-    };
-    
-    let payer = match parse_keypair(&payer_bytes) {
+    let payer = match decode_keypair(&payer_keypair_bs58) {
         Ok(keypair) => keypair,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
     };
     
     // Parse the pubkeys
     let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
         Ok(pubkey) => pubkey,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
     };
     
     let leaf_owner = match parse_pubkey(&leaf_owner_str) {
         Ok(pubkey) => pubkey,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
     };
     
     let new_owner = match parse_pubkey(&new_owner_str) {
         Ok(pubkey) => pubkey,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the transfer instruction; the payer signs as either the owner or the delegate
+    let transfer_ix = instructions::transfer(&payer.pubkey(), &tree_pubkey, &leaf_owner, &leaf_delegate, &new_owner, &asset_proof);
+    
+    // Send the transaction
+    match send_transaction(vec![transfer_ix], &payer, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn burn(
+    env: Env,
+    args: (BurnRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| burn_impl(env, args))
+}
+
+fn burn_impl(
+    env: Env,
+    args: (BurnRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let BurnRequestNif {
+        leaf_owner_keypair: leaf_owner_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        asset_proof,
+    } = request;
+    
+    // Decode the leaf owner keypair
+    let leaf_owner = match decode_keypair(&leaf_owner_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
     };
     
-    let _asset_id = match parse_pubkey(&asset_id_str) {
+    // Parse the tree pubkey
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
         Ok(pubkey) => pubkey,
-        Err(e) => return (atoms::error(), e.to_string()).encode(env),
+        Err(e) => return (atoms::error(), e).encode(env),
     };
     
-    // Connect to Solana
-    let client = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
     
-    // Create the transfer instruction
-    let transfer_ix = TransferBuilder::new()
-        .merkle_tree(tree_pubkey)
-        .leaf_owner(leaf_owner, false)
-        .new_leaf_owner(new_owner)
-        .instruction();
+    // Create the burn instruction
+    let burn_ix = instructions::burn(&leaf_owner.pubkey(), &tree_pubkey, &leaf_owner.pubkey(), &leaf_owner.pubkey(), &asset_proof);
     
     // Send the transaction
-    match send_transaction(&client, vec![transfer_ix], &payer, vec![]) {
-        Ok(signature) => {
-            let signature_str = signature.to_string();
-            
-            let result = Term::map_new(env);
+    match send_transaction(vec![burn_ix], &leaf_owner, vec![], &tx_options) {
+        Ok(outcome) => {
             let ok_map = Term::map_new(env);
             
-            let ok_map = ok_map.map_put("signature".encode(env), signature_str.encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
             
-            result.map_put(atoms::ok().encode(env), ok_map).unwrap()
+            (atoms::ok(), ok_map).encode(env)
         },
-        Err(e) => {
-            let result = Term::map_new(env);
-            let error_term = e.to_string().encode(env);
-            result.map_put(atoms::error().encode(env), error_term).unwrap()
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn delegate(
+    env: Env,
+    args: (DelegateRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| delegate_impl(env, args))
+}
+
+fn delegate_impl(
+    env: Env,
+    args: (DelegateRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let DelegateRequestNif {
+        leaf_owner_keypair: leaf_owner_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        previous_delegate: previous_delegate_str,
+        new_delegate: new_delegate_str,
+        asset_proof,
+    } = request;
+    
+    // Decode the leaf owner keypair
+    let leaf_owner = match decode_keypair(&leaf_owner_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let previous_delegate = match previous_delegate_str {
+        Some(previous_delegate_str) => match parse_pubkey(&previous_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner.pubkey(),
+    };
+    
+    let new_delegate = match parse_pubkey(&new_delegate_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the delegate instruction
+    let delegate_ix = instructions::delegate(&tree_pubkey, &leaf_owner.pubkey(), &previous_delegate, &new_delegate, &asset_proof);
+    
+    // Send the transaction
+    match send_transaction(vec![delegate_ix], &leaf_owner, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn cancel_delegate(
+    env: Env,
+    args: (CancelDelegateRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| cancel_delegate_impl(env, args))
+}
+
+fn cancel_delegate_impl(
+    env: Env,
+    args: (CancelDelegateRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let CancelDelegateRequestNif {
+        leaf_owner_keypair: leaf_owner_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        previous_delegate: previous_delegate_str,
+        asset_proof,
+    } = request;
+    
+    // Decode the leaf owner keypair
+    let leaf_owner = match decode_keypair(&leaf_owner_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let previous_delegate = match parse_pubkey(&previous_delegate_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Delegating back to the owner clears the delegation
+    let new_delegate = leaf_owner.pubkey();
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the delegate instruction
+    let delegate_ix = instructions::delegate(&tree_pubkey, &leaf_owner.pubkey(), &previous_delegate, &new_delegate, &asset_proof);
+    
+    // Send the transaction
+    match send_transaction(vec![delegate_ix], &leaf_owner, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn redeem(
+    env: Env,
+    args: (RedeemRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| redeem_impl(env, args))
+}
+
+fn redeem_impl(
+    env: Env,
+    args: (RedeemRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let RedeemRequestNif {
+        leaf_owner_keypair: leaf_owner_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        leaf_delegate: leaf_delegate_str,
+        asset_proof,
+    } = request;
+    
+    // Decode the leaf owner keypair
+    let leaf_owner = match decode_keypair(&leaf_owner_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner.pubkey(),
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the redeem instruction
+    let (redeem_ix, voucher) = instructions::redeem(&tree_pubkey, &leaf_owner.pubkey(), &leaf_delegate, &asset_proof);
+    
+    // Send the transaction
+    match send_transaction(vec![redeem_ix], &leaf_owner, vec![], &tx_options) {
+        Ok(outcome) => {
+            let voucher_str = voucher.to_string();
+            
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("voucher".encode(env), voucher_str.encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn cancel_redeem(
+    env: Env,
+    args: (CancelRedeemRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| cancel_redeem_impl(env, args))
+}
+
+fn cancel_redeem_impl(
+    env: Env,
+    args: (CancelRedeemRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let CancelRedeemRequestNif {
+        leaf_owner_keypair: leaf_owner_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        asset_proof,
+    } = request;
+    
+    // Decode the leaf owner keypair
+    let leaf_owner = match decode_keypair(&leaf_owner_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the cancel redeem instruction
+    let (cancel_redeem_ix, voucher) = instructions::cancel_redeem(&tree_pubkey, &leaf_owner.pubkey(), &asset_proof);
+    
+    // Send the transaction
+    match send_transaction(vec![cancel_redeem_ix], &leaf_owner, vec![], &tx_options) {
+        Ok(outcome) => {
+            let voucher_str = voucher.to_string();
+            
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("voucher".encode(env), voucher_str.encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn decompress_v1(
+    env: Env,
+    args: (DecompressRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| decompress_v1_impl(env, args))
+}
+
+fn decompress_v1_impl(
+    env: Env,
+    args: (DecompressRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let DecompressRequestNif {
+        leaf_owner_keypair: leaf_owner_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        nonce,
+        metadata: metadata_args,
+    } = request;
+    
+    // Decode the leaf owner keypair
+    let leaf_owner = match decode_keypair(&leaf_owner_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the tree pubkey
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the metadata args
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the decompress instruction
+    let (decompress_ix, mint) = match instructions::decompress_v1(&tree_pubkey, &leaf_owner.pubkey(), nonce, metadata) {
+        Ok(decompress) => decompress,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Send the transaction
+    match send_transaction(vec![decompress_ix], &leaf_owner, vec![], &tx_options) {
+        Ok(outcome) => {
+            let mint_str = mint.to_string();
+            
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("mint".encode(env), mint_str.encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn mint_v1(
+    env: Env,
+    args: (MintRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| mint_v1_impl(env, args))
+}
+
+fn mint_v1_impl(
+    env: Env,
+    args: (MintRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let MintRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        metadata: metadata_args,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        validate_uris: uri_check_options,
+    } = request;
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the tree pubkey
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The leaf goes to the payer unless another owner is given
+    let (leaf_owner, leaf_delegate) = match parse_leaf_recipient(&payer, leaf_owner_str, leaf_delegate_str) {
+        Ok(recipient) => recipient,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the metadata args
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // A URI that is unreachable or does not hold metadata JSON would break the NFT for good
+    if let Some(options) = &uri_check_options {
+        if let Err(e) = uri_check::validate_metadata_uri(&metadata_args, options) {
+            return (atoms::error(), e).encode(env);
+        }
+    }
+    
+    // Create the mint instruction
+    let mint_ix = instructions::mint_v1(&payer.pubkey(), &tree_pubkey, &leaf_owner, &leaf_delegate, metadata);
+    
+    // Send the transaction
+    match send_transaction(vec![mint_ix], &payer, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            let ok_map = events::put_minted_leaf(env, ok_map, &tree_pubkey, &outcome.outcome, &tx_options);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn verify_creator(
+    env: Env,
+    args: (VerifyCreatorRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| verify_creator_impl(env, args))
+}
+
+fn verify_creator_impl(
+    env: Env,
+    args: (VerifyCreatorRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let VerifyCreatorRequestNif {
+        creator_keypair: creator_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        asset_proof,
+        metadata: metadata_args,
+    } = request;
+    
+    // Decode the creator keypair
+    let creator = match decode_keypair(&creator_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the verify creator instruction
+    let verify_creator_ix = instructions::verify_creator(&creator.pubkey(), &tree_pubkey, &leaf_owner, &leaf_delegate, &asset_proof, metadata);
+    
+    // Send the transaction
+    match send_transaction(vec![verify_creator_ix], &creator, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn unverify_creator(
+    env: Env,
+    args: (VerifyCreatorRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| unverify_creator_impl(env, args))
+}
+
+fn unverify_creator_impl(
+    env: Env,
+    args: (VerifyCreatorRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let VerifyCreatorRequestNif {
+        creator_keypair: creator_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        asset_proof,
+        metadata: metadata_args,
+    } = request;
+    
+    // Decode the creator keypair
+    let creator = match decode_keypair(&creator_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the unverify creator instruction
+    let unverify_creator_ix = instructions::unverify_creator(&creator.pubkey(), &tree_pubkey, &leaf_owner, &leaf_delegate, &asset_proof, metadata);
+    
+    // Send the transaction
+    match send_transaction(vec![unverify_creator_ix], &creator, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn verify_collection(
+    env: Env,
+    args: (VerifyCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| verify_collection_impl(env, args))
+}
+
+fn verify_collection_impl(
+    env: Env,
+    args: (VerifyCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let VerifyCollectionRequestNif {
+        collection_authority_keypair: collection_authority_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        collection_mint: collection_mint_str,
+        asset_proof,
+        metadata: metadata_args,
+    } = request;
+    
+    // Decode the collection authority keypair
+    let collection_authority = match decode_keypair(&collection_authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    let collection_mint = match parse_pubkey(&collection_mint_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the verify collection instruction
+    let verify_collection_ix = instructions::verify_collection(
+        &collection_authority.pubkey(),
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &collection_mint,
+        &asset_proof,
+        metadata,
+    );
+    
+    // Send the transaction
+    match send_transaction(vec![verify_collection_ix], &collection_authority, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn unverify_collection(
+    env: Env,
+    args: (VerifyCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| unverify_collection_impl(env, args))
+}
+
+fn unverify_collection_impl(
+    env: Env,
+    args: (VerifyCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let VerifyCollectionRequestNif {
+        collection_authority_keypair: collection_authority_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        collection_mint: collection_mint_str,
+        asset_proof,
+        metadata: metadata_args,
+    } = request;
+    
+    // Decode the collection authority keypair
+    let collection_authority = match decode_keypair(&collection_authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    let collection_mint = match parse_pubkey(&collection_mint_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the unverify collection instruction
+    let unverify_collection_ix = instructions::unverify_collection(
+        &collection_authority.pubkey(),
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &collection_mint,
+        &asset_proof,
+        metadata,
+    );
+    
+    // Send the transaction
+    match send_transaction(vec![unverify_collection_ix], &collection_authority, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn set_and_verify_collection(
+    env: Env,
+    args: (VerifyCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| set_and_verify_collection_impl(env, args))
+}
+
+fn set_and_verify_collection_impl(
+    env: Env,
+    args: (VerifyCollectionRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let VerifyCollectionRequestNif {
+        collection_authority_keypair: collection_authority_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        collection_mint: collection_mint_str,
+        asset_proof,
+        metadata: metadata_args,
+    } = request;
+    
+    // Decode the collection authority keypair
+    let collection_authority = match decode_keypair(&collection_authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    let collection_mint = match parse_pubkey(&collection_mint_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Convert the asset proof and metadata args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the set and verify collection instruction
+    let set_and_verify_collection_ix = instructions::set_and_verify_collection(
+        &collection_authority.pubkey(),
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &collection_mint,
+        &asset_proof,
+        metadata,
+    );
+    
+    // Send the transaction
+    match send_transaction(vec![set_and_verify_collection_ix], &collection_authority, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn update_metadata(
+    env: Env,
+    args: (UpdateMetadataRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| update_metadata_impl(env, args))
+}
+
+fn update_metadata_impl(
+    env: Env,
+    args: (UpdateMetadataRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let UpdateMetadataRequestNif {
+        authority_keypair: authority_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        asset_proof,
+        current_metadata: metadata_args,
+        update_args,
+    } = request;
+    
+    // Decode the authority keypair
+    let authority = match decode_keypair(&authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_owner = match parse_pubkey(&leaf_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let leaf_delegate = match leaf_delegate_str {
+        Some(leaf_delegate_str) => match parse_pubkey(&leaf_delegate_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => leaf_owner,
+    };
+    
+    // Convert the asset proof, current metadata and update args
+    let asset_proof = match convert_asset_proof(&asset_proof) {
+        Ok(asset_proof) => asset_proof,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let current_metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let update_args = match convert_update_args(&update_args) {
+        Ok(update_args) => update_args,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the update metadata instruction
+    let update_metadata_ix = instructions::update_metadata(
+        &authority.pubkey(),
+        &tree_pubkey,
+        &leaf_owner,
+        &leaf_delegate,
+        &asset_proof,
+        current_metadata,
+        update_args,
+    );
+    
+    // Send the transaction
+    match send_transaction(vec![update_metadata_ix], &authority, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn set_tree_delegate(
+    env: Env,
+    args: (SetTreeDelegateRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| set_tree_delegate_impl(env, args))
+}
+
+fn set_tree_delegate_impl(
+    env: Env,
+    args: (SetTreeDelegateRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let SetTreeDelegateRequestNif {
+        tree_creator_keypair: tree_creator_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        new_delegate: new_delegate_str,
+    } = request;
+    
+    // Decode the tree creator keypair
+    let tree_creator = match decode_keypair(&tree_creator_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let new_delegate = match parse_pubkey(&new_delegate_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the set tree delegate instruction
+    let set_tree_delegate_ix = instructions::set_tree_delegate(&tree_creator.pubkey(), &tree_pubkey, &new_delegate);
+    
+    // Send the transaction
+    match send_transaction(vec![set_tree_delegate_ix], &tree_creator, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn set_decompressible_state(
+    env: Env,
+    args: (SetDecompressibleStateRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| set_decompressible_state_impl(env, args))
+}
+
+fn set_decompressible_state_impl(
+    env: Env,
+    args: (SetDecompressibleStateRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let SetDecompressibleStateRequestNif {
+        tree_creator_keypair: tree_creator_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        decompressible_state,
+    } = request;
+    
+    // Decode the tree creator keypair
+    let tree_creator = match decode_keypair(&tree_creator_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the tree pubkey
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Create the set decompressible state instruction
+    let set_decompressible_state_ix = instructions::set_decompressible_state(
+        &tree_creator.pubkey(),
+        &tree_pubkey,
+        convert_decompressible_state(&decompressible_state),
+    );
+    
+    // Send the transaction
+    match send_transaction(vec![set_decompressible_state_ix], &tree_creator, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn transfer_with_das(
+    env: Env,
+    args: (TransferWithDasRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| transfer_with_das_impl(env, args))
+}
+
+// Trims a proof fetched from DAS to the canopy depth given with the request, or read from the tree
+fn trim_das_proof(
+    leaf: &mut das::DasLeaf,
+    canopy_depth: Option<u32>,
+    tx_options: &TransactionOptionsNif,
+) -> Result<(), BubblegumError> {
+    let canopy_depth = match canopy_depth {
+        Some(canopy_depth) => canopy_depth,
+        None => tree::fetch_canopy_depth(&tx_options.rpc_url, &tx_options.retry, &leaf.tree)?,
+    };
+    leaf.asset_proof.truncate_to_canopy(canopy_depth);
+    Ok(())
+}
+
+fn transfer_with_das_impl(
+    env: Env,
+    args: (TransferWithDasRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let TransferWithDasRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        asset_id,
+        new_owner: new_owner_str,
+        canopy_depth,
+        proof_cache,
+    } = request;
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let new_owner = match parse_pubkey(&new_owner_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Fetch the leaf and its proof, from the proof cache while the tree is unchanged, otherwise from DAS
+    let leaf = match &proof_cache {
+        Some(cache) => cache.fetch_leaf(&tx_options.rpc_url, &asset_id, &tx_options.retry),
+        None => das::fetch_leaf(&tx_options.rpc_url, &asset_id, &tx_options.retry),
+    };
+    let mut leaf = match leaf {
+        Ok(leaf) => leaf,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // DAS returns the full proof; leave out the nodes the tree's canopy already holds
+    if let Err(e) = trim_das_proof(&mut leaf, canopy_depth, &tx_options) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    // Create the transfer instruction; the payer signs as either the owner or the delegate
+    let transfer_ix = instructions::transfer(&payer.pubkey(), &leaf.tree, &leaf.owner, &leaf.delegate, &new_owner, &leaf.asset_proof);
+    
+    // Send the transaction
+    match send_transaction(vec![transfer_ix], &payer, vec![], &tx_options) {
+        Ok(outcome) => {
+            // The transfer replaced the leaf, so its cached proof no longer verifies
+            if let Some(cache) = &proof_cache {
+                cache.invalidate(&asset_id);
+            }
+            
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn burn_with_das(
+    env: Env,
+    args: (BurnWithDasRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| burn_with_das_impl(env, args))
+}
+
+fn burn_with_das_impl(
+    env: Env,
+    args: (BurnWithDasRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let BurnWithDasRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        asset_id,
+        canopy_depth,
+    } = request;
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Fetch the leaf and its proof from DAS
+    let mut leaf = match das::fetch_leaf(&tx_options.rpc_url, &asset_id, &tx_options.retry) {
+        Ok(leaf) => leaf,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // DAS returns the full proof; leave out the nodes the tree's canopy already holds
+    if let Err(e) = trim_das_proof(&mut leaf, canopy_depth, &tx_options) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    // Create the burn instruction; the payer signs as either the owner or the delegate
+    let burn_ix = instructions::burn(&payer.pubkey(), &leaf.tree, &leaf.owner, &leaf.delegate, &leaf.asset_proof);
+    
+    // Send the transaction
+    match send_transaction(vec![burn_ix], &payer, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
         },
+        Err(e) => (atoms::error(), e).encode(env),
     }
 }
 
+#[rustler::nif]
+fn get_asset_id(
+    env: Env,
+    args: (String, u64),
+) -> Term {
+    let (tree_pubkey_str, nonce) = args;
+    
+    // Parse the tree pubkey
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Derive the asset PDA, no RPC call needed
+    let asset_id = mpl_bubblegum::utils::get_asset_id(&tree_pubkey, nonce);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("asset_id".encode(env), asset_id.to_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+// rustler::resource! implements the resource trait inside the function that registers it
+#[allow(non_local_definitions)]
+fn load(env: Env, _info: Term) -> bool {
+    rustler::resource!(rpc::RpcClientResource, env);
+    rustler::resource!(rpc::RpcPoolResource, env);
+    rustler::resource!(subscription::TreeSubscriptionResource, env);
+    rustler::resource!(keypair::SecretKeyResource, env);
+    rustler::resource!(proof_cache::ProofCacheResource, env);
+    rustler::resource!(mint_counter::MintCounterResource, env);
+    logging::init();
+    true
+}
+
 rustler::init!("Elixir.SolanaBubblegum.Bubblegum", [
     create_tree_config,
     mint_to_collection_v1,
-    transfer
-]);
+    transfer,
+    burn,
+    delegate,
+    cancel_delegate,
+    redeem,
+    cancel_redeem,
+    decompress_v1,
+    mint_v1,
+    verify_creator,
+    unverify_creator,
+    verify_collection,
+    unverify_collection,
+    set_and_verify_collection,
+    update_metadata,
+    set_tree_delegate,
+    set_decompressible_state,
+    transfer_with_das,
+    burn_with_das,
+    get_asset_id,
+    batch::mint_batch_to_collection_v1,
+    build::build_create_tree_config,
+    build::build_mint_v1,
+    build::build_mint_to_collection_v1,
+    build::build_transfer,
+    build::build_burn,
+    build::build_delegate,
+    build::build_cancel_delegate,
+    build::build_redeem,
+    build::build_cancel_redeem,
+    build::build_decompress_v1,
+    build::build_verify_creator,
+    build::build_unverify_creator,
+    build::build_verify_collection,
+    build::build_unverify_collection,
+    build::build_set_and_verify_collection,
+    build::build_update_metadata,
+    build::build_set_tree_delegate,
+    build::build_set_decompressible_state,
+    bulk::bulk_mint,
+    bulk::airdrop_to_owners,
+    bulk::load_metadata_file,
+    collection::create_collection_nft,
+    collection::update_collection_metadata,
+    collection::approve_collection_authority,
+    collection::revoke_collection_authority,
+    collection::set_collection_size,
+    transaction::build_transaction,
+    transaction::send_raw_transaction,
+    transaction::attach_signatures_and_send,
+    transaction::simulate_transaction,
+    transaction::confirm_signature,
+    fees::get_priority_fee_estimate,
+    fees::estimate_fee,
+    lookup_table::create_lookup_table,
+    lookup_table::extend_lookup_table,
+    lookup_table::deactivate_lookup_table,
+    lookup_table::close_lookup_table,
+    reclaim::check_tree_empty,
+    reclaim::reclaim_lookup_table,
+    nonce::create_nonce_account,
+    keypair::generate_keypair,
+    keypair::keypair_from_seed,
+    keypair::keypair_from_mnemonic,
+    keypair::encrypt_keypair,
+    keypair::decrypt_keypair,
+    keypair::load_secret_key,
+    signer::register_signer,
+    signer::unregister_signer,
+    signer::list_signers,
+    hash::hash_metadata,
+    hash::hash_creators,
+    hash::compute_leaf_hash,
+    hash::verify_proof,
+    events::parse_transaction_events,
+    subscription::subscribe_tree,
+    subscription::unsubscribe_tree,
+    subscription::await_finalized,
+    account::request_airdrop,
+    account::get_balance,
+    account::get_account_info,
+    account::transfer_sol,
+    telemetry::set_telemetry_handler,
+    logging::set_log_handler,
+    logging::set_log_level,
+    history::get_signatures_for_address,
+    history::get_transaction,
+    tree::calculate_tree_cost,
+    tree::recommend_tree_config,
+    tree::get_tree_config,
+    tree::get_merkle_tree_state,
+    das::get_asset,
+    das::get_asset_proof,
+    das::get_assets_by_owner,
+    das::get_assets_by_group,
+    das::get_assets_by_creator,
+    das::search_assets,
+    das::get_royalty_info,
+    das::verify_ownership,
+    snapshot::snapshot_holders,
+    transfer_batch::transfer_batch,
+    core_asset::create_core_asset,
+    core_asset::transfer_core_asset,
+    core_asset::burn_core_asset,
+    rpc::connect,
+    rpc::connect_pool,
+    proof_cache::new_proof_cache,
+    proof_cache::proof_cache_stats,
+    mint_counter::new_mint_counter,
+    mint_counter::next_leaf_index,
+    mint_counter::reconcile_mint_counter,
+    uri_check::check_metadata_uris,
+    offchain_metadata::build_offchain_metadata,
+    offchain_metadata::validate_offchain_metadata,
+    upload::upload_metadata,
+    mint_complete::mint_complete,
+    reply::cancel
+], load = load);
@@ -0,0 +1,199 @@
+use rustler::{Encoder, Env, LocalPid, NifUnitEnum, OwnedEnv, Term};
+use std::{
+    fmt,
+    sync::{mpsc, Mutex, OnceLock},
+    thread,
+};
+use tracing::{
+    field::{Field, Visit},
+    level_filters::LevelFilter,
+    Event, Level, Subscriber,
+};
+use tracing_log::{AsLog, LogTracer, NormalizeEvent};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    reload, Layer, Registry,
+};
+
+use crate::atoms;
+
+// Levels as Elixir's Logger names them, plus `:trace` for the noisiest records and `:none`
+#[derive(NifUnitEnum, Clone, Copy)]
+pub enum LogLevelNif {
+    Error,
+    Warning,
+    Info,
+    Debug,
+    Trace,
+    None,
+}
+
+impl LogLevelNif {
+    fn to_level_filter(self) -> LevelFilter {
+        match self {
+            LogLevelNif::Error => LevelFilter::ERROR,
+            LogLevelNif::Warning => LevelFilter::WARN,
+            LogLevelNif::Info => LevelFilter::INFO,
+            LogLevelNif::Debug => LevelFilter::DEBUG,
+            LogLevelNif::Trace => LevelFilter::TRACE,
+            LogLevelNif::None => LevelFilter::OFF,
+        }
+    }
+    
+    // Logger has no trace level, so trace records are logged as debug
+    fn from_level(level: &Level) -> Self {
+        if *level == Level::ERROR {
+            LogLevelNif::Error
+        } else if *level == Level::WARN {
+            LogLevelNif::Warning
+        } else if *level == Level::INFO {
+            LogLevelNif::Info
+        } else {
+            LogLevelNif::Debug
+        }
+    }
+}
+
+// Records below warnings stay in the NIF until a lower level is set
+const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::WARN;
+
+// One log record of this library or of the Solana crates it uses
+struct LogRecord {
+    level: LogLevelNif,
+    target: String,
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Encoder for LogRecord {
+    // {:bubblegum_log, level, target, message, %{field => value}}
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        let mut fields = Term::map_new(env);
+        for (name, value) in &self.fields {
+            fields = fields.map_put(name.encode(env), value.encode(env)).unwrap();
+        }
+        
+        (atoms::bubblegum_log(), self.level, self.target.as_str(), self.message.as_str(), fields).encode(env)
+    }
+}
+
+// Collects the message and the other fields of an event as text
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl FieldVisitor {
+    fn record(&mut self, field: &Field, value: String) {
+        match field.name() {
+            "message" => self.message = value,
+            // Bookkeeping fields that tracing-log adds to records of the log crate
+            name if name.starts_with("log.") => {},
+            name => self.fields.push((name.to_string(), value)),
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+    
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, format!("{:?}", value));
+    }
+}
+
+// Where records go. Like telemetry events, they are handed to a delivery thread, since most
+// are logged on VM-managed threads that cannot send messages
+static HANDLER: OnceLock<Mutex<Option<mpsc::Sender<LogRecord>>>> = OnceLock::new();
+
+static LEVEL: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+fn handler() -> &'static Mutex<Option<mpsc::Sender<LogRecord>>> {
+    HANDLER.get_or_init(|| Mutex::new(None))
+}
+
+struct ForwardingLayer;
+
+impl<S: Subscriber> Layer<S> for ForwardingLayer {
+    fn on_event(&self, event: &Event<'_>, _context: Context<'_, S>) {
+        let handler = handler().lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let sender = match handler.as_ref() {
+            Some(sender) => sender,
+            None => return,
+        };
+        
+        // Records of the log crate carry their real level and target in their fields
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+        
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        
+        let _ = sender.send(LogRecord {
+            level: LogLevelNif::from_level(metadata.level()),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        });
+    }
+}
+
+// Installs the subscriber that forwards records to the handler, and routes the records of the
+// log crate, which the Solana crates use, through it. Only the first NIF load installs them
+pub(crate) fn init() {
+    let (filter, level) = reload::Layer::new(DEFAULT_LOG_LEVEL);
+    let subscriber = Registry::default().with(filter).with(ForwardingLayer);
+    
+    if tracing::subscriber::set_global_default(subscriber).is_ok() {
+        let _ = LEVEL.set(level);
+        let _ = LogTracer::init_with_filter(DEFAULT_LOG_LEVEL.as_log());
+    }
+}
+
+fn spawn_delivery(pid: LocalPid, records: mpsc::Receiver<LogRecord>) {
+    thread::spawn(move || {
+        let mut env = OwnedEnv::new();
+        
+        // Ends once the handler is replaced or removed, which drops the sending half
+        for record in records {
+            env.send_and_clear(&pid, |env| record.encode(env));
+        }
+    });
+}
+
+#[rustler::nif]
+pub fn set_log_handler(
+    env: Env,
+    pid: Option<LocalPid>,
+) -> Term {
+    // Replacing the sender ends the delivery thread of the previous handler
+    let sender = pid.map(|pid| {
+        let (sender, records) = mpsc::channel();
+        spawn_delivery(pid, records);
+        sender
+    });
+    
+    *handler().lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = sender;
+    
+    atoms::ok().encode(env)
+}
+
+#[rustler::nif]
+pub fn set_log_level(
+    env: Env,
+    level: LogLevelNif,
+) -> Term {
+    let level_filter = level.to_level_filter();
+    
+    if let Some(handle) = LEVEL.get() {
+        let _ = handle.modify(|filter| *filter = level_filter);
+    }
+    
+    // The log crate checks its own maximum before a record is even built
+    tracing_log::log::set_max_level(level_filter.as_log());
+    
+    atoms::ok().encode(env)
+}
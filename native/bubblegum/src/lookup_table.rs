@@ -0,0 +1,238 @@
+use rustler::{Encoder, Env, Term};
+use solana_sdk::{address_lookup_table::instruction as lookup_table_instruction, signer::Signer};
+
+use crate::{
+    atoms, decode_keypair,
+    keypair::KeypairNif,
+    parse_pubkey, reply, send_transaction, send_transaction_part,
+    transaction::{TransactionOptionsNif, TransactionOutcome},
+    BubblegumError,
+};
+
+// Extending with more addresses than this at once overflows a legacy transaction
+const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn create_lookup_table(
+    env: Env,
+    args: (KeypairNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| create_lookup_table_impl(env, args))
+}
+
+fn create_lookup_table_impl(
+    env: Env,
+    args: (KeypairNif, TransactionOptionsNif),
+) -> Term {
+    let (authority_keypair_bs58, tx_options) = args;
+    
+    // Decode the authority keypair
+    let authority = match decode_keypair(&authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Connect to Solana
+    let client = tx_options.rpc_url.client();
+    
+    // The table address is derived from the authority and a recent slot
+    let recent_slot = match client.get_slot() {
+        Ok(slot) => slot,
+        Err(e) => {
+            return (atoms::error(), BubblegumError::SolanaClientError(e.to_string())).encode(env);
+        },
+    };
+    
+    let (create_ix, lookup_table) =
+        lookup_table_instruction::create_lookup_table(authority.pubkey(), authority.pubkey(), recent_slot);
+    
+    // Send the transaction
+    match send_transaction(vec![create_ix], &authority, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            let ok_map = ok_map.map_put("lookup_table".encode(env), lookup_table.to_string().encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn extend_lookup_table(
+    env: Env,
+    args: (KeypairNif, String, Vec<String>, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.3.reply_options(), move |env| extend_lookup_table_impl(env, args))
+}
+
+fn extend_lookup_table_impl(
+    env: Env,
+    args: (KeypairNif, String, Vec<String>, TransactionOptionsNif),
+) -> Term {
+    let (authority_keypair_bs58, lookup_table_str, address_strs, tx_options) = args;
+    
+    // Decode the authority keypair
+    let authority = match decode_keypair(&authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys
+    let lookup_table = match parse_pubkey(&lookup_table_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let addresses = match address_strs.iter().map(|address| parse_pubkey(address)).collect::<Result<Vec<_>, _>>() {
+        Ok(addresses) => addresses,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    if addresses.is_empty() {
+        return (atoms::error(), BubblegumError::InvalidArgument("No addresses to add to the lookup table".to_string())).encode(env);
+    }
+    
+    // Send one transaction per chunk so long proofs still fit
+    let mut outcomes = Vec::new();
+    for (part, chunk) in addresses.chunks(MAX_ADDRESSES_PER_EXTEND).enumerate() {
+        let extend_ix = lookup_table_instruction::extend_lookup_table(
+            lookup_table,
+            authority.pubkey(),
+            Some(authority.pubkey()),
+            chunk.to_vec(),
+        );
+        
+        match send_transaction_part(vec![extend_ix], &authority, vec![], &tx_options, part) {
+            Ok(outcome) => outcomes.push(outcome),
+            Err(e) => {
+                return (atoms::error(), e).encode(env);
+            },
+        }
+    }
+    
+    let ok_map = Term::map_new(env);
+    
+    let signatures: Vec<String> = outcomes
+        .iter()
+        .filter_map(|sent| match &sent.outcome {
+            TransactionOutcome::Sent(signature) => Some(signature.to_string()),
+            TransactionOutcome::Simulated(_) | TransactionOutcome::Planned(_) => None,
+        })
+        .collect();
+    let simulations: Vec<Term> = outcomes
+        .iter()
+        .filter_map(|sent| match &sent.outcome {
+            TransactionOutcome::Simulated(simulation) => Some(simulation.encode(env)),
+            TransactionOutcome::Sent(_) | TransactionOutcome::Planned(_) => None,
+        })
+        .collect();
+    let plans: Vec<Term> = outcomes
+        .iter()
+        .filter_map(|sent| match &sent.outcome {
+            TransactionOutcome::Planned(plan) => Some(plan.encode(env)),
+            TransactionOutcome::Sent(_) | TransactionOutcome::Simulated(_) => None,
+        })
+        .collect();
+    let attempts: u32 = outcomes.iter().map(|sent| sent.attempts).sum();
+    
+    let ok_map = ok_map.map_put("signatures".encode(env), signatures.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("simulations".encode(env), simulations.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("plans".encode(env), plans.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("attempts".encode(env), attempts.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("lookup_table".encode(env), lookup_table.to_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn deactivate_lookup_table(
+    env: Env,
+    args: (KeypairNif, String, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.2.reply_options(), move |env| deactivate_lookup_table_impl(env, args))
+}
+
+fn deactivate_lookup_table_impl(
+    env: Env,
+    args: (KeypairNif, String, TransactionOptionsNif),
+) -> Term {
+    let (authority_keypair_bs58, lookup_table_str, tx_options) = args;
+    
+    // Decode the authority keypair
+    let authority = match decode_keypair(&authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the lookup table address
+    let lookup_table = match parse_pubkey(&lookup_table_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let deactivate_ix = lookup_table_instruction::deactivate_lookup_table(lookup_table, authority.pubkey());
+    
+    // Send the transaction
+    match send_transaction(vec![deactivate_ix], &authority, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn close_lookup_table(
+    env: Env,
+    args: (KeypairNif, String, Option<String>, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.3.reply_options(), move |env| close_lookup_table_impl(env, args))
+}
+
+fn close_lookup_table_impl(
+    env: Env,
+    args: (KeypairNif, String, Option<String>, TransactionOptionsNif),
+) -> Term {
+    let (authority_keypair_bs58, lookup_table_str, recipient_str, tx_options) = args;
+    
+    // Decode the authority keypair
+    let authority = match decode_keypair(&authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys; the rent goes back to the authority unless a recipient is given
+    let lookup_table = match parse_pubkey(&lookup_table_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let recipient = match recipient_str {
+        Some(recipient_str) => match parse_pubkey(&recipient_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => authority.pubkey(),
+    };
+    
+    let close_ix = lookup_table_instruction::close_lookup_table(lookup_table, authority.pubkey(), recipient);
+    
+    // Send the transaction
+    match send_transaction(vec![close_ix], &authority, vec![], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
@@ -0,0 +1,173 @@
+use rustler::{Encoder, Env, Term};
+use solana_sdk::signer::Signer;
+
+use crate::{
+    atoms, convert_metadata_args, decode_keypair, events, instructions, offchain_metadata, parse_leaf_recipient,
+    parse_pubkey, reply,
+    request::MintCompleteRequestNif,
+    send_transaction,
+    transaction::TransactionOptionsNif,
+    upload::{self, UploadSpec},
+    BubblegumError, MetadataArgsNif,
+};
+
+// Length of the id of an Arweave data item, base64url encoded
+const DATA_ITEM_ID_LENGTH: usize = 43;
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn mint_complete(
+    env: Env,
+    args: (MintCompleteRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| mint_complete_impl(env, args))
+}
+
+fn mint_complete_impl(
+    env: Env,
+    args: (MintCompleteRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let MintCompleteRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        merkle_tree: tree_pubkey_str,
+        collection_mint: collection_pubkey_str,
+        metadata: offchain,
+        creators,
+        is_mutable,
+        leaf_owner: leaf_owner_str,
+        leaf_delegate: leaf_delegate_str,
+        tree_creator_or_delegate_keypair: tree_creator_or_delegate_keypair_bs58,
+        collection_authority_keypair: collection_authority_keypair_bs58,
+        collection_authority_record: collection_authority_record_str,
+        provider,
+        node_url,
+        gateway_url,
+        fund,
+    } = request;
+    
+    // Decode the keypairs and parse the accounts
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection_pubkey = match collection_pubkey_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The leaf goes to the payer unless another owner is given
+    let (leaf_owner, leaf_delegate) = match parse_leaf_recipient(&payer, leaf_owner_str, leaf_delegate_str) {
+        Ok(recipient) => recipient,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_creator_or_delegate = match tree_creator_or_delegate_keypair_bs58.as_ref().map(decode_keypair).transpose() {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection_authority = match collection_authority_keypair_bs58.as_ref().map(decode_keypair).transpose() {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let collection_authority_record = match collection_authority_record_str.as_deref().map(parse_pubkey).transpose() {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Both halves of the metadata are checked before anything is paid for. The URI is not known
+    // until the upload, so the on-chain metadata is checked with one of the same length
+    if let Err(e) = offchain_metadata::validate(&offchain) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    let gateway_url = gateway_url.trim_end_matches('/');
+    let mut metadata_args = MetadataArgsNif {
+        name: offchain.name.clone(),
+        symbol: offchain.symbol.clone().unwrap_or_default(),
+        uri: format!("{}/{}", gateway_url, "x".repeat(DATA_ITEM_ID_LENGTH)),
+        seller_fee_basis_points: offchain.seller_fee_basis_points.unwrap_or(0),
+        primary_sale_happened: false,
+        is_mutable,
+        edition_nonce: None,
+        creators,
+        collection: collection_pubkey_str,
+        collection_verified: false,
+        uses: None,
+        token_program_version: None,
+        token_standard: None,
+    };
+    if let Err(e) = convert_metadata_args(&metadata_args) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    let json = match serde_json::to_string_pretty(&offchain) {
+        Ok(json) => json,
+        Err(e) => return (atoms::error(), BubblegumError::SerializationError(e.to_string())).encode(env),
+    };
+    
+    // Upload the JSON, then mint against its URI
+    let spec = UploadSpec {
+        provider,
+        data: json.as_bytes(),
+        content_type: "application/json",
+        tags: &[],
+        node_url: &node_url,
+        gateway_url,
+        fund,
+    };
+    let uploaded = match upload::upload(&payer, &spec, &tx_options) {
+        Ok(uploaded) => uploaded,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    metadata_args.uri = uploaded.uri.clone();
+    let metadata = match convert_metadata_args(&metadata_args) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let mint_ix = match &collection_pubkey {
+        Some(collection_pubkey) => instructions::mint_to_collection_v1(
+            &payer.pubkey(),
+            &tree_pubkey,
+            &leaf_owner,
+            &leaf_delegate,
+            &tree_creator_or_delegate.as_ref().unwrap_or(&payer).pubkey(),
+            &collection_authority.as_ref().unwrap_or(&payer).pubkey(),
+            collection_authority_record,
+            collection_pubkey,
+            metadata,
+        ),
+        None => instructions::mint_v1(&payer.pubkey(), &tree_pubkey, &leaf_owner, &leaf_delegate, metadata),
+    };
+    
+    // mint_v1 takes the payer as tree authority, so only a collection mint has other signers
+    let signers = match collection_pubkey {
+        Some(_) => tree_creator_or_delegate.iter().chain(collection_authority.iter()).collect(),
+        None => Vec::new(),
+    };
+    
+    match send_transaction(vec![mint_ix], &payer, signers, &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("uri".encode(env), uploaded.uri.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("upload_id".encode(env), uploaded.id.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("upload_price".encode(env), uploaded.price.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("funding_signature".encode(env), uploaded.funding_signature.encode(env)).unwrap();
+            let ok_map = outcome.put_into(env, ok_map);
+            let ok_map = events::put_minted_leaf(env, ok_map, &tree_pubkey, &outcome.outcome, &tx_options);
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
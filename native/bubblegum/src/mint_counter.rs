@@ -0,0 +1,98 @@
+use mpl_bubblegum::accounts::TreeConfig;
+use rustler::{Encoder, Env, ResourceArc, Term};
+use solana_sdk::pubkey::Pubkey;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{
+    atoms, parse_pubkey, retry::RetryPolicyNif, rpc::RpcEndpoint, tree::fetch_account_data, tree_config_pda,
+    BubblegumError,
+};
+
+// The leaf index the next mint to a tree gets, counted locally so many mints can be sent
+// without waiting for each other. Every mint takes the tree config's `num_minted` as its nonce
+// and leaf index, and increments it
+pub struct MintCounterResource {
+    tree: Pubkey,
+    next: AtomicU64,
+}
+
+fn fetch_num_minted(
+    rpc_url: &RpcEndpoint,
+    retry: &RetryPolicyNif,
+    tree: &Pubkey,
+) -> Result<u64, BubblegumError> {
+    let address = tree_config_pda(tree);
+    let data = fetch_account_data(rpc_url, retry, &address)?;
+    let tree_config = TreeConfig::from_bytes(&data)
+        .map_err(|e| BubblegumError::SerializationError(format!("tree config {}: {}", address, e)))?;
+    Ok(tree_config.num_minted)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn new_mint_counter(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (tree_pubkey_str, retry, rpc_url) = args;
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let num_minted = match fetch_num_minted(&rpc_url, &retry, &tree_pubkey) {
+        Ok(num_minted) => num_minted,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let resource = ResourceArc::new(MintCounterResource {
+        tree: tree_pubkey,
+        next: AtomicU64::new(num_minted),
+    });
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("mint_counter".encode(env), resource.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("next_leaf_index".encode(env), num_minted.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+// Takes the next leaf index for a mint about to be sent; concurrent callers never get the same one
+#[rustler::nif]
+pub fn next_leaf_index(env: Env, counter: ResourceArc<MintCounterResource>) -> Term {
+    let index = counter.next.fetch_add(1, Ordering::SeqCst);
+    let asset_id = mpl_bubblegum::utils::get_asset_id(&counter.tree, index);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("index".encode(env), index.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("asset_id".encode(env), asset_id.to_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn reconcile_mint_counter(
+    env: Env,
+    args: (ResourceArc<MintCounterResource>, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (counter, retry, rpc_url) = args;
+    
+    let num_minted = match fetch_num_minted(&rpc_url, &retry, &counter.tree) {
+        Ok(num_minted) => num_minted,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Once the mints sent so far are confirmed, the tree config is the truth: indexes taken by
+    // mints that failed are handed out again, and mints made elsewhere are skipped over
+    let previous = counter.next.swap(num_minted, Ordering::SeqCst);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("next_leaf_index".encode(env), num_minted.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("unused".encode(env), previous.saturating_sub(num_minted).encode(env)).unwrap();
+    let ok_map = ok_map.map_put("minted_elsewhere".encode(env), num_minted.saturating_sub(previous).encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
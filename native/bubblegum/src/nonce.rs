@@ -0,0 +1,83 @@
+use rustler::{Encoder, Env, Term};
+use solana_client::{nonce_utils, rpc_client::RpcClient};
+use solana_sdk::{
+    hash::Hash,
+    nonce::State as NonceState,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction,
+};
+
+use crate::{
+    atoms, decode_keypair, keypair::KeypairNif, parse_pubkey, reply, send_transaction, transaction::TransactionOptionsNif,
+    BubblegumError,
+};
+
+// Durable nonce stored in a nonce account, used in place of a recent blockhash
+pub(crate) fn fetch_nonce(client: &RpcClient, nonce_account: &Pubkey) -> Result<Hash, BubblegumError> {
+    let account = nonce_utils::get_account_with_commitment(client, nonce_account, client.commitment())
+        .map_err(|e| BubblegumError::SolanaClientError(e.to_string()))?;
+    
+    nonce_utils::data_from_account(&account)
+        .map(|data| data.blockhash())
+        .map_err(|e| BubblegumError::SerializationError(e.to_string()))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn create_nonce_account(
+    env: Env,
+    args: (KeypairNif, Option<String>, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.2.reply_options(), move |env| create_nonce_account_impl(env, args))
+}
+
+fn create_nonce_account_impl(
+    env: Env,
+    args: (KeypairNif, Option<String>, TransactionOptionsNif),
+) -> Term {
+    let (payer_keypair_bs58, nonce_authority_str, tx_options) = args;
+    
+    // Decode the payer keypair
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The payer is the nonce authority unless another one is given
+    let nonce_authority = match nonce_authority_str {
+        Some(nonce_authority_str) => match parse_pubkey(&nonce_authority_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => payer.pubkey(),
+    };
+    
+    let lamports = match tx_options.rent_exempt_lamports(NonceState::size()) {
+        Ok(lamports) => lamports,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Allocate and initialize the nonce account
+    let nonce_account = Keypair::new();
+    let create_ixs = system_instruction::create_nonce_account(
+        &payer.pubkey(),
+        &nonce_account.pubkey(),
+        &nonce_authority,
+        lamports,
+    );
+    
+    // Send the transaction
+    match send_transaction(create_ixs, &payer, vec![&nonce_account], &tx_options) {
+        Ok(outcome) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = outcome.put_into(env, ok_map);
+            let ok_map = ok_map.map_put("nonce_account".encode(env), nonce_account.pubkey().to_string().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("nonce_authority".encode(env), nonce_authority.to_string().encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
@@ -0,0 +1,182 @@
+use rustler::{Encoder, Env, NifStruct, NifUntaggedEnum, Term};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::{
+    atoms, uri_check, BubblegumError, MAX_NAME_LENGTH, MAX_SELLER_FEE_BASIS_POINTS, MAX_SYMBOL_LENGTH,
+};
+
+// Categories the Metaplex token standard defines for `properties.category`
+const CATEGORIES: [&str; 5] = ["image", "video", "audio", "vr", "html"];
+
+// The JSON a metadata URI points to, as the Metaplex token standard lays it out. The same
+// struct is built from Elixir and parsed from JSON, so both directions share one set of checks
+#[derive(Serialize, Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.OffchainMetadata"]
+pub struct OffchainMetadataNif {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seller_fee_basis_points: Option<u16>,
+    pub image: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<OffchainAttributeNif>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<OffchainPropertiesNif>,
+}
+
+#[derive(Serialize, Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.OffchainAttribute"]
+pub struct OffchainAttributeNif {
+    pub trait_type: String,
+    pub value: AttributeValueNif,
+    // How marketplaces render a numeric value, e.g. "number" or "boost_percentage"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, NifUntaggedEnum)]
+#[serde(untagged)]
+pub enum AttributeValueNif {
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+#[derive(Serialize, Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.OffchainProperties"]
+pub struct OffchainPropertiesNif {
+    #[serde(default)]
+    pub files: Vec<OffchainFileNif>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, NifStruct)]
+#[module = "SolanaBubblegum.Types.OffchainFile"]
+pub struct OffchainFileNif {
+    pub uri: String,
+    // MIME type of the file; `type` in the JSON
+    #[serde(rename = "type", default)]
+    pub mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cdn: Option<bool>,
+}
+
+fn is_supported_uri(uri: &str) -> bool {
+    uri_check::gateway_url(uri).is_ok()
+}
+
+// Checks the metadata against the token standard and the on-chain limits it has to match, and
+// reports every violation at once
+pub(crate) fn validate(metadata: &OffchainMetadataNif) -> Result<(), BubblegumError> {
+    let mut violations = Vec::new();
+    
+    if metadata.name.is_empty() {
+        violations.push(("name", "is empty".to_string()));
+    } else if metadata.name.len() > MAX_NAME_LENGTH {
+        violations.push(("name", format!("is {} bytes, the on-chain name allows at most {}", metadata.name.len(), MAX_NAME_LENGTH)));
+    }
+    
+    if let Some(symbol) = metadata.symbol.as_ref().filter(|symbol| symbol.len() > MAX_SYMBOL_LENGTH) {
+        violations.push(("symbol", format!("is {} bytes, the on-chain symbol allows at most {}", symbol.len(), MAX_SYMBOL_LENGTH)));
+    }
+    
+    if let Some(seller_fee_basis_points) = metadata.seller_fee_basis_points.filter(|fee| *fee > MAX_SELLER_FEE_BASIS_POINTS) {
+        violations.push((
+            "seller_fee_basis_points",
+            format!("is {}, at most {} are allowed", seller_fee_basis_points, MAX_SELLER_FEE_BASIS_POINTS),
+        ));
+    }
+    
+    if metadata.image.is_empty() {
+        violations.push(("image", "is empty".to_string()));
+    } else if !is_supported_uri(&metadata.image) {
+        violations.push(("image", format!("{} is not an http, https, ar or ipfs URI", metadata.image)));
+    }
+    
+    if let Some(animation_url) = metadata.animation_url.as_ref().filter(|url| !is_supported_uri(url)) {
+        violations.push(("animation_url", format!("{} is not an http, https, ar or ipfs URI", animation_url)));
+    }
+    
+    if let Some(external_url) = metadata.external_url.as_ref().filter(|url| !url.starts_with("https://") && !url.starts_with("http://")) {
+        violations.push(("external_url", format!("{} is not an http or https URL", external_url)));
+    }
+    
+    let mut trait_types = HashSet::new();
+    for (index, attribute) in metadata.attributes.iter().enumerate() {
+        if attribute.trait_type.is_empty() {
+            violations.push(("attributes", format!("attribute {} has no trait_type", index)));
+        } else if !trait_types.insert(attribute.trait_type.as_str()) {
+            violations.push(("attributes", format!("trait_type {} appears more than once", attribute.trait_type)));
+        }
+        if matches!(attribute.value, AttributeValueNif::Float(value) if !value.is_finite()) {
+            violations.push(("attributes", format!("attribute {} has a value JSON cannot hold", index)));
+        }
+    }
+    
+    if let Some(properties) = &metadata.properties {
+        for (index, file) in properties.files.iter().enumerate() {
+            if !is_supported_uri(&file.uri) {
+                violations.push(("properties", format!("file {} has no http, https, ar or ipfs URI", index)));
+            }
+            if file.mime_type.is_empty() {
+                violations.push(("properties", format!("file {} has no type", index)));
+            }
+        }
+        
+        if let Some(category) = properties.category.as_ref().filter(|category| !CATEGORIES.contains(&category.as_str())) {
+            violations.push(("properties", format!("category {} is not one of {}", category, CATEGORIES.join(", "))));
+        }
+    }
+    
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(BubblegumError::InvalidMetadata(violations))
+    }
+}
+
+#[rustler::nif]
+pub fn build_offchain_metadata(env: Env, metadata: OffchainMetadataNif) -> Term {
+    if let Err(e) = validate(&metadata) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    let json = match serde_json::to_string_pretty(&metadata) {
+        Ok(json) => json,
+        Err(e) => return (atoms::error(), BubblegumError::SerializationError(e.to_string())).encode(env),
+    };
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("json".encode(env), json.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn validate_offchain_metadata(env: Env, json: String) -> Term {
+    // JSON that does not fit the layout at all, e.g. without a name, fails on its first problem
+    let metadata = match serde_json::from_str::<OffchainMetadataNif>(&json) {
+        Ok(metadata) => metadata,
+        Err(e) => return (atoms::error(), BubblegumError::InvalidMetadata(vec![("json", e.to_string())])).encode(env),
+    };
+    
+    if let Err(e) = validate(&metadata) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("metadata".encode(env), metadata.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
@@ -0,0 +1,111 @@
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+use crate::SPL_ACCOUNT_COMPRESSION_PROGRAM_ID;
+
+// Custom errors of the Bubblegum program, in the order of its error enum starting at 6000
+const BUBBLEGUM_ERRORS: &[&str] = &[
+    "asset_owner_mismatch",
+    "public_key_mismatch",
+    "hashing_mismatch",
+    "unsupported_schema_version",
+    "creator_share_total_must_be_100",
+    "duplicate_creator_address",
+    "creator_did_not_verify",
+    "creator_not_found",
+    "no_creators_present",
+    "creator_hash_mismatch",
+    "data_hash_mismatch",
+    "creators_too_long",
+    "metadata_name_too_long",
+    "metadata_symbol_too_long",
+    "metadata_uri_too_long",
+    "metadata_basis_points_too_high",
+    "tree_authority_incorrect",
+    "insufficient_mint_capacity",
+    "numerical_overflow_error",
+    "incorrect_owner",
+    "collection_cannot_be_verified_in_this_instruction",
+    "collection_not_found",
+    "already_verified",
+    "already_unverified",
+    "update_authority_incorrect",
+    "leaf_authority_must_sign",
+    "collection_must_be_sized",
+    "metadata_mint_mismatch",
+    "invalid_collection_authority",
+    "invalid_delegate_record",
+    "collection_master_edition_account_invalid",
+    "collection_must_be_a_unique_master_edition",
+    "unknown_external_error",
+    "decompression_disabled",
+    "missing_collection_mint_account",
+    "missing_collection_metadata_account",
+    "collection_mismatch",
+    "metadata_immutable",
+    "primary_sale_can_only_be_flipped_to_true",
+    "creator_did_not_unverify",
+    "invalid_token_standard",
+    "invalid_canopy_size",
+];
+
+// Custom errors of the spl-account-compression program, starting at 6000
+const ACCOUNT_COMPRESSION_ERRORS: &[&str] = &[
+    "incorrect_leaf_length",
+    "concurrent_merkle_tree_error",
+    "zero_copy_error",
+    "concurrent_merkle_tree_constants_error",
+    "canopy_length_mismatch",
+    "incorrect_authority",
+    "incorrect_account_owner",
+    "incorrect_account_type",
+    "leaf_index_out_of_bounds",
+];
+
+// spl-account-compression reports every failed tree operation as `concurrent_merkle_tree_error`;
+// the logged message of the underlying tree error tells which one it was
+const CONCURRENT_MERKLE_TREE_ERRORS: &[(&str, &str)] = &[
+    ("invalid root recomputed from proof", "invalid_proof"),
+    ("current leaf value does not match the supplied proof's leaf value", "leaf_contents_modified"),
+    ("tree is full", "tree_full"),
+];
+
+// Anchor numbers the custom errors of a program from this code on
+const ANCHOR_ERROR_CODE_OFFSET: u32 = 6000;
+
+// The innermost program that failed with a custom error, from its
+// `Program <id> failed: custom program error: 0x...` log line
+pub(crate) fn failing_program(logs: &[String]) -> Option<Pubkey> {
+    logs.iter().find_map(|line| {
+        let rest = line.strip_prefix("Program ")?;
+        let (program_id, _) = rest.split_once(" failed: custom program error")?;
+        Pubkey::from_str(program_id).ok()
+    })
+}
+
+// Name of a custom error raised by Bubblegum or spl-account-compression
+pub(crate) fn decode(program_id: &Pubkey, code: u32, logs: &[String]) -> Option<&'static str> {
+    let index = code.checked_sub(ANCHOR_ERROR_CODE_OFFSET)? as usize;
+    
+    if *program_id == mpl_bubblegum::ID {
+        return BUBBLEGUM_ERRORS.get(index).copied();
+    }
+    
+    if *program_id == SPL_ACCOUNT_COMPRESSION_PROGRAM_ID {
+        let name = ACCOUNT_COMPRESSION_ERRORS.get(index).copied()?;
+        if name != "concurrent_merkle_tree_error" {
+            return Some(name);
+        }
+        
+        let tree_error = logs.iter().find_map(|line| {
+            let line = line.to_lowercase();
+            CONCURRENT_MERKLE_TREE_ERRORS
+                .iter()
+                .find(|(message, _)| line.contains(message))
+                .map(|(_, name)| *name)
+        });
+        return Some(tree_error.unwrap_or(name));
+    }
+    
+    None
+}
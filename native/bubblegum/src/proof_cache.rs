@@ -0,0 +1,200 @@
+use rustler::{Encoder, Env, ResourceArc, Term};
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_sdk::pubkey::Pubkey;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+
+use crate::{
+    atoms,
+    das::{self, DasLeaf},
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+    BubblegumError,
+};
+
+// The sequence number follows the account type, header version, buffer size, depth, authority,
+// creation slot and padding of a concurrent Merkle tree account
+const SEQUENCE_NUMBER_OFFSET: usize = 1 + 1 + 4 + 4 + 32 + 8 + 6;
+
+// A leaf fetched from DAS and the sequence number of its tree at the time. Every change to the
+// tree bumps the sequence number, and the proof goes stale with it
+struct CachedLeaf {
+    leaf: DasLeaf,
+    sequence_number: u64,
+}
+
+struct CacheState {
+    capacity: usize,
+    entries: HashMap<String, CachedLeaf>,
+    // Asset ids from least to most recently used
+    order: VecDeque<String>,
+    hits: u64,
+    misses: u64,
+}
+
+impl CacheState {
+    fn touch(&mut self, asset_id: &str) {
+        self.order.retain(|id| id != asset_id);
+        self.order.push_back(asset_id.to_string());
+    }
+    
+    fn remove(&mut self, asset_id: &str) {
+        self.entries.remove(asset_id);
+        self.order.retain(|id| id != asset_id);
+    }
+    
+    fn insert(&mut self, asset_id: &str, cached: CachedLeaf) {
+        self.entries.insert(asset_id.to_string(), cached);
+        self.touch(asset_id);
+        while self.entries.len() > self.capacity {
+            match self.order.pop_front() {
+                Some(evicted) => self.entries.remove(&evicted),
+                None => break,
+            };
+        }
+    }
+}
+
+// Leaves and proofs of recently used assets, kept across calls so repeated transfers of the same
+// assets skip the two DAS requests while their trees are unchanged
+pub struct ProofCacheResource {
+    state: Mutex<CacheState>,
+}
+
+impl ProofCacheResource {
+    // The leaf of an asset from the cache while its tree's sequence number is unchanged, from DAS
+    // otherwise
+    pub(crate) fn fetch_leaf(
+        &self,
+        rpc_url: &RpcEndpoint,
+        asset_id: &str,
+        retry: &RetryPolicyNif,
+    ) -> Result<DasLeaf, BubblegumError> {
+        let cached_tree = {
+            let state = self.state.lock().unwrap();
+            state.entries.get(asset_id).map(|cached| cached.leaf.tree)
+        };
+        
+        if let Some(tree) = cached_tree {
+            let sequence_number = fetch_sequence_number(rpc_url, retry, &tree)?;
+            let mut state = self.state.lock().unwrap();
+            let hit = state
+                .entries
+                .get(asset_id)
+                .filter(|cached| cached.sequence_number == sequence_number)
+                .map(|cached| cached.leaf.clone());
+            
+            if let Some(leaf) = hit {
+                state.hits += 1;
+                state.touch(asset_id);
+                return Ok(leaf);
+            }
+            
+            // The tree changed, so no proof cached for it is current anymore
+            let stale = state
+                .entries
+                .iter()
+                .filter(|(_, cached)| cached.leaf.tree == tree)
+                .map(|(id, _)| id.clone())
+                .collect::<Vec<_>>();
+            for id in stale {
+                state.remove(&id);
+            }
+        }
+        
+        let leaf = das::fetch_leaf(rpc_url, asset_id, retry)?;
+        
+        // Read after the proof, so a change landing in between leaves an older sequence number
+        // in the entry and the next lookup refetches rather than trusting a stale proof
+        let sequence_number = fetch_sequence_number(rpc_url, retry, &leaf.tree)?;
+        
+        let mut state = self.state.lock().unwrap();
+        state.misses += 1;
+        state.insert(
+            asset_id,
+            CachedLeaf {
+                leaf: leaf.clone(),
+                sequence_number,
+            },
+        );
+        
+        Ok(leaf)
+    }
+    
+    // Drops an asset whose leaf was just changed by this process
+    pub(crate) fn invalidate(&self, asset_id: &str) {
+        self.state.lock().unwrap().remove(asset_id);
+    }
+}
+
+// Reads only the sequence number of a tree rather than the whole account, which runs to
+// megabytes for deep trees with large canopies
+fn fetch_sequence_number(
+    rpc_url: &RpcEndpoint,
+    retry: &RetryPolicyNif,
+    tree: &Pubkey,
+) -> Result<u64, BubblegumError> {
+    let config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        data_slice: Some(UiDataSliceConfig {
+            offset: SEQUENCE_NUMBER_OFFSET,
+            length: 8,
+        }),
+        ..RpcAccountInfoConfig::default()
+    };
+    
+    let (account, _attempts) = retry.run(|| {
+        rpc_url.with_failover(|connection| {
+            connection
+                .client
+                .get_account_with_config(tree, config.clone())
+                .map_err(BubblegumError::from_client_error)
+        })
+    })?;
+    
+    match account.value {
+        Some(account) if account.data.len() == 8 => Ok(u64::from_le_bytes(account.data.try_into().unwrap())),
+        _ => Err(BubblegumError::InvalidTreeConfig(format!("{} is not a concurrent merkle tree", tree))),
+    }
+}
+
+#[rustler::nif]
+pub fn new_proof_cache(env: Env, capacity: usize) -> Term {
+    if capacity == 0 {
+        let message = "proof cache capacity must be at least 1".to_string();
+        return (atoms::error(), BubblegumError::InvalidArgument(message)).encode(env);
+    }
+    
+    let resource = ResourceArc::new(ProofCacheResource {
+        state: Mutex::new(CacheState {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            hits: 0,
+            misses: 0,
+        }),
+    });
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("proof_cache".encode(env), resource.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn proof_cache_stats(env: Env, cache: ResourceArc<ProofCacheResource>) -> Term {
+    let state = cache.state.lock().unwrap();
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("hits".encode(env), state.hits.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("misses".encode(env), state.misses.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("size".encode(env), state.entries.len().encode(env)).unwrap();
+    let ok_map = ok_map.map_put("capacity".encode(env), state.capacity.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
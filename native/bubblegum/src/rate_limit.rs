@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use rustler::NifStruct;
+use solana_client::{
+    client_error::Result as ClientResult,
+    rpc_request::RpcRequest,
+    rpc_sender::{RpcSender, RpcTransportStats},
+};
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{telemetry::InstrumentedSender, BubblegumError};
+
+// How many requests a connection may make: `requests_per_second` on average, and up to
+// `burst` at once after it was idle. The burst defaults to one second worth of requests
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.RateLimit"]
+pub struct RateLimitNif {
+    pub requests_per_second: u32,
+    pub burst: Option<u32>,
+}
+
+// Token bucket shared by every request made through a connection
+pub(crate) struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    // Tokens left and when they were last topped up
+    bucket: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: &RateLimitNif) -> Result<Self, BubblegumError> {
+        if limit.requests_per_second == 0 {
+            return Err(BubblegumError::InvalidArgument("A rate limit needs a positive request rate".to_string()));
+        }
+        
+        let burst = limit.burst.unwrap_or(limit.requests_per_second).max(1) as f64;
+        Ok(RateLimiter {
+            requests_per_second: limit.requests_per_second as f64,
+            burst,
+            bucket: Mutex::new((burst, Instant::now())),
+        })
+    }
+    
+    // Blocks until a request may be made, then takes its token
+    pub(crate) fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let (tokens, refilled_at) = *bucket;
+                let now = Instant::now();
+                let tokens = (tokens + now.duration_since(refilled_at).as_secs_f64() * self.requests_per_second)
+                    .min(self.burst);
+                
+                if tokens >= 1.0 {
+                    *bucket = (tokens - 1.0, now);
+                    return;
+                }
+                
+                *bucket = (tokens, now);
+                Duration::from_secs_f64((1.0 - tokens) / self.requests_per_second)
+            };
+            thread::sleep(wait);
+        }
+    }
+}
+
+// Sends the requests of an RpcClient over HTTP once the rate limiter lets them through
+pub(crate) struct RateLimitedSender {
+    sender: InstrumentedSender,
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitedSender {
+    pub(crate) fn new(url: String, http: reqwest::Client, limiter: Arc<RateLimiter>) -> Self {
+        RateLimitedSender {
+            sender: InstrumentedSender::new(url, http),
+            limiter,
+        }
+    }
+}
+
+#[async_trait]
+impl RpcSender for RateLimitedSender {
+    async fn send(&self, request: RpcRequest, params: serde_json::Value) -> ClientResult<serde_json::Value> {
+        // The blocking RpcClient runs each request to completion on the calling thread, so
+        // waiting here holds up only the caller
+        self.limiter.acquire();
+        self.sender.send(request, params).await
+    }
+    
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.sender.get_transport_stats()
+    }
+    
+    fn url(&self) -> String {
+        self.sender.url()
+    }
+}
@@ -0,0 +1,265 @@
+use mpl_bubblegum::{
+    accounts::{TreeConfig, Voucher},
+    types::LeafSchema,
+};
+use rustler::{Encoder, Env, NifStruct, NifUnitEnum, Term};
+use solana_client::{
+    rpc_config::RpcProgramAccountsConfig,
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    address_lookup_table::{instruction as lookup_table_instruction, state::AddressLookupTable},
+    hash::hashv,
+    keccak,
+    pubkey::Pubkey,
+    signer::Signer,
+    slot_hashes::MAX_ENTRIES,
+};
+
+use crate::{
+    atoms, decode_keypair,
+    keypair::KeypairNif,
+    parse_pubkey, reply,
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+    send_transaction,
+    transaction::TransactionOptionsNif,
+    tree::decode_merkle_tree,
+    tree_config_pda, BubblegumError,
+};
+
+// Vouchers are the discriminator, the leaf schema (its tag and six fields), the leaf index, then
+// the tree; every voucher has the same size
+const VOUCHER_SIZE: u64 = 8 + 169 + 4 + 32;
+const VOUCHER_MERKLE_TREE_OFFSET: usize = 8 + 169 + 4;
+
+// A redeemed leaf waiting to be decompressed; cancel_redeem closes it and puts the leaf back
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.Voucher"]
+pub struct VoucherNif {
+    pub address: String,
+    pub asset_id: String,
+    pub leaf_owner: String,
+    pub index: u32,
+    pub nonce: u64,
+    pub lamports: u64,
+}
+
+// Where `reclaim_lookup_table` left a table: deactivated now, still cooling down, or closed
+#[derive(NifUnitEnum)]
+pub enum LookupTableReclaimNif {
+    Deactivated,
+    CoolingDown,
+    Closed,
+}
+
+// Root of a tree whose leaves are all empty, as burning and redeeming leave them
+fn empty_root(max_depth: u32) -> [u8; 32] {
+    (0..max_depth).fold([0u8; 32], |node, _| keccak::hashv(&[&node, &node]).to_bytes())
+}
+
+// The vouchers of a tree, found by their Anchor discriminator and the tree they point to
+fn fetch_vouchers(
+    rpc_url: &RpcEndpoint,
+    retry: &RetryPolicyNif,
+    tree: &Pubkey,
+) -> Result<Vec<VoucherNif>, BubblegumError> {
+    let discriminator = &hashv(&[b"account:Voucher"]).to_bytes()[..8];
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(VOUCHER_SIZE),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, discriminator)),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(VOUCHER_MERKLE_TREE_OFFSET, tree.as_ref())),
+        ]),
+        ..RpcProgramAccountsConfig::default()
+    };
+    
+    let (accounts, _attempts) = retry.run(|| {
+        rpc_url.with_failover(|connection| {
+            connection
+                .client
+                .get_program_accounts_with_config(&mpl_bubblegum::ID, config.clone())
+                .map_err(BubblegumError::from_client_error)
+        })
+    })?;
+    
+    accounts
+        .into_iter()
+        .map(|(address, account)| {
+            let voucher = Voucher::from_bytes(&account.data)
+                .map_err(|e| BubblegumError::SerializationError(format!("voucher {}: {}", address, e)))?;
+            let LeafSchema::V1 { id, owner, nonce, .. } = voucher.leaf_schema;
+            
+            Ok(VoucherNif {
+                address: address.to_string(),
+                asset_id: id.to_string(),
+                leaf_owner: owner.to_string(),
+                index: voucher.index,
+                nonce,
+                lamports: account.lamports,
+            })
+        })
+        .collect()
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn check_tree_empty(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (tree_pubkey_str, retry, rpc_url) = args;
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The tree and its tree config, read together so they describe the same slot
+    let tree_config_address = tree_config_pda(&tree_pubkey);
+    let result = retry.run(|| {
+        rpc_url.with_failover(|connection| {
+            connection
+                .client
+                .get_multiple_accounts(&[tree_pubkey, tree_config_address])
+                .map_err(BubblegumError::from_client_error)
+        })
+    });
+    
+    let (tree_account, tree_config_account) = match result {
+        Ok((accounts, _attempts)) => match accounts.as_slice() {
+            [Some(tree_account), Some(tree_config_account)] => (tree_account.clone(), tree_config_account.clone()),
+            _ => {
+                let message = format!("{} is not a Bubblegum tree", tree_pubkey);
+                return (atoms::error(), BubblegumError::InvalidTreeConfig(message)).encode(env);
+            },
+        },
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let state = match decode_merkle_tree(&tree_pubkey, &tree_account.data) {
+        Ok(state) => state,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_config = match TreeConfig::from_bytes(&tree_config_account.data) {
+        Ok(tree_config) => tree_config,
+        Err(e) => {
+            let message = format!("tree config {}: {}", tree_config_address, e);
+            return (atoms::error(), BubblegumError::SerializationError(message)).encode(env);
+        },
+    };
+    
+    let vouchers = match fetch_vouchers(&rpc_url, &retry, &tree_pubkey) {
+        Ok(vouchers) => vouchers,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // No leaf is left once every minted one was burned or redeemed; redeemed leaves come back
+    // when their voucher is cancelled, so a tree with vouchers is not done with yet
+    let empty = state.root == bs58::encode(empty_root(state.max_depth)).into_string();
+    
+    // Bubblegum is the authority of its trees and has no instruction to close one, so the rent
+    // of the tree and its tree config stays locked even when the tree is empty
+    let locked_lamports = tree_account.lamports + tree_config_account.lamports;
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("tree".encode(env), tree_pubkey_str.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("num_minted".encode(env), tree_config.num_minted.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("empty".encode(env), empty.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("vouchers".encode(env), vouchers.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("locked_lamports".encode(env), locked_lamports.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn reclaim_lookup_table(
+    env: Env,
+    args: (KeypairNif, String, Option<String>, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.3.reply_options(), move |env| reclaim_lookup_table_impl(env, args))
+}
+
+fn reclaim_lookup_table_impl(
+    env: Env,
+    args: (KeypairNif, String, Option<String>, TransactionOptionsNif),
+) -> Term {
+    let (authority_keypair_bs58, lookup_table_str, recipient_str, tx_options) = args;
+    
+    // Decode the authority keypair
+    let authority = match decode_keypair(&authority_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the pubkeys; the rent goes back to the authority unless a recipient is given
+    let lookup_table = match parse_pubkey(&lookup_table_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let recipient = match recipient_str {
+        Some(recipient_str) => match parse_pubkey(&recipient_str) {
+            Ok(pubkey) => pubkey,
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => authority.pubkey(),
+    };
+    
+    // The table and the current slot decide which step is next
+    let result = tx_options.retry.run(|| {
+        tx_options.rpc_url.with_failover(|connection| {
+            let account = connection
+                .client
+                .get_account(&lookup_table)
+                .map_err(BubblegumError::from_client_error)?;
+            let slot = connection.client.get_slot().map_err(BubblegumError::from_client_error)?;
+            Ok((account, slot))
+        })
+    });
+    
+    let (account, slot) = match result {
+        Ok(((account, slot), _attempts)) => (account, slot),
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let deactivation_slot = match AddressLookupTable::deserialize(&account.data) {
+        Ok(table) => table.meta.deactivation_slot,
+        Err(e) => return (atoms::error(), BubblegumError::SerializationError(e.to_string())).encode(env),
+    };
+    
+    // A table can be closed once its deactivation slot has left the slot hashes sysvar
+    let (status, instruction, closable_after_slot) = if deactivation_slot == u64::MAX {
+        let instruction = lookup_table_instruction::deactivate_lookup_table(lookup_table, authority.pubkey());
+        (LookupTableReclaimNif::Deactivated, Some(instruction), Some(slot.saturating_add(MAX_ENTRIES as u64)))
+    } else if slot > deactivation_slot.saturating_add(MAX_ENTRIES as u64) {
+        let instruction = lookup_table_instruction::close_lookup_table(lookup_table, authority.pubkey(), recipient);
+        (LookupTableReclaimNif::Closed, Some(instruction), None)
+    } else {
+        (LookupTableReclaimNif::CoolingDown, None, Some(deactivation_slot.saturating_add(MAX_ENTRIES as u64)))
+    };
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("lookup_table".encode(env), lookup_table_str.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("status".encode(env), status.encode(env)).unwrap();
+    
+    let ok_map = match instruction {
+        Some(instruction) => match send_transaction(vec![instruction], &authority, vec![], &tx_options) {
+            Ok(outcome) => outcome.put_into(env, ok_map),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        None => ok_map,
+    };
+    
+    // Closing returns the whole balance; until then the result says when closing becomes possible
+    let reclaimed_lamports = match status {
+        LookupTableReclaimNif::Closed => account.lamports,
+        _ => 0,
+    };
+    let ok_map = ok_map.map_put("closable_after_slot".encode(env), closable_after_slot.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("reclaimed_lamports".encode(env), reclaimed_lamports.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
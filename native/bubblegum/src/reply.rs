@@ -0,0 +1,234 @@
+use rustler::{Encoder, Env, LocalPid, OwnedEnv, Term};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU8, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex, OnceLock,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{atoms, BubblegumError};
+
+const SENDING: u8 = 1;
+const STOPPED: u8 = 2;
+
+// Whether the work of an operation has started sending and whether it was told to stop. The
+// work checks in before every transaction it sends, and a timeout or `cancel` stops it; the
+// first of the two decides whether the caller hears that nothing was sent or that a
+// transaction may still land
+#[derive(Default)]
+pub(crate) struct Control(AtomicU8);
+
+impl Control {
+    // Stops the work at its next send; true when a transaction may already have gone out
+    fn stop(&self) -> bool {
+        self.0.fetch_or(STOPPED, Ordering::SeqCst) & SENDING != 0
+    }
+    
+    fn start_send(&self) -> Result<(), BubblegumError> {
+        if self.0.fetch_or(SENDING, Ordering::SeqCst) & STOPPED != 0 {
+            return Err(BubblegumError::Cancelled);
+        }
+        Ok(())
+    }
+}
+
+thread_local! {
+    // Control of the operation whose work runs on this thread, if any
+    static CONTROL: RefCell<Option<Arc<Control>>> = const { RefCell::new(None) };
+}
+
+// Control of the operation running on this thread, to hand to the threads its work spawns
+pub(crate) fn current() -> Option<Arc<Control>> {
+    CONTROL.with(|control| control.borrow().clone())
+}
+
+// Runs `work` as part of the operation `control` belongs to
+pub(crate) fn within<T>(control: Option<Arc<Control>>, work: impl FnOnce() -> T) -> T {
+    let outer = CONTROL.with(|current| current.replace(control));
+    let result = work();
+    CONTROL.with(|current| current.replace(outer));
+    result
+}
+
+// Called right before a transaction is sent. Fails with `Cancelled` once the operation timed
+// out or was cancelled, so a given-up operation sends nothing more
+pub(crate) fn before_send() -> Result<(), BubblegumError> {
+    CONTROL.with(|control| match &*control.borrow() {
+        Some(control) => control.start_send(),
+        None => Ok(()),
+    })
+}
+
+// A new unique reference. Rustler 0.29 has no reference type, so this goes through the NIF API
+pub(crate) fn make_ref(env: Env) -> Term {
+    // Safety: enif_make_ref returns a term of the environment it is given
+    unsafe { Term::new(env, rustler_sys::enif_make_ref(env.as_c_arg())) }
+}
+
+// The error a stopped operation settles with
+fn stopped_error(control: &Control, timeout_ms: Option<u64>) -> BubblegumError {
+    match (control.stop(), timeout_ms) {
+        (false, Some(timeout_ms)) => BubblegumError::Timeout(timeout_ms),
+        (true, Some(timeout_ms)) => BubblegumError::TimeoutUnknownOutcome(timeout_ms),
+        (false, None) => BubblegumError::Cancelled,
+        (true, None) => BubblegumError::CancelledUnknownOutcome,
+    }
+}
+
+// An operation running in the background. The work, its timeout and `cancel` race to settle it,
+// and only the first one replies, so the caller gets exactly one result
+struct Operation {
+    pid: LocalPid,
+    settled: AtomicBool,
+    control: Arc<Control>,
+}
+
+impl Operation {
+    fn settle(&self) -> bool {
+        !self.settled.swap(true, Ordering::SeqCst)
+    }
+}
+
+// Operations still running, by their reference in the external term format
+fn operations() -> &'static Mutex<HashMap<Vec<u8>, Arc<Operation>>> {
+    static OPERATIONS: OnceLock<Mutex<HashMap<Vec<u8>, Arc<Operation>>>> = OnceLock::new();
+    OPERATIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// Settles the operation and forgets it; false when something else settled it first
+fn settle(key: &[u8], operation: &Operation) -> bool {
+    operations().lock().unwrap().remove(key);
+    operation.settle()
+}
+
+// How a NIF that sends transactions hands back its result
+#[derive(Clone, Copy)]
+pub(crate) struct ReplyOptions {
+    pub async_reply: bool,
+    pub timeout_ms: Option<u64>,
+}
+
+// Runs a NIF's work in the background and replies to the caller when `async_reply` is set,
+// otherwise on the calling thread; either way, gives up on the work after the timeout, if any
+pub(crate) fn dispatch<'a, F>(env: Env<'a>, options: ReplyOptions, work: F) -> Term<'a>
+where
+    F: for<'b> FnOnce(Env<'b>) -> Term<'b> + Send + 'static,
+{
+    if options.async_reply {
+        spawn(env, options.timeout_ms, work)
+    } else {
+        with_timeout(env, options.timeout_ms, work)
+    }
+}
+
+// Runs a NIF's work on a background thread and returns `{:ok, ref}` right away. Once the work
+// is done the calling process receives `{:bubblegum_result, ref, {:ok, map} | {:error, reason}}`.
+// When `timeout_ms` passes first, the reason is of kind `:timeout` if nothing was sent yet and
+// `:timeout_unknown_outcome` if a transaction was under way. Either way the work sends nothing
+// more and its result is dropped; a request it is blocked on still runs until the HTTP timeout
+pub(crate) fn spawn<'a, F>(env: Env<'a>, timeout_ms: Option<u64>, work: F) -> Term<'a>
+where
+    F: for<'b> FnOnce(Env<'b>) -> Term<'b> + Send + 'static,
+{
+    let pid = env.pid();
+    let reference = make_ref(env);
+    let key = reference.encode(env).to_binary().to_vec();
+    
+    let operation = Arc::new(Operation {
+        pid,
+        settled: AtomicBool::new(false),
+        control: Arc::new(Control::default()),
+    });
+    operations().lock().unwrap().insert(key.clone(), operation.clone());
+    
+    let (done, finished) = mpsc::channel::<()>();
+    if let Some(timeout_ms) = timeout_ms {
+        let mut timeout_env = OwnedEnv::new();
+        let saved_reference = timeout_env.save(reference.encode(env));
+        let operation = operation.clone();
+        let key = key.clone();
+        
+        // Waits for the work and replies in its place if it is not done in time
+        thread::spawn(move || {
+            if finished.recv_timeout(Duration::from_millis(timeout_ms)) == Err(RecvTimeoutError::Timeout)
+                && settle(&key, &operation)
+            {
+                let e = stopped_error(&operation.control, Some(timeout_ms));
+                timeout_env.send_and_clear(&pid, |env| {
+                    (atoms::bubblegum_result(), saved_reference.load(env), (atoms::error(), e)).encode(env)
+                });
+            }
+        });
+    }
+    
+    let mut owned_env = OwnedEnv::new();
+    let saved_reference = owned_env.save(reference.encode(env));
+    
+    thread::spawn(move || {
+        let control = Some(operation.control.clone());
+        let result = within(control, || owned_env.run(|env| owned_env.save(work(env))));
+        let _ = done.send(());
+        
+        if settle(&key, &operation) {
+            owned_env.send_and_clear(&pid, |env| {
+                (atoms::bubblegum_result(), saved_reference.load(env), result.load(env)).encode(env)
+            });
+        }
+    });
+    
+    (atoms::ok(), reference).encode(env)
+}
+
+// Runs a NIF's work on the calling thread, or, with a timeout, on another thread that is given
+// up on once `timeout_ms` passes, with the same errors and the same stop as `spawn`
+fn with_timeout<'a, F>(env: Env<'a>, timeout_ms: Option<u64>, work: F) -> Term<'a>
+where
+    F: for<'b> FnOnce(Env<'b>) -> Term<'b> + Send + 'static,
+{
+    let timeout_ms = match timeout_ms {
+        Some(timeout_ms) => timeout_ms,
+        None => return work(env),
+    };
+    
+    let control = Arc::new(Control::default());
+    let work_control = Some(control.clone());
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let owned_env = OwnedEnv::new();
+        let result = within(work_control, || owned_env.run(|env| owned_env.save(work(env))));
+        let _ = sender.send((owned_env, result));
+    });
+    
+    match receiver.recv_timeout(Duration::from_millis(timeout_ms)) {
+        Ok((owned_env, result)) => owned_env.run(|owned| result.load(owned).in_env(env)),
+        Err(_) => (atoms::error(), stopped_error(&control, Some(timeout_ms))).encode(env),
+    }
+}
+
+// Settles an operation started with `async: true` before its work is done. The caller receives
+// `{:bubblegum_result, ref, {:error, reason}}` in place of the result, with kind `:cancelled`
+// when nothing was sent yet, or `:cancelled_unknown_outcome` when a transaction was under way
+// and may still land. The work sends nothing more either way
+#[rustler::nif]
+pub fn cancel<'a>(env: Env<'a>, reference: Term<'a>) -> Term<'a> {
+    let key = reference.to_binary().to_vec();
+    let operation = operations().lock().unwrap().get(&key).cloned();
+    
+    match operation {
+        Some(operation) if settle(&key, &operation) => {
+            let e = stopped_error(&operation.control, None);
+            let message = (atoms::bubblegum_result(), reference, (atoms::error(), e));
+            env.send(&operation.pid, message.encode(env));
+            atoms::ok().encode(env)
+        },
+        _ => {
+            let e = BubblegumError::InvalidArgument("No operation in progress for this reference".to_string());
+            (atoms::error(), e).encode(env)
+        },
+    }
+}
@@ -0,0 +1,405 @@
+use rustler::{NifStruct, ResourceArc};
+
+use crate::{
+    keypair::KeypairNif, offchain_metadata::OffchainMetadataNif, proof_cache::ProofCacheResource,
+    upload::UploadProviderNif, uri_check::UriCheckOptionsNif, AssetProofNif, CreatorNif, DecompressibleStateNif,
+    MetadataArgsNif, TokenProgramVersionNif, UpdateArgsNif,
+};
+
+// Named arguments of the Bubblegum operations that sign and send a transaction. Keypairs
+// are given in any format `KeypairNif` accepts, every other account is a base58 public key. A missing leaf delegate
+// defaults to the leaf owner.
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.CreateTreeConfigRequest"]
+pub struct CreateTreeConfigRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub canopy_depth: u32,
+    pub public: bool,
+    // Generated, and returned with the result, when missing
+    pub tree_keypair: Option<KeypairNif>,
+    // Owner of the tree, when it is not the payer
+    pub tree_creator_keypair: Option<KeypairNif>,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.MintRequest"]
+pub struct MintRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub metadata: MetadataArgsNif,
+    // Defaults to the payer
+    pub leaf_owner: Option<String>,
+    pub leaf_delegate: Option<String>,
+    // Fetches the metadata URI before minting when set
+    pub validate_uris: Option<UriCheckOptionsNif>,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.MintToCollectionRequest"]
+pub struct MintToCollectionRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub collection_mint: String,
+    pub metadata: MetadataArgsNif,
+    // Defaults to the payer
+    pub leaf_owner: Option<String>,
+    pub leaf_delegate: Option<String>,
+    // Both default to the payer
+    pub tree_creator_or_delegate_keypair: Option<KeypairNif>,
+    pub collection_authority_keypair: Option<KeypairNif>,
+    // Authority record PDA of a delegated collection authority
+    pub collection_authority_record: Option<String>,
+    // Fetches the metadata URI before minting when set
+    pub validate_uris: Option<UriCheckOptionsNif>,
+}
+
+// Mints every NFT of `metadata` to the same collection, packing as many mints as fit in
+// each transaction
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.MintBatchToCollectionRequest"]
+pub struct MintBatchToCollectionRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub collection_mint: String,
+    pub metadata: Vec<MetadataArgsNif>,
+    pub tree_creator_or_delegate_keypair: Option<KeypairNif>,
+    pub collection_authority_keypair: Option<KeypairNif>,
+    pub collection_authority_record: Option<String>,
+}
+
+// Mints every NFT of `metadata` from a pool of `workers` threads, to a collection when
+// `collection_mint` is given
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.BulkMintRequest"]
+pub struct BulkMintRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub collection_mint: Option<String>,
+    pub metadata: Vec<MetadataArgsNif>,
+    pub tree_creator_or_delegate_keypair: Option<KeypairNif>,
+    pub collection_authority_keypair: Option<KeypairNif>,
+    pub collection_authority_record: Option<String>,
+    pub workers: u32,
+    pub max_transactions_per_second: Option<u32>,
+    // Fetches every metadata URI before the job starts when set
+    pub validate_uris: Option<UriCheckOptionsNif>,
+}
+
+// Mints the same `metadata` once to every recipient, through the bulk mint pipeline
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.AirdropRequest"]
+pub struct AirdropRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub collection_mint: Option<String>,
+    pub metadata: MetadataArgsNif,
+    pub recipients: Vec<String>,
+    pub tree_creator_or_delegate_keypair: Option<KeypairNif>,
+    pub collection_authority_keypair: Option<KeypairNif>,
+    pub collection_authority_record: Option<String>,
+    pub workers: u32,
+    pub max_transactions_per_second: Option<u32>,
+    // Fetches the metadata URI before the job starts when set
+    pub validate_uris: Option<UriCheckOptionsNif>,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.TransferRequest"]
+pub struct TransferRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub leaf_owner: String,
+    pub leaf_delegate: Option<String>,
+    pub new_owner: String,
+    pub asset_proof: AssetProofNif,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.BurnRequest"]
+pub struct BurnRequestNif {
+    pub leaf_owner_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub asset_proof: AssetProofNif,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.DelegateRequest"]
+pub struct DelegateRequestNif {
+    pub leaf_owner_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub previous_delegate: Option<String>,
+    pub new_delegate: String,
+    pub asset_proof: AssetProofNif,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.CancelDelegateRequest"]
+pub struct CancelDelegateRequestNif {
+    pub leaf_owner_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub previous_delegate: String,
+    pub asset_proof: AssetProofNif,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.RedeemRequest"]
+pub struct RedeemRequestNif {
+    pub leaf_owner_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub leaf_delegate: Option<String>,
+    pub asset_proof: AssetProofNif,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.CancelRedeemRequest"]
+pub struct CancelRedeemRequestNif {
+    pub leaf_owner_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub asset_proof: AssetProofNif,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.DecompressRequest"]
+pub struct DecompressRequestNif {
+    pub leaf_owner_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub nonce: u64,
+    pub metadata: MetadataArgsNif,
+}
+
+// Used to both verify and unverify a creator
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.VerifyCreatorRequest"]
+pub struct VerifyCreatorRequestNif {
+    pub creator_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub leaf_owner: String,
+    pub leaf_delegate: Option<String>,
+    pub asset_proof: AssetProofNif,
+    pub metadata: MetadataArgsNif,
+}
+
+// Used to verify, unverify, and set and verify a collection
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.VerifyCollectionRequest"]
+pub struct VerifyCollectionRequestNif {
+    pub collection_authority_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub leaf_owner: String,
+    pub leaf_delegate: Option<String>,
+    pub collection_mint: String,
+    pub asset_proof: AssetProofNif,
+    pub metadata: MetadataArgsNif,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.UpdateMetadataRequest"]
+pub struct UpdateMetadataRequestNif {
+    pub authority_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub leaf_owner: String,
+    pub leaf_delegate: Option<String>,
+    pub asset_proof: AssetProofNif,
+    pub current_metadata: MetadataArgsNif,
+    pub update_args: UpdateArgsNif,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.SetTreeDelegateRequest"]
+pub struct SetTreeDelegateRequestNif {
+    pub tree_creator_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub new_delegate: String,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.SetDecompressibleStateRequest"]
+pub struct SetDecompressibleStateRequestNif {
+    pub tree_creator_keypair: KeypairNif,
+    pub merkle_tree: String,
+    pub decompressible_state: DecompressibleStateNif,
+}
+
+// The leaf, proof and owner are fetched from the DAS API by asset id
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.TransferWithDasRequest"]
+pub struct TransferWithDasRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub asset_id: String,
+    pub new_owner: String,
+    pub canopy_depth: Option<u32>,
+    pub proof_cache: Option<ResourceArc<ProofCacheResource>>,
+}
+
+// Transfers every asset of `transfers` to its new owner; leaves and proofs are fetched from DAS
+// and the transactions sent from a pool of `workers` threads
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.TransferBatchRequest"]
+pub struct TransferBatchRequestNif {
+    pub payer_keypair: KeypairNif,
+    // Pairs of asset id and new owner
+    pub transfers: Vec<(String, String)>,
+    pub canopy_depth: Option<u32>,
+    pub workers: u32,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.BurnWithDasRequest"]
+pub struct BurnWithDasRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub asset_id: String,
+    pub canopy_depth: Option<u32>,
+}
+
+// Creates an uncompressed Metaplex Core asset, owned by `owner` or the payer. `authority_keypair`
+// signs for the update authority of `collection` when it is not the payer
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.CoreCreateRequest"]
+pub struct CoreCreateRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub name: String,
+    pub uri: String,
+    pub owner: Option<String>,
+    pub collection: Option<String>,
+    pub update_authority: Option<String>,
+    // Generated when missing
+    pub asset_keypair: Option<KeypairNif>,
+    pub authority_keypair: Option<KeypairNif>,
+}
+
+// `authority_keypair` is the owner or a delegate, when it is not the payer
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.CoreTransferRequest"]
+pub struct CoreTransferRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub asset: String,
+    pub new_owner: String,
+    pub collection: Option<String>,
+    pub authority_keypair: Option<KeypairNif>,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.CoreBurnRequest"]
+pub struct CoreBurnRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub asset: String,
+    pub collection: Option<String>,
+    pub authority_keypair: Option<KeypairNif>,
+}
+
+// Mints a regular, sized collection NFT through Token Metadata
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.CreateCollectionRequest"]
+pub struct CreateCollectionRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Vec<CreatorNif>,
+    pub is_mutable: bool,
+    // Generated when missing
+    pub mint_keypair: Option<KeypairNif>,
+    // Update authority and holder of the collection NFT, when it is not the payer
+    pub update_authority_keypair: Option<KeypairNif>,
+    pub token_program_version: Option<TokenProgramVersionNif>,
+}
+
+// Changes the Token Metadata of a collection NFT; fields left nil keep their current value
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.UpdateCollectionRequest"]
+pub struct UpdateCollectionRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub collection_mint: String,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+    pub seller_fee_basis_points: Option<u16>,
+    pub creators: Option<Vec<CreatorNif>>,
+    pub primary_sale_happened: Option<bool>,
+    // Can only ever be turned off
+    pub is_mutable: Option<bool>,
+    pub new_update_authority: Option<String>,
+    // Current update authority, when it is not the payer
+    pub update_authority_keypair: Option<KeypairNif>,
+}
+
+// Lets another wallet verify NFTs into a collection through a collection authority record
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.ApproveCollectionAuthorityRequest"]
+pub struct ApproveCollectionAuthorityRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub collection_mint: String,
+    pub new_collection_authority: String,
+    // Update authority of the collection, when it is not the payer
+    pub update_authority_keypair: Option<KeypairNif>,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.RevokeCollectionAuthorityRequest"]
+pub struct RevokeCollectionAuthorityRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub collection_mint: String,
+    pub delegate_authority: String,
+    // The update authority or the delegate itself, when it is not the payer
+    pub revoke_authority_keypair: Option<KeypairNif>,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.SetCollectionSizeRequest"]
+pub struct SetCollectionSizeRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub collection_mint: String,
+    pub size: u64,
+    // Update authority or delegated collection authority, when it is not the payer
+    pub collection_authority_keypair: Option<KeypairNif>,
+    // Authority record PDA of a delegated collection authority
+    pub collection_authority_record: Option<String>,
+}
+
+// Stores `data`, usually off-chain metadata JSON, permanently; the payer signs the upload and
+// pays for it
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.UploadRequest"]
+pub struct UploadRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub data: String,
+    pub content_type: String,
+    pub provider: UploadProviderNif,
+    // Node the upload is sent to and gateway the returned URI points at
+    pub node_url: String,
+    pub gateway_url: String,
+    // Tops up the payer's balance on the node from its SOL when it is short
+    pub fund: bool,
+    // Extra tags stored with the data, besides its Content-Type
+    pub tags: Vec<(String, String)>,
+}
+
+// Uploads the off-chain metadata and mints an NFT pointing at it in one call. The on-chain
+// name, symbol and royalty are taken from the off-chain metadata so the two always agree
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.MintCompleteRequest"]
+pub struct MintCompleteRequestNif {
+    pub payer_keypair: KeypairNif,
+    pub merkle_tree: String,
+    // Mints with mint_v1 when missing
+    pub collection_mint: Option<String>,
+    pub metadata: OffchainMetadataNif,
+    pub creators: Vec<CreatorNif>,
+    pub is_mutable: bool,
+    // Defaults to the payer
+    pub leaf_owner: Option<String>,
+    pub leaf_delegate: Option<String>,
+    // Both default to the payer
+    pub tree_creator_or_delegate_keypair: Option<KeypairNif>,
+    pub collection_authority_keypair: Option<KeypairNif>,
+    pub collection_authority_record: Option<String>,
+    // Where the metadata JSON is uploaded, as for `UploadRequestNif`
+    pub provider: UploadProviderNif,
+    pub node_url: String,
+    pub gateway_url: String,
+    pub fund: bool,
+}
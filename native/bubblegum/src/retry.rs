@@ -0,0 +1,119 @@
+use rustler::{NifStruct, NifUnitEnum};
+use std::{
+    cell::Cell,
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    thread,
+    time::Duration,
+};
+
+use crate::BubblegumError;
+
+thread_local! {
+    // Attempt of the innermost retried operation running on this thread, reported with the
+    // requests it makes
+    static ATTEMPT: Cell<u32> = const { Cell::new(1) };
+}
+
+pub(crate) fn current_attempt() -> u32 {
+    ATTEMPT.with(|attempt| attempt.get())
+}
+
+// Failures that can succeed when tried again a little later
+#[derive(NifUnitEnum, Clone, Copy, PartialEq, Eq)]
+pub enum RetryableErrorNif {
+    RateLimited,
+    ServerError,
+    Timeout,
+    BlockhashNotFound,
+    NodeBehind,
+}
+
+// How often and how patiently RPC and DAS calls are retried
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.RetryPolicy"]
+pub struct RetryPolicyNif {
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub jitter: bool,
+    pub retry_on: Vec<RetryableErrorNif>,
+}
+
+// Sorts an error into a retryable class by the message the RPC node or HTTP client gave
+pub(crate) fn classify(error: &BubblegumError) -> Option<RetryableErrorNif> {
+    let message = error.to_string().to_lowercase();
+    let mentions = |patterns: &[&str]| patterns.iter().any(|pattern| message.contains(pattern));
+    
+    if mentions(&["blockhash not found", "blockhashnotfound", "block height exceeded"]) {
+        Some(RetryableErrorNif::BlockhashNotFound)
+    } else if mentions(&["node is behind", "node is unhealthy", "minimum context slot has not been reached"]) {
+        Some(RetryableErrorNif::NodeBehind)
+    } else if mentions(&["429", "too many requests"]) {
+        Some(RetryableErrorNif::RateLimited)
+    } else if mentions(&["500", "502", "503", "504"]) {
+        Some(RetryableErrorNif::ServerError)
+    } else if mentions(&["timed out", "timeout"]) {
+        Some(RetryableErrorNif::Timeout)
+    } else {
+        None
+    }
+}
+
+impl RetryPolicyNif {
+    // For calls nested in an operation that is retried as a whole
+    pub(crate) fn single_attempt() -> Self {
+        RetryPolicyNif {
+            max_attempts: 1,
+            base_delay_ms: 0,
+            max_delay_ms: 0,
+            jitter: false,
+            retry_on: Vec::new(),
+        }
+    }
+    
+    pub(crate) fn is_retryable(&self, error: &BubblegumError) -> bool {
+        classify(error).is_some_and(|class| self.retry_on.contains(&class))
+    }
+    
+    // Exponential backoff: the base delay doubles after every attempt, up to the maximum.
+    // With jitter a random half of it is dropped, so concurrent callers spread out
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay_ms
+            .saturating_mul(1u64 << attempt.saturating_sub(1).min(32))
+            .min(self.max_delay_ms);
+        
+        if !self.jitter || delay == 0 {
+            return Duration::from_millis(delay);
+        }
+        
+        let random = RandomState::new().build_hasher().finish();
+        Duration::from_millis(delay / 2 + random % (delay / 2 + 1))
+    }
+    
+    // Runs `operation` until it succeeds, fails with an error that is not retryable, or runs
+    // out of attempts. Returns the result with the number of attempts it took
+    pub(crate) fn run<T>(
+        &self,
+        mut operation: impl FnMut() -> Result<T, BubblegumError>,
+    ) -> Result<(T, u32), BubblegumError> {
+        let outer_attempt = current_attempt();
+        let mut attempt = 1;
+        let result = loop {
+            ATTEMPT.with(|current| current.set(attempt));
+            match operation() {
+                Ok(value) => break Ok((value, attempt)),
+                Err(e) if attempt < self.max_attempts && self.is_retryable(&e) => {
+                    tracing::warn!(attempt, max_attempts = self.max_attempts, error = %e, "Retrying after a transient failure");
+                    thread::sleep(self.delay(attempt));
+                    attempt += 1;
+                },
+                Err(e) => break Err(e),
+            }
+        };
+        
+        ATTEMPT.with(|current| current.set(outer_attempt));
+        result
+    }
+}
@@ -0,0 +1,475 @@
+use rustler::{Decoder, Encoder, Env, NifResult, ResourceArc, Term};
+use solana_client::rpc_client::{RpcClient, RpcClientConfig};
+use solana_sdk::{commitment_config::CommitmentConfig, hash::Hash};
+use std::{
+    sync::{Arc, Mutex, Weak},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    atoms,
+    http::{HttpClients, HttpOptionsNif},
+    rate_limit::{RateLimitNif, RateLimitedSender, RateLimiter},
+    telemetry::InstrumentedSender,
+    transaction::CommitmentNif,
+    BubblegumError,
+};
+
+// How long an endpoint is skipped after a transient failure, unless a health check clears it sooner
+const DEFAULT_UNHEALTHY_COOLDOWN: Duration = Duration::from_secs(30);
+
+// Target slot time of the cluster, used to turn a refresh interval in slots into a duration
+const SLOT_DURATION: Duration = Duration::from_millis(400);
+
+// A blockhash expires after 150 slots; refreshing no later than this leaves room to land with it
+const MAX_BLOCKHASH_REFRESH_SLOTS: u64 = 100;
+
+// An RPC client and the HTTP client used for DAS requests to the same endpoint. Both keep their
+// connection pools, so repeated calls skip the TCP and TLS handshakes, and share a rate limit
+pub(crate) struct Connection {
+    pub url: String,
+    pub client: Arc<RpcClient>,
+    pub http: reqwest::blocking::Client,
+    // HTTP client the RPC client sends through, kept to rebuild its sender
+    rpc_http: reqwest::Client,
+    blockhash_cache: Option<Arc<BlockhashCache>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+// The latest blockhash, the last block height it is valid at, and when it was fetched. A
+// blockhash stays valid for about 150 slots, so one fetch can serve every transaction sent
+// within the refresh interval
+struct BlockhashCache {
+    latest: Mutex<Option<(Hash, u64, Instant)>>,
+    max_age: Duration,
+}
+
+pub struct RpcClientResource {
+    connection: Connection,
+}
+
+struct PoolMember {
+    connection: Connection,
+    weight: u32,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+struct PoolState {
+    members: Vec<PoolMember>,
+    // Smooth weighted round-robin counters, one per member
+    current_weights: Mutex<Vec<i64>>,
+    unhealthy_cooldown: Duration,
+}
+
+// Several endpoints behind one handle; requests go to a healthy endpoint picked by weight and
+// fail over to the others on rate limits, server errors and timeouts
+pub struct RpcPoolResource {
+    state: Arc<PoolState>,
+}
+
+// Where a NIF sends its requests: a plain URL, which gets fresh clients for the call,
+// a connection returned by `connect`, or a pool returned by `connect_pool`
+pub enum RpcEndpoint {
+    Url(String),
+    Connection(ResourceArc<RpcClientResource>),
+    Pool(ResourceArc<RpcPoolResource>),
+}
+
+impl Connection {
+    fn new(url: String, commitment: CommitmentConfig, http: HttpClients) -> Self {
+        let sender = InstrumentedSender::new(url.clone(), http.rpc.clone());
+        
+        Connection {
+            client: Arc::new(RpcClient::new_sender(sender, RpcClientConfig::with_commitment(commitment))),
+            http: http.das,
+            rpc_http: http.rpc,
+            url,
+            blockhash_cache: None,
+            rate_limiter: None,
+        }
+    }
+    
+    // Holds every RPC and DAS request of the connection to the limit. Has to come before the
+    // blockhash cache, whose refresh thread takes the client as it is then
+    fn with_rate_limit(mut self, limiter: RateLimiter, commitment: CommitmentConfig) -> Self {
+        let limiter = Arc::new(limiter);
+        let sender = RateLimitedSender::new(self.url.clone(), self.rpc_http.clone(), limiter.clone());
+        
+        self.client = Arc::new(RpcClient::new_sender(sender, RpcClientConfig::with_commitment(commitment)));
+        self.rate_limiter = Some(limiter);
+        self
+    }
+    
+    // Waits for the rate limit, if any, before a request the RPC client does not make itself
+    pub(crate) fn throttle(&self) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire();
+        }
+    }
+    
+    // Caches the latest blockhash for `refresh_slots` slots and refreshes it in the background
+    fn with_blockhash_cache(mut self, refresh_slots: u64) -> Self {
+        let interval = SLOT_DURATION * refresh_slots.clamp(1, MAX_BLOCKHASH_REFRESH_SLOTS) as u32;
+        let cache = Arc::new(BlockhashCache {
+            latest: Mutex::new(None),
+            max_age: interval,
+        });
+        
+        spawn_blockhash_refresh(self.client.clone(), Arc::downgrade(&cache), interval);
+        self.blockhash_cache = Some(cache);
+        self
+    }
+    
+    // The cached blockhash and its last valid block height while they are fresh, otherwise newly
+    // fetched ones. The cache holds blockhashes at the connection's commitment, so another
+    // commitment always fetches
+    pub(crate) fn latest_blockhash(&self, commitment: Option<CommitmentConfig>) -> Result<(Hash, u64), BubblegumError> {
+        if let Some(commitment) = commitment.filter(|commitment| *commitment != self.client.commitment()) {
+            return self
+                .client
+                .get_latest_blockhash_with_commitment(commitment)
+                .map_err(|e| BubblegumError::SolanaClientError(e.to_string()));
+        }
+        
+        let cache = match &self.blockhash_cache {
+            Some(cache) => cache,
+            None => return fetch_blockhash(&self.client),
+        };
+        
+        if let Some((blockhash, last_valid_block_height, fetched_at)) = *cache.latest.lock().unwrap() {
+            if fetched_at.elapsed() < cache.max_age {
+                return Ok((blockhash, last_valid_block_height));
+            }
+        }
+        
+        let (blockhash, last_valid_block_height) = fetch_blockhash(&self.client)?;
+        *cache.latest.lock().unwrap() = Some((blockhash, last_valid_block_height, Instant::now()));
+        Ok((blockhash, last_valid_block_height))
+    }
+    
+    // Drops the cached blockhash after the cluster reported it expired
+    pub(crate) fn invalidate_blockhash(&self) {
+        if let Some(cache) = &self.blockhash_cache {
+            *cache.latest.lock().unwrap() = None;
+        }
+    }
+}
+
+fn fetch_blockhash(client: &RpcClient) -> Result<(Hash, u64), BubblegumError> {
+    client
+        .get_latest_blockhash_with_commitment(client.commitment())
+        .map_err(|e| BubblegumError::SolanaClientError(e.to_string()))
+}
+
+// Keeps the cached blockhash fresh until the connection is garbage collected
+fn spawn_blockhash_refresh(client: Arc<RpcClient>, cache: Weak<BlockhashCache>, interval: Duration) {
+    thread::spawn(move || loop {
+        let cache = match cache.upgrade() {
+            Some(cache) => cache,
+            None => return,
+        };
+        
+        // A failed refresh leaves the old entry to expire, and the next caller fetches again
+        if let Ok((blockhash, last_valid_block_height)) = fetch_blockhash(&client) {
+            *cache.latest.lock().unwrap() = Some((blockhash, last_valid_block_height, Instant::now()));
+        }
+        
+        drop(cache);
+        thread::sleep(interval);
+    });
+}
+
+impl PoolMember {
+    fn is_healthy(&self) -> bool {
+        match *self.unhealthy_until.lock().unwrap() {
+            Some(until) => Instant::now() >= until,
+            None => true,
+        }
+    }
+    
+    fn mark_unhealthy(&self, cooldown: Duration) {
+        *self.unhealthy_until.lock().unwrap() = Some(Instant::now() + cooldown);
+    }
+    
+    fn mark_healthy(&self) {
+        *self.unhealthy_until.lock().unwrap() = None;
+    }
+}
+
+impl PoolState {
+    // Order in which to try the members: the weighted pick first, then the other healthy
+    // members by weight, then the unhealthy ones as a last resort
+    fn attempt_order(&self) -> Vec<usize> {
+        let healthy: Vec<usize> = (0..self.members.len()).filter(|&i| self.members[i].is_healthy()).collect();
+        
+        let mut order = Vec::with_capacity(self.members.len());
+        if let Some(first) = self.pick(&healthy) {
+            order.push(first);
+        }
+        
+        let mut rest: Vec<usize> = healthy.into_iter().filter(|i| !order.contains(i)).collect();
+        rest.sort_by_key(|&i| std::cmp::Reverse(self.members[i].weight));
+        order.extend(rest);
+        
+        let unhealthy: Vec<usize> = (0..self.members.len()).filter(|i| !order.contains(i)).collect();
+        order.extend(unhealthy);
+        order
+    }
+    
+    fn pick(&self, candidates: &[usize]) -> Option<usize> {
+        let mut current_weights = self.current_weights.lock().unwrap();
+        let total: i64 = candidates.iter().map(|&i| self.members[i].weight as i64).sum();
+        
+        let mut best: Option<usize> = None;
+        for &i in candidates {
+            current_weights[i] += self.members[i].weight as i64;
+            if best.is_none_or(|b| current_weights[i] > current_weights[b]) {
+                best = Some(i);
+            }
+        }
+        if let Some(b) = best {
+            current_weights[b] -= total;
+        }
+        best
+    }
+    
+    fn preferred(&self) -> &Connection {
+        &self.members[self.attempt_order()[0]].connection
+    }
+}
+
+// Rate limits, server errors and network failures are worth trying on another endpoint;
+// anything else, such as a failing program, would fail there too
+pub(crate) fn is_transient(error: &BubblegumError) -> bool {
+    let message = error.to_string().to_lowercase();
+    ["429", "too many requests", "500", "502", "503", "504", "timed out", "timeout", "connection"]
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+impl RpcEndpoint {
+    // Client for calls that are not retried; a pool hands out its preferred healthy endpoint
+    pub(crate) fn client(&self) -> Arc<RpcClient> {
+        match self {
+            RpcEndpoint::Url(url) => Connection::new(url.clone(), CommitmentConfig::confirmed(), HttpClients::default()).client,
+            RpcEndpoint::Connection(resource) => resource.connection.client.clone(),
+            RpcEndpoint::Pool(pool) => pool.state.preferred().client.clone(),
+        }
+    }
+    
+    // The single endpoint requests go to; a pool has none
+    pub(crate) fn url(&self) -> Option<String> {
+        match self {
+            RpcEndpoint::Url(url) => Some(url.clone()),
+            RpcEndpoint::Connection(resource) => Some(resource.connection.url.clone()),
+            RpcEndpoint::Pool(_) => None,
+        }
+    }
+    
+    pub(crate) fn latest_blockhash(&self, commitment: Option<CommitmentConfig>) -> Result<(Hash, u64), BubblegumError> {
+        self.with_failover(|connection| connection.latest_blockhash(commitment))
+    }
+    
+    pub(crate) fn invalidate_blockhash(&self) {
+        match self {
+            RpcEndpoint::Url(_) => {},
+            RpcEndpoint::Connection(resource) => resource.connection.invalidate_blockhash(),
+            RpcEndpoint::Pool(pool) => {
+                for member in &pool.state.members {
+                    member.connection.invalidate_blockhash();
+                }
+            },
+        }
+    }
+    
+    // Runs `request` against the endpoint. For a pool, transient failures mark the endpoint
+    // unhealthy and the request is retried on the next one
+    pub(crate) fn with_failover<T>(
+        &self,
+        mut request: impl FnMut(&Connection) -> Result<T, BubblegumError>,
+    ) -> Result<T, BubblegumError> {
+        match self {
+            RpcEndpoint::Url(url) => request(&Connection::new(url.clone(), CommitmentConfig::confirmed(), HttpClients::default())),
+            RpcEndpoint::Connection(resource) => request(&resource.connection),
+            RpcEndpoint::Pool(pool) => {
+                let state = &pool.state;
+                let mut last_error = None;
+                
+                for index in state.attempt_order() {
+                    let member = &state.members[index];
+                    match request(&member.connection) {
+                        Err(e) if is_transient(&e) => {
+                            tracing::warn!(endpoint = %member.connection.url, error = %e, "Failing over from an unhealthy RPC endpoint");
+                            member.mark_unhealthy(state.unhealthy_cooldown);
+                            last_error = Some(e);
+                        },
+                        result => return result,
+                    }
+                }
+                
+                Err(last_error.unwrap_or_else(|| {
+                    BubblegumError::SolanaClientError("RPC pool has no endpoints".to_string())
+                }))
+            },
+        }
+    }
+}
+
+impl<'a> Decoder<'a> for RpcEndpoint {
+    fn decode(term: Term<'a>) -> NifResult<Self> {
+        if let Ok(resource) = term.decode::<ResourceArc<RpcClientResource>>() {
+            return Ok(RpcEndpoint::Connection(resource));
+        }
+        if let Ok(pool) = term.decode::<ResourceArc<RpcPoolResource>>() {
+            return Ok(RpcEndpoint::Pool(pool));
+        }
+        Ok(RpcEndpoint::Url(term.decode()?))
+    }
+}
+
+impl Encoder for RpcEndpoint {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        match self {
+            RpcEndpoint::Url(url) => url.encode(env),
+            RpcEndpoint::Connection(resource) => resource.encode(env),
+            RpcEndpoint::Pool(pool) => pool.encode(env),
+        }
+    }
+}
+
+// Checks every member with `getHealth` until the pool is garbage collected
+fn spawn_health_checks(state: Weak<PoolState>, interval: Duration) {
+    thread::spawn(move || loop {
+        thread::sleep(interval);
+        
+        let state = match state.upgrade() {
+            Some(state) => state,
+            None => return,
+        };
+        
+        for member in &state.members {
+            match member.connection.client.get_health() {
+                Ok(()) => member.mark_healthy(),
+                Err(_) => member.mark_unhealthy(state.unhealthy_cooldown),
+            }
+        }
+    });
+}
+
+#[rustler::nif]
+pub fn connect(
+    env: Env,
+    args: (String, Option<CommitmentNif>, Option<u64>, Option<RateLimitNif>, Option<HttpOptionsNif>),
+) -> Term {
+    let (rpc_url, commitment, blockhash_refresh_slots, rate_limit, http_options) = args;
+    
+    let commitment = commitment
+        .map(|commitment| commitment.to_commitment_config())
+        .unwrap_or_else(CommitmentConfig::confirmed);
+    
+    let rate_limiter = match rate_limit.as_ref().map(RateLimiter::new).transpose() {
+        Ok(rate_limiter) => rate_limiter,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let http = match http_options.as_ref().map(HttpClients::new).transpose() {
+        Ok(http) => http.unwrap_or_default(),
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // No request is made yet, besides the blockhash refresh when asked for; the clients connect
+    // on first use and keep their connections open
+    let connection = Connection::new(rpc_url, commitment, http);
+    let connection = match rate_limiter {
+        Some(rate_limiter) => connection.with_rate_limit(rate_limiter, commitment),
+        None => connection,
+    };
+    let connection = match blockhash_refresh_slots {
+        Some(refresh_slots) => connection.with_blockhash_cache(refresh_slots),
+        None => connection,
+    };
+    
+    let resource = ResourceArc::new(RpcClientResource { connection });
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("connection".encode(env), resource.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+// Weighted endpoints, commitment, health check interval, unhealthy cooldown, rate limit and
+// HTTP options of a pool
+type ConnectPoolArgs = (
+    Vec<(String, u32)>,
+    Option<CommitmentNif>,
+    Option<u64>,
+    Option<u64>,
+    Option<RateLimitNif>,
+    Option<HttpOptionsNif>,
+);
+
+#[rustler::nif]
+pub fn connect_pool(
+    env: Env,
+    args: ConnectPoolArgs,
+) -> Term {
+    let (endpoints, commitment, health_check_interval_ms, unhealthy_cooldown_ms, rate_limit, http_options) = args;
+    
+    if endpoints.is_empty() {
+        return (atoms::error(), BubblegumError::InvalidArgument("An RPC pool needs at least one endpoint".to_string())).encode(env);
+    }
+    if endpoints.iter().any(|(_, weight)| *weight == 0) {
+        return (atoms::error(), BubblegumError::InvalidArgument("RPC pool endpoint weights must be positive".to_string())).encode(env);
+    }
+    
+    let commitment = commitment
+        .map(|commitment| commitment.to_commitment_config())
+        .unwrap_or_else(CommitmentConfig::confirmed);
+    
+    // Every endpoint gets a rate limit of its own, since providers throttle independently.
+    // The HTTP options apply to all of them
+    let mut members: Vec<PoolMember> = Vec::with_capacity(endpoints.len());
+    for (url, weight) in endpoints {
+        let http = match http_options.as_ref().map(HttpClients::new).transpose() {
+            Ok(http) => http.unwrap_or_default(),
+            Err(e) => return (atoms::error(), e).encode(env),
+        };
+        
+        let connection = match &rate_limit {
+            Some(rate_limit) => match RateLimiter::new(rate_limit) {
+                Ok(rate_limiter) => Connection::new(url, commitment, http).with_rate_limit(rate_limiter, commitment),
+                Err(e) => return (atoms::error(), e).encode(env),
+            },
+            None => Connection::new(url, commitment, http),
+        };
+        
+        members.push(PoolMember {
+            connection,
+            weight,
+            unhealthy_until: Mutex::new(None),
+        });
+    }
+    
+    let state = Arc::new(PoolState {
+        current_weights: Mutex::new(vec![0; members.len()]),
+        members,
+        unhealthy_cooldown: unhealthy_cooldown_ms
+            .map(Duration::from_millis)
+            .unwrap_or(DEFAULT_UNHEALTHY_COOLDOWN),
+    });
+    
+    // Active health checks are optional; without them endpoints recover after the cooldown
+    if let Some(interval_ms) = health_check_interval_ms {
+        spawn_health_checks(Arc::downgrade(&state), Duration::from_millis(interval_ms.max(1)));
+    }
+    
+    let pool = ResourceArc::new(RpcPoolResource { state });
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("pool".encode(env), pool.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
@@ -0,0 +1,102 @@
+use rustler::{Encoder, Env, NifResult, ResourceArc, Term};
+use solana_sdk::signer::Signer;
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+use zeroize::Zeroizing;
+
+use crate::{
+    atoms,
+    keypair::{KeypairNif, SecretKeyResource},
+    parse_keypair, BubblegumError,
+};
+
+// Keypairs registered by name, shared by every process of the node. Dropping an entry
+// wipes its key once no call is still signing with it
+static SIGNERS: OnceLock<RwLock<HashMap<String, ResourceArc<SecretKeyResource>>>> = OnceLock::new();
+
+fn signers() -> &'static RwLock<HashMap<String, ResourceArc<SecretKeyResource>>> {
+    SIGNERS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+// Signer names are atoms or strings; `:treasury` and `"treasury"` name the same signer
+pub(crate) fn decode_name(term: Term) -> NifResult<String> {
+    if term.is_atom() {
+        return term.atom_to_string();
+    }
+    term.decode::<String>()
+}
+
+// The keypair bytes of a registered signer
+pub(crate) fn registered(name: &str) -> Result<Zeroizing<Vec<u8>>, BubblegumError> {
+    let signers = signers().read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    signers
+        .get(name)
+        .map(|resource| resource.secret_bytes())
+        .ok_or_else(|| BubblegumError::InvalidKeypair(format!("No signer registered as {}", name)))
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn register_signer<'a>(
+    env: Env<'a>,
+    args: (Term<'a>, KeypairNif),
+) -> Term<'a> {
+    let (name_term, keypair) = args;
+    
+    let name = match decode_name(name_term) {
+        Ok(name) => name,
+        Err(_) => return (atoms::error(), BubblegumError::InvalidArgument("Signer name must be an atom or a string".to_string())).encode(env),
+    };
+    
+    // Resolved once here, so every call that signs as this signer skips decoding and decryption
+    let keypair = match keypair.secret_bytes().and_then(|bytes| parse_keypair(&bytes)) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    let pubkey = keypair.pubkey().to_string();
+    
+    // Registering a name again replaces its keypair
+    let resource = ResourceArc::new(SecretKeyResource::new(&keypair));
+    signers()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(name, resource);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("pubkey".encode(env), pubkey.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn unregister_signer<'a>(
+    env: Env<'a>,
+    name_term: Term<'a>,
+) -> Term<'a> {
+    let name = match decode_name(name_term) {
+        Ok(name) => name,
+        Err(_) => return (atoms::error(), BubblegumError::InvalidArgument("Signer name must be an atom or a string".to_string())).encode(env),
+    };
+    
+    signers()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(&name);
+    
+    atoms::ok().encode(env)
+}
+
+#[rustler::nif]
+pub fn list_signers(env: Env) -> Term {
+    let signers = signers().read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    
+    // Names with the public keys they sign as; the keys themselves never leave the registry
+    let mut listed = Term::map_new(env);
+    for (name, resource) in signers.iter() {
+        listed = listed.map_put(name.encode(env), resource.pubkey.to_string().encode(env)).unwrap();
+    }
+    
+    (atoms::ok(), listed).encode(env)
+}
@@ -0,0 +1,113 @@
+use rustler::{Encoder, Env, NifStruct, Term};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::{
+    atoms,
+    das::{self, AssetListNif},
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+};
+
+// DAS caps every page at 1000 items
+const PAGE_LIMIT: u32 = 1000;
+
+// How much of a collection a snapshot covers and which assets count
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.SnapshotOptions"]
+pub struct SnapshotOptionsNif {
+    // Cursor a previous, incomplete snapshot stopped at
+    pub cursor: Option<String>,
+    // Pages to fetch before returning; nil fetches the whole collection
+    pub max_pages: Option<u32>,
+    // Only count assets in this Merkle tree
+    pub tree: Option<String>,
+    pub include_burnt: bool,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.Holder"]
+pub struct HolderNif {
+    pub owner: String,
+    pub count: u64,
+}
+
+// Holders of the pages fetched, largest first. `cursor` is where to resume when `complete` is false
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.HolderSnapshot"]
+pub struct HolderSnapshotNif {
+    pub holders: Vec<HolderNif>,
+    pub assets: u64,
+    pub pages: u32,
+    pub cursor: Option<String>,
+    pub complete: bool,
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn snapshot_holders(
+    env: Env,
+    args: (String, SnapshotOptionsNif, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (collection_mint, options, retry, rpc_url) = args;
+    
+    // Without a page number DAS pages by cursor, which only moves forward in id order, so a
+    // snapshot can be resumed where it stopped
+    let mut params = json!({
+        "groupKey": "collection",
+        "groupValue": collection_mint,
+        "limit": PAGE_LIMIT,
+        "sortBy": { "sortBy": "id", "sortDirection": "asc" },
+    });
+    
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    let mut assets = 0;
+    let mut pages = 0;
+    let mut cursor = options.cursor;
+    let mut complete = false;
+    
+    while options.max_pages.is_none_or(|max_pages| pages < max_pages) {
+        if let Some(cursor) = &cursor {
+            params["cursor"] = json!(cursor);
+        }
+        
+        let list: AssetListNif = match das::das_request(&rpc_url, "getAssetsByGroup", params.clone(), &retry) {
+            Ok(list) => list,
+            Err(e) => return (atoms::error(), e).encode(env),
+        };
+        pages += 1;
+        
+        let page_len = list.items.len();
+        let counted = list
+            .items
+            .into_iter()
+            .filter(|asset| options.include_burnt || !asset.burnt)
+            .filter(|asset| options.tree.as_ref().is_none_or(|tree| asset.compression.tree == *tree));
+        for asset in counted {
+            *counts.entry(asset.ownership.owner).or_insert(0) += 1;
+            assets += 1;
+        }
+        
+        cursor = list.cursor;
+        if page_len < PAGE_LIMIT as usize || cursor.is_none() {
+            complete = true;
+            cursor = None;
+            break;
+        }
+    }
+    
+    let mut holders = counts
+        .into_iter()
+        .map(|(owner, count)| HolderNif { owner, count })
+        .collect::<Vec<_>>();
+    holders.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.owner.cmp(&b.owner)));
+    
+    let snapshot = HolderSnapshotNif {
+        holders,
+        assets,
+        pages,
+        cursor,
+        complete,
+    };
+    
+    (atoms::ok(), snapshot).encode(env)
+}
@@ -0,0 +1,351 @@
+use rustler::{env::SavedTerm, Encoder, Env, LocalPid, NifStruct, OwnedEnv, ResourceArc, Term};
+use solana_client::{
+    pubsub_client::PubsubClient,
+    rpc_client::RpcClient,
+    rpc_config::{RpcSignatureSubscribeConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter},
+    rpc_response::RpcSignatureResult,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use std::{
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    atoms,
+    events::{fetch_transaction_events, ChangeLogEventNif, LeafSchemaEventNif},
+    parse_pubkey, reply,
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+    BubblegumError,
+};
+
+// How often the subscription thread checks whether it was stopped while no logs arrive
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// How often await_finalized checks the signature status when it cannot use the websocket
+const FINALIZATION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// Reconnect delays after the websocket drops or cannot connect, doubling up to the maximum
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+// The compression events of one transaction that touched a subscribed tree
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.TreeEvent"]
+pub struct TreeEventNif {
+    pub signature: String,
+    pub slot: u64,
+    pub leaf_schema_events: Vec<LeafSchemaEventNif>,
+    pub change_log_events: Vec<ChangeLogEventNif>,
+}
+
+// A running tree subscription; it stops once unsubscribed or garbage collected
+pub struct TreeSubscriptionResource {
+    stopped: Arc<AtomicBool>,
+}
+
+impl Drop for TreeSubscriptionResource {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+// Where the subscription delivers `{:bubblegum_tree_event, ref, event}` and
+// `{:bubblegum_tree_error, ref, error}` messages
+struct Owner {
+    pid: LocalPid,
+    reference_env: OwnedEnv,
+    reference: SavedTerm,
+}
+
+impl Owner {
+    fn send<F>(&self, message: F)
+    where
+        F: for<'a> FnOnce(Env<'a>, Term<'a>) -> Term<'a>,
+    {
+        let mut message_env = OwnedEnv::new();
+        message_env.send_and_clear(&self.pid, |env| {
+            let reference = self.reference_env.run(|reference_env| self.reference.load(reference_env).in_env(env));
+            message(env, reference)
+        });
+    }
+    
+    fn send_error(&self, error: BubblegumError) {
+        self.send(|env, reference| (atoms::bubblegum_tree_error(), reference, error).encode(env));
+    }
+}
+
+// The websocket endpoint next to an RPC endpoint, the way the Solana CLI derives it: the same
+// host over ws(s), with the local validator's RPC port 8899 moved to its websocket port 8900
+fn websocket_url(rpc_url: &str) -> String {
+    let url = if let Some(rest) = rpc_url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        rpc_url.to_string()
+    };
+    url.replace(":8899", ":8900")
+}
+
+// Sleeps for `delay`, waking early when the subscription is stopped
+fn sleep_unless_stopped(stopped: &AtomicBool, delay: Duration) {
+    let deadline = Instant::now() + delay;
+    while !stopped.load(Ordering::Relaxed) {
+        let now = Instant::now();
+        if now >= deadline {
+            return;
+        }
+        thread::sleep((deadline - now).min(STOP_POLL_INTERVAL));
+    }
+}
+
+// Subscribes to the logs of every transaction mentioning the tree and sends the owner the
+// events of each successful one. A dropped websocket is reconnected and resubscribed
+fn run_subscription(
+    tree: Pubkey,
+    ws_url: String,
+    retry: RetryPolicyNif,
+    rpc_url: RpcEndpoint,
+    owner: Owner,
+    stopped: Arc<AtomicBool>,
+) {
+    let mut reconnect_delay = INITIAL_RECONNECT_DELAY;
+    
+    while !stopped.load(Ordering::Relaxed) {
+        let filter = RpcTransactionLogsFilter::Mentions(vec![tree.to_string()]);
+        let config = RpcTransactionLogsConfig {
+            commitment: Some(CommitmentConfig::confirmed()),
+        };
+        
+        let (mut subscription, receiver) = match PubsubClient::logs_subscribe(&ws_url, filter, config) {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                owner.send_error(BubblegumError::SolanaClientError(e.to_string()));
+                sleep_unless_stopped(&stopped, reconnect_delay);
+                reconnect_delay = (reconnect_delay * 2).min(MAX_RECONNECT_DELAY);
+                continue;
+            },
+        };
+        reconnect_delay = INITIAL_RECONNECT_DELAY;
+        
+        while !stopped.load(Ordering::Relaxed) {
+            let logs = match receiver.recv_timeout(STOP_POLL_INTERVAL) {
+                Ok(response) => response.value,
+                Err(e) if e.is_timeout() => continue,
+                Err(_) => {
+                    owner.send_error(BubblegumError::SolanaClientError("tree subscription disconnected".to_string()));
+                    break;
+                },
+            };
+            
+            // Failed transactions change nothing
+            if logs.err.is_some() {
+                continue;
+            }
+            
+            // The logs only carry the signature; the events are in the transaction's inner instructions
+            let events = Signature::from_str(&logs.signature)
+                .map_err(|e| BubblegumError::InvalidSignature(e.to_string()))
+                .and_then(|signature| fetch_transaction_events(&rpc_url, &retry, &signature));
+            
+            match events {
+                Ok(events) if events.leaf_schema_events.is_empty() && events.change_log_events.is_empty() => {},
+                Ok(events) => {
+                    let event = TreeEventNif {
+                        signature: logs.signature,
+                        slot: events.slot,
+                        leaf_schema_events: events.leaf_schema_events,
+                        change_log_events: events.change_log_events,
+                    };
+                    owner.send(|env, reference| (atoms::bubblegum_tree_event(), reference, event).encode(env));
+                },
+                Err(e) => owner.send_error(e),
+            }
+        }
+        
+        let _ = subscription.shutdown();
+    }
+}
+
+#[rustler::nif]
+pub fn subscribe_tree(
+    env: Env,
+    args: (String, LocalPid, Option<String>, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (tree_pubkey_str, pid, ws_url, retry, rpc_url) = args;
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Without an explicit websocket URL, use the one next to the RPC endpoint
+    let ws_url = match ws_url {
+        Some(ws_url) => ws_url,
+        None => match rpc_url.with_failover(|connection| Ok(connection.url.clone())) {
+            Ok(url) => websocket_url(&url),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+    };
+    
+    let reference = reply::make_ref(env);
+    let reference_env = OwnedEnv::new();
+    let saved_reference = reference_env.save(reference.encode(env));
+    let owner = Owner {
+        pid,
+        reference_env,
+        reference: saved_reference,
+    };
+    
+    let stopped = Arc::new(AtomicBool::new(false));
+    let resource = ResourceArc::new(TreeSubscriptionResource {
+        stopped: stopped.clone(),
+    });
+    
+    thread::spawn(move || run_subscription(tree_pubkey, ws_url, retry, rpc_url, owner, stopped));
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("subscription".encode(env), resource.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("reference".encode(env), reference.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+#[rustler::nif]
+pub fn unsubscribe_tree(
+    env: Env,
+    subscription: ResourceArc<TreeSubscriptionResource>,
+) -> Term {
+    subscription.stopped.store(true, Ordering::Relaxed);
+    atoms::ok().encode(env)
+}
+
+// Slot and error of the transaction once finalized, from its signature status
+fn finalized_status(
+    client: &RpcClient,
+    signature: &Signature,
+) -> Result<Option<(u64, Option<BubblegumError>)>, BubblegumError> {
+    let statuses = client
+        .get_signature_statuses(&[*signature])
+        .map_err(BubblegumError::from_client_error)?;
+    
+    Ok(statuses
+        .value
+        .into_iter()
+        .next()
+        .flatten()
+        .filter(|status| status.satisfies_commitment(CommitmentConfig::finalized()))
+        .map(|status| {
+            let error = status
+                .err
+                .map(|err| BubblegumError::from_transaction_error(&err, err.to_string(), Vec::new()));
+            (status.slot, error)
+        }))
+}
+
+// Waits for the transaction to be finalized, notified over the websocket when it can subscribe
+// and checking the signature status otherwise. None when the timeout passes first
+fn wait_for_finalization(
+    client: &RpcClient,
+    ws_url: &str,
+    signature: &Signature,
+    timeout: Duration,
+) -> Result<Option<(u64, Option<BubblegumError>)>, BubblegumError> {
+    let deadline = Instant::now() + timeout;
+    let config = RpcSignatureSubscribeConfig {
+        commitment: Some(CommitmentConfig::finalized()),
+        enable_received_notification: Some(false),
+    };
+    
+    if let Ok((mut subscription, receiver)) = PubsubClient::signature_subscribe(ws_url, signature, Some(config)) {
+        // The transaction may have been finalized before the subscription started
+        let finalized = match finalized_status(client, signature)? {
+            Some(finalized) => Some(finalized),
+            None => loop {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                match receiver.recv_timeout(remaining) {
+                    Ok(response) => match response.value {
+                        RpcSignatureResult::ProcessedSignature(result) => {
+                            let error = result
+                                .err
+                                .map(|err| BubblegumError::from_transaction_error(&err, err.to_string(), Vec::new()));
+                            break Some((response.context.slot, error));
+                        },
+                        RpcSignatureResult::ReceivedSignature(_) => continue,
+                    },
+                    Err(e) if e.is_timeout() => {
+                        let _ = subscription.shutdown();
+                        return Ok(None);
+                    },
+                    // The websocket dropped; poll for the rest of the timeout
+                    Err(_) => break None,
+                }
+            },
+        };
+        
+        let _ = subscription.shutdown();
+        if finalized.is_some() {
+            return Ok(finalized);
+        }
+    }
+    
+    loop {
+        if let Some(finalized) = finalized_status(client, signature)? {
+            return Ok(Some(finalized));
+        }
+        
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Ok(None);
+        }
+        thread::sleep(FINALIZATION_POLL_INTERVAL.min(remaining));
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn await_finalized(
+    env: Env,
+    args: (String, u64, Option<String>, RpcEndpoint),
+) -> Term {
+    let (signature_str, timeout_ms, ws_url, rpc_url) = args;
+    
+    // A bad signature fails right away, before the wait starts
+    let signature = match Signature::from_str(&signature_str) {
+        Ok(signature) => signature,
+        Err(e) => return (atoms::error(), BubblegumError::InvalidSignature(e.to_string())).encode(env),
+    };
+    
+    // Always waits in the background, until its own timeout; the caller receives
+    // `{:bubblegum_result, ref, {:ok, map} | {:error, reason}}`
+    reply::spawn(env, None, move |env| {
+        let client = rpc_url.client();
+        let ws_url = ws_url.unwrap_or_else(|| websocket_url(client.url().as_str()));
+        
+        match wait_for_finalization(&client, &ws_url, &signature, Duration::from_millis(timeout_ms)) {
+            Ok(finalized) => {
+                let (slot, error) = match finalized {
+                    Some((slot, error)) => (Some(slot), error.map(|e| e.to_nif(env))),
+                    None => (None, None),
+                };
+                
+                let ok_map = Term::map_new(env);
+                
+                let ok_map = ok_map.map_put("signature".encode(env), signature_str.encode(env)).unwrap();
+                let ok_map = ok_map.map_put("finalized".encode(env), slot.is_some().encode(env)).unwrap();
+                let ok_map = ok_map.map_put("slot".encode(env), slot.encode(env)).unwrap();
+                let ok_map = ok_map.map_put("error".encode(env), error.encode(env)).unwrap();
+                
+                (atoms::ok(), ok_map).encode(env)
+            },
+            Err(e) => (atoms::error(), e).encode(env),
+        }
+    })
+}
@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use rustler::{Encoder, Env, LocalPid, NifUnitEnum, OwnedEnv, Term};
+use solana_client::{
+    client_error::Result as ClientResult,
+    rpc_request::RpcRequest,
+    rpc_sender::{RpcSender, RpcTransportStats},
+};
+use solana_rpc_client::http_sender::HttpSender;
+use solana_sdk::instruction::Instruction;
+use std::{
+    sync::{mpsc, Mutex, OnceLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    atoms,
+    error::ErrorKindNif,
+    history,
+    retry::{self, RetryableErrorNif},
+    BubblegumError,
+};
+
+#[derive(NifUnitEnum, Clone, Copy)]
+pub enum TelemetryEventNif {
+    Rpc,
+    Transaction,
+}
+
+// Why a request failed: the retry class of a transient failure, otherwise the kind of the error
+pub(crate) enum ErrorClass {
+    Retryable(RetryableErrorNif),
+    Kind(ErrorKindNif),
+}
+
+impl ErrorClass {
+    pub(crate) fn of(error: &BubblegumError) -> Self {
+        match retry::classify(error) {
+            Some(class) => ErrorClass::Retryable(class),
+            None => ErrorClass::Kind(error.kind()),
+        }
+    }
+}
+
+impl Encoder for ErrorClass {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        match self {
+            ErrorClass::Retryable(class) => class.encode(env),
+            ErrorClass::Kind(kind) => kind.encode(env),
+        }
+    }
+}
+
+// One RPC request or transaction. Transactions sent through a pool have no single endpoint;
+// the RPC events of the requests that sent them name each one
+pub(crate) struct TelemetryEvent {
+    pub event: TelemetryEventNif,
+    pub operation: Option<String>,
+    pub endpoint: Option<String>,
+    pub duration: Duration,
+    pub attempts: u32,
+    pub error: Option<ErrorClass>,
+}
+
+impl Encoder for TelemetryEvent {
+    // {:bubblegum_telemetry, event, measurements, metadata}, with the duration in microseconds
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        let result = match self.error {
+            Some(_) => atoms::error(),
+            None => atoms::ok(),
+        };
+        
+        let duration = self.duration.as_micros() as u64;
+        
+        let measurements = Term::map_new(env);
+        let measurements = measurements.map_put(atoms::duration().encode(env), duration.encode(env)).unwrap();
+        let measurements = measurements.map_put(atoms::attempts().encode(env), self.attempts.encode(env)).unwrap();
+        
+        let metadata = Term::map_new(env);
+        let metadata = metadata.map_put(atoms::operation().encode(env), self.operation.encode(env)).unwrap();
+        let metadata = metadata.map_put(atoms::endpoint().encode(env), self.endpoint.encode(env)).unwrap();
+        let metadata = metadata.map_put(atoms::result().encode(env), result.encode(env)).unwrap();
+        let metadata = metadata.map_put(atoms::error_class().encode(env), self.error.encode(env)).unwrap();
+        
+        (atoms::bubblegum_telemetry(), self.event, measurements, metadata).encode(env)
+    }
+}
+
+// Names a transaction after its first Bubblegum or spl-account-compression instruction, or
+// after the program of its first instruction when it has none
+pub(crate) fn transaction_operation(instructions: &[Instruction]) -> Option<String> {
+    instructions
+        .iter()
+        .find_map(|instruction| history::instruction_name(&instruction.program_id, &instruction.data))
+        .or_else(|| {
+            instructions
+                .first()
+                .and_then(|instruction| history::program_name(&instruction.program_id))
+        })
+        .map(str::to_string)
+}
+
+// Where events go. NIFs run on VM-managed threads, which cannot send messages from outside a
+// process env, so a delivery thread sends them on to the handler
+static HANDLER: OnceLock<Mutex<Option<mpsc::Sender<TelemetryEvent>>>> = OnceLock::new();
+
+fn handler() -> &'static Mutex<Option<mpsc::Sender<TelemetryEvent>>> {
+    HANDLER.get_or_init(|| Mutex::new(None))
+}
+
+// Reports an event to the handler, if one is set; without one this does nothing
+pub(crate) fn emit(event: TelemetryEvent) {
+    let handler = handler().lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(sender) = handler.as_ref() {
+        let _ = sender.send(event);
+    }
+}
+
+fn spawn_delivery(pid: LocalPid, events: mpsc::Receiver<TelemetryEvent>) {
+    thread::spawn(move || {
+        let mut env = OwnedEnv::new();
+        
+        // Ends once the handler is replaced or removed, which drops the sending half
+        for event in events {
+            env.send_and_clear(&pid, |env| event.encode(env));
+        }
+    });
+}
+
+// Sends the requests of an RpcClient over HTTP and reports each one, with the attempt of the
+// retried operation it was made in
+pub(crate) struct InstrumentedSender {
+    sender: HttpSender,
+}
+
+impl InstrumentedSender {
+    pub(crate) fn new(url: String, http: reqwest::Client) -> Self {
+        InstrumentedSender {
+            sender: HttpSender::new_with_client(url, http),
+        }
+    }
+}
+
+#[async_trait]
+impl RpcSender for InstrumentedSender {
+    async fn send(&self, request: RpcRequest, params: serde_json::Value) -> ClientResult<serde_json::Value> {
+        let started = Instant::now();
+        let result = self.sender.send(request, params).await;
+        
+        emit(TelemetryEvent {
+            event: TelemetryEventNif::Rpc,
+            operation: Some(request.to_string()),
+            endpoint: Some(self.sender.url()),
+            duration: started.elapsed(),
+            attempts: retry::current_attempt(),
+            error: result
+                .as_ref()
+                .err()
+                .map(|e| ErrorClass::of(&BubblegumError::SolanaClientError(e.to_string()))),
+        });
+        
+        result
+    }
+    
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.sender.get_transport_stats()
+    }
+    
+    fn url(&self) -> String {
+        self.sender.url()
+    }
+}
+
+#[rustler::nif]
+pub fn set_telemetry_handler(
+    env: Env,
+    pid: Option<LocalPid>,
+) -> Term {
+    // Replacing the sender ends the delivery thread of the previous handler
+    let sender = pid.map(|pid| {
+        let (sender, events) = mpsc::channel();
+        spawn_delivery(pid, events);
+        sender
+    });
+    
+    *handler().lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = sender;
+    
+    atoms::ok().encode(env)
+}
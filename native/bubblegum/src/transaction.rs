@@ -0,0 +1,773 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use rustler::{Binary, Encoder, Env, NifStruct, NifUnitEnum, Term};
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcSendTransactionConfig, RpcSimulateTransactionConfig},
+};
+use solana_sdk::{
+    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
+    commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::{v0, Message, VersionedMessage},
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::Signature,
+    system_instruction,
+    transaction::VersionedTransaction,
+};
+use std::{
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    atoms,
+    build::{AccountMetaNif, InstructionNif},
+    fees::PriorityFeeNif,
+    history::{self, InstructionSummaryNif},
+    instructions,
+    nonce::fetch_nonce,
+    parse_hash, parse_pubkey,
+    reply::{self, ReplyOptions},
+    retry::RetryPolicyNif,
+    rpc::RpcEndpoint,
+    BubblegumError,
+};
+
+// How long confirm_signature waits between signature status checks
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Compute units the runtime grants each instruction of a transaction without a compute unit
+// limit, and the most a transaction may use
+const DEFAULT_INSTRUCTION_COMPUTE_UNITS: u32 = 200_000;
+const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+
+// Base fee of every signature a transaction carries
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+#[derive(NifUnitEnum)]
+pub enum CommitmentNif {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.SendOptions"]
+pub struct SendOptionsNif {
+    pub skip_preflight: bool,
+    pub preflight_commitment: Option<CommitmentNif>,
+    pub max_retries: Option<usize>,
+    pub min_context_slot: Option<u64>,
+    pub confirm: bool,
+    pub commitment: Option<CommitmentNif>,
+}
+
+// Per-call settings shared by every NIF that signs and sends a transaction
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.TransactionOptions"]
+pub struct TransactionOptionsNif {
+    pub rpc_url: RpcEndpoint,
+    pub compute_unit_limit: Option<u32>,
+    pub compute_unit_price_micro_lamports: Option<u64>,
+    pub priority_fee: Option<PriorityFeeNif>,
+    pub priority_fee_percentile: Option<u8>,
+    pub priority_fee_url: Option<String>,
+    pub address_lookup_tables: Vec<String>,
+    pub simulate_only: bool,
+    pub dry_run: bool,
+    pub idempotency_key: Option<String>,
+    pub memo: Option<String>,
+    pub send_options: SendOptionsNif,
+    pub async_reply: bool,
+    // Milliseconds before the call gives up on its work, whether it replies async or not
+    pub timeout_ms: Option<u64>,
+    pub retry: RetryPolicyNif,
+}
+
+// What the cluster reported when simulating a transaction
+pub(crate) struct Simulation {
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+    pub error: Option<String>,
+}
+
+// A transaction is either sent, or only simulated when `simulate_only` is set
+pub(crate) enum TransactionOutcome {
+    Sent(Signature),
+    Simulated(Simulation),
+    Planned(Plan),
+}
+
+// What a dry run would have sent, worked out without contacting the cluster
+pub(crate) struct Plan {
+    pub message: VersionedMessage,
+    pub signers: Vec<Pubkey>,
+    pub compute_units: u32,
+    pub compute_unit_price: Option<u64>,
+}
+
+// The outcome of `send_transaction` and how many attempts it took to get there
+pub(crate) struct SendResult {
+    pub outcome: TransactionOutcome,
+    pub attempts: u32,
+    // Whether an earlier transaction with the same idempotency key was returned instead
+    pub duplicate: bool,
+}
+
+impl CommitmentNif {
+    pub(crate) fn to_commitment_config(&self) -> CommitmentConfig {
+        match self {
+            CommitmentNif::Processed => CommitmentConfig::processed(),
+            CommitmentNif::Confirmed => CommitmentConfig::confirmed(),
+            CommitmentNif::Finalized => CommitmentConfig::finalized(),
+        }
+    }
+}
+
+impl SendOptionsNif {
+    // Commitment the blockhash is fetched and the transaction confirmed at; the connection's
+    // own commitment unless the call asks for another
+    pub(crate) fn commitment_config(&self, client: &RpcClient) -> CommitmentConfig {
+        self.commitment
+            .as_ref()
+            .map(|commitment| commitment.to_commitment_config())
+            .unwrap_or_else(|| client.commitment())
+    }
+    
+    pub(crate) fn to_config(&self) -> RpcSendTransactionConfig {
+        RpcSendTransactionConfig {
+            skip_preflight: self.skip_preflight,
+            preflight_commitment: self
+                .preflight_commitment
+                .as_ref()
+                .map(|commitment| commitment.to_commitment_config().commitment),
+            max_retries: self.max_retries,
+            min_context_slot: self.min_context_slot,
+            ..RpcSendTransactionConfig::default()
+        }
+    }
+}
+
+impl Simulation {
+    pub(crate) fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        let map = Term::map_new(env);
+        
+        let map = map.map_put("logs".encode(env), self.logs.encode(env)).unwrap();
+        let map = map.map_put("units_consumed".encode(env), self.units_consumed.encode(env)).unwrap();
+        map.map_put("error".encode(env), self.error.encode(env)).unwrap()
+    }
+}
+
+impl TransactionOutcome {
+    // Adds the signature, or the simulation report, to a NIF's success map
+    pub(crate) fn put_into<'a>(&self, env: Env<'a>, map: Term<'a>) -> Term<'a> {
+        match self {
+            TransactionOutcome::Sent(signature) => {
+                map.map_put("signature".encode(env), signature.to_string().encode(env)).unwrap()
+            },
+            TransactionOutcome::Simulated(simulation) => {
+                map.map_put("simulation".encode(env), simulation.encode(env)).unwrap()
+            },
+            TransactionOutcome::Planned(plan) => map.map_put("plan".encode(env), plan.encode(env)).unwrap(),
+        }
+    }
+}
+
+impl Plan {
+    // The base fee covers every signature; the priority fee is the compute unit price for
+    // every unit the transaction may use, rounded up to whole lamports
+    fn fees(&self) -> (u64, u64) {
+        let base_fee = LAMPORTS_PER_SIGNATURE * self.message.header().num_required_signatures as u64;
+        let priority_fee = self
+            .compute_unit_price
+            .map(|price| priority_fee_lamports(price, self.compute_units))
+            .unwrap_or(0);
+        (base_fee, priority_fee)
+    }
+    
+    pub(crate) fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        let account_keys = self.message.static_account_keys();
+        let header = self.message.header();
+        let (base_fee, priority_fee) = self.fees();
+        
+        let instructions: Vec<InstructionSummaryNif> = self
+            .message
+            .instructions()
+            .iter()
+            .enumerate()
+            .map(|(index, instruction)| {
+                history::summarize(
+                    env,
+                    account_keys,
+                    index as u8,
+                    false,
+                    instruction.program_id_index,
+                    &instruction.accounts,
+                    &instruction.data,
+                )
+            })
+            .collect();
+        let accounts: Vec<AccountMetaNif> = account_keys
+            .iter()
+            .enumerate()
+            .map(|(index, pubkey)| AccountMetaNif {
+                pubkey: pubkey.to_string(),
+                is_signer: index < header.num_required_signatures as usize,
+                is_writable: self.message.is_maybe_writable(index),
+            })
+            .collect();
+        let signers: Vec<String> = self.signers.iter().map(|signer| signer.to_string()).collect();
+        
+        let map = Term::map_new(env);
+        
+        let map = map.map_put("instructions".encode(env), instructions.encode(env)).unwrap();
+        let map = map.map_put("accounts".encode(env), accounts.encode(env)).unwrap();
+        let map = map.map_put("signers".encode(env), signers.encode(env)).unwrap();
+        let map = map.map_put("compute_units".encode(env), self.compute_units.encode(env)).unwrap();
+        let map = map.map_put("compute_unit_price_micro_lamports".encode(env), self.compute_unit_price.encode(env)).unwrap();
+        let map = map.map_put("base_fee_lamports".encode(env), base_fee.encode(env)).unwrap();
+        let map = map.map_put("priority_fee_lamports".encode(env), priority_fee.encode(env)).unwrap();
+        map.map_put("fee_lamports".encode(env), (base_fee + priority_fee).encode(env)).unwrap()
+    }
+}
+
+impl TransactionOptionsNif {
+    pub(crate) fn reply_options(&self) -> ReplyOptions {
+        ReplyOptions {
+            async_reply: self.async_reply,
+            timeout_ms: self.timeout_ms,
+        }
+    }
+    
+    // Rent-exempt balance of an account; a dry run uses the default rent instead of asking the cluster
+    pub(crate) fn rent_exempt_lamports(&self, account_size: usize) -> Result<u64, BubblegumError> {
+        if self.dry_run {
+            return Ok(Rent::default().minimum_balance(account_size));
+        }
+        
+        self.rpc_url
+            .client()
+            .get_minimum_balance_for_rent_exemption(account_size)
+            .map_err(|e| BubblegumError::SolanaClientError(e.to_string()))
+    }
+}
+
+impl SendResult {
+    // Adds the outcome and the attempt count to a NIF's success map
+    pub(crate) fn put_into<'a>(&self, env: Env<'a>, map: Term<'a>) -> Term<'a> {
+        let map = self.outcome.put_into(env, map);
+        let map = map.map_put("attempts".encode(env), self.attempts.encode(env)).unwrap();
+        match self.duplicate {
+            true => map.map_put("duplicate".encode(env), true.encode(env)).unwrap(),
+            false => map,
+        }
+    }
+}
+
+// Simulates a transaction; a failing transaction is still a successful simulation, with `error` set
+pub(crate) fn simulate(
+    client: &RpcClient,
+    transaction: &VersionedTransaction,
+    sig_verify: bool,
+    replace_recent_blockhash: bool,
+) -> Result<Simulation, BubblegumError> {
+    let config = RpcSimulateTransactionConfig {
+        sig_verify,
+        replace_recent_blockhash,
+        commitment: Some(CommitmentConfig::confirmed()),
+        ..RpcSimulateTransactionConfig::default()
+    };
+    
+    let result = client
+        .simulate_transaction_with_config(transaction, config)
+        .map_err(|e| BubblegumError::SolanaClientError(e.to_string()))?
+        .value;
+    
+    Ok(Simulation {
+        logs: result.logs.unwrap_or_default(),
+        units_consumed: result.units_consumed,
+        error: result.err.map(|e| e.to_string()),
+    })
+}
+
+// Submits a transaction and returns its signature without waiting for confirmation
+pub(crate) fn send_transaction_async(
+    client: &RpcClient,
+    transaction: &VersionedTransaction,
+    send_options: &SendOptionsNif,
+) -> Result<Signature, BubblegumError> {
+    client
+        .send_transaction_with_config(transaction, send_options.to_config())
+        .map_err(BubblegumError::from_client_error)
+}
+
+// Checks the signature status until it reaches the commitment or the timeout passes. Returns the
+// slot the transaction landed in, or None when it was not confirmed in time
+pub(crate) fn confirm_signature_status(
+    client: &RpcClient,
+    signature: &Signature,
+    commitment: CommitmentConfig,
+    timeout: Duration,
+) -> Result<Option<u64>, BubblegumError> {
+    let started = Instant::now();
+    
+    loop {
+        let statuses = client
+            .get_signature_statuses(&[*signature])
+            .map_err(|e| BubblegumError::SolanaClientError(e.to_string()))?;
+        
+        if let Some(status) = statuses.value.into_iter().next().flatten() {
+            if let Some(err) = status.err {
+                return Err(BubblegumError::from_transaction_error(&err, err.to_string(), Vec::new()));
+            }
+            if status.satisfies_commitment(commitment) {
+                return Ok(Some(status.slot));
+            }
+        }
+        
+        if started.elapsed() >= timeout {
+            return Ok(None);
+        }
+        thread::sleep(CONFIRMATION_POLL_INTERVAL.min(timeout.saturating_sub(started.elapsed())));
+    }
+}
+
+// Compute budget instructions for the limit and priority fee that were set, if any
+pub(crate) fn compute_budget_instructions(
+    compute_unit_limit: Option<u32>,
+    compute_unit_price_micro_lamports: Option<u64>,
+) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    if let Some(units) = compute_unit_limit {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+    }
+    if let Some(micro_lamports) = compute_unit_price_micro_lamports {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(micro_lamports));
+    }
+    instructions
+}
+
+// Turns an instruction returned by the build-only NIFs back into a Solana instruction
+pub(crate) fn decode_instruction(instruction: &InstructionNif) -> Result<Instruction, BubblegumError> {
+    let accounts = instruction
+        .accounts
+        .iter()
+        .map(|account| {
+            Ok(AccountMeta {
+                pubkey: parse_pubkey(&account.pubkey)?,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+        })
+        .collect::<Result<Vec<_>, BubblegumError>>()?;
+    
+    Ok(Instruction {
+        program_id: parse_pubkey(&instruction.program_id)?,
+        accounts,
+        data: instruction.data.as_slice().to_vec(),
+    })
+}
+
+// Fetches the on-chain lookup tables so a v0 message can reference their addresses
+pub(crate) fn fetch_lookup_tables(
+    client: &RpcClient,
+    addresses: &[String],
+) -> Result<Vec<AddressLookupTableAccount>, BubblegumError> {
+    addresses
+        .iter()
+        .map(|address| {
+            let key = parse_pubkey(address)?;
+            let account = client
+                .get_account(&key)
+                .map_err(|e| BubblegumError::SolanaClientError(e.to_string()))?;
+            let table = AddressLookupTable::deserialize(&account.data)
+                .map_err(|e| BubblegumError::SerializationError(e.to_string()))?;
+            
+            Ok(AddressLookupTableAccount {
+                key,
+                addresses: table.addresses.to_vec(),
+            })
+        })
+        .collect()
+}
+
+// Legacy message when there are no lookup tables, a v0 message that uses them otherwise
+pub(crate) fn compile_message(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    blockhash: Hash,
+) -> Result<VersionedMessage, BubblegumError> {
+    if lookup_tables.is_empty() {
+        return Ok(VersionedMessage::Legacy(Message::new_with_blockhash(instructions, Some(payer), &blockhash)));
+    }
+    
+    v0::Message::try_compile(payer, instructions, lookup_tables, blockhash)
+        .map(VersionedMessage::V0)
+        .map_err(|e| BubblegumError::TransactionError(e.to_string()))
+}
+
+// Compute units a transaction without a compute unit limit may use
+pub(crate) fn default_compute_units(instruction_count: usize) -> u32 {
+    (instruction_count as u32)
+        .saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNITS)
+        .min(MAX_COMPUTE_UNITS)
+}
+
+// The priority fee of a compute unit price over a compute budget, rounded up to whole lamports
+pub(crate) fn priority_fee_lamports(compute_unit_price: u64, compute_units: u32) -> u64 {
+    (compute_unit_price as u128 * compute_units as u128).div_ceil(1_000_000) as u64
+}
+
+// Compiles what send_transaction would send, without contacting the cluster. The blockhash is
+// a placeholder, lookup tables are not applied and `:auto` priority fees, which need an
+// estimate from the cluster, are left out
+pub(crate) fn plan_transaction(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    signers: Vec<Pubkey>,
+    tx_options: &TransactionOptionsNif,
+) -> Result<Plan, BubblegumError> {
+    let compute_unit_price = match &tx_options.priority_fee {
+        Some(PriorityFeeNif::MicroLamports(micro_lamports)) => Some(*micro_lamports),
+        Some(PriorityFeeNif::Auto) => None,
+        None => tx_options.compute_unit_price_micro_lamports,
+    };
+    
+    let mut all_instructions = compute_budget_instructions(tx_options.compute_unit_limit, compute_unit_price);
+    all_instructions.extend_from_slice(instructions);
+    let message = compile_message(payer, &all_instructions, &[], Hash::default())?;
+    
+    let compute_units = tx_options
+        .compute_unit_limit
+        .unwrap_or_else(|| default_compute_units(instructions.len()));
+    
+    Ok(Plan {
+        message,
+        signers,
+        compute_units,
+        compute_unit_price,
+    })
+}
+
+pub(crate) fn serialize_transaction(transaction: &VersionedTransaction) -> Result<String, BubblegumError> {
+    bincode::serialize(transaction)
+        .map(|bytes| BASE64.encode(bytes))
+        .map_err(|e| BubblegumError::SerializationError(e.to_string()))
+}
+
+// Instructions, fee payer, blockhash, nonce account and authority, lookup tables and memo of a
+// transaction to build
+type BuildTransactionArgs<'a> = (
+    Vec<InstructionNif<'a>>,
+    String,
+    Option<String>,
+    Option<(String, String)>,
+    Vec<String>,
+    Option<String>,
+    RpcEndpoint,
+);
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn build_transaction<'a>(
+    env: Env<'a>,
+    args: BuildTransactionArgs<'a>,
+) -> Term<'a> {
+    let (instructions, fee_payer_str, blockhash_str, nonce_strs, lookup_table_strs, memo, rpc_url) = args;
+    
+    // Decode the instructions and the fee payer
+    let mut instructions = match instructions.iter().map(decode_instruction).collect::<Result<Vec<_>, _>>() {
+        Ok(instructions) => instructions,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    if let Some(text) = &memo {
+        instructions.push(instructions::memo(text));
+    }
+    
+    let fee_payer = match parse_pubkey(&fee_payer_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Parse the nonce account and its authority, if any
+    let nonce = match nonce_strs {
+        Some((nonce_account_str, nonce_authority_str)) => {
+            match (parse_pubkey(&nonce_account_str), parse_pubkey(&nonce_authority_str)) {
+                (Ok(nonce_account), Ok(nonce_authority)) => Some((nonce_account, nonce_authority)),
+                (Err(e), _) | (_, Err(e)) => return (atoms::error(), e).encode(env),
+            }
+        },
+        None => None,
+    };
+    
+    // Connect to Solana
+    let client = rpc_url.client();
+    
+    // A durable nonce replaces the blockhash, and advancing it has to be the first instruction;
+    // otherwise use the caller's blockhash, or fetch a fresh one
+    let blockhash = match (nonce, blockhash_str) {
+        (Some((nonce_account, nonce_authority)), _) => match fetch_nonce(&client, &nonce_account) {
+            Ok(nonce_hash) => {
+                instructions.insert(0, system_instruction::advance_nonce_account(&nonce_account, &nonce_authority));
+                nonce_hash
+            },
+            Err(e) => {
+                return (atoms::error(), e).encode(env);
+            },
+        },
+        (None, Some(blockhash_str)) => match parse_hash(&blockhash_str) {
+            Ok(bytes) => Hash::new_from_array(bytes),
+            Err(e) => return (atoms::error(), e).encode(env),
+        },
+        (None, None) => match rpc_url.latest_blockhash(None) {
+            Ok((blockhash, _last_valid_block_height)) => blockhash,
+            Err(e) => {
+                return (atoms::error(), e).encode(env);
+            },
+        },
+    };
+    
+    // Resolve the lookup tables, if any were given
+    let lookup_tables = match fetch_lookup_tables(&client, &lookup_table_strs) {
+        Ok(lookup_tables) => lookup_tables,
+        Err(e) => {
+            return (atoms::error(), e).encode(env);
+        },
+    };
+    
+    let message = match compile_message(&fee_payer, &instructions, &lookup_tables, blockhash) {
+        Ok(message) => message,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // Assemble the unsigned transaction; signature slots stay zeroed until it is signed
+    let num_signers = message.header().num_required_signatures as usize;
+    let signers: Vec<String> = message
+        .static_account_keys()
+        .iter()
+        .take(num_signers)
+        .map(|pubkey| pubkey.to_string())
+        .collect();
+    let transaction = VersionedTransaction {
+        signatures: vec![Signature::default(); num_signers],
+        message,
+    };
+    
+    match serialize_transaction(&transaction) {
+        Ok(transaction_base64) => {
+            let ok_map = Term::map_new(env);
+            
+            let message_base64 = BASE64.encode(transaction.message.serialize());
+            
+            let ok_map = ok_map.map_put("transaction".encode(env), transaction_base64.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("message".encode(env), message_base64.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("blockhash".encode(env), blockhash.to_string().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("signers".encode(env), signers.encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn send_raw_transaction<'a>(
+    env: Env<'a>,
+    args: (Binary<'a>, SendOptionsNif, Option<u64>, RpcEndpoint),
+) -> Term<'a> {
+    let (transaction_bytes, send_options, timeout_ms, rpc_url) = args;
+    
+    // Legacy transactions deserialize as versioned ones too
+    let transaction: VersionedTransaction = match bincode::deserialize(transaction_bytes.as_slice()) {
+        Ok(transaction) => transaction,
+        Err(e) => return (atoms::error(), BubblegumError::SerializationError(e.to_string())).encode(env),
+    };
+    
+    let reply_options = ReplyOptions {
+        async_reply: false,
+        timeout_ms,
+    };
+    reply::dispatch(env, reply_options, move |env| send_signed(env, &transaction, &send_options, &rpc_url))
+}
+
+// Submits a transaction as signed by the caller and optionally waits until the cluster has
+// confirmed it
+fn send_signed<'a>(
+    env: Env<'a>,
+    transaction: &VersionedTransaction,
+    send_options: &SendOptionsNif,
+    rpc_url: &RpcEndpoint,
+) -> Term<'a> {
+    // Connect to Solana
+    let client = rpc_url.client();
+    
+    // A call that timed out sends nothing
+    if let Err(e) = reply::before_send() {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    let signature = match send_transaction_async(&client, transaction, send_options) {
+        Ok(signature) => signature,
+        Err(e) => {
+            return (atoms::error(), e.with_program_id(&transaction.message)).encode(env);
+        },
+    };
+    
+    if send_options.confirm {
+        if let Err(e) = client.poll_for_signature_with_commitment(&signature, send_options.commitment_config(&client)) {
+            return (atoms::error(), BubblegumError::from_client_error(e).with_program_id(&transaction.message)).encode(env);
+        }
+    }
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("signature".encode(env), signature.to_string().encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+// Places each signature in the slot of its signer, after checking it signs the message. Signatures
+// are raw 64 byte binaries, as KMS and HSM APIs return them, or Base58 strings
+fn attach_signatures(
+    transaction: &mut VersionedTransaction,
+    signatures: &[(String, Binary)],
+) -> Result<(), BubblegumError> {
+    let message_bytes = transaction.message.serialize();
+    let num_signers = transaction.message.header().num_required_signatures as usize;
+    
+    // A malformed transaction could hold fewer signature slots than the message has signers
+    if transaction.signatures.len() != num_signers {
+        return Err(BubblegumError::InvalidArgument(format!(
+            "Transaction has {} signatures for {} required signers",
+            transaction.signatures.len(),
+            num_signers
+        )));
+    }
+    
+    for (signer_str, signature_bytes) in signatures {
+        let signer = parse_pubkey(signer_str)?;
+        let position = transaction
+            .message
+            .static_account_keys()
+            .iter()
+            .take(num_signers)
+            .position(|pubkey| *pubkey == signer)
+            .ok_or_else(|| BubblegumError::InvalidArgument(format!("{} is not a signer of the transaction", signer)))?;
+        
+        let signature = match signature_bytes.len() {
+            64 => Signature::try_from(signature_bytes.as_slice())
+                .map_err(|e| BubblegumError::InvalidSignature(e.to_string()))?,
+            _ => std::str::from_utf8(signature_bytes.as_slice())
+                .map_err(|e| BubblegumError::InvalidSignature(e.to_string()))
+                .and_then(|signature_str| {
+                    Signature::from_str(signature_str).map_err(|e| BubblegumError::InvalidSignature(e.to_string()))
+                })?,
+        };
+        
+        if !signature.verify(signer.as_ref(), &message_bytes) {
+            return Err(BubblegumError::InvalidSignature(format!("signature of {} does not sign the message", signer)));
+        }
+        
+        transaction.signatures[position] = signature;
+    }
+    
+    // Every signer has to have signed before the cluster accepts the transaction
+    let missing: Vec<String> = transaction
+        .message
+        .static_account_keys()
+        .iter()
+        .zip(transaction.signatures.iter())
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(pubkey, _)| pubkey.to_string())
+        .collect();
+    if !missing.is_empty() {
+        return Err(BubblegumError::InvalidArgument(format!("missing signatures of {}", missing.join(", "))));
+    }
+    
+    Ok(())
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn attach_signatures_and_send<'a>(
+    env: Env<'a>,
+    args: (Binary<'a>, Vec<(String, Binary<'a>)>, SendOptionsNif, Option<u64>, RpcEndpoint),
+) -> Term<'a> {
+    let (transaction_bytes, signatures, send_options, timeout_ms, rpc_url) = args;
+    
+    let mut transaction: VersionedTransaction = match bincode::deserialize(transaction_bytes.as_slice()) {
+        Ok(transaction) => transaction,
+        Err(e) => return (atoms::error(), BubblegumError::SerializationError(e.to_string())).encode(env),
+    };
+    
+    // Signature slots build_transaction left empty are filled with the external signatures
+    if let Err(e) = attach_signatures(&mut transaction, &signatures) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    let reply_options = ReplyOptions {
+        async_reply: false,
+        timeout_ms,
+    };
+    reply::dispatch(env, reply_options, move |env| send_signed(env, &transaction, &send_options, &rpc_url))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn simulate_transaction<'a>(
+    env: Env<'a>,
+    args: (Binary<'a>, bool, RpcEndpoint),
+) -> Term<'a> {
+    let (transaction_bytes, replace_recent_blockhash, rpc_url) = args;
+    
+    let transaction: VersionedTransaction = match bincode::deserialize(transaction_bytes.as_slice()) {
+        Ok(transaction) => transaction,
+        Err(e) => return (atoms::error(), BubblegumError::SerializationError(e.to_string())).encode(env),
+    };
+    
+    // Connect to Solana
+    let client = rpc_url.client();
+    
+    // Signatures are not checked, so unsigned transactions from build_transaction can be simulated too
+    match simulate(&client, &transaction, false, replace_recent_blockhash) {
+        Ok(simulation) => (atoms::ok(), simulation.encode(env)).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn confirm_signature(
+    env: Env,
+    args: (String, CommitmentNif, u64, RpcEndpoint),
+) -> Term {
+    let (signature_str, commitment, timeout_ms, rpc_url) = args;
+    
+    let signature = match Signature::from_str(&signature_str) {
+        Ok(signature) => signature,
+        Err(e) => return (atoms::error(), BubblegumError::InvalidSignature(e.to_string())).encode(env),
+    };
+    
+    // Connect to Solana
+    let client = rpc_url.client();
+    
+    match confirm_signature_status(
+        &client,
+        &signature,
+        commitment.to_commitment_config(),
+        Duration::from_millis(timeout_ms),
+    ) {
+        Ok(slot) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("signature".encode(env), signature_str.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("confirmed".encode(env), slot.is_some().encode(env)).unwrap();
+            let ok_map = ok_map.map_put("slot".encode(env), slot.encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
@@ -0,0 +1,209 @@
+use rustler::{Encoder, Env, NifStruct, Term};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::Signer};
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use crate::{
+    atoms,
+    batch::{self, MAX_COMPUTE_UNIT_LIMIT},
+    das, decode_keypair,
+    error::ErrorNif,
+    instructions, parse_pubkey, reply,
+    request::TransferBatchRequestNif,
+    send_transaction_part,
+    transaction::{SendResult, TransactionOptionsNif, TransactionOutcome},
+    tree, BubblegumError,
+};
+
+// Rough compute cost of one transfer, including the proof verification of a deep tree
+const TRANSFER_COMPUTE_UNITS: u32 = 60_000;
+
+// How one transfer of a batch fared: sent in the transaction with `signature`, or not, with `error`
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.TransferBatchItem"]
+pub struct TransferBatchItemNif {
+    pub index: usize,
+    pub asset_id: String,
+    pub signature: Option<String>,
+    pub error: Option<ErrorNif>,
+}
+
+// Runs `work` over every item from at most `workers` threads and returns the results in order
+fn parallel_map<T, R, F>(items: &[T], workers: usize, work: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(usize, &T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let workers = workers.max(1).min(items.len());
+    let mut results: Vec<Option<R>> = (0..items.len()).map(|_| None).collect();
+    
+    // The workers send on behalf of the operation, so they stop with it
+    let control = reply::current();
+    thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    reply::within(control.clone(), || {
+                        let mut done = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, Ordering::SeqCst);
+                            match items.get(index) {
+                                Some(item) => done.push((index, work(index, item))),
+                                None => return done,
+                            }
+                        }
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        
+        for handle in handles {
+            for (index, result) in handle.join().unwrap() {
+                results[index] = Some(result);
+            }
+        }
+    });
+    
+    results.into_iter().map(|result| result.unwrap()).collect()
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn transfer_batch(
+    env: Env,
+    args: (TransferBatchRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| transfer_batch_impl(env, args))
+}
+
+fn transfer_batch_impl(
+    env: Env,
+    args: (TransferBatchRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    let TransferBatchRequestNif {
+        payer_keypair: payer_keypair_bs58,
+        transfers,
+        canopy_depth,
+        workers,
+    } = request;
+    
+    // Decode the payer keypair, which signs every transfer as owner or delegate
+    let payer = match decode_keypair(&payer_keypair_bs58) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    let payer_pubkey = payer.pubkey();
+    let workers = workers as usize;
+    
+    // Fetch every leaf and its proof from DAS; an asset that cannot be transferred fails on its own
+    let leaves = parallel_map(&transfers, workers, |_, (asset_id, new_owner_str)| {
+        let new_owner = parse_pubkey(new_owner_str)?;
+        let leaf = das::fetch_leaf(&tx_options.rpc_url, asset_id, &tx_options.retry)?;
+        if leaf.owner != payer_pubkey && leaf.delegate != payer_pubkey {
+            return Err(BubblegumError::InvalidArgument(format!(
+                "{} is neither the owner nor the delegate of {}",
+                payer_pubkey, asset_id
+            )));
+        }
+        Ok((leaf, new_owner))
+    });
+    
+    // DAS returns full proofs; the canopy of each tree is read once to trim them
+    let mut canopy_depths: HashMap<Pubkey, u32> = HashMap::new();
+    let mut failures: Vec<(usize, BubblegumError)> = Vec::new();
+    let mut transfer_ixs: Vec<(usize, Instruction)> = Vec::new();
+    for (index, leaf) in leaves.into_iter().enumerate() {
+        let (mut leaf, new_owner) = match leaf {
+            Ok(leaf) => leaf,
+            Err(e) => {
+                failures.push((index, e));
+                continue;
+            },
+        };
+        
+        let tree_canopy_depth = match canopy_depth.or_else(|| canopy_depths.get(&leaf.tree).copied()) {
+            Some(canopy_depth) => canopy_depth,
+            None => match tree::fetch_canopy_depth(&tx_options.rpc_url, &tx_options.retry, &leaf.tree) {
+                Ok(canopy_depth) => *canopy_depths.entry(leaf.tree).or_insert(canopy_depth),
+                Err(e) => {
+                    failures.push((index, e));
+                    continue;
+                },
+            },
+        };
+        leaf.asset_proof.truncate_to_canopy(tree_canopy_depth);
+        
+        transfer_ixs.push((
+            index,
+            instructions::transfer(&payer_pubkey, &leaf.tree, &leaf.owner, &leaf.delegate, &new_owner, &leaf.asset_proof),
+        ));
+    }
+    
+    // Pack the transfers like a batch mint; proofs make them large, so size usually decides
+    let compute_unit_limit = tx_options.compute_unit_limit.unwrap_or(MAX_COMPUTE_UNIT_LIMIT);
+    let max_transfers_per_transaction = (compute_unit_limit / TRANSFER_COMPUTE_UNITS).max(1) as usize;
+    let packed = batch::pack_instructions(&payer_pubkey, transfer_ixs, max_transfers_per_transaction, tx_options.memo.as_deref());
+    
+    // Send the transactions concurrently; a failed transaction only fails its own transfers.
+    // Transactions are named after their first transfer, which a retried call packs the same way
+    let sent = parallel_map(&packed, workers, |_, transfers| {
+        let (indexes, batch_instructions): (Vec<usize>, Vec<Instruction>) = transfers.iter().cloned().unzip();
+        let result = send_transaction_part(batch_instructions, &payer, vec![], &tx_options, indexes[0]);
+        (indexes, result)
+    });
+    
+    let mut signatures = Vec::new();
+    let mut simulations = Vec::new();
+    let mut plans = Vec::new();
+    let mut attempts = 0;
+    let mut items: Vec<TransferBatchItemNif> = failures
+        .into_iter()
+        .map(|(index, e)| TransferBatchItemNif {
+            index,
+            asset_id: transfers[index].0.clone(),
+            signature: None,
+            error: Some(e.to_nif(env)),
+        })
+        .collect();
+    for (indexes, result) in sent {
+        let result = result.map(|SendResult { outcome, attempts: sent_attempts, .. }| {
+            attempts += sent_attempts;
+            match outcome {
+                TransactionOutcome::Sent(signature) => {
+                    signatures.push(signature.to_string());
+                    Some(signature.to_string())
+                },
+                TransactionOutcome::Simulated(simulation) => {
+                    simulations.push(simulation.encode(env));
+                    None
+                },
+                TransactionOutcome::Planned(plan) => {
+                    plans.push(plan.encode(env));
+                    None
+                },
+            }
+        });
+        items.extend(indexes.into_iter().map(|index| TransferBatchItemNif {
+            index,
+            asset_id: transfers[index].0.clone(),
+            signature: result.as_ref().ok().cloned().flatten(),
+            error: result.as_ref().err().map(|e| e.to_nif(env)),
+        }));
+    }
+    items.sort_by_key(|item| item.index);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("signatures".encode(env), signatures.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("simulations".encode(env), simulations.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("plans".encode(env), plans.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("items".encode(env), items.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("attempts".encode(env), attempts.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
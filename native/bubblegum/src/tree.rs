@@ -0,0 +1,391 @@
+use mpl_bubblegum::{accounts::TreeConfig, types::DecompressibleState};
+use rustler::{Encoder, Env, NifStruct, Term};
+use solana_sdk::{pubkey::Pubkey, rent::Rent, system_instruction::MAX_PERMITTED_DATA_LENGTH};
+
+use crate::{
+    atoms, merkle_tree_account_size, parse_pubkey, retry::RetryPolicyNif, rpc::RpcEndpoint, tree_config_pda,
+    validate_tree_depths, BubblegumError, DecompressibleStateNif,
+};
+
+// The (max_depth, max_buffer_size) pairs spl-account-compression can create a tree with
+const VALID_DEPTH_BUFFER_PAIRS: [(u32, u32); 26] = [
+    (3, 8),
+    (5, 8),
+    (14, 64),
+    (14, 256),
+    (14, 1024),
+    (14, 2048),
+    (15, 64),
+    (16, 64),
+    (17, 64),
+    (18, 64),
+    (19, 64),
+    (20, 64),
+    (20, 256),
+    (20, 1024),
+    (20, 2048),
+    (24, 64),
+    (24, 256),
+    (24, 512),
+    (24, 1024),
+    (24, 2048),
+    (26, 512),
+    (26, 1024),
+    (26, 2048),
+    (30, 512),
+    (30, 1024),
+    (30, 2048),
+];
+
+// The Bubblegum tree config of a tree, as stored on chain
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.TreeConfigAccount"]
+pub struct TreeConfigAccountNif {
+    // Address of the tree config PDA, the authority of the Merkle tree
+    pub address: String,
+    pub merkle_tree: String,
+    pub tree_creator: String,
+    pub tree_delegate: String,
+    pub total_mint_capacity: u64,
+    pub num_minted: u64,
+    pub is_public: bool,
+    pub is_decompressible: DecompressibleStateNif,
+}
+
+// The header and current state of a spl-account-compression concurrent Merkle tree
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.MerkleTreeState"]
+pub struct MerkleTreeStateNif {
+    pub address: String,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    // The tree config PDA for Bubblegum trees
+    pub authority: String,
+    pub creation_slot: u64,
+    // Changes applied to the tree so far
+    pub sequence_number: u64,
+    // Change log buffer slot holding the latest change, and how many slots are in use
+    pub active_index: u64,
+    pub buffer_size: u64,
+    pub root: String,
+    // Leaves appended so far, i.e. the index the next mint gets
+    pub rightmost_index: u32,
+    pub canopy_depth: u32,
+}
+
+// Proof nodes a transaction can carry next to a typical instruction; the canopy caches the rest
+const RECOMMENDED_MAX_PROOF_LENGTH: u32 = 10;
+
+// A tree shape that holds the requested number of NFTs, with what it costs
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.TreeRecommendation"]
+pub struct TreeRecommendationNif {
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub canopy_depth: u32,
+    pub capacity: u64,
+    pub proof_length: u32,
+    pub account_size: usize,
+    pub rent_exempt_lamports: u64,
+}
+
+// Rent-exempt balance of an account, from the cluster when an endpoint is given, otherwise
+// computed locally from the default rent parameters or the given rent rate
+fn rent_exempt_lamports(
+    account_size: usize,
+    lamports_per_byte_year: Option<u64>,
+    rpc_url: Option<&RpcEndpoint>,
+) -> Result<u64, BubblegumError> {
+    match rpc_url {
+        Some(rpc_url) => rpc_url.with_failover(|connection| {
+            connection
+                .client
+                .get_minimum_balance_for_rent_exemption(account_size)
+                .map_err(|e| BubblegumError::SolanaClientError(e.to_string()))
+        }),
+        None => {
+            let rent = Rent {
+                lamports_per_byte_year: lamports_per_byte_year.unwrap_or(Rent::default().lamports_per_byte_year),
+                ..Rent::default()
+            };
+            Ok(rent.minimum_balance(account_size))
+        },
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn calculate_tree_cost(
+    env: Env,
+    args: (u32, u32, u32, Option<u64>, Option<RpcEndpoint>),
+) -> Term {
+    let (max_depth, max_buffer_size, canopy_depth, lamports_per_byte_year, rpc_url) = args;
+    
+    // The canopy can cache at most the whole tree
+    if let Err(e) = validate_tree_depths(max_depth, canopy_depth) {
+        return (atoms::error(), e).encode(env);
+    }
+    
+    let capacity = 1u64 << max_depth;
+    let account_size = merkle_tree_account_size(max_depth, max_buffer_size, canopy_depth);
+    let lamports = match rent_exempt_lamports(account_size, lamports_per_byte_year, rpc_url.as_ref()) {
+        Ok(lamports) => lamports,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("capacity".encode(env), capacity.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("account_size".encode(env), account_size.encode(env)).unwrap();
+    let ok_map = ok_map.map_put("rent_exempt_lamports".encode(env), lamports.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+// The deepest canopy up to the one that keeps proofs short which still fits in an account
+fn recommended_canopy_depth(max_depth: u32, max_buffer_size: u32) -> Option<u32> {
+    let shortest = max_depth.saturating_sub(RECOMMENDED_MAX_PROOF_LENGTH);
+    (0..=shortest)
+        .rev()
+        .find(|&canopy_depth| {
+            merkle_tree_account_size(max_depth, max_buffer_size, canopy_depth) as u64 <= MAX_PERMITTED_DATA_LENGTH
+        })
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn recommend_tree_config(
+    env: Env,
+    args: (u64, u32, Option<u64>, Option<RpcEndpoint>),
+) -> Term {
+    let (nft_count, concurrency, lamports_per_byte_year, rpc_url) = args;
+    
+    // Every valid pair with room for the NFTs and a buffer for as many concurrent changes
+    let mut recommendations = Vec::new();
+    for (max_depth, max_buffer_size) in VALID_DEPTH_BUFFER_PAIRS {
+        let capacity = 1u64 << max_depth;
+        if capacity < nft_count || max_buffer_size < concurrency {
+            continue;
+        }
+        
+        let canopy_depth = match recommended_canopy_depth(max_depth, max_buffer_size) {
+            Some(canopy_depth) => canopy_depth,
+            None => continue,
+        };
+        
+        let account_size = merkle_tree_account_size(max_depth, max_buffer_size, canopy_depth);
+        let rent_exempt_lamports = match rent_exempt_lamports(account_size, lamports_per_byte_year, rpc_url.as_ref()) {
+            Ok(lamports) => lamports,
+            Err(e) => return (atoms::error(), e).encode(env),
+        };
+        
+        recommendations.push(TreeRecommendationNif {
+            max_depth,
+            max_buffer_size,
+            canopy_depth,
+            capacity,
+            proof_length: max_depth - canopy_depth,
+            account_size,
+            rent_exempt_lamports,
+        });
+    }
+    
+    if recommendations.is_empty() {
+        return (atoms::error(), BubblegumError::InvalidArgument(format!(
+            "No tree holds {} NFTs with {} concurrent changes",
+            nft_count, concurrency
+        )))
+        .encode(env);
+    }
+    
+    // Cheapest first
+    recommendations.sort_by_key(|recommendation| recommendation.rent_exempt_lamports);
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("recommendations".encode(env), recommendations.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
+
+// Data of an account, retried as the policy allows
+pub(crate) fn fetch_account_data(
+    rpc_url: &RpcEndpoint,
+    retry: &RetryPolicyNif,
+    address: &Pubkey,
+) -> Result<Vec<u8>, BubblegumError> {
+    retry
+        .run(|| {
+            rpc_url.with_failover(|connection| {
+                connection
+                    .client
+                    .get_account_data(address)
+                    .map_err(BubblegumError::from_client_error)
+            })
+        })
+        .map(|(data, _attempts)| data)
+}
+
+// Canopy depth of a tree, from the size of its account
+pub(crate) fn fetch_canopy_depth(
+    rpc_url: &RpcEndpoint,
+    retry: &RetryPolicyNif,
+    tree: &Pubkey,
+) -> Result<u32, BubblegumError> {
+    let data = fetch_account_data(rpc_url, retry, tree)?;
+    decode_merkle_tree(tree, &data).map(|state| state.canopy_depth)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_tree_config(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (tree_pubkey_str, retry, rpc_url) = args;
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    // The tree config lives at a PDA of the tree
+    let address = tree_config_pda(&tree_pubkey);
+    let data = match fetch_account_data(&rpc_url, &retry, &address) {
+        Ok(data) => data,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let tree_config = match TreeConfig::from_bytes(&data) {
+        Ok(tree_config) => tree_config,
+        Err(e) => {
+            return (atoms::error(), BubblegumError::SerializationError(format!("tree config {}: {}", address, e))).encode(env);
+        },
+    };
+    
+    let tree_config = TreeConfigAccountNif {
+        address: address.to_string(),
+        merkle_tree: tree_pubkey.to_string(),
+        tree_creator: tree_config.tree_creator.to_string(),
+        tree_delegate: tree_config.tree_delegate.to_string(),
+        total_mint_capacity: tree_config.total_mint_capacity,
+        num_minted: tree_config.num_minted,
+        is_public: tree_config.is_public,
+        is_decompressible: match tree_config.is_decompressible {
+            DecompressibleState::Enabled => DecompressibleStateNif::Enabled,
+            DecompressibleState::Disabled => DecompressibleStateNif::Disabled,
+        },
+    };
+    
+    (atoms::ok(), tree_config).encode(env)
+}
+
+// Reads little-endian integers and hashes off the front of account data
+struct AccountReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> AccountReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], BubblegumError> {
+        let end = self.offset + len;
+        let bytes = self.data.get(self.offset..end).ok_or_else(|| {
+            BubblegumError::SerializationError(format!("merkle tree account ends at byte {}", self.data.len()))
+        })?;
+        self.offset = end;
+        Ok(bytes)
+    }
+    
+    fn u32(&mut self) -> Result<u32, BubblegumError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+    
+    fn u64(&mut self) -> Result<u64, BubblegumError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+    
+    fn hash(&mut self) -> Result<[u8; 32], BubblegumError> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+}
+
+// Decodes a ConcurrentMerkleTree account laid out as `merkle_tree_account_size` describes
+pub(crate) fn decode_merkle_tree(address: &Pubkey, data: &[u8]) -> Result<MerkleTreeStateNif, BubblegumError> {
+    let mut reader = AccountReader { data, offset: 0 };
+    
+    // Account type 1 is a concurrent Merkle tree, header version 0 is V1
+    let account_type = reader.take(1)?[0];
+    let header_version = reader.take(1)?[0];
+    if account_type != 1 || header_version != 0 {
+        return Err(BubblegumError::SerializationError(format!(
+            "{} is not a V1 concurrent merkle tree account (type {}, version {})",
+            address, account_type, header_version
+        )));
+    }
+    
+    let max_buffer_size = reader.u32()?;
+    let max_depth = reader.u32()?;
+    let authority = Pubkey::new_from_array(reader.hash()?);
+    let creation_slot = reader.u64()?;
+    // is_batch_initialized and padding
+    reader.take(6)?;
+    
+    let sequence_number = reader.u64()?;
+    let active_index = reader.u64()?;
+    let buffer_size = reader.u64()?;
+    if max_depth > 30 || active_index >= max_buffer_size as u64 {
+        return Err(BubblegumError::SerializationError(format!(
+            "{} has an invalid header (max_depth {}, active index {} of {})",
+            address, max_depth, active_index, max_buffer_size
+        )));
+    }
+    
+    // Each change log is its root, the path of the changed leaf, its index and padding
+    let change_log_size = 32 + 32 * max_depth as usize + 4 + 4;
+    let change_logs = reader.take(max_buffer_size as usize * change_log_size)?;
+    let active = active_index as usize * change_log_size;
+    let root: [u8; 32] = change_logs[active..active + 32].try_into().unwrap();
+    
+    // The rightmost proof, its leaf, then the index of the next leaf
+    reader.take(32 * max_depth as usize + 32)?;
+    let rightmost_index = reader.u32()?;
+    reader.take(4)?;
+    
+    // The canopy holds 2^(depth + 1) - 2 nodes
+    let canopy_nodes = (data.len() - reader.offset) / 32;
+    let canopy_depth = (canopy_nodes + 2).ilog2().saturating_sub(1);
+    
+    Ok(MerkleTreeStateNif {
+        address: address.to_string(),
+        max_depth,
+        max_buffer_size,
+        authority: authority.to_string(),
+        creation_slot,
+        sequence_number,
+        active_index,
+        buffer_size,
+        root: bs58::encode(root).into_string(),
+        rightmost_index,
+        canopy_depth,
+    })
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_merkle_tree_state(
+    env: Env,
+    args: (String, RetryPolicyNif, RpcEndpoint),
+) -> Term {
+    let (tree_pubkey_str, retry, rpc_url) = args;
+    
+    let tree_pubkey = match parse_pubkey(&tree_pubkey_str) {
+        Ok(pubkey) => pubkey,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    let data = match fetch_account_data(&rpc_url, &retry, &tree_pubkey) {
+        Ok(data) => data,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    match decode_merkle_tree(&tree_pubkey, &data) {
+        Ok(state) => (atoms::ok(), state).encode(env),
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
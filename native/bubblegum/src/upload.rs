@@ -0,0 +1,326 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL, Engine};
+use reqwest::blocking::Client;
+use rustler::{Encoder, Env, NifUnitEnum, Term};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha384};
+use solana_sdk::{hash::hash, signature::Keypair, signer::Signer, system_instruction};
+use std::{thread, time::Duration};
+
+use crate::{
+    atoms, decode_keypair, parse_pubkey, reply,
+    request::UploadRequestNif,
+    send_transaction,
+    transaction::{TransactionOptionsNif, TransactionOutcome},
+    BubblegumError,
+};
+
+// ANS-104 signature type of an ed25519 (Solana) key
+const SIGNATURE_TYPE_ED25519: u16 = 2;
+
+// Irys takes a while to credit a funding transfer once it is told of it
+const BALANCE_POLLS: u32 = 10;
+const BALANCE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+const HTTP_TIMEOUT: Duration = Duration::from_secs(60);
+
+// Where metadata is stored; every provider returns a URI that can go straight into MetadataArgs
+#[derive(NifUnitEnum, Clone, Copy)]
+pub enum UploadProviderNif {
+    // Arweave through an Irys (formerly Bundlr) node, paid in SOL
+    Irys,
+}
+
+// What to store and where
+pub(crate) struct UploadSpec<'a> {
+    pub provider: UploadProviderNif,
+    pub data: &'a [u8],
+    pub content_type: &'a str,
+    pub tags: &'a [(String, String)],
+    pub node_url: &'a str,
+    pub gateway_url: &'a str,
+    pub fund: bool,
+}
+
+// A stored upload and what it cost
+pub(crate) struct Upload {
+    pub id: String,
+    pub uri: String,
+    pub price: u64,
+    pub funding_signature: Option<String>,
+}
+
+impl UploadRequestNif {
+    fn spec(&self) -> UploadSpec<'_> {
+        UploadSpec {
+            provider: self.provider,
+            data: self.data.as_bytes(),
+            content_type: &self.content_type,
+            tags: &self.tags,
+            node_url: &self.node_url,
+            gateway_url: &self.gateway_url,
+            fund: self.fund,
+        }
+    }
+}
+
+fn sha384(parts: &[&[u8]]) -> [u8; 48] {
+    let mut hasher = Sha384::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    
+    let mut digest = [0u8; 48];
+    digest.copy_from_slice(&hasher.finalize());
+    digest
+}
+
+// The ANS-104 deep hash of a list of blobs, which is what a data item's signature covers
+fn deep_hash(blobs: &[&[u8]]) -> [u8; 48] {
+    let mut hash = sha384(&[format!("list{}", blobs.len()).as_bytes()]);
+    for blob in blobs {
+        let tag = sha384(&[format!("blob{}", blob.len()).as_bytes()]);
+        let blob_hash = sha384(&[&tag, &sha384(&[*blob])]);
+        hash = sha384(&[&hash, &blob_hash]);
+    }
+    hash
+}
+
+// Avro long, as ANS-104 encodes tag counts and lengths
+fn write_avro_long(value: i64, out: &mut Vec<u8>) {
+    let mut zigzag = ((value << 1) ^ (value >> 63)) as u64;
+    while zigzag >= 0x80 {
+        out.push((zigzag as u8 & 0x7f) | 0x80);
+        zigzag >>= 7;
+    }
+    out.push(zigzag as u8);
+}
+
+fn encode_tags(tags: &[(String, String)]) -> Vec<u8> {
+    let mut out = Vec::new();
+    if tags.is_empty() {
+        return out;
+    }
+    
+    write_avro_long(tags.len() as i64, &mut out);
+    for (name, value) in tags {
+        write_avro_long(name.len() as i64, &mut out);
+        out.extend_from_slice(name.as_bytes());
+        write_avro_long(value.len() as i64, &mut out);
+        out.extend_from_slice(value.as_bytes());
+    }
+    out.push(0);
+    out
+}
+
+// Builds and signs an ANS-104 data item without target or anchor, and returns its bytes and id
+fn sign_data_item(signer: &Keypair, data: &[u8], tags: &[(String, String)]) -> (Vec<u8>, String) {
+    let owner = signer.pubkey().to_bytes();
+    let tag_bytes = encode_tags(tags);
+    
+    let message = deep_hash(&[
+        b"dataitem",
+        b"1",
+        SIGNATURE_TYPE_ED25519.to_string().as_bytes(),
+        &owner,
+        &[],
+        &[],
+        &tag_bytes,
+        data,
+    ]);
+    let signature = signer.sign_message(&message);
+    
+    let mut item = Vec::with_capacity(2 + 64 + 32 + 2 + 16 + tag_bytes.len() + data.len());
+    item.extend_from_slice(&SIGNATURE_TYPE_ED25519.to_le_bytes());
+    item.extend_from_slice(signature.as_ref());
+    item.extend_from_slice(&owner);
+    // Neither target nor anchor
+    item.push(0);
+    item.push(0);
+    item.extend_from_slice(&(tags.len() as u64).to_le_bytes());
+    item.extend_from_slice(&(tag_bytes.len() as u64).to_le_bytes());
+    item.extend_from_slice(&tag_bytes);
+    item.extend_from_slice(data);
+    
+    // The id of a data item is the SHA-256 of its signature
+    let id = BASE64_URL.encode(hash(signature.as_ref()).to_bytes());
+    (item, id)
+}
+
+fn irys_get(client: &Client, url: &str, tx_options: &TransactionOptionsNif) -> Result<String, BubblegumError> {
+    let (body, _attempts) = tx_options.retry.run(|| {
+        client
+            .get(url)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())
+            .map_err(|e| BubblegumError::UploadError(e.to_string()))
+    })?;
+    Ok(body)
+}
+
+// Irys reports amounts as strings or numbers depending on the endpoint
+fn parse_lamports(value: &Value) -> Option<u64> {
+    value.as_u64().or_else(|| value.as_str().and_then(|value| value.trim().parse().ok()))
+}
+
+fn irys_balance(client: &Client, node_url: &str, payer: &Keypair, tx_options: &TransactionOptionsNif) -> Result<u64, BubblegumError> {
+    let url = format!("{}/account/balance/solana?address={}", node_url, payer.pubkey());
+    let body = irys_get(client, &url, tx_options)?;
+    serde_json::from_str::<Value>(&body)
+        .ok()
+        .and_then(|json| parse_lamports(&json["balance"]))
+        .ok_or_else(|| BubblegumError::UploadError(format!("unexpected balance response: {}", body)))
+}
+
+// Sends the node the lamports the upload lacks and tells it of the transfer
+fn fund_irys(
+    client: &Client,
+    node_url: &str,
+    payer: &Keypair,
+    lamports: u64,
+    tx_options: &TransactionOptionsNif,
+) -> Result<String, BubblegumError> {
+    let info = irys_get(client, &format!("{}/info", node_url), tx_options)?;
+    let bundler = serde_json::from_str::<Value>(&info)
+        .ok()
+        .and_then(|json| json["addresses"]["solana"].as_str().map(str::to_string))
+        .ok_or_else(|| BubblegumError::UploadError(format!("{} has no Solana address", node_url)))?;
+    let bundler = parse_pubkey(&bundler)?;
+    
+    let transfer_ix = system_instruction::transfer(&payer.pubkey(), &bundler, lamports);
+    let signature = match send_transaction(vec![transfer_ix], payer, vec![], tx_options)?.outcome {
+        TransactionOutcome::Sent(signature) => signature.to_string(),
+        _ => return Err(BubblegumError::InvalidArgument("an upload cannot be simulated".to_string())),
+    };
+    
+    let body = json!({ "tx_id": signature });
+    tx_options.retry.run(|| {
+        client
+            .post(format!("{}/account/balance/solana", node_url))
+            .json(&body)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| BubblegumError::UploadError(e.to_string()))
+    })?;
+    
+    Ok(signature)
+}
+
+fn upload_to_irys(
+    payer: &Keypair,
+    spec: &UploadSpec,
+    tx_options: &TransactionOptionsNif,
+) -> Result<Upload, BubblegumError> {
+    let client = Client::builder()
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .map_err(|e| BubblegumError::UploadError(e.to_string()))?;
+    let node_url = spec.node_url.trim_end_matches('/');
+    
+    let mut tags = vec![("Content-Type".to_string(), spec.content_type.to_string())];
+    tags.extend(spec.tags.iter().cloned());
+    let (item, id) = sign_data_item(payer, spec.data, &tags);
+    
+    let price_body = irys_get(&client, &format!("{}/price/solana/{}", node_url, item.len()), tx_options)?;
+    let price = parse_lamports(&Value::String(price_body.clone()))
+        .ok_or_else(|| BubblegumError::UploadError(format!("unexpected price response: {}", price_body)))?;
+    
+    // The payer tops up its balance on the node only by what the upload lacks, with a tenth of
+    // the price on top so a price change before the upload lands does not fail it
+    let mut funding_signature = None;
+    let balance = irys_balance(&client, node_url, payer, tx_options)?;
+    if balance < price {
+        if !spec.fund {
+            return Err(BubblegumError::UploadError(format!(
+                "balance of {} lamports on {} is below the price of {}",
+                balance, node_url, price
+            )));
+        }
+        
+        let lamports = price - balance + price / 10;
+        funding_signature = Some(fund_irys(&client, node_url, payer, lamports, tx_options)?);
+        
+        let mut credited = false;
+        for _ in 0..BALANCE_POLLS {
+            if irys_balance(&client, node_url, payer, tx_options)? >= price {
+                credited = true;
+                break;
+            }
+            thread::sleep(BALANCE_POLL_INTERVAL);
+        }
+        if !credited {
+            return Err(BubblegumError::UploadError(format!(
+                "{} has not credited funding transaction {} yet",
+                node_url,
+                funding_signature.unwrap_or_default()
+            )));
+        }
+    }
+    
+    tx_options.retry.run(|| {
+        client
+            .post(format!("{}/tx/solana", node_url))
+            .header("Content-Type", "application/octet-stream")
+            .body(item.clone())
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| BubblegumError::UploadError(e.to_string()))
+    })?;
+    
+    Ok(Upload {
+        uri: format!("{}/{}", spec.gateway_url.trim_end_matches('/'), id),
+        id,
+        price,
+        funding_signature,
+    })
+}
+
+// Stores the data with its provider; nothing is stored when the transaction options only simulate
+pub(crate) fn upload(
+    payer: &Keypair,
+    spec: &UploadSpec,
+    tx_options: &TransactionOptionsNif,
+) -> Result<Upload, BubblegumError> {
+    if tx_options.simulate_only || tx_options.dry_run {
+        return Err(BubblegumError::InvalidArgument("an upload cannot be simulated".to_string()));
+    }
+    
+    match spec.provider {
+        UploadProviderNif::Irys => upload_to_irys(payer, spec, tx_options),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn upload_metadata(
+    env: Env,
+    args: (UploadRequestNif, TransactionOptionsNif),
+) -> Term {
+    reply::dispatch(env, args.1.reply_options(), move |env| upload_metadata_impl(env, args))
+}
+
+fn upload_metadata_impl(
+    env: Env,
+    args: (UploadRequestNif, TransactionOptionsNif),
+) -> Term {
+    let (request, tx_options) = args;
+    
+    // Decode the payer keypair, which signs the upload and pays for it
+    let payer = match decode_keypair(&request.payer_keypair) {
+        Ok(keypair) => keypair,
+        Err(e) => return (atoms::error(), e).encode(env),
+    };
+    
+    match upload(&payer, &request.spec(), &tx_options) {
+        Ok(upload) => {
+            let ok_map = Term::map_new(env);
+            
+            let ok_map = ok_map.map_put("id".encode(env), upload.id.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("uri".encode(env), upload.uri.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("price".encode(env), upload.price.encode(env)).unwrap();
+            let ok_map = ok_map.map_put("funding_signature".encode(env), upload.funding_signature.encode(env)).unwrap();
+            
+            (atoms::ok(), ok_map).encode(env)
+        },
+        Err(e) => (atoms::error(), e).encode(env),
+    }
+}
@@ -0,0 +1,171 @@
+use reqwest::{blocking::Client, StatusCode};
+use rustler::{Encoder, Env, NifStruct, Term};
+use std::{
+    collections::HashMap,
+    io::Read,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use crate::{atoms, BubblegumError, MetadataArgsNif};
+
+// Metadata JSON is a few kilobytes; anything far larger is not metadata
+const MAX_METADATA_BYTES: u64 = 1024 * 1024;
+
+const ARWEAVE_GATEWAY: &str = "https://arweave.net/";
+const IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+// How metadata URIs are checked before minting
+#[derive(NifStruct, Clone)]
+#[module = "SolanaBubblegum.Types.UriCheckOptions"]
+pub struct UriCheckOptionsNif {
+    // Per request, for the HEAD and the GET alike
+    pub timeout_ms: u64,
+    // URIs checked at the same time
+    pub concurrency: u32,
+}
+
+// How one URI fared; `error` is nil when it is reachable and holds valid metadata JSON
+#[derive(NifStruct)]
+#[module = "SolanaBubblegum.Types.UriCheck"]
+pub struct UriCheckNif {
+    pub uri: String,
+    pub error: Option<String>,
+}
+
+// The HTTP URL a metadata URI is fetched from; Arweave and IPFS URIs go through a public gateway
+pub(crate) fn gateway_url(uri: &str) -> Result<String, String> {
+    if let Some(path) = uri.strip_prefix("ar://") {
+        Ok(format!("{}{}", ARWEAVE_GATEWAY, path))
+    } else if let Some(path) = uri.strip_prefix("ipfs://") {
+        Ok(format!("{}{}", IPFS_GATEWAY, path.trim_start_matches("ipfs/")))
+    } else if uri.starts_with("https://") || uri.starts_with("http://") {
+        Ok(uri.to_string())
+    } else {
+        Err("only http, https, ar and ipfs URIs are supported".to_string())
+    }
+}
+
+fn check_uri(client: &Client, uri: &str) -> Result<(), String> {
+    let url = gateway_url(uri)?;
+    
+    // A HEAD request fails fast on missing files; gateways that do not support it are read anyway
+    let head = client.head(&url).send().map_err(|e| e.to_string())?;
+    let status = head.status();
+    if !status.is_success() && status != StatusCode::METHOD_NOT_ALLOWED && status != StatusCode::NOT_IMPLEMENTED {
+        return Err(format!("HEAD returned {}", status));
+    }
+    
+    let response = client.get(&url).send().map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("GET returned {}", response.status()));
+    }
+    
+    let mut body = Vec::new();
+    response
+        .take(MAX_METADATA_BYTES + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| e.to_string())?;
+    if body.len() as u64 > MAX_METADATA_BYTES {
+        return Err(format!("is larger than {} bytes", MAX_METADATA_BYTES));
+    }
+    
+    let json: serde_json::Value = serde_json::from_slice(&body).map_err(|e| format!("is not JSON: {}", e))?;
+    let missing = ["name", "image"]
+        .into_iter()
+        .filter(|field| json.get(field).and_then(|value| value.as_str()).is_none_or(|value| value.is_empty()))
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("metadata JSON lacks {}", missing.join(", ")))
+    }
+}
+
+// Checks every distinct URI once, at most `concurrency` at a time, and returns a result per URI
+// in the order given
+fn check_uris(uris: &[&str], options: &UriCheckOptionsNif) -> Vec<Result<(), String>> {
+    let mut distinct = Vec::new();
+    let mut positions = HashMap::new();
+    for uri in uris {
+        positions.entry(*uri).or_insert_with(|| {
+            distinct.push(*uri);
+            distinct.len() - 1
+        });
+    }
+    
+    let client = match Client::builder().timeout(Duration::from_millis(options.timeout_ms)).build() {
+        Ok(client) => client,
+        Err(e) => return uris.iter().map(|_| Err(e.to_string())).collect(),
+    };
+    
+    let next = AtomicUsize::new(0);
+    let workers = (options.concurrency.max(1) as usize).min(distinct.len());
+    let mut results = vec![Ok(()); distinct.len()];
+    thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut checked = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::SeqCst);
+                        match distinct.get(index) {
+                            Some(uri) => checked.push((index, check_uri(&client, uri))),
+                            None => return checked,
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        
+        for handle in handles {
+            for (index, result) in handle.join().unwrap() {
+                results[index] = result;
+            }
+        }
+    });
+    
+    uris.iter().map(|uri| results[positions[uri]].clone()).collect()
+}
+
+// Checks the URIs of many metadata, failing each one whose URI is bad like invalid metadata
+pub(crate) fn validate_metadata_uris(
+    metadata: &[MetadataArgsNif],
+    options: &UriCheckOptionsNif,
+) -> Vec<Result<(), BubblegumError>> {
+    let uris = metadata.iter().map(|metadata| metadata.uri.as_str()).collect::<Vec<_>>();
+    check_uris(&uris, options)
+        .into_iter()
+        .zip(&uris)
+        .map(|(result, uri)| result.map_err(|e| BubblegumError::InvalidMetadata(vec![("uri", format!("{}: {}", uri, e))])))
+        .collect()
+}
+
+pub(crate) fn validate_metadata_uri(metadata: &MetadataArgsNif, options: &UriCheckOptionsNif) -> Result<(), BubblegumError> {
+    validate_metadata_uris(std::slice::from_ref(metadata), options).remove(0)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn check_metadata_uris(
+    env: Env,
+    args: (Vec<String>, UriCheckOptionsNif),
+) -> Term {
+    let (uris, options) = args;
+    
+    let results = check_uris(&uris.iter().map(String::as_str).collect::<Vec<_>>(), &options);
+    let checks = uris
+        .into_iter()
+        .zip(results)
+        .map(|(uri, result)| UriCheckNif {
+            uri,
+            error: result.err(),
+        })
+        .collect::<Vec<_>>();
+    
+    let ok_map = Term::map_new(env);
+    
+    let ok_map = ok_map.map_put("checks".encode(env), checks.encode(env)).unwrap();
+    
+    (atoms::ok(), ok_map).encode(env)
+}
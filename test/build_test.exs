@@ -0,0 +1,189 @@
+defmodule SolanaBubblegum.BuildTest do
+  use ExUnit.Case
+
+  alias SolanaBubblegum.Build
+  alias SolanaBubblegum.Types.{AccountMeta, AssetProof, Creator, Instruction, MetadataArgs}
+
+  @bubblegum_program_id "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+  @payer "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+  @tree "HXtBm8XZbxaTt41uqaKhwUAa6Z1aPyvJdsZVENiWsetg"
+  @proof_node "9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde"
+
+  defp metadata do
+    %MetadataArgs{
+      name: "Test NFT",
+      symbol: "TNFT",
+      uri: "https://arweave.net/metadata.json",
+      seller_fee_basis_points: 500,
+      primary_sale_happened: false,
+      is_mutable: true,
+      edition_nonce: nil,
+      creators: [%Creator{address: @payer, verified: false, share: 100}],
+      collection: nil,
+      uses: nil
+    }
+  end
+
+  defp asset_proof do
+    %AssetProof{
+      root: "11111111111111111111111111111111",
+      data_hash: "11111111111111111111111111111111",
+      creator_hash: "11111111111111111111111111111111",
+      nonce: 0,
+      index: 0,
+      proof: [@proof_node]
+    }
+  end
+
+  test "create_tree_config builds the account allocation and tree config instructions" do
+    assert {:ok, %{"instructions" => [create_account, create_tree], "tree_pubkey" => @tree}} =
+             Build.create_tree_config(@payer, @tree, 14, 64, 10, true)
+
+    assert %Instruction{program_id: "11111111111111111111111111111111"} = create_account
+    assert %Instruction{program_id: @bubblegum_program_id} = create_tree
+  end
+
+  test "create_tree_config returns error when the canopy is deeper than the tree" do
+    assert {:error, _} = Build.create_tree_config(@payer, @tree, 14, 64, 15, true)
+  end
+
+  test "mint builds a single Bubblegum instruction signed by the payer" do
+    assert {:ok, %{"instructions" => [%Instruction{} = mint_ix]}} = Build.mint(@payer, @tree, metadata())
+
+    assert mint_ix.program_id == @bubblegum_program_id
+    assert is_binary(mint_ix.data)
+    assert %AccountMeta{is_signer: true} = Enum.find(mint_ix.accounts, &(&1.pubkey == @payer))
+  end
+
+  test "mint returns error with invalid tree pubkey" do
+    assert {:error, _} = Build.mint(@payer, "invalid_tree", metadata())
+  end
+
+  test "transfer appends the proof nodes as read-only accounts" do
+    assert {:ok, %{"instructions" => [transfer_ix]}} =
+             Build.transfer(@payer, @tree, @payer, @tree, asset_proof())
+
+    assert %AccountMeta{pubkey: @proof_node, is_signer: false, is_writable: false} =
+             List.last(transfer_ix.accounts)
+  end
+
+  test "transfer leaves out the proof nodes held by the canopy" do
+    proof = %AssetProof{asset_proof() | proof: [@proof_node, @payer, @tree], canopy_depth: 2}
+
+    assert {:ok, %{"instructions" => [trimmed_ix]}} = Build.transfer(@payer, @tree, @payer, @tree, proof)
+    assert {:ok, %{"instructions" => [transfer_ix]}} = Build.transfer(@payer, @tree, @payer, @tree, asset_proof())
+
+    assert length(trimmed_ix.accounts) == length(transfer_ix.accounts)
+    assert %AccountMeta{pubkey: @proof_node, is_writable: false} = List.last(trimmed_ix.accounts)
+  end
+
+  test "transfer returns error with invalid new owner" do
+    assert {:error, _} = Build.transfer(@payer, @tree, @payer, "invalid_owner", asset_proof())
+  end
+
+  test "redeem returns the voucher address" do
+    assert {:ok, %{"instructions" => [_redeem_ix], "voucher" => voucher}} =
+             Build.redeem(@payer, @tree, asset_proof())
+
+    assert is_binary(voucher)
+  end
+
+  test "decompress_v1 rejects a Token-2022 leaf" do
+    assert {:ok, %{"instructions" => [_decompress_ix]}} = Build.decompress_v1(@payer, @tree, 0, metadata())
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             Build.decompress_v1(@payer, @tree, 0, %{metadata() | token_program_version: :token2022})
+  end
+
+  test "set_decompressible_state returns error with invalid tree creator" do
+    assert {:error, _} = Build.set_decompressible_state("invalid_creator", @tree, :enabled)
+  end
+
+  test "transaction assembles an unsigned transaction with the given blockhash" do
+    blockhash = "11111111111111111111111111111111"
+
+    assert {:ok, %{"transaction" => transaction, "blockhash" => ^blockhash, "signers" => [@payer]}} =
+             @payer
+             |> Build.mint(@tree, metadata())
+             |> Build.transaction(@payer, blockhash: blockhash)
+
+    assert {:ok, _bytes} = Base.decode64(transaction)
+  end
+
+  test "transaction returns the message the signers sign" do
+    assert {:ok, %{"transaction" => transaction, "message" => message}} =
+             @payer
+             |> Build.mint(@tree, metadata())
+             |> Build.transaction(@payer, blockhash: "11111111111111111111111111111111")
+
+    # One empty signature slot, then the message
+    assert Base.decode64!(transaction) == <<1, 0::512>> <> Base.decode64!(message)
+  end
+
+  test "transaction appends a memo" do
+    assert {:ok, %{"message" => message}} =
+             @payer
+             |> Build.mint(@tree, metadata())
+             |> Build.transaction(@payer, blockhash: "11111111111111111111111111111111", memo: "order-1234")
+
+    # Memo data is stored verbatim at the end of the message
+    assert String.ends_with?(Base.decode64!(message), "order-1234")
+  end
+
+  test "attach_signatures_and_send rejects a signature that does not sign the message" do
+    {:ok, %{"transaction" => transaction}} =
+      @payer
+      |> Build.mint(@tree, metadata())
+      |> Build.transaction(@payer, blockhash: "11111111111111111111111111111111")
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_signature}} =
+             SolanaBubblegum.attach_signatures_and_send(transaction, %{@payer => <<1::512>>})
+  end
+
+  test "attach_signatures_and_send rejects a signature of a key that is not a signer" do
+    {:ok, %{"transaction" => transaction}} =
+      @payer
+      |> Build.mint(@tree, metadata())
+      |> Build.transaction(@payer, blockhash: "11111111111111111111111111111111")
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.attach_signatures_and_send(transaction, [{@proof_node, <<1::512>>}])
+  end
+
+  test "attach_signatures_and_send rejects a transaction without a slot for each signature" do
+    {:ok, %{"transaction" => transaction}} =
+      @payer
+      |> Build.mint(@tree, metadata())
+      |> Build.transaction(@payer, blockhash: "11111111111111111111111111111111")
+
+    # Drop the one empty signature slot, keeping the message that asks for its signer
+    <<1, _signature::binary-size(64), message::binary>> = Base.decode64!(transaction)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.attach_signatures_and_send(Base.encode64(<<0>> <> message), %{@payer => <<1::512>>})
+  end
+
+  test "transaction returns error with invalid lookup table address" do
+    assert {:error, _} =
+             @payer
+             |> Build.mint(@tree, metadata())
+             |> Build.transaction(@payer,
+               blockhash: "11111111111111111111111111111111",
+               address_lookup_tables: ["invalid_table"]
+             )
+  end
+
+  test "transaction returns error with invalid nonce account" do
+    assert {:error, _} =
+             @payer
+             |> Build.mint(@tree, metadata())
+             |> Build.transaction(@payer, nonce: {"invalid_nonce", @payer})
+  end
+
+  test "transaction returns error with invalid blockhash" do
+    assert {:error, _} =
+             @payer
+             |> Build.mint(@tree, metadata())
+             |> Build.transaction(@payer, blockhash: "not-base58!")
+  end
+end
@@ -0,0 +1,170 @@
+defmodule SolanaBubblegum.DASTest do
+  use ExUnit.Case
+
+  alias SolanaBubblegum.Types.{Creator, Holder, HolderSnapshot, RoyaltyInfo, SearchAssetsParams}
+
+  @unreachable_rpc_url "http://127.0.0.1:1"
+
+  test "get_asset returns error when the RPC endpoint is unreachable" do
+    result =
+      SolanaBubblegum.DAS.get_asset(
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        rpc_url: @unreachable_rpc_url
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "get_asset_proof returns error when the RPC endpoint is unreachable" do
+    result =
+      SolanaBubblegum.DAS.get_asset_proof(
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        rpc_url: @unreachable_rpc_url
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "get_assets_by_owner returns error when the RPC endpoint is unreachable" do
+    result =
+      SolanaBubblegum.DAS.get_assets_by_owner(
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        rpc_url: @unreachable_rpc_url,
+        all: true
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "get_assets_by_group returns error when the RPC endpoint is unreachable" do
+    result =
+      SolanaBubblegum.DAS.get_assets_by_group(
+        "collection",
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        rpc_url: @unreachable_rpc_url,
+        sort_by: :created,
+        sort_direction: :desc
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "get_assets_by_creator returns error when the RPC endpoint is unreachable" do
+    result =
+      SolanaBubblegum.DAS.get_assets_by_creator(
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        rpc_url: @unreachable_rpc_url
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "search_assets returns error when the RPC endpoint is unreachable" do
+    params = %SearchAssetsParams{
+      owner_address: "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+      compressed: true,
+      burnt: false
+    }
+
+    result = SolanaBubblegum.DAS.search_assets(params, rpc_url: @unreachable_rpc_url)
+    assert match?({:error, _}, result)
+  end
+
+  test "verify_ownership returns error when the RPC endpoint is unreachable" do
+    result =
+      SolanaBubblegum.DAS.verify_ownership(
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        "HXtBm8XZbxaTt41uqaKhwUAa6Z1aPyvJdsZVENiWsetg",
+        rpc_url: @unreachable_rpc_url
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "verify_ownership rejects an invalid expected owner before querying" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.DAS.verify_ownership(
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               "not-a-pubkey",
+               rpc_url: @unreachable_rpc_url
+             )
+  end
+
+  test "get_royalty_info returns error when the RPC endpoint is unreachable" do
+    result =
+      SolanaBubblegum.DAS.get_royalty_info(
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        rpc_url: @unreachable_rpc_url
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "royalty_payouts splits the royalty by creator share" do
+    royalty_info = %RoyaltyInfo{
+      asset_id: "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+      seller_fee_basis_points: 500,
+      primary_sale_happened: true,
+      royalty_model: "creators",
+      locked: false,
+      creators: [
+        %Creator{address: "a", verified: true, share: 70},
+        %Creator{address: "b", verified: false, share: 30}
+      ]
+    }
+
+    assert [{"a", 70_000}, {"b", 30_000}] = SolanaBubblegum.DAS.royalty_payouts(royalty_info, 2_000_000)
+  end
+
+  test "snapshot_holders returns error when the RPC endpoint is unreachable" do
+    result =
+      SolanaBubblegum.DAS.snapshot_holders(
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        rpc_url: @unreachable_rpc_url,
+        max_pages: 1
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "merge_snapshots adds up the holders of both parts" do
+    earlier = %HolderSnapshot{
+      holders: [%Holder{owner: "a", count: 2}, %Holder{owner: "b", count: 1}],
+      assets: 3,
+      pages: 1,
+      cursor: "next",
+      complete: false
+    }
+
+    later = %HolderSnapshot{
+      holders: [%Holder{owner: "b", count: 2}, %Holder{owner: "c", count: 1}],
+      assets: 3,
+      pages: 1,
+      cursor: nil,
+      complete: true
+    }
+
+    assert %HolderSnapshot{
+             holders: [
+               %Holder{owner: "b", count: 3},
+               %Holder{owner: "a", count: 2},
+               %Holder{owner: "c", count: 1}
+             ],
+             assets: 6,
+             pages: 2,
+             cursor: nil,
+             complete: true
+           } = SolanaBubblegum.DAS.merge_snapshots(later, earlier)
+  end
+
+  test "get_asset gives up after the configured attempts" do
+    result =
+      SolanaBubblegum.DAS.get_asset(
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        rpc_url: @unreachable_rpc_url,
+        retry: [max_attempts: 2, base_delay_ms: 0, retry_on: [:timeout]]
+      )
+
+    assert match?({:error, _}, result)
+  end
+end
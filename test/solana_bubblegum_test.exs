@@ -2,15 +2,10 @@ defmodule SolanaBubblegumTest do
   use ExUnit.Case
   doctest SolanaBubblegum
 
-  alias SolanaBubblegum.Types.{MetadataArgs, Creator}
+  alias SolanaBubblegum.Types.{AssetProof, MetadataArgs, Creator, UpdateArgs}
 
-  test "create_tree_config returns error with invalid keypair" do
-    result = SolanaBubblegum.create_tree_config("invalid_keypair", 14, 64, 10, true)
-    assert match?({:error, _}, result)
-  end
-
-  test "mint_to_collection returns error with invalid keypair" do
-    metadata = %MetadataArgs{
+  defp metadata do
+    %MetadataArgs{
       name: "Test NFT",
       symbol: "TNFT",
       uri: "https://arweave.net/metadata.json",
@@ -28,19 +23,1232 @@ defmodule SolanaBubblegumTest do
       collection: "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
       uses: nil
     }
+  end
+
+  defp asset_proof do
+    %AssetProof{
+      root: "11111111111111111111111111111111",
+      data_hash: "11111111111111111111111111111111",
+      creator_hash: "11111111111111111111111111111111",
+      nonce: 0,
+      index: 0,
+      proof: []
+    }
+  end
+
+  # An RPC endpoint that answers blockhash requests and leaves every other request unanswered, so
+  # a transaction gets as far as being sent and no further. Tells `owner` the method of each request
+  defp rpc_hanging_on_send(owner) do
+    {:ok, socket} = :gen_tcp.listen(0, [:binary, packet: :http_bin, active: false])
+    {:ok, port} = :inet.port(socket)
+    spawn_link(fn -> accept_rpc(socket, owner) end)
+    {socket, "http://127.0.0.1:#{port}"}
+  end
+
+  # Accepted connections stay open until the listening socket is closed
+  defp accept_rpc(socket, owner) do
+    with {:ok, client} <- :gen_tcp.accept(socket) do
+      spawn(fn -> serve_rpc(client, owner) end)
+      accept_rpc(socket, owner)
+    end
+  end
+
+  defp serve_rpc(client, owner) do
+    with {:ok, body} <- read_rpc_request(client, 0) do
+      %{"id" => id, "method" => method} = Jason.decode!(body)
+      send(owner, {:rpc_request, method})
+
+      if method == "getLatestBlockhash" do
+        value = %{blockhash: "11111111111111111111111111111111", lastValidBlockHeight: 100}
+        response = Jason.encode!(%{jsonrpc: "2.0", id: id, result: %{context: %{slot: 1}, value: value}})
+        headers = "content-type: application/json\r\ncontent-length: #{byte_size(response)}"
+        :ok = :gen_tcp.send(client, ["HTTP/1.1 200 OK\r\n", headers, "\r\n\r\n", response])
+        serve_rpc(client, owner)
+      end
+    end
+  end
+
+  defp read_rpc_request(client, length) do
+    case :gen_tcp.recv(client, 0) do
+      {:ok, {:http_header, _, :"Content-Length", _, value}} ->
+        read_rpc_request(client, String.to_integer(value))
+
+      {:ok, :http_eoh} ->
+        :ok = :inet.setopts(client, packet: :raw)
+        body = :gen_tcp.recv(client, length)
+        :ok = :inet.setopts(client, packet: :http_bin)
+        body
+
+      {:ok, _} ->
+        read_rpc_request(client, length)
+
+      error ->
+        error
+    end
+  end
+
+  test "create_tree_config returns error with invalid keypair" do
+    result = SolanaBubblegum.create_tree_config("invalid_keypair", 14, 64, 10, true)
+    assert match?({:error, _}, result)
+  end
+
+  test "create_tree_config returns error with an invalid tree keypair" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
 
-    result = SolanaBubblegum.mint_to_collection("invalid_keypair", "tree_pubkey", "collection_pubkey", metadata)
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.create_tree_config(payer, 14, 64, 10, true, tree_keypair: "invalid_keypair")
+  end
+
+  test "create_tree_config returns error with an invalid tree creator keypair" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.create_tree_config(payer, 14, 64, 10, false, tree_creator_keypair: "invalid_keypair")
+  end
+
+  test "mint_to_collection returns error with invalid keypair" do
+    result = SolanaBubblegum.mint_to_collection("invalid_keypair", "tree_pubkey", "collection_pubkey", metadata())
+    assert match?({:error, _}, result)
+  end
+
+  test "mint_to_collection returns error with an invalid collection authority keypair" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.mint_to_collection(
+               payer,
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               metadata(),
+               collection_authority_keypair: "invalid_keypair"
+             )
+  end
+
+  test "mint_batch_to_collection returns error with invalid keypair" do
+    result =
+      SolanaBubblegum.mint_batch_to_collection("invalid_keypair", "tree_pubkey", "collection_pubkey", [metadata()])
+
+    assert match?({:error, _}, result)
+  end
+
+  test "mint_batch_to_collection reports invalid metadata per item" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    too_long = %{metadata() | name: String.duplicate("a", 40)}
+
+    assert {:ok, %{signatures: [], items: [item_0, item_1]}} =
+             SolanaBubblegum.mint_batch_to_collection(
+               payer,
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               [too_long, %{metadata() | symbol: String.duplicate("a", 20)}]
+             )
+
+    assert %SolanaBubblegum.Types.BatchMintItem{index: 0, signature: nil, error: %{kind: :invalid_metadata}} = item_0
+    assert %SolanaBubblegum.Types.BatchMintItem{index: 1, error: %{kind: :invalid_metadata}} = item_1
+  end
+
+  test "keypairs are accepted as JSON arrays, byte lists and raw seeds" do
+    seed = List.duplicate(0, 32)
+    too_long = %{metadata() | name: String.duplicate("a", 40)}
+
+    for payer <- ["[" <> Enum.join(seed, ",") <> "]", seed, <<0::256>>] do
+      assert {:ok, %{signatures: [], items: [%{error: %{kind: :invalid_metadata}}]}} =
+               SolanaBubblegum.mint_batch_to_collection(
+                 payer,
+                 "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+                 "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+                 [too_long]
+               )
+    end
+  end
+
+  test "malformed JSON keypair returns invalid_keypair" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.mint("[1, 2,", "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", metadata())
+  end
+
+  test "bulk_mint returns error with invalid keypair" do
+    result = SolanaBubblegum.bulk_mint("invalid_keypair", "tree_pubkey", [metadata()])
     assert match?({:error, _}, result)
   end
 
+  test "bulk_mint reports progress and invalid metadata" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    too_long = %{metadata() | uri: String.duplicate("a", 300)}
+
+    assert {:ok, ref} =
+             SolanaBubblegum.bulk_mint(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", [too_long], workers: 2)
+
+    assert_receive {:bulk_mint_progress, ^ref, 1, 1, 1}
+    assert_receive {:bubblegum_result, ^ref, {:ok, %{"signatures" => [], "items" => [item]}}}
+    assert %SolanaBubblegum.Types.BatchMintItem{index: 0, error: %{kind: :invalid_metadata}} = item
+  end
+
+  test "airdrop_to_owners reports an invalid recipient on its own item" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:ok, ref} =
+             SolanaBubblegum.airdrop_to_owners(
+               payer,
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               metadata(),
+               ["invalid_recipient"]
+             )
+
+    assert_receive {:bulk_mint_progress, ^ref, 1, 1, 1}
+    assert_receive {:bubblegum_result, ^ref, {:ok, %{"signatures" => [], "items" => [item]}}}
+
+    assert %SolanaBubblegum.Types.AirdropItem{
+             index: 0,
+             recipient: "invalid_recipient",
+             asset_id: nil,
+             error: %{kind: :invalid_public_key}
+           } = item
+  end
+
+  test "airdrop_to_owners returns error with invalid metadata" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    too_long = %{metadata() | uri: String.duplicate("a", 300)}
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata}} =
+             SolanaBubblegum.airdrop_to_owners(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", too_long, [
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+             ])
+  end
+
   test "transfer returns error with invalid keypair" do
     result = SolanaBubblegum.transfer(
       "invalid_keypair",
       "tree_pubkey",
       "leaf_owner",
       "new_owner",
-      "asset_id"
+      asset_proof()
     )
     assert match?({:error, _}, result)
   end
+
+  test "burn returns error with invalid keypair" do
+    result = SolanaBubblegum.burn("invalid_keypair", "tree_pubkey", asset_proof())
+    assert match?({:error, _}, result)
+  end
+
+  test "delegate returns error with invalid keypair" do
+    result = SolanaBubblegum.delegate("invalid_keypair", "tree_pubkey", "new_delegate", asset_proof())
+    assert match?({:error, _}, result)
+  end
+
+  test "cancel_delegate returns error with invalid keypair" do
+    result = SolanaBubblegum.cancel_delegate("invalid_keypair", "tree_pubkey", "delegate", asset_proof())
+    assert match?({:error, _}, result)
+  end
+
+  test "redeem returns error with invalid keypair" do
+    result = SolanaBubblegum.redeem("invalid_keypair", "tree_pubkey", asset_proof())
+    assert match?({:error, _}, result)
+  end
+
+  test "cancel_redeem returns error with invalid keypair" do
+    result = SolanaBubblegum.cancel_redeem("invalid_keypair", "tree_pubkey", asset_proof())
+    assert match?({:error, _}, result)
+  end
+
+  test "decompress_v1 returns error with invalid keypair" do
+    result = SolanaBubblegum.decompress_v1("invalid_keypair", "tree_pubkey", 0, metadata())
+    assert match?({:error, _}, result)
+  end
+
+  test "mint returns error with invalid keypair" do
+    result = SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", %{metadata() | collection: nil})
+    assert match?({:error, _}, result)
+  end
+
+  test "verify_creator returns error with invalid keypair" do
+    result = SolanaBubblegum.verify_creator("invalid_keypair", "tree_pubkey", "leaf_owner", asset_proof(), metadata())
+    assert match?({:error, _}, result)
+  end
+
+  test "unverify_creator returns error with invalid keypair" do
+    result = SolanaBubblegum.unverify_creator("invalid_keypair", "tree_pubkey", "leaf_owner", asset_proof(), metadata())
+    assert match?({:error, _}, result)
+  end
+
+  test "verify_collection returns error with invalid keypair" do
+    result =
+      SolanaBubblegum.verify_collection(
+        "invalid_keypair",
+        "tree_pubkey",
+        "leaf_owner",
+        "collection_mint",
+        asset_proof(),
+        metadata()
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "unverify_collection returns error with invalid keypair" do
+    result =
+      SolanaBubblegum.unverify_collection(
+        "invalid_keypair",
+        "tree_pubkey",
+        "leaf_owner",
+        "collection_mint",
+        asset_proof(),
+        metadata()
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "set_and_verify_collection returns error with invalid keypair" do
+    result =
+      SolanaBubblegum.set_and_verify_collection(
+        "invalid_keypair",
+        "tree_pubkey",
+        "leaf_owner",
+        "collection_mint",
+        asset_proof(),
+        metadata()
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "update_metadata returns error with invalid keypair" do
+    result =
+      SolanaBubblegum.update_metadata(
+        "invalid_keypair",
+        "tree_pubkey",
+        "leaf_owner",
+        asset_proof(),
+        metadata(),
+        %UpdateArgs{name: "Renamed NFT"}
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "set_tree_delegate returns error with invalid keypair" do
+    result = SolanaBubblegum.set_tree_delegate("invalid_keypair", "tree_pubkey", "new_delegate")
+    assert match?({:error, _}, result)
+  end
+
+  test "set_decompressible_state returns error with invalid keypair" do
+    result = SolanaBubblegum.set_decompressible_state("invalid_keypair", "tree_pubkey", :enabled)
+    assert match?({:error, _}, result)
+  end
+
+  test "transfer_with_das returns error with invalid keypair" do
+    result = SolanaBubblegum.transfer_with_das("invalid_keypair", "asset_id", "new_owner")
+    assert match?({:error, _}, result)
+  end
+
+  test "transfer_batch returns error with invalid keypair" do
+    result = SolanaBubblegum.transfer_batch("invalid_keypair", [{"asset_id", "new_owner"}])
+    assert match?({:error, _}, result)
+  end
+
+  test "transfer_batch fails each asset on its own" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    asset_id = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+
+    assert {:ok, %{signatures: [], items: [first, second]}} =
+             SolanaBubblegum.transfer_batch(payer, [{asset_id, "not-a-pubkey"}, {asset_id, asset_id}],
+               rpc_url: "http://127.0.0.1:1"
+             )
+
+    assert %SolanaBubblegum.Types.TransferBatchItem{index: 0, error: %{kind: :invalid_public_key}} = first
+    assert %SolanaBubblegum.Types.TransferBatchItem{index: 1, signature: nil, error: %{}} = second
+  end
+
+  test "burn_with_das returns error with invalid keypair" do
+    result = SolanaBubblegum.burn_with_das("invalid_keypair", "asset_id")
+    assert match?({:error, _}, result)
+  end
+
+  test "get_asset_id returns error with invalid tree pubkey" do
+    result = SolanaBubblegum.get_asset_id("invalid_tree", 0)
+    assert match?({:error, _}, result)
+  end
+
+  test "hash_creators returns error with invalid creator address" do
+    result = SolanaBubblegum.hash_creators([%Creator{address: "invalid", verified: false, share: 100}])
+    assert match?({:error, _}, result)
+  end
+
+  test "hash_metadata returns an error naming the invalid creator instead of crashing" do
+    creators = [
+      %Creator{address: "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", verified: false, share: 50},
+      %Creator{address: "not_a_pubkey", verified: false, share: 50}
+    ]
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key, message: message}} =
+             SolanaBubblegum.hash_metadata(%{metadata() | creators: creators})
+
+    assert message =~ "creator 1"
+    assert message =~ "not_a_pubkey"
+  end
+
+  test "hash_metadata returns an error with an invalid collection key" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key, message: message}} =
+             SolanaBubblegum.hash_metadata(%{metadata() | collection: "not_a_collection"})
+
+    assert message =~ "not_a_collection"
+  end
+
+  test "metadata breaking Token Metadata limits is rejected with every violation" do
+    creators =
+      for _ <- 1..6 do
+        %Creator{address: "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", verified: false, share: 10}
+      end
+
+    metadata = %{metadata() | name: String.duplicate("a", 33), seller_fee_basis_points: 10_001, creators: creators}
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata, violations: violations}} =
+             SolanaBubblegum.hash_metadata(metadata)
+
+    assert Enum.map(violations, &elem(&1, 0)) == [:name, :seller_fee_basis_points, :creators, :creators]
+  end
+
+  test "hash_metadata accepts uses with a use method" do
+    uses = %SolanaBubblegum.Types.Uses{use_method: :burn, remaining: 3, total: 5}
+    assert {:ok, %{data_hash: _}} = SolanaBubblegum.hash_metadata(%{metadata() | uses: uses})
+  end
+
+  test "token program version and token standard change the data hash" do
+    assert {:ok, %{data_hash: default_hash}} = SolanaBubblegum.hash_metadata(metadata())
+
+    assert {:ok, %{data_hash: ^default_hash}} =
+             SolanaBubblegum.hash_metadata(%{metadata() | token_program_version: :original, token_standard: :non_fungible})
+
+    assert {:ok, %{data_hash: token2022_hash}} =
+             SolanaBubblegum.hash_metadata(%{metadata() | token_program_version: :token2022})
+
+    assert token2022_hash != default_hash
+  end
+
+  test "a verified collection is part of the data hash of a leaf" do
+    pubkey = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+
+    assert {:ok, %{data_hash: unverified_hash}} = SolanaBubblegum.hash_metadata(metadata())
+
+    assert {:ok, %{data_hash: verified_hash}} =
+             SolanaBubblegum.hash_metadata(%{metadata() | collection_verified: true})
+
+    assert verified_hash != unverified_hash
+
+    assert {:ok, %{creator_hash: creator_hash}} = SolanaBubblegum.hash_creators(metadata().creators)
+
+    assert {:ok, %{leaf_hash: verified_leaf}} =
+             SolanaBubblegum.compute_leaf_hash(pubkey, pubkey, pubkey, 0, verified_hash, creator_hash)
+
+    assert {:ok, %{leaf_hash: unverified_leaf}} =
+             SolanaBubblegum.compute_leaf_hash(pubkey, pubkey, pubkey, 0, unverified_hash, creator_hash)
+
+    assert verified_leaf != unverified_leaf
+  end
+
+  test "hash_metadata matches the data hash Bubblegum stores for a verified-collection leaf" do
+    # keccak(keccak(borsh(metadata)) ++ seller_fee_basis_points as u16 LE), the scheme the
+    # Bubblegum program hashes leaves with, worked out apart from this crate
+    assert {:ok, %{data_hash: "4bgErTJ3kGow4VsPy3mh3PbhYzMr1AYdYXsrViVu3hS3"}} =
+             SolanaBubblegum.hash_metadata(%{metadata() | collection_verified: true})
+
+    assert {:ok, %{data_hash: "5woi4LwDHHQ2CPjqn9qad6jGVwsB9fssAQ1g5LhJFw4j"}} =
+             SolanaBubblegum.hash_metadata(metadata())
+  end
+
+  test "uses with more remaining than total are rejected" do
+    uses = %SolanaBubblegum.Types.Uses{use_method: :multiple, remaining: 6, total: 5}
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata, violations: [{:uses, _}]}} =
+             SolanaBubblegum.hash_metadata(%{metadata() | uses: uses})
+  end
+
+  test "compute_leaf_hash returns error with invalid asset id" do
+    result =
+      SolanaBubblegum.compute_leaf_hash(
+        "invalid_asset_id",
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+        0,
+        "11111111111111111111111111111111",
+        "11111111111111111111111111111111"
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "calculate_tree_cost uses the given rent rate" do
+    assert {:ok, %{capacity: 1024, account_size: size, rent_exempt_lamports: lamports}} =
+             SolanaBubblegum.calculate_tree_cost(10, 32, 0, lamports_per_byte_year: 1)
+
+    assert lamports == (size + 128) * 2
+  end
+
+  test "calculate_tree_cost rejects a canopy deeper than the tree" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_tree_config}} =
+             SolanaBubblegum.calculate_tree_cost(10, 32, 12)
+  end
+
+  test "recommend_tree_config returns large enough trees, cheapest first" do
+    assert {:ok, %{recommendations: [_ | _] = recommendations}} =
+             SolanaBubblegum.recommend_tree_config(50_000, concurrency: 100)
+
+    assert Enum.all?(recommendations, &(&1.capacity >= 50_000 and &1.max_buffer_size >= 100))
+    assert Enum.all?(recommendations, &(&1.proof_length <= 10 or &1.max_depth >= 26))
+
+    lamports = Enum.map(recommendations, & &1.rent_exempt_lamports)
+    assert lamports == Enum.sort(lamports)
+  end
+
+  test "generate_keypair returns a new keypair each time" do
+    assert {:ok, %{keypair: keypair, pubkey: pubkey}} = SolanaBubblegum.generate_keypair()
+    assert {:ok, %{keypair: other_keypair, pubkey: other_pubkey}} = SolanaBubblegum.generate_keypair()
+    assert keypair != other_keypair
+    assert pubkey != other_pubkey
+  end
+
+  test "keypair_from_seed returns error with a short seed" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.keypair_from_seed(<<0::128>>)
+  end
+
+  test "keypair_from_mnemonic derives a keypair per account" do
+    mnemonic = String.duplicate("abandon ", 11) <> "about"
+
+    assert {:ok, %{pubkey: first}} = SolanaBubblegum.keypair_from_mnemonic(mnemonic)
+    assert {:ok, %{pubkey: ^first}} = SolanaBubblegum.keypair_from_mnemonic(mnemonic, account: 0, change: 0)
+    assert {:ok, %{pubkey: second}} = SolanaBubblegum.keypair_from_mnemonic(mnemonic, account: 1)
+    assert first != second
+  end
+
+  test "keypair_from_mnemonic returns error with invalid mnemonic" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.keypair_from_mnemonic("not a mnemonic")
+  end
+
+  test "encrypt_keypair and decrypt_keypair round trip" do
+    # Keypair with an all-zero secret key
+    keypair = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:ok, encrypted} = SolanaBubblegum.encrypt_keypair(keypair, "correct horse")
+    assert {:ok, other} = SolanaBubblegum.encrypt_keypair(keypair, "correct horse")
+    assert encrypted != other
+
+    assert {:ok, %{keypair: ^keypair}} = SolanaBubblegum.decrypt_keypair(encrypted, "correct horse")
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.decrypt_keypair(encrypted, "wrong horse")
+  end
+
+  test "encrypted keypairs are accepted wherever a keypair is" do
+    {:ok, encrypted} = SolanaBubblegum.encrypt_keypair(<<0::256>>, "correct horse")
+    too_long = %{metadata() | name: String.duplicate("a", 40)}
+
+    assert {:ok, %{signatures: [], items: [%{error: %{kind: :invalid_metadata}}]}} =
+             SolanaBubblegum.mint_batch_to_collection(
+               {:encrypted, encrypted, "correct horse"},
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               [too_long]
+             )
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.mint({:encrypted, encrypted, "wrong horse"}, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", metadata())
+  end
+
+  test "load_secret_key returns a secret key accepted wherever a keypair is" do
+    {:ok, encrypted} = SolanaBubblegum.encrypt_keypair(<<0::256>>, "correct horse")
+
+    assert {:ok, %{secret_key: secret_key, pubkey: "4zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"}} =
+             SolanaBubblegum.load_secret_key({:encrypted, encrypted, "correct horse"})
+
+    assert is_reference(secret_key)
+
+    assert {:ok, %{signatures: [], items: [%{error: %{kind: :invalid_metadata}}]}} =
+             SolanaBubblegum.mint_batch_to_collection(
+               secret_key,
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               [%{metadata() | name: String.duplicate("a", 40)}]
+             )
+  end
+
+  test "load_secret_key returns error with invalid keypair" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.load_secret_key("invalid_keypair")
+  end
+
+  test "registered signers are accepted wherever a keypair is" do
+    assert {:ok, %{pubkey: "4zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"}} =
+             SolanaBubblegum.register_signer(:test_signer, <<0::256>>)
+
+    assert {:ok, %{"test_signer" => "4zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"}} = SolanaBubblegum.list_signers()
+
+    too_long = %{metadata() | name: String.duplicate("a", 40)}
+
+    assert {:ok, %{signatures: [], items: [%{error: %{kind: :invalid_metadata}}]}} =
+             SolanaBubblegum.mint_batch_to_collection(
+               {:signer, "test_signer"},
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               [too_long]
+             )
+
+    assert :ok = SolanaBubblegum.unregister_signer(:test_signer)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.mint({:signer, :test_signer}, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", metadata())
+  end
+
+  test "create_collection_nft returns error with invalid keypair" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.create_collection_nft("invalid_keypair", "My Collection", "https://arweave.net/collection.json")
+  end
+
+  test "create_collection_nft reports invalid metadata before sending" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata}} =
+             SolanaBubblegum.create_collection_nft(payer, String.duplicate("a", 40), "https://arweave.net/collection.json",
+               seller_fee_basis_points: 20_000
+             )
+  end
+
+  test "update_collection_metadata returns error when nothing is to be changed" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.update_collection_metadata(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", [])
+  end
+
+  test "update_collection_metadata returns error with invalid new update authority" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.update_collection_metadata(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               new_update_authority: "invalid_authority"
+             )
+  end
+
+  test "approve_collection_authority returns error with invalid new authority" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.approve_collection_authority(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", "invalid_authority")
+  end
+
+  test "revoke_collection_authority returns error with invalid keypair" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.revoke_collection_authority(
+               "invalid_keypair",
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               "HXtBm8XZbxaTt41uqaKhwUAa6Z1aPyvJdsZVENiWsetg"
+             )
+  end
+
+  test "set_collection_size returns error with invalid collection authority record" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.set_collection_size(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", 10,
+               collection_authority_record: "invalid_record"
+             )
+  end
+
+  test "request_airdrop returns error with invalid pubkey" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.request_airdrop("invalid_pubkey", 1_000_000_000)
+  end
+
+  test "get_balance returns error with invalid pubkey" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.get_balance("invalid_pubkey")
+  end
+
+  test "get_account_info returns error with invalid pubkey" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.get_account_info("invalid_pubkey", encoding: :binary)
+  end
+
+  test "get_signatures_for_address returns error with invalid pubkey" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.get_signatures_for_address("invalid_pubkey")
+  end
+
+  test "get_signatures_for_address returns error with invalid before signature" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_signature}} =
+             SolanaBubblegum.get_signatures_for_address("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               before: "invalid_signature"
+             )
+  end
+
+  test "get_signatures_for_address returns error with out of range limit" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.get_signatures_for_address("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", limit: 1001)
+  end
+
+  test "get_transaction returns error with invalid signature" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_signature}} =
+             SolanaBubblegum.get_transaction("invalid_signature")
+  end
+
+  test "set_telemetry_handler reports RPC requests" do
+    assert :ok = SolanaBubblegum.set_telemetry_handler(self())
+
+    # Nothing listens on port 1, so the request fails without reaching the network
+    assert {:error, _} =
+             SolanaBubblegum.get_balance("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", rpc_url: "http://127.0.0.1:1")
+
+    assert_receive {:bubblegum_telemetry, :rpc, %{duration: _, attempts: 1},
+                    %{operation: "getBalance", endpoint: "http://127.0.0.1:1", result: :error, error_class: _}}
+
+    assert :ok = SolanaBubblegum.set_telemetry_handler(nil)
+  end
+
+  test "set_log_handler forwards failed transactions" do
+    assert :ok = SolanaBubblegum.set_log_level(:warning)
+    assert :ok = SolanaBubblegum.set_log_handler(self())
+
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    # Nothing listens on port 1, so fetching the blockhash fails without reaching the network
+    assert {:error, _} =
+             SolanaBubblegum.mint(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", metadata(),
+               rpc_url: "http://127.0.0.1:1"
+             )
+
+    assert_receive {:bubblegum_log, :error, target, "Transaction failed", %{"operation" => "mint_v1"}}
+                   when is_binary(target)
+
+    assert :ok = SolanaBubblegum.set_log_handler(nil)
+  end
+
+  test "mint with dry_run returns the plan without contacting the RPC endpoint" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    tree = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+
+    assert {:ok, %{plan: plan, attempts: 0}} =
+             SolanaBubblegum.mint(payer, tree, metadata(),
+               rpc_url: "http://127.0.0.1:1",
+               dry_run: true
+             )
+
+    assert [%{instruction: :mint_v1, program: :bubblegum}] = plan["instructions"]
+    assert plan["fee_lamports"] == 5000
+    assert plan["priority_fee_lamports"] == 0
+  end
+
+  test "estimate_fee returns error with a malformed transaction" do
+    result = SolanaBubblegum.estimate_fee(Base.encode64("not a transaction"))
+    assert match?({:error, _}, result)
+  end
+
+  test "mint with an idempotency key carries a memo instruction" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    tree = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+
+    assert {:ok, %{plan: plan}} =
+             SolanaBubblegum.mint(payer, tree, metadata(), dry_run: true, idempotency_key: "order-1234")
+
+    assert [%{instruction: :mint_v1}, %{program: :memo}] = plan["instructions"]
+  end
+
+  test "mint with a memo appends a memo instruction" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    tree = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+
+    assert {:ok, %{plan: plan}} = SolanaBubblegum.mint(payer, tree, metadata(), dry_run: true, memo: "order-1234")
+
+    assert [%{instruction: :mint_v1}, %{program: :memo, accounts: []}] = plan["instructions"]
+  end
+
+  test "transfer_sol returns error with invalid keypair" do
+    result = SolanaBubblegum.transfer_sol("invalid_keypair", "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", 1_000)
+    assert match?({:error, _}, result)
+  end
+
+  test "transfer_sol rejects a zero amount" do
+    # Keypair with an all-zero secret key
+    from = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.transfer_sol(from, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", 0)
+  end
+
+  test "check_tree_empty returns error with invalid tree pubkey" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.check_tree_empty("invalid_pubkey")
+  end
+
+  test "reclaim_lookup_table returns error with invalid keypair" do
+    result = SolanaBubblegum.reclaim_lookup_table("invalid_keypair", "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr")
+    assert match?({:error, _}, result)
+  end
+
+  test "new_proof_cache returns an empty cache accepted by transfer_with_das" do
+    assert {:ok, cache} = SolanaBubblegum.new_proof_cache(10)
+    assert is_reference(cache)
+
+    assert {:ok, %{hits: 0, misses: 0, size: 0, capacity: 10}} = SolanaBubblegum.proof_cache_stats(cache)
+
+    result =
+      SolanaBubblegum.transfer_with_das("invalid_keypair", "asset_id", "new_owner", proof_cache: cache)
+
+    assert match?({:error, _}, result)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} = SolanaBubblegum.new_proof_cache(0)
+  end
+
+  test "new_mint_counter returns error with invalid tree pubkey" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.new_mint_counter("invalid_tree", rpc_url: "http://127.0.0.1:1")
+  end
+
+  test "mint returns error with invalid leaf owner" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.mint(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", metadata(),
+               leaf_owner: "invalid_owner"
+             )
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.mint_to_collection(
+               payer,
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               metadata(),
+               leaf_owner: "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr",
+               leaf_delegate: "invalid_delegate"
+             )
+  end
+
+  test "load_metadata_file reports invalid rows with their line" do
+    path = Path.join(System.tmp_dir!(), "bubblegum_metadata_#{System.unique_integer([:positive])}.jsonl")
+
+    File.write!(path, """
+    {"name": "Drop #1", "symbol": "DROP", "uri": "https://example.com/1.json", "creators": [{"address": "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", "share": 100}]}
+
+    {"name": "Drop #2", "uri": "https://example.com/2.json", "creators": [{"address": "not-a-pubkey", "share": 100}]}
+    not json
+    """)
+
+    try do
+      assert {:ok, %{metadata: [metadata], lines: [1], errors: [bad_creator, bad_json]}} =
+               SolanaBubblegum.load_metadata_file(path)
+
+      assert metadata.name == "Drop #1"
+      assert metadata.is_mutable
+      assert %SolanaBubblegum.Types.MetadataRowError{line: 3} = bad_creator
+      assert %SolanaBubblegum.Types.MetadataRowError{line: 4} = bad_json
+    after
+      File.rm(path)
+    end
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.load_metadata_file(path)
+  end
+
+  test "validate_uris rejects an unsupported URI before sending" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    tree = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata, message: message}} =
+             SolanaBubblegum.mint(payer, tree, %{metadata() | uri: "ftp://example.com/1.json"},
+               validate_uris: true
+             )
+
+    assert message =~ "ftp://example.com/1.json"
+
+    assert {:ok, [%SolanaBubblegum.Types.UriCheck{uri: "ftp://example.com/1.json", error: error}]} =
+             SolanaBubblegum.check_metadata_uris(["ftp://example.com/1.json"], timeout_ms: 1_000)
+
+    assert error =~ "supported"
+  end
+
+  test "build_offchain_metadata round-trips through validate_offchain_metadata" do
+    alias SolanaBubblegum.Types.{OffchainAttribute, OffchainFile, OffchainMetadata, OffchainProperties}
+
+    metadata = %OffchainMetadata{
+      name: "Test NFT",
+      image: "https://arweave.net/image.png",
+      attributes: [
+        %OffchainAttribute{trait_type: "Background", value: "Blue"},
+        %OffchainAttribute{trait_type: "Level", value: 5, display_type: "number"}
+      ],
+      properties: %OffchainProperties{
+        files: [%OffchainFile{uri: "https://arweave.net/image.png", mime_type: "image/png"}],
+        category: "image"
+      }
+    }
+
+    assert {:ok, json} = SolanaBubblegum.build_offchain_metadata(metadata)
+    assert %{"name" => "Test NFT", "properties" => %{"files" => [%{"type" => "image/png"}]}} = Jason.decode!(json)
+    refute Map.has_key?(Jason.decode!(json), "description")
+    assert {:ok, ^metadata} = SolanaBubblegum.validate_offchain_metadata(json)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata, violations: violations}} =
+             SolanaBubblegum.build_offchain_metadata(%{
+               metadata
+               | image: "ftp://example.com/image.png",
+                 attributes: [%OffchainAttribute{trait_type: "Level", value: 1}, %OffchainAttribute{trait_type: "Level", value: 2}]
+             })
+
+    assert Keyword.has_key?(violations, :image)
+    assert Keyword.has_key?(violations, :attributes)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata}} =
+             SolanaBubblegum.validate_offchain_metadata(~s({"image": "https://arweave.net/image.png"}))
+  end
+
+  test "upload_metadata validates before contacting the storage node" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.upload_metadata("invalid", ~s({"name": "Test NFT"}))
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.upload_metadata(payer, ~s({"name": "Test NFT"}), simulate_only: true)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata}} =
+             SolanaBubblegum.upload_metadata(payer, %SolanaBubblegum.Types.OffchainMetadata{name: "", image: ""})
+  end
+
+  test "mint_complete checks both halves of the metadata before uploading" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    tree = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+    offchain = %SolanaBubblegum.Types.OffchainMetadata{name: "Test NFT", image: "https://arweave.net/image.png"}
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata}} =
+             SolanaBubblegum.mint_complete(payer, tree, %{offchain | image: ""})
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata, violations: violations}} =
+             SolanaBubblegum.mint_complete(payer, tree, offchain,
+               creators: [%Creator{address: tree, verified: false, share: 50}]
+             )
+
+    assert Keyword.has_key?(violations, :creators)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.mint_complete(payer, tree, offchain, simulate_only: true)
+  end
+
+  test "core asset operations check their arguments before sending" do
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+    asset = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr"
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_metadata}} =
+             SolanaBubblegum.create_core_asset(payer, "", "https://arweave.net/asset.json")
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.create_core_asset(payer, "My Asset", "https://arweave.net/asset.json",
+               collection: asset,
+               update_authority: asset
+             )
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.transfer_core_asset(payer, asset, "not-a-pubkey")
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.burn_core_asset("invalid_keypair", asset)
+  end
+
+  test "parse_transaction_events returns error with invalid signature" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_signature}} =
+             SolanaBubblegum.parse_transaction_events("invalid_signature")
+  end
+
+  test "await_finalized returns error with invalid signature" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_signature}} =
+             SolanaBubblegum.await_finalized("invalid_signature")
+  end
+
+  test "subscribe_tree returns error with invalid tree" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.subscribe_tree("invalid_tree", self())
+  end
+
+  test "get_tree_config returns error with invalid tree" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.get_tree_config("invalid_tree")
+  end
+
+  test "get_merkle_tree_state returns error with invalid tree" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_public_key}} =
+             SolanaBubblegum.get_merkle_tree_state("invalid_tree")
+  end
+
+  test "verify_proof returns error with invalid root" do
+    result = SolanaBubblegum.verify_proof("not-base58!", "11111111111111111111111111111111", [], 0)
+    assert match?({:error, _}, result)
+  end
+
+  test "send_raw_transaction returns error with a malformed transaction" do
+    result = SolanaBubblegum.send_raw_transaction(Base.encode64("not a transaction"))
+    assert match?({:error, _}, result)
+  end
+
+  test "mint returns error with invalid keypair when a compute budget is set" do
+    result =
+      SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata(),
+        compute_unit_limit: 200_000,
+        compute_unit_price_micro_lamports: 10_000
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "transfer returns error with invalid keypair when lookup tables are given" do
+    result =
+      SolanaBubblegum.transfer("invalid_keypair", "tree_pubkey", "owner", "new_owner", asset_proof(),
+        address_lookup_tables: ["HXtBm8XZbxaTt41uqaKhwUAa6Z1aPyvJdsZVENiWsetg"]
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "mint returns error with invalid keypair when a retry policy is given" do
+    result =
+      SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata(),
+        retry: %SolanaBubblegum.Types.RetryPolicy{max_attempts: 5, jitter: false}
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "get_priority_fee_estimate returns error with invalid account" do
+    result = SolanaBubblegum.get_priority_fee_estimate(["invalid_account"])
+    assert match?({:error, _}, result)
+  end
+
+  test "create_lookup_table returns error with invalid keypair" do
+    result = SolanaBubblegum.create_lookup_table("invalid_keypair")
+    assert match?({:error, _}, result)
+  end
+
+  test "extend_lookup_table returns error with invalid keypair" do
+    result = SolanaBubblegum.extend_lookup_table("invalid_keypair", "lookup_table", ["tree_pubkey"])
+    assert match?({:error, _}, result)
+  end
+
+  test "close_lookup_table returns error with invalid keypair" do
+    result = SolanaBubblegum.close_lookup_table("invalid_keypair", "lookup_table")
+    assert match?({:error, _}, result)
+  end
+
+  test "create_nonce_account returns error with invalid keypair" do
+    result = SolanaBubblegum.create_nonce_account("invalid_keypair")
+    assert match?({:error, _}, result)
+  end
+
+  test "simulate returns error with a malformed transaction" do
+    result = SolanaBubblegum.simulate(Base.encode64("not a transaction"))
+    assert match?({:error, _}, result)
+  end
+
+  test "mint returns error with invalid keypair in simulate_only mode" do
+    result = SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata(), simulate_only: true)
+    assert match?({:error, _}, result)
+  end
+
+  test "transfer returns error with invalid keypair when send options are set" do
+    result =
+      SolanaBubblegum.transfer("invalid_keypair", "tree_pubkey", "owner", "new_owner", asset_proof(),
+        skip_preflight: true,
+        preflight_commitment: :processed,
+        max_retries: 3,
+        min_context_slot: 1,
+        confirm: false
+      )
+
+    assert match?({:error, _}, result)
+  end
+
+  test "confirm_signature returns error with invalid signature" do
+    result = SolanaBubblegum.confirm_signature("not-a-signature", :confirmed, timeout: 0)
+    assert match?({:error, _}, result)
+  end
+
+  test "mint returns error with invalid keypair in async mode" do
+    result = SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata(), async: true)
+    assert match?({:ok, ref} when is_reference(ref), result)
+
+    {:ok, ref} = result
+    assert_receive {:bubblegum_result, ^ref, {:error, _}}, 5_000
+  end
+
+  test "mint with a timeout returns the result of the work when it finishes in time" do
+    result = SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata(), timeout_ms: 5_000)
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} = result
+  end
+
+  test "an async mint replies with a timeout error when the RPC endpoint hangs" do
+    # Accepts connections but never answers
+    {:ok, socket} = :gen_tcp.listen(0, [])
+    {:ok, port} = :inet.port(socket)
+
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:ok, ref} =
+             SolanaBubblegum.mint(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", metadata(),
+               rpc_url: "http://127.0.0.1:#{port}",
+               async: true,
+               timeout_ms: 100
+             )
+
+    assert_receive {:bubblegum_result, ^ref, {:error, %SolanaBubblegum.Types.Error{kind: :timeout}}}, 5_000
+    :gen_tcp.close(socket)
+  end
+
+  test "cancel settles an async operation with a cancelled error" do
+    {:ok, socket} = :gen_tcp.listen(0, [])
+    {:ok, port} = :inet.port(socket)
+
+    # Keypair with an all-zero secret key
+    payer = "111111111111111111111111111111114zvwRjXUKGfvwnParsHAS3HuSVzV5cA4McphgmoCtajS"
+
+    assert {:ok, ref} =
+             SolanaBubblegum.mint(payer, "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr", metadata(),
+               rpc_url: "http://127.0.0.1:#{port}",
+               async: true
+             )
+
+    assert :ok = SolanaBubblegum.cancel(ref)
+    assert_receive {:bubblegum_result, ^ref, {:error, %SolanaBubblegum.Types.Error{kind: :cancelled}}}
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} = SolanaBubblegum.cancel(ref)
+    :gen_tcp.close(socket)
+  end
+
+  test "a transfer that times out after sending its transaction returns an unknown outcome" do
+    {socket, rpc_url} = rpc_hanging_on_send(self())
+    {:ok, %{keypair: sender}} = SolanaBubblegum.generate_keypair()
+    {:ok, %{pubkey: recipient}} = SolanaBubblegum.generate_keypair()
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :timeout_unknown_outcome}} =
+             SolanaBubblegum.transfer_sol(sender, recipient, 1_000, rpc_url: rpc_url, timeout_ms: 1_000)
+
+    assert_received {:rpc_request, "sendTransaction"}
+    :gen_tcp.close(socket)
+  end
+
+  test "cancel after an async transfer sent its transaction replies with an unknown outcome" do
+    {socket, rpc_url} = rpc_hanging_on_send(self())
+    {:ok, %{keypair: sender}} = SolanaBubblegum.generate_keypair()
+    {:ok, %{pubkey: recipient}} = SolanaBubblegum.generate_keypair()
+
+    assert {:ok, ref} = SolanaBubblegum.transfer_sol(sender, recipient, 1_000, rpc_url: rpc_url, async: true)
+    assert_receive {:rpc_request, "sendTransaction"}, 5_000
+
+    assert :ok = SolanaBubblegum.cancel(ref)
+
+    assert_receive {:bubblegum_result, ^ref,
+                    {:error, %SolanaBubblegum.Types.Error{kind: :cancelled_unknown_outcome}}}
+
+    :gen_tcp.close(socket)
+  end
+
+  test "connect returns a connection accepted in place of the RPC URL" do
+    assert {:ok, conn} = SolanaBubblegum.connect("http://localhost:8899")
+    assert is_reference(conn)
+
+    result = SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata(), rpc_url: conn)
+    assert match?({:error, _}, result)
+  end
+
+  test "connect with a blockhash cache returns a connection" do
+    assert {:ok, conn} = SolanaBubblegum.connect("http://127.0.0.1:1", blockhash_refresh_slots: 20)
+    assert is_reference(conn)
+
+    result = SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata(), rpc_url: conn)
+    assert match?({:error, _}, result)
+  end
+
+  test "connect with a rate limit returns a connection" do
+    assert {:ok, conn} =
+             SolanaBubblegum.connect("http://localhost:8899", rate_limit: [requests_per_second: 5, burst: 10])
+
+    assert is_reference(conn)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.connect("http://localhost:8899", rate_limit: [requests_per_second: 0])
+  end
+
+  test "connect with HTTP options returns a connection" do
+    assert {:ok, conn} =
+             SolanaBubblegum.connect("http://localhost:8899",
+               headers: %{"x-api-key" => "secret"},
+               proxy: "http://localhost:3128",
+               timeout: 5_000
+             )
+
+    assert is_reference(conn)
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} =
+             SolanaBubblegum.connect("http://localhost:8899", headers: %{"bad header" => "value"})
+  end
+
+  test "connect_pool accepts bare URLs and weighted endpoints" do
+    assert {:ok, pool} =
+             SolanaBubblegum.connect_pool([{"http://localhost:8899", 2}, "http://localhost:8900"])
+
+    assert is_reference(pool)
+
+    result = SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata(), rpc_url: pool)
+    assert match?({:error, _}, result)
+  end
+
+  test "connect_pool rejects an empty endpoint list" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_argument}} = SolanaBubblegum.connect_pool([])
+  end
+
+  test "errors are structs with a kind and a message" do
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair} = error} =
+             SolanaBubblegum.mint("invalid_keypair", "tree_pubkey", metadata())
+
+    assert to_string(error) =~ "Invalid keypair"
+    assert error.program_error_code == nil
+    assert error.logs == []
+  end
+
+  test "native functions take request structs with named fields" do
+    request = %SolanaBubblegum.Types.TransferRequest{
+      payer_keypair: "invalid_keypair",
+      merkle_tree: "tree_pubkey",
+      leaf_owner: "leaf_owner",
+      new_owner: "new_owner",
+      asset_proof: asset_proof()
+    }
+
+    assert request.leaf_delegate == nil
+
+    assert {:error, %SolanaBubblegum.Types.Error{kind: :invalid_keypair}} =
+             SolanaBubblegum.Bubblegum.transfer(request, %SolanaBubblegum.Types.TransactionOptions{
+               rpc_url: "http://127.0.0.1:1"
+             })
+  end
 end